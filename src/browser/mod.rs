@@ -1,13 +1,221 @@
-use anyhow::{Context, Result};
+use anyhow::{bail, Context, Result};
+use std::process::{Command, Stdio};
 
-/// Open a URL in the user's default browser
+/// Open a URL in the user's browser
 ///
 /// # Arguments
 /// * `url` - The URL to open (e.g., GitHub PR URL)
+/// * `browser` - Optional command template from config (e.g., `firefox --new-tab {url}`).
+///   When `None`, the system default browser is used.
 ///
 /// # Errors
 /// Returns error if browser cannot be opened (e.g., no browser available)
-pub fn open_url(url: &str) -> Result<()> {
-    webbrowser::open(url).with_context(|| format!("Failed to open browser for URL: {}", url))?;
+pub fn open_url(url: &str, browser: Option<&str>) -> Result<()> {
+    match browser {
+        Some(template) => open_with_command(url, template),
+        None => {
+            webbrowser::open(url)
+                .with_context(|| format!("Failed to open browser for URL: {}", url))?;
+            Ok(())
+        }
+    }
+}
+
+/// A tab of a PR's GitHub page, opened instead of the Conversation tab
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PrTab {
+    Files,
+    Commits,
+    Checks,
+}
+
+impl PrTab {
+    fn path(self) -> &'static str {
+        match self {
+            PrTab::Files => "files",
+            PrTab::Commits => "commits",
+            PrTab::Checks => "checks",
+        }
+    }
+}
+
+/// URL of a tab on a PR page. Issues have no tabs, so any other URL is
+/// returned unchanged.
+pub fn pr_tab_url(url: &str, tab: Option<PrTab>) -> String {
+    match tab {
+        Some(tab) if url.contains("/pull/") => {
+            format!("{}/{}", url.trim_end_matches('/'), tab.path())
+        }
+        _ => url.to_string(),
+    }
+}
+
+/// A page linked from a PR, opened instead of the PR itself
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PrPage {
+    /// The repository's home page
+    Repo,
+    /// The author's profile
+    Author,
+}
+
+/// URL of the repo (`owner/repo`) or author page for a PR, on the same host
+/// as `pr_url` so GitHub Enterprise links stay on the instance
+pub fn pr_page_url(pr_url: &str, page: PrPage, repo: &str, author: &str) -> String {
+    // "https://host/owner/repo/pull/1" -> "https://host"
+    let root_len = pr_url
+        .match_indices('/')
+        .nth(2)
+        .map_or(pr_url.len(), |(i, _)| i);
+    let path = match page {
+        PrPage::Repo => repo,
+        PrPage::Author => author,
+    };
+    format!("{}/{}", &pr_url[..root_len], path)
+}
+
+/// Spawn a browser command built from a template, without waiting for it to
+/// exit. A background thread reaps it so it doesn't linger as a zombie.
+fn open_with_command(url: &str, template: &str) -> Result<()> {
+    let args = build_command(template, url)?;
+    let mut child = Command::new(&args[0])
+        .args(&args[1..])
+        .stdin(Stdio::null())
+        .stdout(Stdio::null())
+        .stderr(Stdio::null())
+        .spawn()
+        .with_context(|| format!("Failed to run browser command: {}", template))?;
+    std::thread::spawn(move || child.wait());
     Ok(())
 }
+
+/// Split a command template into arguments and substitute `{url}`.
+///
+/// Arguments are split on whitespace; single or double quotes group words
+/// (e.g., `open -a 'Google Chrome' {url}`). If the template has no `{url}`
+/// placeholder, the URL is appended as the last argument.
+pub fn build_command(template: &str, url: &str) -> Result<Vec<String>> {
+    let mut args = split_args(template)?;
+    if args.is_empty() {
+        bail!("Browser command is empty");
+    }
+
+    if args.iter().any(|a| a.contains("{url}")) {
+        for arg in &mut args {
+            *arg = arg.replace("{url}", url);
+        }
+    } else {
+        args.push(url.to_string());
+    }
+    Ok(args)
+}
+
+fn split_args(s: &str) -> Result<Vec<String>> {
+    let mut args = Vec::new();
+    let mut current = String::new();
+    let mut in_word = false;
+    let mut quote: Option<char> = None;
+
+    for c in s.chars() {
+        match quote {
+            Some(q) if c == q => quote = None,
+            Some(_) => current.push(c),
+            None if c == '\'' || c == '"' => {
+                quote = Some(c);
+                in_word = true;
+            }
+            None if c.is_whitespace() => {
+                if in_word {
+                    args.push(std::mem::take(&mut current));
+                    in_word = false;
+                }
+            }
+            None => {
+                current.push(c);
+                in_word = true;
+            }
+        }
+    }
+
+    if quote.is_some() {
+        bail!("Unclosed quote in browser command: {}", s);
+    }
+    if in_word {
+        args.push(current);
+    }
+    Ok(args)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const URL: &str = "https://github.com/acme/widgets/pull/1";
+
+    #[test]
+    fn test_pr_tab_url() {
+        assert_eq!(pr_tab_url(URL, None), URL);
+        assert_eq!(
+            pr_tab_url(URL, Some(PrTab::Files)),
+            format!("{}/files", URL)
+        );
+        assert_eq!(
+            pr_tab_url(&format!("{}/", URL), Some(PrTab::Checks)),
+            format!("{}/checks", URL)
+        );
+        let issue = "https://github.com/acme/widgets/issues/2";
+        assert_eq!(pr_tab_url(issue, Some(PrTab::Commits)), issue);
+    }
+
+    #[test]
+    fn test_pr_page_url() {
+        assert_eq!(
+            pr_page_url(URL, PrPage::Repo, "acme/widgets", "alice"),
+            "https://github.com/acme/widgets"
+        );
+        assert_eq!(
+            pr_page_url(URL, PrPage::Author, "acme/widgets", "alice"),
+            "https://github.com/alice"
+        );
+        assert_eq!(
+            pr_page_url(
+                "https://git.example.com/acme/widgets/pull/1",
+                PrPage::Author,
+                "acme/widgets",
+                "alice"
+            ),
+            "https://git.example.com/alice"
+        );
+    }
+
+    #[test]
+    fn test_build_command_substitutes_url() {
+        let args = build_command("firefox --new-tab {url}", URL).unwrap();
+        assert_eq!(args, vec!["firefox", "--new-tab", URL]);
+    }
+
+    #[test]
+    fn test_build_command_quoted_argument() {
+        let args = build_command("open -a 'Google Chrome' {url}", URL).unwrap();
+        assert_eq!(args, vec!["open", "-a", "Google Chrome", URL]);
+    }
+
+    #[test]
+    fn test_build_command_appends_url_without_placeholder() {
+        let args = build_command("chromium --profile-directory=Work", URL).unwrap();
+        assert_eq!(args, vec!["chromium", "--profile-directory=Work", URL]);
+    }
+
+    #[test]
+    fn test_build_command_rejects_empty_and_unclosed() {
+        assert!(build_command("   ", URL).is_err());
+        assert!(build_command("open -a 'Google Chrome {url}", URL).is_err());
+    }
+
+    #[test]
+    #[cfg(unix)]
+    fn test_open_with_command_spawns_or_reports_missing_program() {
+        assert!(open_with_command(URL, "true").is_ok());
+        assert!(open_with_command(URL, "pr-bro-no-such-browser").is_err());
+    }
+}
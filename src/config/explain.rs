@@ -0,0 +1,82 @@
+use anyhow::Result;
+
+use super::Config;
+use crate::scoring::merge_scoring_configs;
+
+/// Render the effective scoring config for each query as YAML: global scoring
+/// (or the built-in defaults) merged with the query's overrides, exactly as
+/// `fetch_and_score_prs` applies it. Unset factors show as `null`.
+pub fn explain_config(config: &Config) -> Result<String> {
+    let global = config.scoring.clone().unwrap_or_default();
+
+    let mut out = String::new();
+    for (i, query) in config.queries.iter().enumerate() {
+        let merged = merge_scoring_configs(&global, query.scoring.as_ref());
+        let yaml = serde_saphyr::to_string(&merged)
+            .map_err(|e| anyhow::anyhow!("Failed to serialize scoring config: {}", e))?;
+
+        if i > 0 {
+            out.push('\n');
+        }
+        out.push_str(&format!(
+            "# Query {}: {}\n# {}\n",
+            i + 1,
+            query.name.as_deref().unwrap_or("(unnamed)"),
+            query.query
+        ));
+        if query.scoring.is_none() {
+            out.push_str("# (no overrides, global scoring applies)\n");
+        }
+        if let Some(weight) = query.weight {
+            out.push_str(&format!("# Final scores x{} (weight)\n", weight));
+        }
+        out.push_str(yaml.trim_end());
+        out.push('\n');
+    }
+    Ok(out)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_explain_config_shows_merged_scoring() {
+        let yaml = r#"
+scoring:
+  base_score: 50
+  age: "+1 per 1h"
+queries:
+  - name: urgent
+    query: "is:pr label:urgent"
+    scoring:
+      age: "+10 per 1h"
+  - query: "is:pr is:open"
+"#;
+        let config: Config = serde_saphyr::from_str(yaml).unwrap();
+        let out = explain_config(&config).unwrap();
+
+        let (first, second) = out.split_once("# Query 2").unwrap();
+        assert!(first.starts_with("# Query 1: urgent\n# is:pr label:urgent\n"));
+        // Query override wins, global base_score is inherited
+        assert!(first.contains("+10 per 1h"));
+        assert!(first.contains("base_score: 50"));
+        assert!(second.contains("(no overrides, global scoring applies)"));
+        assert!(second.contains("+1 per 1h"));
+    }
+
+    #[test]
+    fn test_explain_config_omits_empty_label_effect() {
+        let yaml = r#"
+scoring:
+  labels:
+    - { name: hotfix, set_base: 1000 }
+queries:
+  - query: "is:pr is:open"
+"#;
+        let config: Config = serde_saphyr::from_str(yaml).unwrap();
+        let out = explain_config(&config).unwrap();
+        assert!(out.contains("set_base: 1000"));
+        assert!(!out.contains("effect:"));
+    }
+}
@@ -1,9 +1,15 @@
 use anyhow::{Context, Result};
+use std::collections::HashMap;
 use std::io::{BufRead, Write};
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 
-use crate::config::{get_config_path, Config, QueryConfig};
-use crate::scoring::{Effect, LabelEffect, RangeOp, ScoringConfig, SizeBucket, SizeConfig};
+use crate::config::{
+    get_config_path, Config, DedupStrategy, IndexStyle, QueryConfig, RateLimitGuard,
+    ScoreBarConfig, SnoozedSort, TableColumn, Tiebreak,
+};
+use crate::scoring::{
+    ApprovalsConfig, Effect, LabelEffect, RangeOp, ScoringConfig, SizeBucket, SizeConfig,
+};
 
 /// Prompt user with a message and return their trimmed input.
 fn prompt(message: &str) -> Result<String> {
@@ -53,20 +59,10 @@ fn typewriter(text: &str) {
     println!();
 }
 
-/// Validate an approvals effect string using the same "per N" trick as validation.rs.
+/// Validate an approvals effect string.
 /// Approvals use "per N" to mean "per N approvals", not per time unit.
-/// We convert bare numeric per-parts to "per 1sec" for parsing validation.
 fn validate_approvals_str(s: &str) -> Result<(), String> {
-    let parseable_str = if let Some((effect_part, per_part)) = s.split_once(" per ") {
-        if per_part.trim().chars().all(|c| c.is_numeric() || c == '.') {
-            format!("{} per 1sec", effect_part)
-        } else {
-            s.to_string()
-        }
-    } else {
-        s.to_string()
-    };
-    Effect::parse(&parseable_str)
+    Effect::parse_per_count(s)
         .map(|_| ())
         .map_err(|e| e.to_string())
 }
@@ -91,6 +87,7 @@ pub fn run_init_wizard(default_path: Option<PathBuf>) -> Result<()> {
 
         // Base score
         typewriter("The base score is the starting point for every PR. All other factors add to or multiply this number.");
+        typewriter("Factor effects use four operators: '+N' adds, '-N' subtracts, 'xN' multiplies and '/N' divides.");
         let base_score: f64 = loop {
             let base_str = prompt_with_default("Base score", "100")?;
             match base_str.parse::<f64>() {
@@ -105,6 +102,7 @@ pub fn run_init_wizard(default_path: Option<PathBuf>) -> Result<()> {
         typewriter("The age factor rewards older PRs so they don't get forgotten.");
         typewriter("Format: '+N per DURATION' adds points over time (e.g., '+1 per 1h' adds 1 point per hour).");
         typewriter("Format: 'xN per DURATION' compounds over time (e.g., 'x1.05 per 1d' multiplies score by 1.05 each day).");
+        typewriter("Use '-N per DURATION' or '/N per DURATION' to decay the score instead (e.g., '/2 per 1w' halves it each week).");
         let age = loop {
             let input = prompt_with_default("Age factor", "+1 per 1h")?;
             match Effect::parse(&input) {
@@ -121,6 +119,8 @@ pub fn run_init_wizard(default_path: Option<PathBuf>) -> Result<()> {
         typewriter("Available formats:");
         typewriter("  +N per 1  -- adds N points per approval (e.g., '+10 per 1')");
         typewriter("  xN per 1  -- multiplies score by N per approval (e.g., 'x0.8 per 1' to deprioritize approved PRs)");
+        typewriter("  -N per 1  -- subtracts N points per approval (e.g., '-10 per 1')");
+        typewriter("  /N per 1  -- divides score by N per approval (e.g., '/2 per 1')");
         typewriter("  +N        -- flat add regardless of count (e.g., '+20')");
         typewriter("  xN        -- flat multiply regardless of count (e.g., 'x2')");
         let approvals = loop {
@@ -164,7 +164,7 @@ pub fn run_init_wizard(default_path: Option<PathBuf>) -> Result<()> {
                     }
                 };
                 let effect = loop {
-                    let e = prompt("  Score effect (e.g., 'x5', 'x1', 'x0.5'): ")?;
+                    let e = prompt("  Score effect (e.g., 'x5', 'x1', '/2'): ")?;
                     if e.is_empty() {
                         println!("  Effect is required.");
                         continue;
@@ -185,6 +185,8 @@ pub fn run_init_wizard(default_path: Option<PathBuf>) -> Result<()> {
             } else {
                 Some(SizeConfig {
                     exclude: None,
+                    metric: None,
+                    on_exclude_failure: None,
                     buckets: Some(buckets),
                 })
             }
@@ -242,7 +244,7 @@ pub fn run_init_wizard(default_path: Option<PathBuf>) -> Result<()> {
                 println!("  Label name is required.");
             };
             let effect = loop {
-                let e = prompt("  Score effect (e.g., '+50', 'x0.5', 'x2'): ")?;
+                let e = prompt("  Score effect (e.g., '+50', '-20', 'x2', '/2'): ")?;
                 if e.is_empty() {
                     println!("  Effect is required.");
                     continue;
@@ -252,7 +254,11 @@ pub fn run_init_wizard(default_path: Option<PathBuf>) -> Result<()> {
                     Err(err) => println!("  Invalid effect: {}. Try again.", err),
                 }
             };
-            label_effects.push(LabelEffect { name, effect });
+            label_effects.push(LabelEffect {
+                name,
+                effect,
+                set_base: None,
+            });
             add_label = prompt_yes_no("  Add another label rule?", false)?;
         }
         let labels = if label_effects.is_empty() {
@@ -264,11 +270,25 @@ pub fn run_init_wizard(default_path: Option<PathBuf>) -> Result<()> {
         ScoringConfig {
             base_score: Some(base_score),
             age: Some(age),
-            approvals: Some(approvals),
+            age_basis: None,
+            approvals: Some(ApprovalsConfig::Effect(approvals)),
             size,
             labels,
             previously_reviewed,
             draft,
+            approved_by_trusted: None,
+            updated_since_my_review: None,
+            re_review_requested: None,
+            changes_requested: None,
+            reactions: None,
+            comments: None,
+            team_review_requested: None,
+            ci_status: None,
+            commits_behind: None,
+            authored: None,
+            authored_base_score: None,
+            sla: None,
+            disabled_factors: None,
         }
     } else {
         ScoringConfig::default()
@@ -310,6 +330,11 @@ pub fn run_init_wizard(default_path: Option<PathBuf>) -> Result<()> {
             name: Some(name),
             query,
             scoring: None,
+            max_age: None,
+            include_issues: false,
+            enrich: true,
+            group: None,
+            weight: None,
         });
 
         let add_another = prompt_yes_no("Add another query?", false)?;
@@ -349,11 +374,56 @@ pub fn run_init_wizard(default_path: Option<PathBuf>) -> Result<()> {
         queries,
         auto_refresh_interval: 300,
         theme: "auto".to_string(),
+        colorblind: false,
+        tiebreak: Tiebreak::default(),
+        dedup: DedupStrategy::default(),
+        browser: None,
+        trusted_reviewers: Vec::new(),
+        teams: Vec::new(),
+        bot_reviewers: Vec::new(),
+        count_bot_approvals: false,
+        score_bar: ScoreBarConfig::default(),
+        open_on_select: false,
+        notify_threshold: None,
+        notify: false,
+        max_age: None,
+        wrap_titles: false,
+        hide_withdrawn_requests: false,
+        exclude_own: false,
+        columns: TableColumn::default_layout(),
+        show_author: false,
+        snoozed_sort: SnoozedSort::default(),
+        confirm_quit: false,
+        cache_compression: false,
+        use_graphql: false,
+        index_style: IndexStyle::default(),
+        timezone: None,
+        rate_limit_guard: RateLimitGuard::default(),
+        query_concurrency: None,
+        label_colors: HashMap::new(),
+        max_title_width: None,
+        snooze_reminder_window: None,
+        oldest_alert: None,
+        focus_duration: None,
+        exclude_archived: false,
     };
 
     let yaml = serde_saphyr::to_string(&config)
         .map_err(|e| anyhow::anyhow!("Failed to serialize config: {}", e))?;
 
+    // Keep the config being replaced, hand edits and all
+    if config_path.exists() {
+        let backup = backup_path(&config_path, chrono::Local::now());
+        match std::fs::copy(&config_path, &backup) {
+            Ok(_) => println!("Backed up the old config to {}", backup.display()),
+            Err(e) => eprintln!(
+                "Could not back up the old config to {}: {}. Overwriting anyway.",
+                backup.display(),
+                e
+            ),
+        }
+    }
+
     // Create parent directories
     if let Some(parent) = config_path.parent() {
         std::fs::create_dir_all(parent)
@@ -370,3 +440,29 @@ pub fn run_init_wizard(default_path: Option<PathBuf>) -> Result<()> {
 
     Ok(())
 }
+
+/// Where to back up a config before overwriting it: alongside, with a
+/// timestamp so an earlier backup is never replaced
+/// (`config.yaml` -> `config.yaml.20250101-093000.bak`)
+fn backup_path(path: &Path, now: chrono::DateTime<chrono::Local>) -> PathBuf {
+    let mut name = path.file_name().unwrap_or_default().to_os_string();
+    name.push(format!(".{}.bak", now.format("%Y%m%d-%H%M%S")));
+    path.with_file_name(name)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::TimeZone;
+
+    #[test]
+    fn test_backup_path_is_timestamped_next_to_config() {
+        let now = chrono::Local
+            .with_ymd_and_hms(2025, 1, 2, 9, 30, 5)
+            .unwrap();
+        assert_eq!(
+            backup_path(Path::new("/home/me/.config/pr-bro/config.yaml"), now),
+            PathBuf::from("/home/me/.config/pr-bro/config.yaml.20250102-093005.bak")
+        );
+    }
+}
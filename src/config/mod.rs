@@ -1,12 +1,24 @@
+mod explain;
 mod init;
 mod schema;
 
+pub use explain::explain_config;
 pub use init::run_init_wizard;
-pub use schema::{Config, QueryConfig};
+pub use schema::{
+    BarStyle, Config, DedupStrategy, DisplayTimezone, IndexStyle, QueryConfig, RateLimitGuard,
+    ScoreBarConfig, SnoozedSort, TableColumn, Tiebreak,
+};
 
 use anyhow::{Context, Result};
 use std::fs;
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
+
+/// File name of a repo-committed config shared by a team
+pub const LOCAL_CONFIG_FILE: &str = ".pr-bro.yaml";
+
+/// The only settings a repo-local config may set. Anything else is personal
+/// (or, like `browser`, runs commands) and must not come from a checkout.
+pub const LOCAL_CONFIG_KEYS: &[&str] = &["queries", "scoring", "teams"];
 
 /// Get the config directory path (~/.config/pr-bro/)
 pub fn get_config_dir() -> PathBuf {
@@ -33,11 +45,21 @@ pub fn ensure_config_dir() -> Result<()> {
     Ok(())
 }
 
+/// Find the nearest `.pr-bro.yaml` in `start` or one of its ancestors
+pub fn find_local_config(start: &Path) -> Option<PathBuf> {
+    start
+        .ancestors()
+        .map(|dir| dir.join(LOCAL_CONFIG_FILE))
+        .find(|path| path.is_file())
+}
+
 /// Load configuration from a YAML file
 ///
 /// # Arguments
 ///
 /// * `path` - Optional path to config file. If None, uses default path (~/.config/pr-bro/config.yaml)
+/// * `local_path` - Optional repo-local config (see [`find_local_config`]) merged
+///   under the user's config: the user's settings win and its queries come first
 ///
 /// # Errors
 ///
@@ -45,7 +67,7 @@ pub fn ensure_config_dir() -> Result<()> {
 /// - The config file does not exist
 /// - The config file cannot be read
 /// - The YAML cannot be parsed
-pub fn load_config(path: Option<PathBuf>) -> Result<Config> {
+pub fn load_config(path: Option<PathBuf>, local_path: Option<&Path>) -> Result<Config> {
     let config_path = path.unwrap_or_else(get_config_path);
 
     if !config_path.exists() {
@@ -58,8 +80,183 @@ pub fn load_config(path: Option<PathBuf>) -> Result<Config> {
     let config_content = fs::read_to_string(&config_path)
         .with_context(|| format!("Failed to read config file at {}", config_path.display()))?;
 
-    let config: Config = serde_saphyr::from_str(&config_content)
+    let Some(local_path) = local_path else {
+        let config: Config = serde_saphyr::from_str(&config_content).map_err(|e| {
+            anyhow::anyhow!("Failed to parse config {}: {}", config_path.display(), e)
+        })?;
+        return Ok(config);
+    };
+
+    let local_content = fs::read_to_string(local_path)
+        .with_context(|| format!("Failed to read local config at {}", local_path.display()))?;
+
+    // Merge as untyped values so a field only counts as set if the file sets it
+    let user: serde_json::Value = serde_saphyr::from_str(&config_content)
         .map_err(|e| anyhow::anyhow!("Failed to parse config {}: {}", config_path.display(), e))?;
+    let local: serde_json::Value = serde_saphyr::from_str(&local_content).map_err(|e| {
+        anyhow::anyhow!(
+            "Failed to parse local config {}: {}",
+            local_path.display(),
+            e
+        )
+    })?;
+
+    let local = shared_local_settings(local, local_path);
+    serde_json::from_value(merge_config_values(local, user)).map_err(|e| {
+        anyhow::anyhow!(
+            "Failed to parse config {} merged with {}: {}",
+            config_path.display(),
+            local_path.display(),
+            e
+        )
+    })
+}
+
+/// Keep only the `LOCAL_CONFIG_KEYS` of a repo-local config, warning about
+/// the rest
+fn shared_local_settings(local: serde_json::Value, local_path: &Path) -> serde_json::Value {
+    let serde_json::Value::Object(mut local) = local else {
+        return local;
+    };
+    local.retain(|key, _| {
+        let shared = LOCAL_CONFIG_KEYS.contains(&key.as_str());
+        if !shared {
+            log::warn!(
+                "Ignoring '{}' in {}: a repo-local config may only set {}",
+                key,
+                local_path.display(),
+                LOCAL_CONFIG_KEYS.join(", ")
+            );
+        }
+        shared
+    });
+    serde_json::Value::Object(local)
+}
+
+/// Overlay the user's config on a repo-local one. Mappings merge key by key
+/// with the user's value winning; top-level `queries` are concatenated with
+/// the user's first, so they keep priority under first-match dedup.
+fn merge_config_values(local: serde_json::Value, user: serde_json::Value) -> serde_json::Value {
+    use serde_json::Value;
+
+    let Value::Object(mut local) = local else {
+        return user;
+    };
+    let local_queries = local.remove("queries");
+    let mut merged = merge_values(Value::Object(local), user);
+
+    if let (Some(Value::Array(local_queries)), Value::Object(merged)) = (local_queries, &mut merged)
+    {
+        match merged.get_mut("queries") {
+            Some(Value::Array(queries)) => queries.extend(local_queries),
+            None | Some(Value::Null) => {
+                merged.insert("queries".to_string(), Value::Array(local_queries));
+            }
+            // Leave anything else for deserialization to reject
+            Some(_) => {}
+        }
+    }
+    merged
+}
+
+/// Recursive mapping merge below the top level; non-mapping values are replaced
+fn merge_values(base: serde_json::Value, overlay: serde_json::Value) -> serde_json::Value {
+    use serde_json::Value;
+
+    match (base, overlay) {
+        (Value::Object(mut base), Value::Object(overlay)) => {
+            for (key, value) in overlay {
+                let merged = match base.remove(&key) {
+                    Some(existing) if !value.is_null() => merge_values(existing, value),
+                    Some(existing) => existing,
+                    None => value,
+                };
+                base.insert(key, merged);
+            }
+            Value::Object(base)
+        }
+        (_, overlay) => overlay,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn write(dir: &Path, name: &str, content: &str) -> PathBuf {
+        let path = dir.join(name);
+        fs::write(&path, content).unwrap();
+        path
+    }
+
+    fn temp_dir(name: &str) -> PathBuf {
+        let dir = std::env::temp_dir().join(format!(
+            "pr-bro-test-config-{}-{}",
+            name,
+            std::process::id()
+        ));
+        let _ = fs::remove_dir_all(&dir);
+        fs::create_dir_all(&dir).unwrap();
+        dir
+    }
+
+    #[test]
+    fn test_find_local_config_walks_up() {
+        let root = temp_dir("find");
+        let nested = root.join("crates").join("core");
+        fs::create_dir_all(&nested).unwrap();
+        let local = write(&root, LOCAL_CONFIG_FILE, "queries: []");
+
+        assert_eq!(find_local_config(&nested), Some(local));
+        fs::remove_dir_all(&root).unwrap();
+    }
 
-    Ok(config)
+    #[test]
+    fn test_local_config_merges_under_user_config() {
+        let dir = temp_dir("merge");
+        let user = write(
+            &dir,
+            "config.yaml",
+            "theme: light\nscoring:\n  age: \"+1 per 1h\"\nqueries:\n  - name: mine\n    query: \"is:pr author:@me\"\n",
+        );
+        let local = write(
+            &dir,
+            LOCAL_CONFIG_FILE,
+            "teams: [acme/core]\nscoring:\n  base_score: 50\n  age: \"+5 per 1h\"\nqueries:\n  - name: team\n    query: \"is:pr repo:acme/api\"\n",
+        );
+
+        let config = load_config(Some(user), Some(&local)).unwrap();
+        // The user's values win, the team's fill the gaps
+        assert_eq!(config.theme, "light");
+        assert_eq!(config.teams, vec!["acme/core"]);
+        let scoring = config.scoring.unwrap();
+        assert_eq!(scoring.base_score, Some(50.0));
+        assert_eq!(scoring.age.as_deref(), Some("+1 per 1h"));
+        // Both sets of queries apply, the user's first
+        let names: Vec<_> = config
+            .queries
+            .iter()
+            .map(|q| q.name.as_deref().unwrap())
+            .collect();
+        assert_eq!(names, vec!["mine", "team"]);
+        fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn test_local_config_only_sets_shared_settings() {
+        let dir = temp_dir("shared");
+        let user = write(&dir, "config.yaml", "queries: []\n");
+        let local = write(
+            &dir,
+            LOCAL_CONFIG_FILE,
+            "browser: \"sh -c 'touch /tmp/pwned' {url}\"\nopen_on_select: true\nexclude_own: true\nqueries:\n  - query: \"is:pr repo:acme/api\"\n",
+        );
+
+        let config = load_config(Some(user), Some(&local)).unwrap();
+        assert_eq!(config.browser, None);
+        assert!(!config.open_on_select);
+        assert!(!config.exclude_own);
+        assert_eq!(config.queries.len(), 1);
+        fs::remove_dir_all(&dir).unwrap();
+    }
 }
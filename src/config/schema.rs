@@ -1,4 +1,7 @@
+use chrono::{DateTime, FixedOffset, Local, Utc};
+use ratatui::style::Color;
 use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
 
 use crate::scoring::ScoringConfig;
 
@@ -6,10 +9,225 @@ fn default_refresh_interval() -> u64 {
     300
 }
 
+fn default_enrich() -> bool {
+    true
+}
+
 fn default_theme() -> String {
     "auto".to_string()
 }
 
+/// How to order PRs with identical scores
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Deserialize, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Tiebreak {
+    /// Oldest PR first (smallest created_at)
+    #[default]
+    Age,
+    /// Most recently updated PR first (largest updated_at)
+    Updated,
+}
+
+/// How list positions are labeled in the index column, and accepted by
+/// `open`, `snooze`, and `unsnooze`
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Deserialize, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum IndexStyle {
+    /// 1-based numbers
+    #[default]
+    Number,
+    /// a..z, then aa, ab, ...
+    Letter,
+    /// Short IDs hashed from the PR URL, stable across refreshes
+    Id,
+}
+
+/// What to do when enriching a query's results would likely run past the
+/// remaining GitHub rate limit
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Deserialize, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum RateLimitGuard {
+    /// Log a warning and enrich everything anyway
+    #[default]
+    Warn,
+    /// Enrich only as many PRs as the remaining limit allows
+    Degrade,
+    /// Skip the check
+    Off,
+}
+
+/// Zone for absolute timestamps in detail output, from the `timezone` setting
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum DisplayTimezone {
+    /// The system's local zone (unset or "local")
+    #[default]
+    Local,
+    Utc,
+    /// A fixed offset such as "+05:30"
+    Fixed(FixedOffset),
+}
+
+impl DisplayTimezone {
+    /// Parse "local", "utc", or an offset like "+05:30" / "-0800"
+    pub fn parse(s: &str) -> Result<Self, String> {
+        match s.trim().to_lowercase().as_str() {
+            "local" => Ok(DisplayTimezone::Local),
+            "utc" | "z" => Ok(DisplayTimezone::Utc),
+            other => other
+                .parse::<FixedOffset>()
+                .map(DisplayTimezone::Fixed)
+                .map_err(|_| {
+                    "expected \"local\", \"utc\", or an offset like \"+05:30\"".to_string()
+                }),
+        }
+    }
+
+    /// Format a timestamp in this zone to the minute, e.g. "2026-03-04 14:05 +01:00"
+    pub fn format(&self, time: DateTime<Utc>) -> String {
+        const FORMAT: &str = "%Y-%m-%d %H:%M %:z";
+        match self {
+            DisplayTimezone::Local => time.with_timezone(&Local).format(FORMAT).to_string(),
+            DisplayTimezone::Utc => time.format("%Y-%m-%d %H:%M UTC").to_string(),
+            DisplayTimezone::Fixed(offset) => time.with_timezone(offset).format(FORMAT).to_string(),
+        }
+    }
+}
+
+/// Order of the TUI's Snoozed view
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Deserialize, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum SnoozedSort {
+    /// Highest score first, like the Active view
+    #[default]
+    Score,
+    /// Soonest to expire first; indefinite snoozes last
+    Remaining,
+}
+
+/// Which query's scoring applies when a PR matches several queries
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Deserialize, Serialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum DedupStrategy {
+    /// The first matching query in config order
+    #[default]
+    First,
+    /// Whichever matching query scores the PR highest
+    HighestScore,
+}
+
+/// Glyph set for TUI score bars
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Deserialize, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum BarStyle {
+    /// Unicode block glyphs (`█`/`░`)
+    #[default]
+    Block,
+    /// Plain ASCII (`#`/`-`) for terminals with poor Unicode support
+    Ascii,
+}
+
+impl BarStyle {
+    /// Glyphs for the (filled, empty) parts of the bar
+    pub fn glyphs(self) -> (&'static str, &'static str) {
+        match self {
+            BarStyle::Block => ("█", "░"),
+            BarStyle::Ascii => ("#", "-"),
+        }
+    }
+}
+
+/// A column in `list` table output
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum TableColumn {
+    /// 1-based row number, usable with `open`/`snooze`
+    Index,
+    Score,
+    /// Time since the PR was opened
+    Age,
+    Repo,
+    Author,
+    /// Expands to fill the remaining terminal width
+    Title,
+    /// Short reference, e.g. `owner/repo#123`
+    Ref,
+    Url,
+    /// Score per changed line (empty PRs count as one line)
+    Efficiency,
+}
+
+impl TableColumn {
+    /// Today's fixed layout: index, score, title, ref
+    pub fn default_layout() -> Vec<TableColumn> {
+        vec![
+            TableColumn::Index,
+            TableColumn::Score,
+            TableColumn::Title,
+            TableColumn::Ref,
+        ]
+    }
+
+    /// Name as written in config
+    pub fn name(self) -> &'static str {
+        match self {
+            TableColumn::Index => "index",
+            TableColumn::Score => "score",
+            TableColumn::Age => "age",
+            TableColumn::Repo => "repo",
+            TableColumn::Author => "author",
+            TableColumn::Title => "title",
+            TableColumn::Ref => "ref",
+            TableColumn::Url => "url",
+            TableColumn::Efficiency => "efficiency",
+        }
+    }
+}
+
+fn default_bar_width() -> usize {
+    8
+}
+
+/// Allowed range for `score_bar.width`
+pub const BAR_WIDTH_RANGE: std::ops::RangeInclusive<usize> = 1..=40;
+
+/// Appearance of the score bar shown next to each score in the TUI
+#[derive(Debug, Clone, Deserialize, Serialize)]
+#[serde(deny_unknown_fields)]
+pub struct ScoreBarConfig {
+    /// Bar width in characters (1-40, defaults to 8)
+    #[serde(default = "default_bar_width")]
+    pub width: usize,
+
+    /// Glyph style: "block" (default) or "ascii"
+    #[serde(default)]
+    pub style: BarStyle,
+}
+
+impl Default for ScoreBarConfig {
+    fn default() -> Self {
+        Self {
+            width: default_bar_width(),
+            style: BarStyle::default(),
+        }
+    }
+}
+
+impl ScoreBarConfig {
+    /// Check that the bar width is within `BAR_WIDTH_RANGE`
+    pub fn validate(&self) -> Result<(), String> {
+        if BAR_WIDTH_RANGE.contains(&self.width) {
+            Ok(())
+        } else {
+            Err(format!(
+                "score_bar.width: must be between {} and {}, got {}",
+                BAR_WIDTH_RANGE.start(),
+                BAR_WIDTH_RANGE.end(),
+                self.width
+            ))
+        }
+    }
+}
+
 #[derive(Debug, Clone, Deserialize, Serialize)]
 #[serde(deny_unknown_fields)]
 pub struct Config {
@@ -26,6 +244,326 @@ pub struct Config {
     /// Theme selection: "dark", "light", or "auto" (detects terminal background)
     #[serde(default = "default_theme")]
     pub theme: String,
+
+    /// Use color-blind-friendly score colors (orange/blue/gray) and mark score levels with glyphs
+    #[serde(default)]
+    pub colorblind: bool,
+
+    /// Tie-break order for PRs with equal scores: "age" (oldest first, default) or "updated" (most recently updated first)
+    #[serde(default)]
+    pub tiebreak: Tiebreak,
+
+    /// Which query scores a PR matched by several queries: "first" (config order, default) or "highest-score"
+    #[serde(default)]
+    pub dedup: DedupStrategy,
+
+    /// Command used to open PRs, e.g. "firefox --new-tab {url}". `{url}` is replaced with the PR URL; unset uses the system default browser
+    #[serde(default)]
+    pub browser: Option<String>,
+
+    /// GitHub logins whose approval triggers the `approved_by_trusted` scoring factor
+    #[serde(default)]
+    pub trusted_reviewers: Vec<String>,
+
+    /// Teams you belong to, as "org/slug" (or a bare slug for any org), for
+    /// the `team_review_requested` scoring factor
+    #[serde(default)]
+    pub teams: Vec<String>,
+
+    /// Logins whose approvals don't count toward `approvals`, on top of GitHub
+    /// Apps such as `dependabot[bot]`
+    #[serde(default)]
+    pub bot_reviewers: Vec<String>,
+
+    /// Count approvals from bots like any other approval
+    #[serde(default)]
+    pub count_bot_approvals: bool,
+
+    /// TUI score bar appearance (width and glyph style)
+    #[serde(default)]
+    pub score_bar: ScoreBarConfig,
+
+    /// In the TUI, open each PR in the browser when it is selected (debounced, toggle with `O`)
+    #[serde(default)]
+    pub open_on_select: bool,
+
+    /// In the TUI, ring the terminal bell when a refresh surfaces a new PR scoring at least this much
+    #[serde(default)]
+    pub notify_threshold: Option<f64>,
+
+    /// Also show a desktop notification for new PRs above `notify_threshold`
+    #[serde(default)]
+    pub notify: bool,
+
+    /// Drop PRs older than this (humantime, e.g. "90d"); per-query `max_age` overrides
+    #[serde(default)]
+    pub max_age: Option<String>,
+
+    /// In table output, wrap long titles onto a second line instead of truncating
+    #[serde(default)]
+    pub wrap_titles: bool,
+
+    /// Drop PRs from the active list when your review request on them was withdrawn
+    #[serde(default)]
+    pub hide_withdrawn_requests: bool,
+
+    /// Drop PRs you authored from every query (the `is_author` factor only down-weights them)
+    #[serde(default)]
+    pub exclude_own: bool,
+
+    /// Columns shown in table output, in order (defaults to index, score, title, ref)
+    #[serde(default = "TableColumn::default_layout")]
+    pub columns: Vec<TableColumn>,
+
+    /// Show a per-author colored author column in the TUI (toggle with `a`)
+    #[serde(default)]
+    pub show_author: bool,
+
+    /// Order of the TUI's Snoozed view: "score" (default) or "remaining" (toggle with `S`)
+    #[serde(default)]
+    pub snoozed_sort: SnoozedSort,
+
+    /// In the TUI, ask before quitting while a refresh is still running
+    #[serde(default)]
+    pub confirm_quit: bool,
+
+    /// Gzip HTTP cache entries on disk (existing uncompressed entries still load)
+    #[serde(default)]
+    pub cache_compression: bool,
+
+    /// Enrich PRs with one GraphQL query each instead of several REST calls,
+    /// adding `reviewDecision` and re-review detection
+    #[serde(default)]
+    pub use_graphql: bool,
+
+    /// Index labels: "number" (default), "letter", or "id" (stable across refreshes)
+    #[serde(default)]
+    pub index_style: IndexStyle,
+
+    /// Zone for absolute timestamps in detail output: "local" (default), "utc", or an offset like "+05:30"
+    #[serde(default)]
+    pub timezone: Option<String>,
+
+    /// Before enriching, compare the estimated API calls with the remaining rate limit: "warn" (default), "degrade", or "off"
+    #[serde(default)]
+    pub rate_limit_guard: RateLimitGuard,
+
+    /// Most queries searched at once (unset runs every query in parallel)
+    #[serde(default)]
+    pub query_concurrency: Option<usize>,
+
+    /// Colors for TUI label chips by label name, e.g. `urgent: red` or `wip: "#808080"`;
+    /// other labels get a stable color from their name
+    #[serde(default)]
+    pub label_colors: HashMap<String, String>,
+
+    /// Cut titles to this many chars in table, TSV, and CSV output, even when
+    /// piped (unset: fit the terminal, or no limit when piped)
+    #[serde(default)]
+    pub max_title_width: Option<usize>,
+
+    /// At startup, mention snoozes ending within this long (humantime, e.g. "6h")
+    #[serde(default)]
+    pub snooze_reminder_window: Option<String>,
+
+    /// Move the oldest PR awaiting your review to the top, marked, once it
+    /// has waited this long (humantime, e.g. "14d")
+    #[serde(default)]
+    pub oldest_alert: Option<String>,
+
+    /// How long focus mode (`t` in the TUI) pauses auto-refresh (humantime,
+    /// e.g. "50m"; unset: 25 minutes)
+    #[serde(default)]
+    pub focus_duration: Option<String>,
+
+    /// Leave out PRs in archived (read-only) repos
+    #[serde(default)]
+    pub exclude_archived: bool,
+}
+
+/// Parse a label color: a color name ("red", "light-blue"), a 256-color
+/// index, or a hex code with or without `#` as GitHub shows them
+pub fn parse_label_color(s: &str) -> Result<Color, String> {
+    let s = s.trim();
+    let hex = if s.len() == 6 && s.bytes().all(|b| b.is_ascii_hexdigit()) {
+        format!("#{}", s)
+    } else {
+        s.to_string()
+    };
+    hex.parse::<Color>().map_err(|_| {
+        "expected a color name like \"red\" or a hex code like \"#d73a4a\"".to_string()
+    })
+}
+
+impl Config {
+    /// Validate non-scoring settings, collecting all errors
+    pub fn validate(&self) -> Result<(), Vec<String>> {
+        let mut errors = Vec::new();
+
+        if let Err(e) = self.score_bar.validate() {
+            errors.push(e);
+        }
+        if let Some(ref max_age) = self.max_age {
+            if let Err(e) = humantime::parse_duration(max_age) {
+                errors.push(format!("max_age: invalid '{}' - {}", max_age, e));
+            }
+        }
+        if let Some(ref window) = self.snooze_reminder_window {
+            if let Err(e) = humantime::parse_duration(window) {
+                errors.push(format!(
+                    "snooze_reminder_window: invalid '{}' - {}",
+                    window, e
+                ));
+            }
+        }
+        if let Some(ref threshold) = self.oldest_alert {
+            if let Err(e) = humantime::parse_duration(threshold) {
+                errors.push(format!("oldest_alert: invalid '{}' - {}", threshold, e));
+            }
+        }
+        if let Some(ref duration) = self.focus_duration {
+            match humantime::parse_duration(duration) {
+                Ok(d) if d.is_zero() => {
+                    errors.push("focus_duration: must be longer than zero".to_string())
+                }
+                Ok(_) => {}
+                Err(e) => errors.push(format!("focus_duration: invalid '{}' - {}", duration, e)),
+            }
+        }
+        if let Some(ref timezone) = self.timezone {
+            if let Err(e) = DisplayTimezone::parse(timezone) {
+                errors.push(format!("timezone: invalid '{}' - {}", timezone, e));
+            }
+        }
+        if self.max_title_width == Some(0) {
+            errors.push("max_title_width: must be at least 1".to_string());
+        }
+        if self.query_concurrency == Some(0) {
+            errors.push("query_concurrency: must be at least 1".to_string());
+        }
+        let mut label_colors: Vec<_> = self.label_colors.iter().collect();
+        label_colors.sort();
+        for (label, color) in label_colors {
+            if let Err(e) = parse_label_color(color) {
+                errors.push(format!(
+                    "label_colors.{}: invalid '{}' - {}",
+                    label, color, e
+                ));
+            }
+        }
+        if self.columns.is_empty() {
+            errors.push("columns: must list at least one column".to_string());
+        }
+        for (i, column) in self.columns.iter().enumerate() {
+            if self.columns[..i].contains(column) {
+                errors.push(format!(
+                    "columns[{}]: duplicate column '{}'",
+                    i,
+                    column.name()
+                ));
+            }
+        }
+        let mut groups: Vec<&str> = Vec::new();
+        for (i, query) in self.queries.iter().enumerate() {
+            if let Some(ref group) = query.group {
+                // Tags name sections, so "Acme" and "acme " would split one in two
+                if group.trim().is_empty() {
+                    errors.push(format!("queries[{}].group: must not be empty", i));
+                } else if let Some(other) = groups
+                    .iter()
+                    .find(|other| other.trim().eq_ignore_ascii_case(group.trim()))
+                    .filter(|other| **other != group)
+                {
+                    errors.push(format!(
+                        "queries[{}].group: '{}' differs from '{}' only in case or spacing",
+                        i, group, other
+                    ));
+                } else {
+                    groups.push(group);
+                }
+            }
+            if let Some(ref max_age) = query.max_age {
+                if let Err(e) = humantime::parse_duration(max_age) {
+                    errors.push(format!(
+                        "queries[{}].max_age: invalid '{}' - {}",
+                        i, max_age, e
+                    ));
+                }
+            }
+            if let Some(weight) = query.weight {
+                if !(weight.is_finite() && weight > 0.0) {
+                    errors.push(format!(
+                        "queries[{}].weight: must be a positive number, got {}",
+                        i, weight
+                    ));
+                }
+            }
+        }
+
+        if errors.is_empty() {
+            Ok(())
+        } else {
+            Err(errors)
+        }
+    }
+
+    /// Zone for absolute timestamps; local when unset or invalid
+    pub fn display_timezone(&self) -> DisplayTimezone {
+        self.timezone
+            .as_deref()
+            .and_then(|s| DisplayTimezone::parse(s).ok())
+            .unwrap_or_default()
+    }
+
+    /// Startup reminder for snoozes ending within `snooze_reminder_window`,
+    /// if set
+    pub fn snooze_reminder(&self, snooze_state: &crate::snooze::SnoozeState) -> Option<String> {
+        let label = self.snooze_reminder_window.as_deref()?;
+        let window = humantime::parse_duration(label).ok()?;
+        snooze_state.expiring_notice(window, label, Utc::now())
+    }
+
+    /// How long a PR must wait before `oldest_alert` flags it (None when unset)
+    pub fn oldest_alert_threshold(&self) -> Option<std::time::Duration> {
+        self.oldest_alert
+            .as_deref()
+            .and_then(|s| humantime::parse_duration(s).ok())
+    }
+
+    /// How long focus mode pauses auto-refresh
+    pub fn focus_duration(&self) -> std::time::Duration {
+        self.focus_duration
+            .as_deref()
+            .and_then(|s| humantime::parse_duration(s).ok())
+            .unwrap_or(std::time::Duration::from_secs(25 * 60))
+    }
+
+    /// Configured chip color for a label (case-insensitive, like GitHub labels);
+    /// None when unset or invalid
+    pub fn label_color(&self, label: &str) -> Option<Color> {
+        self.label_colors
+            .iter()
+            .find(|(name, _)| name.eq_ignore_ascii_case(label))
+            .and_then(|(_, color)| parse_label_color(color).ok())
+    }
+
+    /// Effective max age for the query at `query_index` (per-query overrides global).
+    /// Returns None when no cutoff applies or the value doesn't parse.
+    pub fn max_age_for_query(&self, query_index: usize) -> Option<std::time::Duration> {
+        self.queries
+            .get(query_index)
+            .and_then(|q| q.max_age.as_deref())
+            .or(self.max_age.as_deref())
+            .and_then(|s| humantime::parse_duration(s).ok())
+    }
+
+    /// Remove all max-age cutoffs (for `--include-old`)
+    pub fn clear_max_age(&mut self) {
+        self.max_age = None;
+        for query in &mut self.queries {
+            query.max_age = None;
+        }
+    }
 }
 
 #[derive(Debug, Clone, Deserialize, Serialize)]
@@ -37,4 +575,265 @@ pub struct QueryConfig {
     /// Per-query scoring configuration (merges with global scoring — set fields override, unset fields inherit from global)
     #[serde(default)]
     pub scoring: Option<ScoringConfig>,
+
+    /// Per-query max age cutoff (overrides global `max_age`)
+    #[serde(default)]
+    pub max_age: Option<String>,
+
+    /// Also list issues matching this query (scored without PR-only factors)
+    #[serde(default)]
+    pub include_issues: bool,
+
+    /// Fetch details, reviews, and files for each result. When false, only
+    /// the search itself runs and scores are marked incomplete.
+    #[serde(default = "default_enrich")]
+    pub enrich: bool,
+
+    /// Tag for this query's PRs (e.g. an org name), shown by `--group-by group`
+    #[serde(default)]
+    pub group: Option<String>,
+
+    /// Multiplier on the final score of PRs scored under this query
+    #[serde(default)]
+    pub weight: Option<f64>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_score_bar_defaults_when_absent() {
+        let yaml = r#"
+queries:
+  - query: "is:pr is:open"
+"#;
+        let config: Config = serde_saphyr::from_str(yaml).unwrap();
+        assert_eq!(config.score_bar.width, 8);
+        assert_eq!(config.score_bar.style, BarStyle::Block);
+        assert!(config.score_bar.validate().is_ok());
+    }
+
+    #[test]
+    fn test_score_bar_parse_ascii() {
+        let yaml = r#"
+queries:
+  - query: "is:pr is:open"
+score_bar:
+  width: 20
+  style: ascii
+"#;
+        let config: Config = serde_saphyr::from_str(yaml).unwrap();
+        assert_eq!(config.score_bar.width, 20);
+        assert_eq!(config.score_bar.style.glyphs(), ("#", "-"));
+    }
+
+    #[test]
+    fn test_max_age_per_query_overrides_global() {
+        let yaml = r#"
+max_age: "90d"
+queries:
+  - query: "is:pr is:open"
+  - query: "is:pr author:@me"
+    max_age: "1y"
+"#;
+        let config: Config = serde_saphyr::from_str(yaml).unwrap();
+        assert!(config.validate().is_ok());
+        assert_eq!(
+            config.max_age_for_query(0),
+            Some(std::time::Duration::from_secs(90 * 86400))
+        );
+        assert_eq!(
+            config.max_age_for_query(1),
+            Some(humantime::parse_duration("1y").unwrap())
+        );
+    }
+
+    #[test]
+    fn test_query_weight_must_be_positive() {
+        let yaml = r#"
+queries:
+  - query: "is:pr repo:acme/core"
+    weight: 1.5
+  - query: "is:pr org:acme"
+    weight: 0
+"#;
+        let config: Config = serde_saphyr::from_str(yaml).unwrap();
+        assert_eq!(config.queries[0].weight, Some(1.5));
+        let errors = config.validate().unwrap_err();
+        assert_eq!(errors.len(), 1);
+        assert!(errors[0].starts_with("queries[1].weight"));
+    }
+
+    #[test]
+    fn test_max_age_invalid_and_cleared() {
+        let yaml = r#"
+max_age: "ninety days"
+queries:
+  - query: "is:pr is:open"
+    max_age: "30d"
+"#;
+        let mut config: Config = serde_saphyr::from_str(yaml).unwrap();
+        let errors = config.validate().unwrap_err();
+        assert_eq!(errors.len(), 1);
+        assert!(errors[0].starts_with("max_age: invalid"));
+
+        config.clear_max_age();
+        assert_eq!(config.max_age_for_query(0), None);
+    }
+
+    #[test]
+    fn test_query_groups_must_be_consistent() {
+        let yaml = r#"
+queries:
+  - query: "org:acme is:pr is:open"
+    group: Acme
+  - query: "org:acme review-requested:@me"
+    group: Acme
+  - query: "org:acme-labs is:pr is:open"
+    group: "acme "
+  - query: "org:other is:pr is:open"
+    group: ""
+"#;
+        let config: Config = serde_saphyr::from_str(yaml).unwrap();
+        assert_eq!(config.queries[0].group.as_deref(), Some("Acme"));
+        let errors = config.validate().unwrap_err();
+        assert_eq!(
+            errors,
+            vec![
+                "queries[2].group: 'acme ' differs from 'Acme' only in case or spacing",
+                "queries[3].group: must not be empty",
+            ]
+        );
+    }
+
+    #[test]
+    fn test_columns_default_and_custom() {
+        let yaml = r#"
+queries:
+  - query: "is:pr is:open"
+"#;
+        let config: Config = serde_saphyr::from_str(yaml).unwrap();
+        assert_eq!(config.columns, TableColumn::default_layout());
+
+        let yaml = r#"
+columns: [index, age, repo, title, url]
+queries:
+  - query: "is:pr is:open"
+"#;
+        let config: Config = serde_saphyr::from_str(yaml).unwrap();
+        assert!(config.validate().is_ok());
+        assert_eq!(config.columns[1], TableColumn::Age);
+        assert_eq!(config.columns[4], TableColumn::Url);
+    }
+
+    #[test]
+    fn test_columns_invalid() {
+        let yaml = r#"
+columns: [index, bogus]
+queries:
+  - query: "is:pr is:open"
+"#;
+        assert!(serde_saphyr::from_str::<Config>(yaml).is_err());
+
+        let yaml = r#"
+columns: [score, title, score]
+queries:
+  - query: "is:pr is:open"
+"#;
+        let config: Config = serde_saphyr::from_str(yaml).unwrap();
+        let errors = config.validate().unwrap_err();
+        assert_eq!(
+            errors,
+            vec!["columns[2]: duplicate column 'score'".to_string()]
+        );
+    }
+
+    #[test]
+    fn test_dedup_parse() {
+        let yaml = r#"
+dedup: highest-score
+queries:
+  - query: "is:pr is:open"
+"#;
+        let config: Config = serde_saphyr::from_str(yaml).unwrap();
+        assert_eq!(config.dedup, DedupStrategy::HighestScore);
+    }
+
+    #[test]
+    fn test_label_colors() {
+        let yaml = r##"
+label_colors:
+  urgent: red
+  Needs-Review: "d73a4a"
+  wip: "#80808"
+queries: []
+"##;
+        let config: Config = serde_saphyr::from_str(yaml).unwrap();
+        assert_eq!(config.label_color("URGENT"), Some(Color::Red));
+        assert_eq!(
+            config.label_color("needs-review"),
+            Some(Color::Rgb(0xd7, 0x3a, 0x4a))
+        );
+        assert_eq!(config.label_color("wip"), None);
+        assert_eq!(config.label_color("docs"), None);
+        let errors = config.validate().unwrap_err();
+        assert_eq!(errors.len(), 1);
+        assert!(errors[0].starts_with("label_colors.wip: invalid '#80808'"));
+    }
+
+    #[test]
+    fn test_query_concurrency_must_be_positive() {
+        let mut config: Config = serde_saphyr::from_str("queries: []").unwrap();
+        assert_eq!(config.query_concurrency, None);
+        config.query_concurrency = Some(0);
+        assert_eq!(
+            config.validate().unwrap_err(),
+            vec!["query_concurrency: must be at least 1".to_string()]
+        );
+    }
+
+    #[test]
+    fn test_display_timezone() {
+        let time: DateTime<Utc> = "2026-03-04T13:05:00Z".parse().unwrap();
+        let format = |s: &str| DisplayTimezone::parse(s).map(|tz| tz.format(time));
+
+        assert_eq!(format("UTC"), Ok("2026-03-04 13:05 UTC".to_string()));
+        assert_eq!(format("+05:30"), Ok("2026-03-04 18:35 +05:30".to_string()));
+        assert_eq!(format("-0800"), Ok("2026-03-04 05:05 -08:00".to_string()));
+        assert_eq!(DisplayTimezone::parse("local"), Ok(DisplayTimezone::Local));
+        assert!(DisplayTimezone::parse("Europe/Berlin").is_err());
+
+        let mut config: Config = serde_saphyr::from_str("queries: []").unwrap();
+        assert_eq!(config.display_timezone(), DisplayTimezone::Local);
+        config.timezone = Some("nowhere".to_string());
+        assert_eq!(
+            config.validate().unwrap_err(),
+            vec!["timezone: invalid 'nowhere' - expected \"local\", \"utc\", or an offset like \"+05:30\"".to_string()]
+        );
+    }
+
+    #[test]
+    fn test_enrich_defaults_to_true() {
+        let yaml = r#"
+queries:
+  - query: "is:pr is:open"
+  - query: "org:acme is:pr is:open"
+    enrich: false
+"#;
+        let config: Config = serde_saphyr::from_str(yaml).unwrap();
+        assert!(config.queries[0].enrich);
+        assert!(!config.queries[1].enrich);
+    }
+
+    #[test]
+    fn test_score_bar_width_out_of_range() {
+        for width in [0, 41] {
+            let bar = ScoreBarConfig {
+                width,
+                style: BarStyle::Block,
+            };
+            assert!(bar.validate().is_err());
+        }
+    }
 }
@@ -1,4 +1,19 @@
 use anyhow::{Context, Result};
+use std::io::IsTerminal;
+
+/// Fail fast when nobody can answer a token prompt (cron, CI, piped stdin)
+/// instead of blocking on input that never arrives.
+fn ensure_can_prompt(problem: &str) -> Result<()> {
+    if std::io::stdin().is_terminal() {
+        return Ok(());
+    }
+    anyhow::bail!(
+        "{} and stdin is not a terminal, so pr-bro can't prompt for one. \
+         Set {} to a valid GitHub token for non-interactive use.",
+        problem,
+        super::ENV_TOKEN_VAR
+    )
+}
 
 /// Prompts user to enter GitHub personal access token
 pub fn prompt_for_token() -> Result<String> {
@@ -19,8 +34,11 @@ pub fn prompt_for_token() -> Result<String> {
     Ok(token.to_string())
 }
 
-/// Re-prompts for token when the existing one is rejected by GitHub
+/// Re-prompts for token when the existing one is rejected by GitHub.
+/// Errors immediately if stdin is not a terminal.
 pub fn reprompt_for_token() -> Result<String> {
+    ensure_can_prompt("Your GitHub token was rejected (invalid or expired)")?;
+
     eprintln!();
     eprintln!("Your GitHub token was rejected (invalid or expired).");
     eprintln!("Please provide a new token.");
@@ -34,13 +52,16 @@ pub fn reprompt_for_token() -> Result<String> {
 }
 
 /// Setup token if missing - checks env var, then prompts interactively
-/// Returns the token (either from env var or newly prompted)
+/// Returns the token (either from env var or newly prompted).
+/// Errors immediately if there is no token and stdin is not a terminal.
 pub fn setup_token_if_missing() -> Result<String> {
     // Check env var first
     if let Some(token) = super::get_token_from_env() {
         return Ok(token);
     }
 
+    ensure_can_prompt("No GitHub token found")?;
+
     // No env var set, prompt for token
     let token = prompt_for_token()?;
 
@@ -1,13 +1,19 @@
-use crate::buffered_eprintln;
-use crate::config::Config;
+use crate::config::{Config, DedupStrategy, Tiebreak};
 use crate::github::cache::CacheConfig;
 use crate::github::types::PullRequest;
-use crate::scoring::{calculate_score, merge_scoring_configs, ScoreResult};
+use crate::github::SearchTiming;
+use crate::pins::PinState;
+use crate::scoring::{
+    calculate_score, efficiency, merge_scoring_configs, ScoreResult, ScoringConfig,
+};
 use crate::snooze::{filter_active_prs, filter_snoozed_prs, SnoozeState};
 use anyhow::Result;
 use futures::stream::{FuturesUnordered, StreamExt};
-use std::collections::{HashMap, HashSet};
+use std::borrow::Borrow;
+use std::collections::HashMap;
 use std::fmt;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::time::{Duration, Instant};
 
 /// Typed error for GitHub authentication failures (401 / Bad credentials).
 /// Callers can downcast `anyhow::Error` to this type to distinguish auth
@@ -25,8 +31,50 @@ impl fmt::Display for AuthError {
 
 impl std::error::Error for AuthError {}
 
+/// Search and enrichment time for one query
+#[derive(Debug, Clone)]
+pub struct QueryTiming {
+    pub name: String,
+    pub timing: SearchTiming,
+}
+
+/// Where time went during `fetch_and_score_prs`, reported by `--profile-timing`.
+/// Queries run in parallel, so `queries_total` is wall time, not a sum.
+#[derive(Debug, Clone, Default)]
+pub struct FetchTimings {
+    pub queries: Vec<QueryTiming>,
+    pub queries_total: Duration,
+    pub scoring: Duration,
+}
+
+/// Set once the empty-query notice has been shown, so TUI refreshes don't repeat it
+static EMPTY_QUERY_NOTICE_SHOWN: AtomicBool = AtomicBool::new(false);
+
+/// One-line notice naming queries that returned nothing (often a typo), or
+/// None when every query found something
+fn empty_query_notice(empty_queries: &[String]) -> Option<String> {
+    if empty_queries.is_empty() {
+        return None;
+    }
+    let names = empty_queries
+        .iter()
+        .map(|name| format!("\"{}\"", name))
+        .collect::<Vec<_>>()
+        .join(", ");
+    let noun = if empty_queries.len() == 1 {
+        "query"
+    } else {
+        "queries"
+    };
+    Some(format!(
+        "no results for {} {} (check for typos if that's unexpected)",
+        noun, names
+    ))
+}
+
 /// Fetch PRs from all configured queries, deduplicate, score, and split into
 /// active and snoozed lists. Both lists are sorted by score descending.
+/// Also returns the remaining rate limit (if known) and phase timings.
 ///
 /// This function is called from main.rs for initial load and from the TUI
 /// event loop for manual/auto refresh.
@@ -35,60 +83,120 @@ pub async fn fetch_and_score_prs(
     config: &Config,
     snooze_state: &SnoozeState,
     cache_config: &CacheConfig,
-    verbose: bool,
     auth_username: Option<&str>,
 ) -> Result<(
     Vec<(PullRequest, ScoreResult)>,
     Vec<(PullRequest, ScoreResult)>,
     Option<u64>,
+    FetchTimings,
 )> {
-    if verbose {
-        let cache_status = if cache_config.enabled {
-            "enabled"
-        } else {
-            "disabled (--no-cache)"
-        };
-        buffered_eprintln!("Cache: {}", cache_status);
-    }
+    let mut timings = FetchTimings::default();
+
+    let cache_status = if cache_config.enabled {
+        "enabled"
+    } else {
+        "disabled (--no-cache)"
+    };
+    log::info!("Cache: {}", cache_status);
 
     // Resolve global scoring config once (fallback for queries without per-query scoring)
     let global_scoring = config.scoring.clone().unwrap_or_default();
 
-    // Search PRs for each query in parallel
+    // Search PRs for each query in parallel (up to `query_concurrency` at once)
     let mut all_prs = Vec::new();
     let mut any_succeeded = false;
+    let mut empty_queries = Vec::new();
 
-    let mut futures = FuturesUnordered::new();
+    // Drop a rate limit left over from an earlier fetch that failed midway
+    crate::github::take_observed_rate_limit();
+
+    let queries_start = Instant::now();
     let auth_username_owned = auth_username.map(|s| s.to_string());
-    for (query_index, query_config) in config.queries.iter().enumerate() {
-        let client = client.clone();
-        let query = query_config.query.clone();
-        let query_name = query_config.name.clone();
-        let auth_username_clone = auth_username_owned.clone();
-        // Merge scoring config for this query to get the effective exclude patterns
-        let merged_scoring = merge_scoring_configs(&global_scoring, query_config.scoring.as_ref());
-        let exclude_patterns = merged_scoring.size.and_then(|s| s.exclude);
-        futures.push(async move {
-            let result = crate::github::search_and_enrich_prs(
-                &client,
-                &query,
-                auth_username_clone.as_deref(),
-                exclude_patterns,
-            )
-            .await;
-            (query_name, query, query_index, result)
+    let mut pending = config
+        .queries
+        .iter()
+        .enumerate()
+        .map(|(query_index, query_config)| {
+            let client = client.clone();
+            let query = query_config.query.clone();
+            let query_name = query_config.name.clone();
+            let include_issues = query_config.include_issues;
+            let enrich = query_config.enrich;
+            let auth_username_clone = auth_username_owned.clone();
+            let reviewers = crate::github::ReviewerRules {
+                trusted: config.trusted_reviewers.clone(),
+                bots: config.bot_reviewers.clone(),
+                count_bot_approvals: config.count_bot_approvals,
+                teams: config.teams.clone(),
+            };
+            let use_graphql = config.use_graphql;
+            let rate_limit_guard = config.rate_limit_guard;
+            // Merge scoring config for this query to get the effective exclude patterns
+            let merged_scoring =
+                merge_scoring_configs(&global_scoring, query_config.scoring.as_ref());
+            let size_metric = merged_scoring
+                .size
+                .as_ref()
+                .and_then(|s| s.metric)
+                .unwrap_or_default();
+            let age_basis = merged_scoring.age_basis.unwrap_or_default();
+            let compare_base = merged_scoring.commits_behind.is_some();
+            let fetch_ci = merged_scoring.ci_status.is_some();
+            let exclude_patterns = merged_scoring.size.and_then(|s| s.exclude);
+            async move {
+                let result = if !enrich {
+                    search_only(
+                        &client,
+                        &query,
+                        auth_username_clone.as_deref(),
+                        include_issues,
+                    )
+                    .await
+                } else {
+                    crate::github::search_and_enrich_prs(
+                        &client,
+                        &query,
+                        auth_username_clone.as_deref(),
+                        exclude_patterns,
+                        size_metric,
+                        age_basis,
+                        compare_base,
+                        fetch_ci,
+                        include_issues,
+                        &reviewers,
+                        use_graphql,
+                        rate_limit_guard,
+                    )
+                    .await
+                };
+                (query_name, query, query_index, result)
+            }
         });
-    }
+
+    // Run up to `query_concurrency` searches at once, starting the next query
+    // as each one finishes
+    let mut futures = FuturesUnordered::new();
+    let max_concurrent = config.query_concurrency.unwrap_or(usize::MAX);
+    futures.extend(pending.by_ref().take(max_concurrent));
 
     while let Some((name, query, query_index, result)) = futures.next().await {
+        if let Some(next) = pending.next() {
+            futures.push(next);
+        }
         match result {
-            Ok(prs) => {
-                if verbose {
-                    buffered_eprintln!(
-                        "  Found {} PRs for {}",
-                        prs.len(),
-                        name.as_deref().unwrap_or(&query)
-                    );
+            Ok((prs, timing)) => {
+                timings.queries.push(QueryTiming {
+                    name: name.clone().unwrap_or_else(|| query.clone()),
+                    timing,
+                });
+                log::info!(
+                    "  Found {} PRs for {}",
+                    prs.len(),
+                    name.as_deref().unwrap_or(&query)
+                );
+                if prs.is_empty() {
+                    empty_queries
+                        .push((query_index, name.clone().unwrap_or_else(|| query.clone())));
                 }
                 // Extend with (pr, query_index) pairs to track which query each PR came from
                 all_prs.extend(prs.into_iter().map(|pr| (pr, query_index)));
@@ -99,7 +207,7 @@ pub async fn fetch_and_score_prs(
                 if e.downcast_ref::<AuthError>().is_some() {
                     return Err(e);
                 }
-                buffered_eprintln!(
+                log::warn!(
                     "Query failed: {} - {}",
                     name.as_deref().unwrap_or(&query),
                     e
@@ -108,71 +216,391 @@ pub async fn fetch_and_score_prs(
         }
     }
 
+    timings.queries_total = queries_start.elapsed();
+
+    // Queries finish in any order; report empty ones in config order
+    empty_queries.sort();
+    let empty_names: Vec<String> = empty_queries.into_iter().map(|(_, name)| name).collect();
+    if let Some(notice) = empty_query_notice(&empty_names) {
+        if !EMPTY_QUERY_NOTICE_SHOWN.swap(true, Ordering::Relaxed) {
+            log::warn!("{}", notice);
+        }
+    }
+
     // If all queries failed, return error
     if !any_succeeded && !config.queries.is_empty() {
         anyhow::bail!("All queries failed. Check your network connection and GitHub token.");
     }
 
     // Deduplicate PRs by URL (same PR may appear in multiple queries)
-    // First match wins: track both unique PRs and their query index
-    let mut seen_urls = HashSet::new();
-    let mut pr_to_query_index = HashMap::new();
-    let unique_prs: Vec<_> = all_prs
-        .into_iter()
-        .filter_map(|(pr, query_idx)| {
-            if seen_urls.insert(pr.url.clone()) {
-                pr_to_query_index.insert(pr.url.clone(), query_idx);
-                Some(pr)
-            } else {
-                None
-            }
-        })
-        .collect();
+    let now = chrono::Utc::now();
+    let (unique_prs, pr_to_query_index) = dedup_and_filter(all_prs, config, now);
+
+    // Drop PRs in archived repos, looking up repos enrichment didn't cover
+    let unique_prs = if config.exclude_archived {
+        let kept = drop_archived(client, unique_prs).await;
+        log::info!("After excluding archived repos: {} PRs", kept.len());
+        kept
+    } else {
+        unique_prs
+    };
 
-    if verbose {
-        buffered_eprintln!("After deduplication: {} unique PRs", unique_prs.len());
+    // Flag PRs whose review request on you was withdrawn since an earlier refresh
+    let mut unique_prs = unique_prs;
+    if let Some(username) = auth_username {
+        track_review_requests(&mut unique_prs, username, now);
     }
 
     // Split into active and snoozed
-    let active_prs = filter_active_prs(unique_prs.clone(), snooze_state);
+    let mut active_prs = filter_active_prs(unique_prs.clone(), snooze_state);
+    if config.hide_withdrawn_requests {
+        active_prs.retain(|pr| !pr.review_request_withdrawn);
+    }
     let snoozed_prs = filter_snoozed_prs(unique_prs, snooze_state);
 
-    if verbose {
-        buffered_eprintln!(
-            "After filter: {} active, {} snoozed",
-            active_prs.len(),
-            snoozed_prs.len()
-        );
+    log::info!(
+        "After filter: {} active, {} snoozed",
+        active_prs.len(),
+        snoozed_prs.len()
+    );
+
+    let scoring_start = Instant::now();
+
+    // Score each list sorted, then float the longest-waiting PR if it's overdue
+    let mut active_scored = score_unique_prs(active_prs, &pr_to_query_index, config);
+    let snoozed_scored = score_unique_prs(snoozed_prs, &pr_to_query_index, config);
+    if let Some(threshold) = config.oldest_alert_threshold() {
+        flag_oldest_unreviewed(&mut active_scored, threshold, now);
     }
+    timings.scoring = scoring_start.elapsed();
+
+    // Rate limit as reported on the enrichment responses; ask for it only
+    // when none carried it (best-effort, don't fail the whole fetch)
+    let rate_limit_remaining = match crate::github::take_observed_rate_limit() {
+        Some(remaining) => Some(remaining),
+        None => match client.ratelimit().get().await {
+            Ok(rate_limit) => Some(rate_limit.resources.core.remaining as u64),
+            Err(e) => {
+                log::debug!("Rate limit lookup failed: {}", e);
+                None
+            }
+        },
+    };
 
-    // Score active PRs (merge per-query scoring config with global for each PR)
-    let mut active_scored: Vec<_> = active_prs
+    Ok((active_scored, snoozed_scored, rate_limit_remaining, timings))
+}
+
+/// Score PRs read back from `--dump-raw` output instead of fetched, for
+/// `pr-bro score-test`. `input` is a dump directory or one search dump in
+/// it. Each search dump is scored with the config query whose text it was
+/// searched with, or with the global scoring when none matches. No API calls
+/// are made, so snoozes and review request tracking are left out, and
+/// `exclude_archived` only sees repos whose flag is in the dump. Otherwise
+/// the PRs go through the same dedup, filters, and scoring as a live fetch.
+pub fn score_dump(
+    config: &Config,
+    input: &std::path::Path,
+    auth_username: Option<&str>,
+) -> Result<Vec<(PullRequest, ScoreResult)>> {
+    use crate::github::replay::{load_search_dumps, replay_enrichment};
+
+    let global_scoring = config.scoring.clone().unwrap_or_default();
+    // Out of range for dumps of queries the config doesn't have
+    let scoring_for = |query_idx: usize| {
+        let query_scoring = config
+            .queries
+            .get(query_idx)
+            .and_then(|q| q.scoring.as_ref());
+        merge_scoring_configs(&global_scoring, query_scoring)
+    };
+    let reviewers = crate::github::ReviewerRules {
+        trusted: config.trusted_reviewers.clone(),
+        bots: config.bot_reviewers.clone(),
+        count_bot_approvals: config.count_bot_approvals,
+        teams: config.teams.clone(),
+    };
+    let dir = if input.is_dir() {
+        input
+    } else {
+        input.parent().unwrap_or(std::path::Path::new("."))
+    };
+
+    let mut all_prs = Vec::new();
+    for search in load_search_dumps(input)? {
+        // The query is searched as written, or with " is:pr" appended
+        let query_idx = config
+            .queries
+            .iter()
+            .position(|q| search.query == q.query || search.query == format!("{} is:pr", q.query))
+            .unwrap_or_else(|| {
+                log::warn!(
+                    "No configured query matches '{}'; scoring it with the global scoring",
+                    search.query
+                );
+                usize::MAX
+            });
+        let scoring = scoring_for(query_idx);
+        let exclude_patterns = scoring
+            .size
+            .as_ref()
+            .and_then(|s| s.exclude.clone())
+            .unwrap_or_default();
+        let size_metric = scoring.size.as_ref().and_then(|s| s.metric);
+        for mut pr in search.prs {
+            replay_enrichment(
+                dir,
+                &mut pr,
+                auth_username,
+                &exclude_patterns,
+                size_metric.unwrap_or_default(),
+                scoring.age_basis.unwrap_or_default(),
+                &reviewers,
+            )?;
+            all_prs.push((pr, query_idx));
+        }
+    }
+
+    let now = chrono::Utc::now();
+    let (mut unique_prs, pr_to_query_index) = dedup_and_filter(all_prs, config, now);
+    // No lookups here, unlike drop_archived: only flags in the dump count
+    if config.exclude_archived {
+        unique_prs.retain(|pr| pr.repo_archived != Some(true));
+    }
+    let mut scored = score_unique_prs(unique_prs, &pr_to_query_index, config);
+    if let Some(threshold) = config.oldest_alert_threshold() {
+        flag_oldest_unreviewed(&mut scored, threshold, now);
+    }
+    Ok(scored)
+}
+
+/// Run a query's search without enrichment (`enrich: false`): one API call,
+/// and the PRs keep only what the search result carries
+async fn search_only(
+    client: &octocrab::Octocrab,
+    query: &str,
+    auth_username: Option<&str>,
+    include_issues: bool,
+) -> Result<(Vec<PullRequest>, SearchTiming)> {
+    let search_start = Instant::now();
+    let mut prs = crate::github::search_prs(client, query, include_issues).await?;
+    if let Some(username) = auth_username {
+        for pr in &mut prs {
+            pr.is_author = pr.author.eq_ignore_ascii_case(username);
+        }
+    }
+    let timing = SearchTiming {
+        search: search_start.elapsed(),
+        enrichment: Duration::ZERO,
+    };
+    Ok((prs, timing))
+}
+
+/// Deduplicate fetched or replayed PRs, tag each with its query's group, and
+/// drop PRs past their query's `max_age` and (with `exclude_own`) your own
+fn dedup_and_filter(
+    all_prs: Vec<(PullRequest, usize)>,
+    config: &Config,
+    now: chrono::DateTime<chrono::Utc>,
+) -> (Vec<PullRequest>, HashMap<String, usize>) {
+    let global_scoring = config.scoring.clone().unwrap_or_default();
+    let (mut unique_prs, pr_to_query_index) = dedup_prs(all_prs, config.dedup, |pr, query_idx| {
+        score_for_query(pr, query_idx, config, &global_scoring).score
+    });
+    log::info!("After deduplication: {} unique PRs", unique_prs.len());
+
+    for pr in &mut unique_prs {
+        pr.group = config
+            .queries
+            .get(pr_to_query_index[&pr.url])
+            .and_then(|query| query.group.clone());
+    }
+
+    unique_prs.retain(|pr| {
+        !exceeds_max_age(
+            pr,
+            config.max_age_for_query(pr_to_query_index[&pr.url]),
+            now,
+        )
+    });
+    log::info!("After max_age cutoff: {} PRs", unique_prs.len());
+
+    (
+        drop_own_prs(unique_prs, config.exclude_own),
+        pr_to_query_index,
+    )
+}
+
+/// Score deduplicated PRs under the query each was kept for, sorted by score
+/// and then the configured tie-breaker
+fn score_unique_prs(
+    prs: Vec<PullRequest>,
+    pr_to_query_index: &HashMap<String, usize>,
+    config: &Config,
+) -> Vec<(PullRequest, ScoreResult)> {
+    let global_scoring = config.scoring.clone().unwrap_or_default();
+    let mut scored: Vec<_> = prs
         .into_iter()
         .map(|pr| {
-            // Look up which query this PR came from and merge its scoring config
-            let query_idx = pr_to_query_index.get(&pr.url).copied().unwrap_or(0);
-            let scoring =
-                merge_scoring_configs(&global_scoring, config.queries[query_idx].scoring.as_ref());
-            let result = calculate_score(&pr, &scoring);
+            let query_idx = pr_to_query_index[&pr.url];
+            let result = score_for_query(&pr, query_idx, config, &global_scoring);
             (pr, result)
         })
         .collect();
+    sort_scored_prs(&mut scored, config.tiebreak);
+    scored
+}
+
+/// Score a PR with its query's merged scoring config and weight. Replayed
+/// searches no query matches have an out-of-range index and get the global
+/// scoring, unweighted. PRs left unenriched (`enrich: false`, or held back or
+/// cut off by the rate limit) lack review and size data, so their scores are
+/// marked incomplete.
+fn score_for_query(
+    pr: &PullRequest,
+    query_idx: usize,
+    config: &Config,
+    global_scoring: &ScoringConfig,
+) -> ScoreResult {
+    let query = config.queries.get(query_idx);
+    let scoring = merge_scoring_configs(global_scoring, query.and_then(|q| q.scoring.as_ref()));
+    let mut result = calculate_score(pr, &scoring);
+    result.apply_weight(query.and_then(|q| q.weight));
+    if pr.requested_reviewers.is_none() && !pr.is_issue {
+        result.incomplete = true;
+    }
+    result
+}
 
-    // Score snoozed PRs (merge per-query scoring config with global for each PR)
-    let mut snoozed_scored: Vec<_> = snoozed_prs
+/// Deduplicate PRs matched by several queries, keeping one copy per URL and
+/// the query whose scoring config applies to it. Returns the unique PRs in
+/// first-seen order (by query order) and a URL -> query index map.
+///
+/// `First` keeps the earliest query in config order. `HighestScore` scores
+/// every candidate with `score` and keeps the best (ties go to the earlier
+/// query); this costs one extra scoring pass per duplicate, which is cheap
+/// compared to fetching.
+fn dedup_prs(
+    mut all_prs: Vec<(PullRequest, usize)>,
+    strategy: DedupStrategy,
+    score: impl Fn(&PullRequest, usize) -> f64,
+) -> (Vec<PullRequest>, HashMap<String, usize>) {
+    // Queries complete in any order; restore config order so "first" is stable
+    all_prs.sort_by_key(|(_, query_idx)| *query_idx);
+
+    let mut order: Vec<String> = Vec::new();
+    let mut best: HashMap<String, (PullRequest, usize, Option<f64>)> = HashMap::new();
+    for (pr, query_idx) in all_prs {
+        match best.get_mut(&pr.url) {
+            None => {
+                order.push(pr.url.clone());
+                best.insert(pr.url.clone(), (pr, query_idx, None));
+            }
+            Some(current) => {
+                if strategy == DedupStrategy::First {
+                    continue;
+                }
+                let current_score = *current
+                    .2
+                    .get_or_insert_with(|| score(&current.0, current.1));
+                let candidate_score = score(&pr, query_idx);
+                if candidate_score > current_score {
+                    *current = (pr, query_idx, Some(candidate_score));
+                }
+            }
+        }
+    }
+
+    let mut pr_to_query_index = HashMap::new();
+    let unique_prs = order
         .into_iter()
-        .map(|pr| {
-            // Look up which query this PR came from and merge its scoring config
-            let query_idx = pr_to_query_index.get(&pr.url).copied().unwrap_or(0);
-            let scoring =
-                merge_scoring_configs(&global_scoring, config.queries[query_idx].scoring.as_ref());
-            let result = calculate_score(&pr, &scoring);
-            (pr, result)
+        .filter_map(|url| best.remove(&url))
+        .map(|(pr, query_idx, _)| {
+            pr_to_query_index.insert(pr.url.clone(), query_idx);
+            pr
         })
         .collect();
+    (unique_prs, pr_to_query_index)
+}
+
+/// Drop PRs whose repo is archived. Enriched PRs carry the flag from their
+/// details; other repos (unenriched queries, issues) are looked up once each.
+/// A repo that can't be looked up keeps its PRs.
+async fn drop_archived(client: &octocrab::Octocrab, prs: Vec<PullRequest>) -> Vec<PullRequest> {
+    let mut archived = known_archived_repos(&prs);
+    let unknown: std::collections::BTreeSet<&str> = prs
+        .iter()
+        .map(|pr| pr.repo.as_str())
+        .filter(|repo| !archived.contains_key(*repo))
+        .collect();
+    let lookups =
+        futures::future::join_all(unknown.into_iter().map(|repo| async move {
+            (repo, crate::github::fetch_repo_archived(client, repo).await)
+        }))
+        .await;
+    for (repo, result) in lookups {
+        match result {
+            Ok(is_archived) => {
+                archived.insert(repo.to_string(), is_archived);
+            }
+            Err(e) => log::warn!("Could not check whether {} is archived: {:#}", repo, e),
+        }
+    }
+    prs.into_iter()
+        .filter(|pr| !archived.get(&pr.repo).copied().unwrap_or(false))
+        .collect()
+}
+
+/// Archived flags already known from enrichment, by repo
+fn known_archived_repos(prs: &[PullRequest]) -> HashMap<String, bool> {
+    prs.iter()
+        .filter_map(|pr| Some((pr.repo.clone(), pr.repo_archived?)))
+        .collect()
+}
+
+/// Compare requested reviewers against the persisted last-seen sets and save
+/// the new ones. Best-effort: state file errors are reported, never fatal.
+fn track_review_requests(
+    prs: &mut [PullRequest],
+    username: &str,
+    now: chrono::DateTime<chrono::Utc>,
+) {
+    let path = crate::review_requests::get_review_requests_path();
+    let mut state = crate::review_requests::load_review_request_state(&path).unwrap_or_else(|e| {
+        log::warn!("{}. Starting review request tracking fresh.", e);
+        crate::review_requests::ReviewRequestState::new()
+    });
+    state.update(prs, username, now);
+    if let Err(e) = crate::review_requests::save_review_request_state(&path, &state) {
+        log::warn!("{}", e);
+    }
+}
+
+/// Drop your own PRs when `exclude_own` is set (`is_author` is only set
+/// when the username is known)
+fn drop_own_prs(prs: Vec<PullRequest>, exclude_own: bool) -> Vec<PullRequest> {
+    if !exclude_own {
+        return prs;
+    }
+    let kept: Vec<_> = prs.into_iter().filter(|pr| !pr.is_author).collect();
+    log::info!("After excluding own PRs: {} PRs", kept.len());
+    kept
+}
+
+/// Whether a PR was created longer ago than `max_age` (None means no cutoff)
+fn exceeds_max_age(
+    pr: &PullRequest,
+    max_age: Option<std::time::Duration>,
+    now: chrono::DateTime<chrono::Utc>,
+) -> bool {
+    max_age
+        .and_then(|max| chrono::Duration::from_std(max).ok())
+        .is_some_and(|max| now - pr.created_at > max)
+}
 
-    // Sort both lists by score descending, then by age ascending (older first for ties)
-    let sort_fn = |a: &(PullRequest, ScoreResult), b: &(PullRequest, ScoreResult)| {
+/// Sort scored PRs by score descending. Equal scores are ordered by `tiebreak`:
+/// `Age` puts older PRs first, `Updated` puts most recently updated PRs first.
+pub fn sort_scored_prs(prs: &mut [(PullRequest, ScoreResult)], tiebreak: Tiebreak) {
+    prs.sort_by(|a, b| {
         // Primary: score descending
         let score_cmp =
             b.1.score
@@ -181,18 +609,387 @@ pub async fn fetch_and_score_prs(
         if score_cmp != std::cmp::Ordering::Equal {
             return score_cmp;
         }
-        // Tie-breaker: age ascending (older first = smaller created_at)
-        a.0.created_at.cmp(&b.0.created_at)
+        match tiebreak {
+            // Age ascending (older first = smaller created_at)
+            Tiebreak::Age => a.0.created_at.cmp(&b.0.created_at),
+            // Recency descending (recently updated first = larger updated_at)
+            Tiebreak::Updated => b.0.updated_at.cmp(&a.0.updated_at),
+        }
+    });
+}
+
+/// Flag the PR that has waited longest for your review and move it to the
+/// front, but only once its wait exceeds `threshold`. Waiting counts from the
+/// first review request when known. Your own PRs, issues, and PRs you have
+/// reviewed don't count.
+fn flag_oldest_unreviewed(
+    prs: &mut Vec<(PullRequest, ScoreResult)>,
+    threshold: std::time::Duration,
+    now: chrono::DateTime<chrono::Utc>,
+) {
+    let Ok(threshold) = chrono::Duration::from_std(threshold) else {
+        return;
     };
+    let oldest = prs
+        .iter()
+        .enumerate()
+        .filter(|(_, (pr, _))| !pr.user_has_reviewed && !pr.is_author && !pr.is_issue)
+        .map(|(i, (pr, _))| (i, now - pr.review_requested_at.unwrap_or(pr.created_at)))
+        .filter(|(_, waited)| *waited > threshold)
+        .max_by_key(|(i, waited)| (*waited, std::cmp::Reverse(*i)));
+    if let Some((i, _)) = oldest {
+        let mut entry = prs.remove(i);
+        entry.1.oldest_alert = true;
+        prs.insert(0, entry);
+    }
+}
 
-    active_scored.sort_by(sort_fn);
-    snoozed_scored.sort_by(sort_fn);
+/// Order scored PRs by score per changed line, highest first (`--sort
+/// efficiency`). Stable, so equal values keep their score order.
+pub fn sort_by_efficiency<T: Borrow<(PullRequest, ScoreResult)>>(prs: &mut [T]) {
+    prs.sort_by(|a, b| {
+        let (a, b) = (a.borrow(), b.borrow());
+        efficiency(b.1.score, b.0.size()).total_cmp(&efficiency(a.1.score, a.0.size()))
+    });
+}
 
-    // Fetch rate limit info (best-effort, don't fail the whole fetch if unavailable)
-    let rate_limit_remaining = match client.ratelimit().get().await {
-        Ok(rate_limit) => Some(rate_limit.resources.core.remaining as u64),
-        Err(_) => None,
-    };
+/// Move pinned PRs to the front, keeping the existing (score) order within
+/// the pinned and unpinned groups
+pub fn float_pinned<T: Borrow<(PullRequest, ScoreResult)>>(prs: &mut [T], pins: &PinState) {
+    prs.sort_by_key(|entry| !pins.is_pinned(&entry.borrow().0.url));
+}
+
+/// Drop PRs scoring below `min_score` (`--min-score`). Pinned PRs stay,
+/// since a pin means the PR matters whatever its score.
+pub fn drop_below_min_score(
+    prs: &mut Vec<(PullRequest, ScoreResult)>,
+    min_score: f64,
+    pins: &PinState,
+) {
+    prs.retain(|(pr, result)| result.score >= min_score || pins.is_pinned(&pr.url));
+}
+
+/// Parse a `--min-score` value. Only finite numbers: NaN would hide every
+/// unpinned PR and infinity every one, with no hint why.
+pub fn parse_min_score(value: &str) -> std::result::Result<f64, String> {
+    let score: f64 = value
+        .parse()
+        .map_err(|_| format!("'{}' is not a number", value))?;
+    if !score.is_finite() {
+        return Err(format!("'{}' is not a finite number", value));
+    }
+    Ok(score)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::scoring::ScoreBreakdown;
+    use chrono::{Duration, Utc};
+
+    fn scored_pr(
+        number: u64,
+        score: f64,
+        created_hours_ago: i64,
+        updated_hours_ago: i64,
+    ) -> (PullRequest, ScoreResult) {
+        let pr = PullRequest {
+            title: format!("PR #{}", number),
+            number,
+            author: "author".to_string(),
+            url: format!("https://github.com/owner/repo/pull/{}", number),
+            created_at: Utc::now() - Duration::hours(created_hours_ago),
+            updated_at: Utc::now() - Duration::hours(updated_hours_ago),
+            ..PullRequest::test_default()
+        };
+        let result = ScoreResult {
+            score,
+            incomplete: false,
+            sla_breached: false,
+            oldest_alert: false,
+            breakdown: ScoreBreakdown {
+                base_score: score,
+                factors: vec![],
+                disabled: vec![],
+            },
+        };
+        (pr, result)
+    }
+
+    fn numbers(prs: &[(PullRequest, ScoreResult)]) -> Vec<u64> {
+        prs.iter().map(|(pr, _)| pr.number).collect()
+    }
+
+    #[test]
+    fn test_sort_score_descending_ignores_tiebreak() {
+        let mut prs = vec![scored_pr(1, 10.0, 1, 1), scored_pr(2, 20.0, 1, 1)];
+        sort_scored_prs(&mut prs, Tiebreak::Updated);
+        assert_eq!(numbers(&prs), vec![2, 1]);
+    }
+
+    #[test]
+    fn test_tiebreak_age_oldest_first() {
+        // Equal scores: #1 is newer but recently updated, #2 is older but stale
+        let mut prs = vec![scored_pr(1, 50.0, 2, 1), scored_pr(2, 50.0, 48, 40)];
+        sort_scored_prs(&mut prs, Tiebreak::Age);
+        assert_eq!(numbers(&prs), vec![2, 1]);
+    }
+
+    #[test]
+    fn test_tiebreak_updated_most_recent_first() {
+        let mut prs = vec![scored_pr(2, 50.0, 48, 40), scored_pr(1, 50.0, 2, 1)];
+        sort_scored_prs(&mut prs, Tiebreak::Updated);
+        assert_eq!(numbers(&prs), vec![1, 2]);
+    }
+
+    #[test]
+    fn test_float_pinned_keeps_score_order_within_groups() {
+        let mut prs = vec![
+            scored_pr(1, 40.0, 1, 1),
+            scored_pr(2, 30.0, 1, 1),
+            scored_pr(3, 20.0, 1, 1),
+            scored_pr(4, 10.0, 1, 1),
+        ];
+        let mut pins = PinState::new();
+        pins.toggle("https://github.com/owner/repo/pull/4");
+        pins.toggle("https://github.com/owner/repo/pull/2");
+
+        float_pinned(&mut prs, &pins);
+        assert_eq!(numbers(&prs), vec![2, 4, 1, 3]);
+    }
 
-    Ok((active_scored, snoozed_scored, rate_limit_remaining))
+    #[test]
+    fn test_min_score_keeps_pinned_prs() {
+        let mut prs = vec![
+            scored_pr(1, 40.0, 1, 1),
+            scored_pr(2, 30.0, 1, 1),
+            scored_pr(3, 20.0, 1, 1),
+            scored_pr(4, 10.0, 1, 1),
+        ];
+        let mut pins = PinState::new();
+        pins.toggle("https://github.com/owner/repo/pull/4");
+
+        drop_below_min_score(&mut prs, 30.0, &pins);
+        assert_eq!(numbers(&prs), vec![1, 2, 4]);
+        // The default threshold drops nothing (scores floor at zero)
+        drop_below_min_score(&mut prs, 0.0, &PinState::new());
+        assert_eq!(prs.len(), 3);
+    }
+
+    #[test]
+    fn test_drop_own_prs_only_when_enabled() {
+        let mine = PullRequest {
+            is_author: true,
+            ..PullRequest::test_default()
+        };
+        let theirs = PullRequest {
+            number: 2,
+            ..PullRequest::test_default()
+        };
+        let prs = vec![mine, theirs];
+
+        assert_eq!(drop_own_prs(prs.clone(), false).len(), 2);
+        let kept = drop_own_prs(prs, true);
+        assert_eq!(kept.len(), 1);
+        assert_eq!(kept[0].number, 2);
+    }
+
+    #[test]
+    fn test_parse_min_score_requires_finite_number() {
+        assert_eq!(parse_min_score("12.5"), Ok(12.5));
+        assert_eq!(parse_min_score("-3"), Ok(-3.0));
+        for value in ["NaN", "inf", "-infinity", "high"] {
+            assert!(
+                parse_min_score(value).is_err(),
+                "{} should be rejected",
+                value
+            );
+        }
+    }
+
+    #[test]
+    fn test_score_test_min_score_without_pins() {
+        let dir = std::env::temp_dir().join(format!("pr-bro-score-test-{}", std::process::id()));
+        std::fs::create_dir_all(&dir).unwrap();
+        let item = |number: u64, comments: u32| {
+            serde_json::json!({
+                "number": number,
+                "title": format!("PR {}", number),
+                "html_url": format!("https://github.com/acme/widgets/pull/{}", number),
+                "user": { "login": "octocat" },
+                "created_at": "2024-01-01T00:00:00Z",
+                "updated_at": "2024-01-02T00:00:00Z",
+                "comments": comments,
+                "pull_request": {}
+            })
+        };
+        let dump = serde_json::json!({
+            "request": "GET /search/issues?q=review-requested:@me is:pr",
+            "response": { "items": [item(1, 5), item(2, 0)] },
+        });
+        std::fs::write(dir.join("search-1.json"), dump.to_string()).unwrap();
+        let config: Config = serde_saphyr::from_str(
+            "queries:\n  - query: \"review-requested:@me\"\nscoring:\n  comments: \"+100 per 1\"\n",
+        )
+        .unwrap();
+
+        let mut scored = score_dump(&config, &dir, None).unwrap();
+        std::fs::remove_dir_all(&dir).unwrap();
+        assert_eq!(numbers(&scored), vec![1, 2]);
+
+        // score-test has no pins, so nothing survives the cutoff by pinning
+        let cutoff = (scored[0].1.score + scored[1].1.score) / 2.0;
+        drop_below_min_score(&mut scored, cutoff, &PinState::new());
+        assert_eq!(numbers(&scored), vec![1]);
+    }
+
+    #[test]
+    fn test_score_test_applies_max_age_and_oldest_alert_like_fetch() {
+        let dir =
+            std::env::temp_dir().join(format!("pr-bro-score-test-age-{}", std::process::id()));
+        std::fs::create_dir_all(&dir).unwrap();
+        let recent = (chrono::Utc::now() - chrono::Duration::days(2)).to_rfc3339();
+        let item = |number: u64, created_at: &str| {
+            serde_json::json!({
+                "number": number,
+                "title": format!("PR {}", number),
+                "html_url": format!("https://github.com/acme/widgets/pull/{}", number),
+                "user": { "login": "octocat" },
+                "created_at": created_at,
+                "updated_at": created_at,
+                "pull_request": {}
+            })
+        };
+        let dump = serde_json::json!({
+            "request": "GET /search/issues?q=review-requested:@me is:pr",
+            "response": { "items": [item(1, "2024-01-01T00:00:00Z"), item(2, &recent)] },
+        });
+        std::fs::write(dir.join("search-1.json"), dump.to_string()).unwrap();
+        let config: Config = serde_saphyr::from_str(
+            "queries:\n  - query: \"review-requested:@me\"\nmax_age: 30d\noldest_alert: 1d\n",
+        )
+        .unwrap();
+
+        let scored = score_dump(&config, &dir, None).unwrap();
+        std::fs::remove_dir_all(&dir).unwrap();
+        assert_eq!(numbers(&scored), vec![2]);
+        assert!(scored[0].1.oldest_alert);
+    }
+
+    #[test]
+    fn test_oldest_unreviewed_moves_to_front_past_threshold() {
+        let day = std::time::Duration::from_secs(86_400);
+        let mut reviewed = scored_pr(1, 40.0, 24 * 30, 1);
+        reviewed.0.user_has_reviewed = true;
+        let mut prs = vec![
+            reviewed,
+            scored_pr(2, 30.0, 24 * 3, 1),
+            scored_pr(3, 20.0, 24 * 10, 1),
+            scored_pr(4, 10.0, 24, 1),
+        ];
+
+        // Nothing unreviewed has waited two weeks
+        flag_oldest_unreviewed(&mut prs, 14 * day, Utc::now());
+        assert_eq!(numbers(&prs), vec![1, 2, 3, 4]);
+        assert!(prs.iter().all(|(_, result)| !result.oldest_alert));
+
+        // #1 is older but already reviewed; #3 is the oldest still waiting
+        flag_oldest_unreviewed(&mut prs, 7 * day, Utc::now());
+        assert_eq!(numbers(&prs), vec![3, 1, 2, 4]);
+        assert!(prs[0].1.oldest_alert);
+        assert!(prs[1..].iter().all(|(_, result)| !result.oldest_alert));
+    }
+
+    #[test]
+    fn test_sort_by_efficiency_favors_small_prs() {
+        // 1000 over 500 lines = 2 per line; 300 over 50 = 6; an empty PR counts as 1 line
+        let mut prs = vec![
+            scored_pr(1, 1000.0, 1, 1),
+            scored_pr(2, 300.0, 1, 1),
+            scored_pr(3, 4.0, 1, 1),
+        ];
+        prs[0].0.additions = 500;
+        prs[1].0.additions = 50;
+        sort_by_efficiency(&mut prs);
+        assert_eq!(numbers(&prs), vec![2, 3, 1]);
+    }
+
+    /// Query matches as (pr number, query index), in completion order
+    fn query_matches(matches: &[(u64, usize)]) -> Vec<(PullRequest, usize)> {
+        matches
+            .iter()
+            .map(|&(number, query_idx)| (scored_pr(number, 0.0, 1, 1).0, query_idx))
+            .collect()
+    }
+
+    #[test]
+    fn test_dedup_first_uses_config_order() {
+        // Query 1 finished before query 0, but query 0 still wins
+        let all = query_matches(&[(7, 1), (8, 1), (7, 0)]);
+        let (unique, index) = dedup_prs(all, DedupStrategy::First, |_, _| unreachable!());
+        assert_eq!(
+            unique.iter().map(|pr| pr.number).collect::<Vec<_>>(),
+            vec![7, 8]
+        );
+        assert_eq!(index["https://github.com/owner/repo/pull/7"], 0);
+        assert_eq!(index["https://github.com/owner/repo/pull/8"], 1);
+    }
+
+    #[test]
+    fn test_dedup_highest_score_picks_best_query() {
+        let all = query_matches(&[(7, 0), (7, 1), (7, 2), (8, 2)]);
+        let scores = [10.0, 30.0, 30.0];
+        let (unique, index) = dedup_prs(all, DedupStrategy::HighestScore, |_, query_idx| {
+            scores[query_idx]
+        });
+        assert_eq!(unique.len(), 2);
+        // Tie between queries 1 and 2 goes to the earlier one
+        assert_eq!(index["https://github.com/owner/repo/pull/7"], 1);
+        assert_eq!(index["https://github.com/owner/repo/pull/8"], 2);
+    }
+
+    #[test]
+    fn test_tiebreak_default_is_age() {
+        assert_eq!(Tiebreak::default(), Tiebreak::Age);
+    }
+
+    #[test]
+    fn test_known_archived_repos() {
+        let (mut archived, _) = scored_pr(1, 0.0, 1, 1);
+        archived.repo = "acme/old".to_string();
+        archived.repo_archived = Some(true);
+        let (mut active, _) = scored_pr(2, 0.0, 1, 1);
+        active.repo_archived = Some(false);
+        let (unknown, _) = scored_pr(3, 0.0, 1, 1);
+
+        let known = known_archived_repos(&[archived, unknown, active]);
+        assert_eq!(known.len(), 2);
+        assert!(known["acme/old"]);
+        assert!(!known["owner/repo"]);
+    }
+
+    #[test]
+    fn test_exceeds_max_age() {
+        let (pr, _) = scored_pr(1, 0.0, 24 * 100, 1);
+        let now = Utc::now();
+        let ninety_days = Some(std::time::Duration::from_secs(90 * 86400));
+
+        assert!(exceeds_max_age(&pr, ninety_days, now));
+        assert!(!exceeds_max_age(&pr, None, now));
+
+        let (recent, _) = scored_pr(2, 0.0, 24, 1);
+        assert!(!exceeds_max_age(&recent, ninety_days, now));
+    }
+
+    #[test]
+    fn test_empty_query_notice() {
+        assert_eq!(empty_query_notice(&[]), None);
+        assert_eq!(
+            empty_query_notice(&["team reviews".to_string()]).unwrap(),
+            "no results for query \"team reviews\" (check for typos if that's unexpected)"
+        );
+        assert!(empty_query_notice(&["a".to_string(), "b".to_string()])
+            .unwrap()
+            .starts_with("no results for queries \"a\", \"b\""));
+    }
 }
@@ -2,15 +2,23 @@ use anyhow::{Context, Result};
 use http::{HeaderMap, Uri};
 use octocrab::service::middleware::cache::{CacheKey, CacheStorage, CacheWriter, CachedResponse};
 use std::collections::HashMap;
-use std::path::PathBuf;
+use std::io::{Read, Write};
+use std::path::{Path, PathBuf};
 use std::sync::{Arc, Mutex};
 
 /// Configuration for HTTP response caching
 #[derive(Clone, Debug)]
 pub struct CacheConfig {
-    pub enabled: bool, // false when --no-cache
+    pub enabled: bool,  // false when --no-cache
+    pub compress: bool, // gzip new disk entries (config: cache_compression)
+    /// Skip cache lookups but still store fresh responses (--refresh-once)
+    pub bypass_reads: bool,
 }
 
+/// Prefix marking a gzip-compressed disk entry. Entries without it are plain
+/// JSON, so caches written before compression was enabled still load.
+const COMPRESSED_MARKER: &[u8] = b"gz1:";
+
 /// Get the platform-appropriate cache directory for pr-bro
 pub fn get_cache_path() -> PathBuf {
     dirs::cache_dir()
@@ -33,6 +41,98 @@ pub fn clear_cache() -> Result<()> {
     }
 }
 
+/// Remove cached responses for one repository (`owner/name`), leaving other
+/// repos' entries alone. Returns the number of entries removed.
+pub fn clear_repo_cache(repo: &str) -> Result<usize> {
+    check_repo(repo)?;
+    remove_repo_entries(&get_cache_path(), repo)
+}
+
+fn check_repo(repo: &str) -> Result<()> {
+    let parts: Vec<&str> = repo.split('/').collect();
+    if parts.len() != 2 || parts.iter().any(|p| p.is_empty()) {
+        anyhow::bail!("Expected a repo as owner/name, got '{}'", repo);
+    }
+    Ok(())
+}
+
+fn remove_repo_entries(cache_path: &Path, repo: &str) -> Result<usize> {
+    if !cache_path.exists() {
+        return Ok(0);
+    }
+    let mut removed = 0usize;
+    for entry in cacache::list_sync(cache_path) {
+        let entry = entry.context("Failed to list cache entries")?;
+        if key_matches_repo(&entry.key, repo) {
+            cacache::remove_sync(cache_path, &entry.key).context("Failed to remove cache entry")?;
+            removed += 1;
+        }
+    }
+    Ok(removed)
+}
+
+/// Whether a cache key (a request URI) is for the given repo's API endpoints,
+/// e.g. `/repos/owner/name/pulls/1`. Search responses span repos and never match.
+fn key_matches_repo(key: &str, repo: &str) -> bool {
+    let Ok(uri) = key.parse::<Uri>() else {
+        return false;
+    };
+    let prefix = format!("/repos/{}", repo.trim_matches('/')).to_lowercase();
+    let path = uri.path().to_lowercase();
+    path == prefix || path.starts_with(&format!("{}/", prefix))
+}
+
+/// Number and total size of cached responses
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct CacheStats {
+    pub entries: usize,
+    pub bytes: u64,
+}
+
+impl CacheStats {
+    /// Human-readable summary, e.g. "42 entries, 1.3 MB"
+    pub fn describe(&self) -> String {
+        let size = if self.bytes >= 1024 * 1024 {
+            format!("{:.1} MB", self.bytes as f64 / (1024.0 * 1024.0))
+        } else if self.bytes >= 1024 {
+            format!("{:.1} KB", self.bytes as f64 / 1024.0)
+        } else {
+            format!("{} B", self.bytes)
+        };
+        let noun = if self.entries == 1 {
+            "entry"
+        } else {
+            "entries"
+        };
+        format!("{} {}, {}", self.entries, noun, size)
+    }
+}
+
+/// Count cached responses, optionally only those for one repo (`owner/name`)
+pub fn cache_stats(repo: Option<&str>) -> Result<CacheStats> {
+    if let Some(repo) = repo {
+        check_repo(repo)?;
+    }
+    count_entries(&get_cache_path(), repo)
+}
+
+fn count_entries(cache_path: &Path, repo: Option<&str>) -> Result<CacheStats> {
+    let mut stats = CacheStats::default();
+    if !cache_path.exists() {
+        return Ok(stats);
+    }
+    for entry in cacache::list_sync(cache_path) {
+        let entry = entry.context("Failed to list cache entries")?;
+        if repo.is_none_or(|repo| key_matches_repo(&entry.key, repo)) {
+            stats.entries += 1;
+            // The index doesn't record sizes for our writes, so measure the content
+            stats.bytes += cacache::read_hash_sync(cache_path, &entry.integrity)
+                .map_or(0, |data| data.len() as u64);
+        }
+    }
+    Ok(stats)
+}
+
 /// Evict cache entries older than 7 days. Returns number of entries removed.
 /// Best-effort: errors during listing or removal are silently ignored.
 pub fn evict_stale_entries() -> usize {
@@ -63,6 +163,8 @@ pub fn evict_stale_entries() -> usize {
 pub struct DiskCache {
     inner: Arc<Mutex<CacheData>>,
     cache_path: PathBuf,
+    compress: bool,
+    bypass_reads: bool,
 }
 
 struct CacheData {
@@ -130,7 +232,7 @@ impl DiskCacheEntry {
 }
 
 impl DiskCache {
-    pub fn new(cache_path: PathBuf) -> Self {
+    pub fn new(cache_path: PathBuf, config: &CacheConfig) -> Self {
         // Don't pre-load disk cache - entries are loaded on demand
         Self {
             inner: Arc::new(Mutex::new(CacheData {
@@ -138,6 +240,8 @@ impl DiskCache {
                 responses: HashMap::new(),
             })),
             cache_path,
+            compress: config.compress,
+            bypass_reads: config.bypass_reads,
         }
     }
 
@@ -148,16 +252,28 @@ impl DiskCache {
         data.responses.clear();
     }
 
-    /// Try to load a cache entry from disk
+    /// Try to load a cache entry from disk. Entries that can't be read back
+    /// are removed, so a bad write doesn't fail on every later run.
     fn load_from_disk(&self, uri_key: &str) -> Option<CacheKey> {
         // Try to read from disk
-        let bytes = cacache::read_sync(&self.cache_path, uri_key).ok()?;
-
-        // Deserialize
-        let entry: DiskCacheEntry = serde_json::from_slice(&bytes).ok()?;
+        let bytes = match cacache::read_sync(&self.cache_path, uri_key) {
+            Ok(bytes) => bytes,
+            Err(cacache::Error::EntryNotFound(..)) => return None,
+            Err(e) => {
+                self.discard_entry(uri_key, &e.into());
+                return None;
+            }
+        };
 
-        // Convert to CacheKey and CachedResponse
-        let (key, response) = entry.to_parts().ok()?;
+        // Deserialize (compressed or plain, regardless of the current setting)
+        // and convert to CacheKey and CachedResponse
+        let (key, response) = match decode_entry(&bytes).and_then(|entry| entry.to_parts()) {
+            Ok(parts) => parts,
+            Err(e) => {
+                self.discard_entry(uri_key, &e);
+                return None;
+            }
+        };
 
         // Populate in-memory cache for subsequent hits
         let mut data = self.inner.lock().unwrap();
@@ -166,10 +282,22 @@ impl DiskCache {
 
         Some(key)
     }
+
+    /// Remove an unreadable disk entry; the next request refetches it
+    fn discard_entry(&self, uri_key: &str, error: &anyhow::Error) {
+        log::debug!("Removing unreadable cache entry {}: {:#}", uri_key, error);
+        let _ = cacache::remove_sync(&self.cache_path, uri_key);
+    }
 }
 
 impl CacheStorage for DiskCache {
     fn try_hit(&self, uri: &Uri) -> Option<CacheKey> {
+        // No hit means no conditional request, so the response is fetched in
+        // full and then written through `writer` as usual
+        if self.bypass_reads {
+            return None;
+        }
+
         let uri_key = uri.to_string();
 
         // Check in-memory first
@@ -193,6 +321,7 @@ impl CacheStorage for DiskCache {
         Box::new(DiskCacheWriter {
             cache: self.inner.clone(),
             cache_path: self.cache_path.clone(),
+            compress: self.compress,
             uri_key: uri.to_string(),
             key,
             response: CachedResponse {
@@ -207,6 +336,7 @@ impl CacheStorage for DiskCache {
 struct DiskCacheWriter {
     cache: Arc<Mutex<CacheData>>,
     cache_path: PathBuf,
+    compress: bool,
     uri_key: String,
     key: CacheKey,
     response: CachedResponse,
@@ -243,18 +373,51 @@ impl Drop for DiskCacheWriter {
 
         // Write to disk (fire-and-forget, don't block on disk errors)
         let entry = DiskCacheEntry::from_parts(&key, &response);
-        if let Ok(serialized) = serde_json::to_vec(&entry) {
+        if let Ok(serialized) = encode_entry(&entry, self.compress) {
             let _ = cacache::write_sync(&self.cache_path, &uri_key, &serialized);
         }
     }
 }
 
+/// Serialize an entry for disk, gzip-compressing it behind `COMPRESSED_MARKER`
+fn encode_entry(entry: &DiskCacheEntry, compress: bool) -> Result<Vec<u8>> {
+    let json = serde_json::to_vec(entry)?;
+    if !compress {
+        return Ok(json);
+    }
+    let mut encoder =
+        flate2::write::GzEncoder::new(COMPRESSED_MARKER.to_vec(), flate2::Compression::default());
+    encoder.write_all(&json)?;
+    Ok(encoder.finish()?)
+}
+
+fn decode_entry(bytes: &[u8]) -> Result<DiskCacheEntry> {
+    match bytes.strip_prefix(COMPRESSED_MARKER) {
+        Some(compressed) => {
+            let mut json = Vec::new();
+            flate2::read::GzDecoder::new(compressed)
+                .read_to_end(&mut json)
+                .context("Failed to decompress cache entry")?;
+            Ok(serde_json::from_slice(&json)?)
+        }
+        None => Ok(serde_json::from_slice(bytes)?),
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
     use http::{HeaderMap, Uri};
     use octocrab::service::middleware::cache::{CacheKey, CacheStorage};
 
+    fn config(compress: bool, bypass_reads: bool) -> CacheConfig {
+        CacheConfig {
+            enabled: true,
+            compress,
+            bypass_reads,
+        }
+    }
+
     fn unique_cache_path(test_name: &str) -> PathBuf {
         let timestamp = std::time::SystemTime::now()
             .duration_since(std::time::UNIX_EPOCH)
@@ -266,7 +429,7 @@ mod tests {
     #[test]
     fn test_valid_json_is_cached() {
         let cache_path = unique_cache_path("valid");
-        let cache = DiskCache::new(cache_path.clone());
+        let cache = DiskCache::new(cache_path.clone(), &config(false, false));
 
         let uri = Uri::from_static("https://api.github.com/repos/test/test/pulls/1");
         let key = CacheKey::ETag("test-etag".to_string());
@@ -285,10 +448,118 @@ mod tests {
         let _ = std::fs::remove_dir_all(&cache_path);
     }
 
+    #[test]
+    fn test_key_matches_repo() {
+        assert!(key_matches_repo(
+            "https://api.github.com/repos/Owner/Name/pulls/1",
+            "owner/name"
+        ));
+        assert!(key_matches_repo(
+            "https://api.github.com/repos/owner/name",
+            "owner/name"
+        ));
+        // Same prefix, different repo
+        assert!(!key_matches_repo(
+            "https://api.github.com/repos/owner/name-two/pulls/1",
+            "owner/name"
+        ));
+        assert!(!key_matches_repo(
+            "https://api.github.com/search/issues?q=repo%3Aowner%2Fname",
+            "owner/name"
+        ));
+    }
+
+    #[test]
+    fn test_remove_repo_entries_keeps_other_repos() {
+        let cache_path = unique_cache_path("repo-clear");
+        let cache = DiskCache::new(cache_path.clone(), &config(false, false));
+        for uri in [
+            "https://api.github.com/repos/acme/widgets/pulls/1",
+            "https://api.github.com/repos/acme/widgets/pulls/1/reviews",
+            "https://api.github.com/repos/acme/gadgets/pulls/2",
+        ] {
+            let uri: Uri = uri.parse().unwrap();
+            let mut writer =
+                cache.writer(&uri, CacheKey::ETag("etag".to_string()), HeaderMap::new());
+            writer.write_body(b"{}");
+        }
+
+        assert_eq!(remove_repo_entries(&cache_path, "acme/widgets").unwrap(), 2);
+        let remaining: Vec<String> = cacache::list_sync(&cache_path)
+            .flatten()
+            .map(|entry| entry.key)
+            .collect();
+        assert_eq!(
+            remaining,
+            vec!["https://api.github.com/repos/acme/gadgets/pulls/2".to_string()]
+        );
+
+        let _ = std::fs::remove_dir_all(&cache_path);
+    }
+
+    #[test]
+    fn test_count_entries_by_repo() {
+        let cache_path = unique_cache_path("stats");
+        assert_eq!(
+            count_entries(&cache_path, None).unwrap(),
+            CacheStats::default()
+        );
+
+        let cache = DiskCache::new(cache_path.clone(), &config(false, false));
+        for uri in [
+            "https://api.github.com/repos/acme/widgets/pulls/1",
+            "https://api.github.com/repos/acme/gadgets/pulls/2",
+        ] {
+            let uri: Uri = uri.parse().unwrap();
+            let mut writer =
+                cache.writer(&uri, CacheKey::ETag("etag".to_string()), HeaderMap::new());
+            writer.write_body(b"{}");
+        }
+
+        let all = count_entries(&cache_path, None).unwrap();
+        assert_eq!(all.entries, 2);
+        assert!(all.bytes > 0);
+        let one = count_entries(&cache_path, Some("acme/widgets")).unwrap();
+        assert_eq!(one.entries, 1);
+        assert!(one.bytes < all.bytes);
+
+        let _ = std::fs::remove_dir_all(&cache_path);
+    }
+
+    #[test]
+    fn test_cache_stats_describe() {
+        let stats = |entries, bytes| CacheStats { entries, bytes };
+        assert_eq!(stats(1, 512).describe(), "1 entry, 512 B");
+        assert_eq!(stats(3, 2048).describe(), "3 entries, 2.0 KB");
+        assert_eq!(stats(40, 3 * 1024 * 1024).describe(), "40 entries, 3.0 MB");
+    }
+
+    #[test]
+    fn test_corrupt_disk_entry_is_removed_after_failed_load() {
+        let cache_path = unique_cache_path("corrupt");
+        let uri = Uri::from_static("https://api.github.com/repos/test/test/pulls/3");
+        cacache::write_sync(&cache_path, uri.to_string(), b"not json").unwrap();
+        // Valid JSON that `to_parts` rejects (no ETag or Last-Modified)
+        let no_key = Uri::from_static("https://api.github.com/repos/test/test/pulls/4");
+        cacache::write_sync(
+            &cache_path,
+            no_key.to_string(),
+            br#"{"etag":null,"last_modified":null,"headers":[],"body":[]}"#,
+        )
+        .unwrap();
+
+        let cache = DiskCache::new(cache_path.clone(), &config(false, false));
+        assert!(cache.try_hit(&uri).is_none());
+        assert!(cache.try_hit(&no_key).is_none());
+        assert_eq!(cacache::list_sync(&cache_path).flatten().count(), 0);
+
+        let _ = std::fs::remove_dir_all(&cache_path);
+    }
+
     #[test]
     fn test_truncated_json_is_not_cached() {
         let cache_path = unique_cache_path("truncated");
-        let cache = DiskCache::new(cache_path.clone());
+        let cache = DiskCache::new(cache_path.clone(), &config(false, false));
 
         let uri = Uri::from_static("https://api.github.com/repos/test/test/pulls/2");
         let key = CacheKey::ETag("test-etag-2".to_string());
@@ -310,7 +581,7 @@ mod tests {
     #[test]
     fn test_empty_body_is_not_cached() {
         let cache_path = unique_cache_path("empty");
-        let cache = DiskCache::new(cache_path.clone());
+        let cache = DiskCache::new(cache_path.clone(), &config(false, false));
 
         let uri = Uri::from_static("https://api.github.com/repos/test/test/pulls/3");
         let key = CacheKey::ETag("test-etag-3".to_string());
@@ -328,4 +599,60 @@ mod tests {
         // Cleanup
         let _ = std::fs::remove_dir_all(&cache_path);
     }
+
+    #[test]
+    fn test_compressed_and_plain_entries_both_load() {
+        let cache_path = unique_cache_path("compressed");
+        let uri = Uri::from_static("https://api.github.com/repos/test/test/pulls/4");
+        let body = br#"{"files":[{"filename":"src/main.rs","patch":"@@ -1 +1 @@"}]}"#;
+
+        // Write compressed, then read back with compression off (and vice versa)
+        let writer_cache = DiskCache::new(cache_path.clone(), &config(true, false));
+        let mut writer =
+            writer_cache.writer(&uri, CacheKey::ETag("e4".to_string()), HeaderMap::new());
+        writer.write_body(body);
+        drop(writer);
+
+        let raw = cacache::read_sync(&cache_path, uri.to_string()).unwrap();
+        assert!(raw.starts_with(COMPRESSED_MARKER));
+
+        let reader_cache = DiskCache::new(cache_path.clone(), &config(false, false));
+        assert!(reader_cache.try_hit(&uri).is_some());
+        assert_eq!(reader_cache.load(&uri).unwrap().body, body.to_vec());
+
+        // Entries written before compression existed are plain JSON
+        let plain_uri = Uri::from_static("https://api.github.com/repos/test/test/pulls/5");
+        let mut writer = reader_cache.writer(
+            &plain_uri,
+            CacheKey::ETag("e5".to_string()),
+            HeaderMap::new(),
+        );
+        writer.write_body(body);
+        drop(writer);
+        let compressed_reader = DiskCache::new(cache_path.clone(), &config(true, false));
+        assert!(compressed_reader.try_hit(&plain_uri).is_some());
+
+        // Cleanup
+        let _ = std::fs::remove_dir_all(&cache_path);
+    }
+
+    #[test]
+    fn test_bypass_reads_still_writes() {
+        let cache_path = unique_cache_path("bypass");
+        let uri = Uri::from_static("https://api.github.com/repos/test/test/pulls/6");
+
+        let refresh_cache = DiskCache::new(cache_path.clone(), &config(false, true));
+        let mut writer =
+            refresh_cache.writer(&uri, CacheKey::ETag("e6".to_string()), HeaderMap::new());
+        writer.write_body(br#"{"id":6}"#);
+        drop(writer);
+
+        // Never hits itself, but a later normal run sees the fresh entry
+        assert!(refresh_cache.try_hit(&uri).is_none());
+        let next_run = DiskCache::new(cache_path.clone(), &config(false, false));
+        assert!(next_run.try_hit(&uri).is_some());
+
+        // Cleanup
+        let _ = std::fs::remove_dir_all(&cache_path);
+    }
 }
@@ -13,7 +13,7 @@ pub fn create_client(
 
     let cache_handle = if cache_config.enabled {
         let cache_path = get_cache_path();
-        let disk_cache = DiskCache::new(cache_path);
+        let disk_cache = DiskCache::new(cache_path, cache_config);
         let cache_handle = Arc::new(disk_cache.clone());
         builder = builder.cache(disk_cache);
         Some(cache_handle)
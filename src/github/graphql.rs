@@ -0,0 +1,461 @@
+//! Optional GraphQL enrichment (`use_graphql: true`): one query per PR
+//! replaces the REST details, reviews, and timeline calls, and adds GitHub's
+//! `reviewDecision`, whether your re-review was requested, and the head
+//! commit's CI rollup.
+
+use anyhow::{anyhow, Context, Result};
+use chrono::{DateTime, Utc};
+use octocrab::Octocrab;
+use serde::Deserialize;
+
+use crate::github::search::{dump_raw, pr_dump_name, PrDetails, ReviewSummary, ReviewerRules};
+use crate::github::types::{CiStatus, ReviewDecision};
+
+/// Page sizes are GitHub's maximum; PRs with more reviews or review requests
+/// than this are rare, and only the counts would be off.
+const PR_REVIEW_QUERY: &str = r#"
+query($owner: String!, $name: String!, $number: Int!) {
+  repository(owner: $owner, name: $name) {
+    pullRequest(number: $number) {
+      additions
+      deletions
+      changedFiles
+      isDraft
+      baseRefName
+      headRefOid
+      reviewDecision
+      repository { isArchived }
+      commits(last: 1) {
+        nodes { commit { statusCheckRollup { state } } }
+      }
+      reviewRequests(first: 100) {
+        nodes { requestedReviewer { ... on User { login } ... on Team { slug } } }
+      }
+      reviews(first: 100) {
+        nodes { state submittedAt commit { oid } author { __typename login } }
+      }
+      timelineItems(first: 100, itemTypes: [REVIEW_REQUESTED_EVENT]) {
+        nodes {
+          ... on ReviewRequestedEvent {
+            createdAt
+            requestedReviewer { ... on User { login } }
+          }
+        }
+      }
+    }
+  }
+}
+"#;
+
+#[derive(Debug, Deserialize)]
+struct GraphqlResponse {
+    data: Option<ResponseData>,
+    #[serde(default)]
+    errors: Vec<GraphqlError>,
+}
+
+#[derive(Debug, Deserialize)]
+struct GraphqlError {
+    message: String,
+}
+
+#[derive(Debug, Deserialize)]
+struct ResponseData {
+    repository: Option<Repository>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct Repository {
+    pull_request: Option<GraphqlPr>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct GraphqlPr {
+    additions: u64,
+    deletions: u64,
+    changed_files: u64,
+    is_draft: bool,
+    base_ref_name: String,
+    head_ref_oid: String,
+    review_decision: Option<ReviewDecision>,
+    /// Absent in dumps taken before it was queried
+    #[serde(default)]
+    repository: Option<PrRepository>,
+    /// The head commit; absent in dumps taken before it was queried
+    #[serde(default)]
+    commits: Option<Nodes<PrCommit>>,
+    review_requests: Nodes<ReviewRequest>,
+    reviews: Nodes<GraphqlReview>,
+    timeline_items: Nodes<ReviewRequestedEvent>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct PrRepository {
+    is_archived: bool,
+}
+
+#[derive(Debug, Deserialize)]
+struct PrCommit {
+    commit: Commit,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct Commit {
+    /// Null when the commit has no checks or statuses
+    status_check_rollup: Option<StatusCheckRollup>,
+}
+
+#[derive(Debug, Deserialize)]
+struct StatusCheckRollup {
+    state: String,
+}
+
+impl StatusCheckRollup {
+    fn ci_status(&self) -> Option<CiStatus> {
+        match self.state.as_str() {
+            "SUCCESS" => Some(CiStatus::Success),
+            "FAILURE" | "ERROR" => Some(CiStatus::Failure),
+            "PENDING" | "EXPECTED" => Some(CiStatus::Pending),
+            _ => None,
+        }
+    }
+}
+
+#[derive(Debug, Deserialize)]
+struct Nodes<T> {
+    nodes: Vec<T>,
+}
+
+/// Team and bot reviewers come back without a login (teams have a `slug`
+/// in review requests); `__typename` is only asked for on review authors,
+/// to tell apps (`Bot`) from users
+#[derive(Debug, Deserialize)]
+struct Actor {
+    login: Option<String>,
+    slug: Option<String>,
+    #[serde(rename = "__typename")]
+    typename: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct ReviewRequest {
+    requested_reviewer: Option<Actor>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct GraphqlReview {
+    state: String,
+    submitted_at: Option<DateTime<Utc>>,
+    /// The head commit the review was made on
+    commit: Option<ReviewCommit>,
+    author: Option<Actor>,
+}
+
+#[derive(Debug, Deserialize)]
+struct ReviewCommit {
+    oid: String,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct ReviewRequestedEvent {
+    created_at: Option<DateTime<Utc>>,
+    requested_reviewer: Option<Actor>,
+}
+
+/// Everything the GraphQL query yields for one PR
+#[derive(Debug)]
+pub(super) struct GraphqlReviewData {
+    pub details: PrDetails,
+    pub reviews: ReviewSummary,
+    pub review_decision: Option<ReviewDecision>,
+    /// Your review was requested again after your last review
+    pub re_review_requested: bool,
+    /// First review request, for `age_basis: review_requested`
+    pub first_review_request: Option<DateTime<Utc>>,
+    /// CI rollup of the head commit
+    pub ci_status: Option<CiStatus>,
+}
+
+/// Fetch a PR's size, reviews, and review requests in one GraphQL query
+pub(super) async fn fetch_pr_review_data(
+    client: &Octocrab,
+    owner: &str,
+    repo: &str,
+    number: u64,
+    auth_username: Option<&str>,
+    reviewers: &ReviewerRules,
+) -> Result<GraphqlReviewData> {
+    let payload = serde_json::json!({
+        "query": PR_REVIEW_QUERY,
+        "variables": { "owner": owner, "name": repo, "number": number },
+    });
+    let raw: serde_json::Value = client
+        .graphql(&payload)
+        .await
+        .context("Failed to fetch PR review data")?;
+    dump_raw(
+        &pr_dump_name(owner, repo, number, "graphql"),
+        &format!("POST /graphql (pull request {}/{}#{})", owner, repo, number),
+        &raw,
+    );
+    parse_review_data(raw, auth_username, reviewers)
+}
+
+/// Summarize a raw GraphQL response for one PR (fetched, or read back from a dump)
+pub(super) fn parse_review_data(
+    raw: serde_json::Value,
+    auth_username: Option<&str>,
+    reviewers: &ReviewerRules,
+) -> Result<GraphqlReviewData> {
+    let response: GraphqlResponse =
+        serde_json::from_value(raw).context("Unexpected GraphQL response")?;
+    let pr = pull_request_from(response)?;
+    Ok(summarize(pr, auth_username, reviewers))
+}
+
+/// Unwrap the PR from a response, surfacing GraphQL errors (which come back
+/// with a 200 status)
+fn pull_request_from(response: GraphqlResponse) -> Result<GraphqlPr> {
+    if !response.errors.is_empty() {
+        let messages: Vec<String> = response.errors.into_iter().map(|e| e.message).collect();
+        return Err(anyhow!("GraphQL error: {}", messages.join("; ")));
+    }
+    response
+        .data
+        .and_then(|d| d.repository)
+        .and_then(|r| r.pull_request)
+        .ok_or_else(|| anyhow!("GraphQL response has no pull request"))
+}
+
+fn summarize(
+    pr: GraphqlPr,
+    auth_username: Option<&str>,
+    reviewers: &ReviewerRules,
+) -> GraphqlReviewData {
+    let is_me = |actor: &Option<Actor>| {
+        auth_username.is_some_and(|username| {
+            actor
+                .as_ref()
+                .and_then(|a| a.login.as_deref())
+                .is_some_and(|login| login.eq_ignore_ascii_case(username))
+        })
+    };
+
+    let reviews = &pr.reviews.nodes;
+    let approvals = reviews
+        .iter()
+        .filter(|r| r.state == "APPROVED")
+        .filter(|r| {
+            r.author.as_ref().is_none_or(|a| {
+                let is_app = a.typename.as_deref() == Some("Bot");
+                reviewers.counts_approval(a.login.as_deref().unwrap_or_default(), is_app)
+            })
+        })
+        .count() as u32;
+    // Your own change requests don't block you from reviewing
+    let changes_requested = reviews
+        .iter()
+        .filter(|r| r.state == "CHANGES_REQUESTED" && !is_me(&r.author))
+        .count() as u32;
+    let user_has_reviewed = reviews.iter().any(|r| is_me(&r.author));
+    let my_last_review_at = reviews
+        .iter()
+        .filter(|r| is_me(&r.author))
+        .filter_map(|r| r.submitted_at)
+        .max();
+    // Same rule as REST: comments don't replace your verdict
+    let my_changes_requested_commit = reviews
+        .iter()
+        .filter(|r| is_me(&r.author))
+        .filter(|r| !matches!(r.state.as_str(), "COMMENTED" | "PENDING"))
+        .max_by_key(|r| r.submitted_at)
+        .filter(|r| r.state == "CHANGES_REQUESTED")
+        .and_then(|r| Some(r.commit.as_ref()?.oid.clone()));
+    let approved_by_trusted = reviews.iter().any(|r| {
+        r.state == "APPROVED"
+            && r.author
+                .as_ref()
+                .and_then(|a| a.login.as_deref())
+                .is_some_and(|login| reviewers.is_trusted(login))
+    });
+
+    // Same rule as the REST timeline: your first request, else anyone's
+    let requests = &pr.timeline_items.nodes;
+    let my_requests = || {
+        requests
+            .iter()
+            .filter(|e| is_me(&e.requested_reviewer))
+            .filter_map(|e| e.created_at)
+    };
+    let first_review_request = my_requests()
+        .min()
+        .or_else(|| requests.iter().filter_map(|e| e.created_at).min());
+    let re_review_requested = my_last_review_at
+        .is_some_and(|reviewed_at| my_requests().any(|requested_at| requested_at > reviewed_at));
+
+    let ci_status = pr
+        .commits
+        .as_ref()
+        .and_then(|commits| commits.nodes.last())
+        .and_then(|node| node.commit.status_check_rollup.as_ref())
+        .and_then(StatusCheckRollup::ci_status);
+
+    GraphqlReviewData {
+        details: PrDetails {
+            additions: pr.additions,
+            deletions: pr.deletions,
+            draft: pr.is_draft,
+            changed_files: Some(pr.changed_files),
+            requested_reviewers: pr
+                .review_requests
+                .nodes
+                .iter()
+                .filter_map(|r| r.requested_reviewer.as_ref()?.login.clone())
+                .collect(),
+            requested_teams: pr
+                .review_requests
+                .nodes
+                .into_iter()
+                .filter_map(|r| r.requested_reviewer?.slug)
+                .collect(),
+            base_ref: pr.base_ref_name,
+            head_sha: pr.head_ref_oid,
+            repo_archived: pr.repository.map(|repo| repo.is_archived),
+        },
+        reviews: ReviewSummary {
+            approvals,
+            changes_requested,
+            user_has_reviewed,
+            approved_by_trusted,
+            my_last_review_at,
+            my_changes_requested_commit,
+        },
+        review_decision: pr.review_decision,
+        re_review_requested,
+        first_review_request,
+        ci_status,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const RESPONSE: &str = r#"{
+        "data": {
+            "repository": {
+                "pullRequest": {
+                    "additions": 120,
+                    "deletions": 30,
+                    "changedFiles": 4,
+                    "isDraft": false,
+                    "baseRefName": "main",
+                    "headRefOid": "abc123",
+                    "reviewDecision": "CHANGES_REQUESTED",
+                    "commits": {
+                        "nodes": [{ "commit": { "statusCheckRollup": { "state": "FAILURE" } } }]
+                    },
+                    "reviewRequests": {
+                        "nodes": [
+                            { "requestedReviewer": { "login": "me" } },
+                            { "requestedReviewer": { "slug": "platform" } },
+                            { "requestedReviewer": {} }
+                        ]
+                    },
+                    "reviews": {
+                        "nodes": [
+                            { "state": "CHANGES_REQUESTED", "submittedAt": "2024-01-02T00:00:00Z", "commit": { "oid": "old456" }, "author": { "login": "Me" } },
+                            { "state": "APPROVED", "submittedAt": "2024-01-03T00:00:00Z", "author": { "login": "lead" } },
+                            { "state": "APPROVED", "submittedAt": "2024-01-03T00:00:00Z", "author": { "__typename": "Bot", "login": "dependabot" } },
+                            { "state": "CHANGES_REQUESTED", "submittedAt": "2024-01-03T00:00:00Z", "author": { "login": "bob" } }
+                        ]
+                    },
+                    "timelineItems": {
+                        "nodes": [
+                            { "createdAt": "2024-01-01T00:00:00Z", "requestedReviewer": { "login": "me" } },
+                            { "createdAt": "2024-01-04T00:00:00Z", "requestedReviewer": { "login": "me" } },
+                            {}
+                        ]
+                    }
+                }
+            }
+        }
+    }"#;
+
+    fn parse(json: &str) -> Result<GraphqlPr> {
+        pull_request_from(serde_json::from_str(json).unwrap())
+    }
+
+    #[test]
+    fn test_summarize_graphql_pr() {
+        let data = summarize(
+            parse(RESPONSE).unwrap(),
+            Some("me"),
+            &ReviewerRules {
+                trusted: vec!["lead".to_string()],
+                ..ReviewerRules::default()
+            },
+        );
+
+        assert_eq!(data.details.additions, 120);
+        assert_eq!(data.details.changed_files, Some(4));
+        assert_eq!(data.details.requested_reviewers, vec!["me".to_string()]);
+        assert_eq!(data.details.requested_teams, vec!["platform".to_string()]);
+        // The app's approval is left out
+        assert_eq!(data.reviews.approvals, 1);
+        // Your own change request is not counted
+        assert_eq!(data.reviews.changes_requested, 1);
+        assert!(data.reviews.user_has_reviewed);
+        assert!(data.reviews.approved_by_trusted);
+        assert_eq!(
+            data.reviews.my_changes_requested_commit.as_deref(),
+            Some("old456")
+        );
+        assert_eq!(data.review_decision, Some(ReviewDecision::ChangesRequested));
+        assert_eq!(
+            data.first_review_request,
+            Some("2024-01-01T00:00:00Z".parse().unwrap())
+        );
+        // Requested again on the 4th, after reviewing on the 2nd
+        assert!(data.re_review_requested);
+        assert_eq!(data.ci_status, Some(CiStatus::Failure));
+    }
+
+    #[test]
+    fn test_no_re_review_without_a_later_request() {
+        let response = RESPONSE.replace("2024-01-04T00:00:00Z", "2024-01-01T12:00:00Z");
+        let data = summarize(
+            parse(&response).unwrap(),
+            Some("me"),
+            &ReviewerRules::default(),
+        );
+        assert!(!data.re_review_requested);
+        assert!(!data.reviews.approved_by_trusted);
+
+        // Never reviewed, so a request is a first request, not a re-request
+        let data = summarize(
+            parse(RESPONSE).unwrap(),
+            Some("someone-else"),
+            &ReviewerRules::default(),
+        );
+        assert!(!data.re_review_requested);
+    }
+
+    #[test]
+    fn test_graphql_errors_are_surfaced() {
+        let err = parse(
+            r#"{ "data": null, "errors": [{ "message": "Could not resolve to a Repository" }] }"#,
+        )
+        .unwrap_err();
+        assert!(err
+            .to_string()
+            .contains("Could not resolve to a Repository"));
+    }
+}
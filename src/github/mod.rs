@@ -1,9 +1,17 @@
 pub mod cache;
 pub mod client;
+mod graphql;
+pub mod replay;
 pub mod search;
 pub mod types;
 
-pub use cache::{clear_cache, evict_stale_entries, get_cache_path, CacheConfig, DiskCache};
+pub use cache::{
+    cache_stats, clear_cache, clear_repo_cache, evict_stale_entries, get_cache_path, CacheConfig,
+    CacheStats, DiskCache,
+};
 pub use client::create_client;
-pub use search::{search_and_enrich_prs, search_prs};
-pub use types::PullRequest;
+pub use search::{
+    fetch_changed_files, fetch_repo_archived, search_and_enrich_prs, search_prs, set_dump_dir,
+    take_observed_rate_limit, ChangedFile, ReviewerRules, SearchTiming,
+};
+pub use types::{PullRequest, ReviewDecision};
@@ -0,0 +1,339 @@
+//! Rebuild PRs from a `--dump-raw` directory without touching the network,
+//! for `pr-bro score-test`. Search dumps give the PRs; the per-PR dumps next
+//! to them fill in what enrichment fetched.
+
+use anyhow::{anyhow, Context, Result};
+use octocrab::models::pulls::Review;
+use octocrab::models::timelines::TimelineEvent;
+use serde::de::DeserializeOwned;
+use serde::Deserialize;
+use std::path::{Path, PathBuf};
+
+use crate::github::graphql;
+use crate::github::search::{
+    apply_details_and_reviews, apply_file_totals, apply_size_exclusions, combine_ci_status,
+    count_patch_hunks, first_review_request, pr_dump_name, summarize_reviews, ChangedFile,
+    CheckRunsResponse, CombinedStatus, CompareResponse, PrDetails, ReviewerRules, SearchResponse,
+};
+use crate::github::types::PullRequest;
+use crate::scoring::{AgeBasis, SizeMetric};
+
+/// PRs from one search dump, with the query that produced them
+#[derive(Debug)]
+pub struct DumpedSearch {
+    /// The search query as sent, which may have ` is:pr` appended
+    pub query: String,
+    pub prs: Vec<PullRequest>,
+}
+
+/// A dump file as written by `--dump-raw`
+#[derive(Debug, Deserialize)]
+struct Dump<T> {
+    request: String,
+    response: T,
+}
+
+/// The fields of a dumped `GET /pulls/N` response that enrichment reads
+#[derive(Debug, Deserialize)]
+struct DumpedDetails {
+    additions: Option<u64>,
+    deletions: Option<u64>,
+    draft: Option<bool>,
+    changed_files: Option<u64>,
+    #[serde(default)]
+    requested_reviewers: Option<Vec<DumpedUser>>,
+    #[serde(default)]
+    requested_teams: Option<Vec<DumpedTeam>>,
+    base: DumpedRef,
+    head: DumpedRef,
+}
+
+#[derive(Debug, Deserialize)]
+struct DumpedUser {
+    login: String,
+}
+
+#[derive(Debug, Deserialize)]
+struct DumpedTeam {
+    slug: String,
+}
+
+#[derive(Debug, Deserialize)]
+struct DumpedRef {
+    #[serde(rename = "ref")]
+    ref_field: String,
+    sha: String,
+    #[serde(default)]
+    repo: Option<DumpedRepo>,
+}
+
+#[derive(Debug, Deserialize)]
+struct DumpedRepo {
+    archived: Option<bool>,
+}
+
+#[derive(Debug, Deserialize)]
+struct DumpedFile {
+    filename: String,
+    additions: u64,
+    deletions: u64,
+    patch: Option<String>,
+}
+
+/// Search dumps to replay: `input` itself when it's a file, otherwise every
+/// `search-N.json` in the directory, in the order they were fetched
+pub fn load_search_dumps(input: &Path) -> Result<Vec<DumpedSearch>> {
+    let paths = if input.is_dir() {
+        let mut numbered: Vec<(usize, PathBuf)> = std::fs::read_dir(input)
+            .with_context(|| format!("Failed to read {}", input.display()))?
+            .filter_map(|entry| {
+                let path = entry.ok()?.path();
+                let seq = search_dump_seq(path.file_name()?.to_str()?)?;
+                Some((seq, path))
+            })
+            .collect();
+        numbered.sort();
+        numbered.into_iter().map(|(_, path)| path).collect()
+    } else {
+        vec![input.to_path_buf()]
+    };
+    if paths.is_empty() {
+        return Err(anyhow!(
+            "No search dumps (search-N.json) in {}",
+            input.display()
+        ));
+    }
+
+    paths
+        .iter()
+        .map(|path| {
+            let dump: Dump<SearchResponse> =
+                read_dump(path)?.ok_or_else(|| anyhow!("{} not found", path.display()))?;
+            let query = dump
+                .request
+                .strip_prefix("GET /search/issues?q=")
+                .ok_or_else(|| anyhow!("{} is not a search dump", path.display()))?
+                .to_string();
+            let prs = dump
+                .response
+                .items
+                .into_iter()
+                .map(|item| item.into_pull_request())
+                .collect();
+            Ok(DumpedSearch { query, prs })
+        })
+        .collect()
+}
+
+/// `N` of a `search-N.json` file name
+fn search_dump_seq(file_name: &str) -> Option<usize> {
+    file_name
+        .strip_prefix("search-")?
+        .strip_suffix(".json")?
+        .parse()
+        .ok()
+}
+
+/// Fill in a PR from the per-PR dumps in `dir`, the way enrichment would
+/// have. A PR without details and reviews dumps (not enriched, or enriched
+/// before dumping was on) is left as the search returned it.
+pub fn replay_enrichment(
+    dir: &Path,
+    pr: &mut PullRequest,
+    auth_username: Option<&str>,
+    exclude_patterns: &[String],
+    size_metric: SizeMetric,
+    age_basis: AgeBasis,
+    reviewers: &ReviewerRules,
+) -> Result<()> {
+    if let Some(username) = auth_username {
+        pr.is_author = pr.author.eq_ignore_ascii_case(username);
+    }
+    if pr.is_issue {
+        return Ok(());
+    }
+    let (owner, repo) = pr
+        .repo
+        .split_once('/')
+        .map(|(owner, repo)| (owner.to_string(), repo.to_string()))
+        .ok_or_else(|| anyhow!("Invalid repo format: {}", pr.repo))?;
+    let number = pr.number;
+    let path = |kind: &str| dir.join(pr_dump_name(&owner, &repo, number, kind));
+
+    let mut have_review_requests = false;
+    if let Some(dump) = read_dump::<serde_json::Value>(&path("graphql"))? {
+        let data = graphql::parse_review_data(dump.response, auth_username, reviewers)?;
+        apply_details_and_reviews(pr, &data.details, &data.reviews, reviewers);
+        pr.review_decision = data.review_decision;
+        pr.re_review_requested = data.re_review_requested;
+        pr.ci_status = data.ci_status;
+        if age_basis == AgeBasis::ReviewRequested {
+            pr.review_requested_at = data.first_review_request;
+        }
+        have_review_requests = true;
+    } else {
+        let details = read_dump::<DumpedDetails>(&path("details"))?;
+        let reviews = read_dump::<Vec<Review>>(&path("reviews"))?;
+        let (Some(details), Some(reviews)) = (details, reviews) else {
+            return Ok(());
+        };
+        let details = details.response;
+        let details = PrDetails {
+            additions: details.additions.unwrap_or(0),
+            deletions: details.deletions.unwrap_or(0),
+            draft: details.draft.unwrap_or(false),
+            changed_files: details.changed_files,
+            requested_reviewers: details
+                .requested_reviewers
+                .unwrap_or_default()
+                .into_iter()
+                .map(|user| user.login)
+                .collect(),
+            requested_teams: details
+                .requested_teams
+                .unwrap_or_default()
+                .into_iter()
+                .map(|team| team.slug)
+                .collect(),
+            repo_archived: details.base.repo.and_then(|repo| repo.archived),
+            base_ref: details.base.ref_field,
+            head_sha: details.head.sha,
+        };
+        let reviews = summarize_reviews(&reviews.response, auth_username, reviewers);
+        apply_details_and_reviews(pr, &details, &reviews, reviewers);
+
+        let checks = read_dump::<CheckRunsResponse>(&path("checks"))?;
+        let status = read_dump::<CombinedStatus>(&path("status"))?;
+        if let (Some(checks), Some(status)) = (checks, status) {
+            pr.ci_status = combine_ci_status(&checks.response, &status.response);
+        }
+    }
+
+    // Exclusions are applied here, from the dumped file list, so they can be
+    // tuned without fetching again
+    let count_hunks = size_metric == SizeMetric::Hunks;
+    if !exclude_patterns.is_empty() || count_hunks {
+        if let Some(dump) = read_dump::<Vec<DumpedFile>>(&path("files"))? {
+            let files: Vec<ChangedFile> = dump
+                .response
+                .into_iter()
+                .map(|f| ChangedFile {
+                    hunks: count_hunks.then(|| f.patch.as_deref().map_or(1, count_patch_hunks)),
+                    filename: f.filename,
+                    additions: f.additions,
+                    deletions: f.deletions,
+                })
+                .collect();
+            let totals = apply_size_exclusions(&files, exclude_patterns)?;
+            apply_file_totals(pr, totals, !exclude_patterns.is_empty());
+        }
+    }
+
+    if let Some(dump) = read_dump::<CompareResponse>(&path("compare"))? {
+        pr.commits_behind = Some(dump.response.behind_by);
+    }
+
+    if age_basis == AgeBasis::ReviewRequested && !have_review_requests {
+        if let Some(dump) = read_dump::<Vec<TimelineEvent>>(&path("timeline"))? {
+            pr.review_requested_at = first_review_request(&dump.response, auth_username);
+        }
+    }
+
+    Ok(())
+}
+
+/// Read one dump file; None when it doesn't exist
+fn read_dump<T: DeserializeOwned>(path: &Path) -> Result<Option<Dump<T>>> {
+    let json = match std::fs::read_to_string(path) {
+        Ok(json) => json,
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => return Ok(None),
+        Err(e) => return Err(e).with_context(|| format!("Failed to read {}", path.display())),
+    };
+    serde_json::from_str(&json)
+        .map(Some)
+        .with_context(|| format!("Failed to parse {}", path.display()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn write(dir: &Path, name: &str, request: &str, response: serde_json::Value) {
+        let body = serde_json::json!({ "request": request, "response": response });
+        std::fs::write(dir.join(name), body.to_string()).unwrap();
+    }
+
+    #[test]
+    fn test_search_dump_seq() {
+        assert_eq!(search_dump_seq("search-12.json"), Some(12));
+        assert_eq!(search_dump_seq("acme-widgets-42-details.json"), None);
+    }
+
+    #[test]
+    fn test_replay_dump_directory() {
+        let dir = std::env::temp_dir().join(format!("pr-bro-replay-{}", std::process::id()));
+        std::fs::create_dir_all(&dir).unwrap();
+        write(
+            &dir,
+            "search-1.json",
+            "GET /search/issues?q=review-requested:@me is:pr",
+            serde_json::json!({ "items": [{
+                "number": 42,
+                "title": "Add widgets",
+                "html_url": "https://github.com/acme/widgets/pull/42",
+                "user": { "login": "octocat" },
+                "created_at": "2024-01-01T00:00:00Z",
+                "updated_at": "2024-01-02T00:00:00Z",
+                "pull_request": {}
+            }]}),
+        );
+        write(
+            &dir,
+            "acme-widgets-42-details.json",
+            "GET /repos/acme/widgets/pulls/42",
+            serde_json::json!({
+                "additions": 120, "deletions": 30, "draft": false, "changed_files": 3,
+                "requested_reviewers": [{ "login": "me" }],
+                "base": { "ref": "main", "sha": "aaa" },
+                "head": { "ref": "feature", "sha": "bbb" }
+            }),
+        );
+        write(
+            &dir,
+            "acme-widgets-42-reviews.json",
+            "GET /repos/acme/widgets/pulls/42/reviews",
+            serde_json::json!([]),
+        );
+        write(
+            &dir,
+            "acme-widgets-42-files.json",
+            "GET /repos/acme/widgets/pulls/42/files",
+            serde_json::json!([
+                { "filename": "src/lib.rs", "additions": 20, "deletions": 10 },
+                { "filename": "Cargo.lock", "additions": 100, "deletions": 20 }
+            ]),
+        );
+
+        let searches = load_search_dumps(&dir).unwrap();
+        assert_eq!(searches.len(), 1);
+        assert_eq!(searches[0].query, "review-requested:@me is:pr");
+        let mut pr = searches[0].prs[0].clone();
+        replay_enrichment(
+            &dir,
+            &mut pr,
+            Some("OctoCat"),
+            &["*.lock".to_string()],
+            SizeMetric::Lines,
+            AgeBasis::default(),
+            &ReviewerRules::default(),
+        )
+        .unwrap();
+        std::fs::remove_dir_all(&dir).unwrap();
+
+        assert_eq!(pr.additions + pr.deletions, 150);
+        assert_eq!(pr.filtered_size, Some(30));
+        assert_eq!(pr.changed_files, Some(1));
+        assert_eq!(pr.requested_reviewers, Some(vec!["me".to_string()]));
+        assert!(pr.is_author);
+    }
+}
@@ -1,19 +1,207 @@
-use crate::buffered_eprintln;
 use anyhow::{anyhow, Context, Result};
+use chrono::{DateTime, Utc};
 use futures::stream::{FuturesUnordered, StreamExt};
+use octocrab::models::pulls::{Review, ReviewState};
+use octocrab::models::timelines::TimelineEvent;
+use octocrab::models::Event;
 use octocrab::Octocrab;
-use std::sync::atomic::{AtomicBool, Ordering};
-use std::sync::Arc;
+use serde::{Deserialize, Serialize};
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicBool, AtomicU64, AtomicUsize, Ordering};
+use std::sync::{Arc, OnceLock};
+use std::time::{Duration, Instant};
 
-use crate::github::types::PullRequest;
+use crate::config::RateLimitGuard;
+use crate::github::graphql;
+use crate::github::types::{CiStatus, PullRequest};
+use crate::scoring::{AgeBasis, ExcludePattern, SizeMetric};
+
+/// Directory for `--dump-raw` response dumps (debug only; unset in normal runs)
+static DUMP_DIR: OnceLock<PathBuf> = OnceLock::new();
+
+/// Numbers search dumps, since one run can search several queries
+static SEARCH_DUMP_SEQ: AtomicUsize = AtomicUsize::new(0);
+
+/// Lowest core rate limit remaining seen on an enrichment response since the
+/// last `take_observed_rate_limit`; `u64::MAX` when none was seen
+static OBSERVED_CORE_REMAINING: AtomicU64 = AtomicU64::new(u64::MAX);
+
+/// Core rate limit remaining from a response's headers. Search and GraphQL
+/// responses report their own, separate limits, so only headers for the
+/// `core` resource count.
+fn core_remaining_from_headers(headers: &http::HeaderMap) -> Option<u64> {
+    let resource = headers.get("x-ratelimit-resource")?.to_str().ok()?;
+    if resource != "core" {
+        return None;
+    }
+    headers
+        .get("x-ratelimit-remaining")?
+        .to_str()
+        .ok()?
+        .parse()
+        .ok()
+}
+
+/// The core rate limit remaining as of the latest enrichment response, then
+/// forget it. Responses finish out of order, so the lowest value seen is the
+/// latest. None when no response carried the headers.
+pub fn take_observed_rate_limit() -> Option<u64> {
+    match OBSERVED_CORE_REMAINING.swap(u64::MAX, Ordering::Relaxed) {
+        u64::MAX => None,
+        remaining => Some(remaining),
+    }
+}
+
+/// Debug only: write the JSON of every search and enrichment response to
+/// `dir` for the rest of the process. Only response bodies are written,
+/// never request headers, so the Authorization token doesn't reach disk.
+pub fn set_dump_dir(dir: PathBuf) -> Result<()> {
+    std::fs::create_dir_all(&dir)
+        .with_context(|| format!("Failed to create dump directory {}", dir.display()))?;
+    DUMP_DIR
+        .set(dir)
+        .map_err(|_| anyhow!("Dump directory already set"))
+}
+
+/// Write one response to the dump directory, if dumping is enabled.
+/// Failures only warn: a debugging aid must never break a fetch.
+pub(super) fn dump_raw<T: Serialize>(file_name: &str, request: &str, response: &T) {
+    let Some(dir) = DUMP_DIR.get() else {
+        return;
+    };
+    if let Err(e) = write_dump(dir, file_name, request, response) {
+        log::warn!("Failed to dump {}: {}", file_name, e);
+    }
+}
+
+fn write_dump<T: Serialize>(
+    dir: &Path,
+    file_name: &str,
+    request: &str,
+    response: &T,
+) -> Result<()> {
+    let body = serde_json::json!({ "request": request, "response": response });
+    let json = serde_json::to_string_pretty(&body)?;
+    std::fs::write(dir.join(file_name), json)?;
+    Ok(())
+}
+
+/// Dump file name for one enrichment response, e.g. `owner-repo-42-reviews.json`
+pub(super) fn pr_dump_name(owner: &str, repo: &str, number: u64, kind: &str) -> String {
+    format!("{}-{}-{}-{}.json", owner, repo, number, kind)
+}
+
+/// Subset of the `/search/issues` response that pr-bro needs.
+#[derive(Debug, Deserialize)]
+pub(super) struct SearchResponse {
+    pub(super) items: Vec<SearchItem>,
+}
+
+#[derive(Debug, Deserialize)]
+pub(super) struct SearchItem {
+    number: u64,
+    title: String,
+    html_url: String,
+    user: SearchUser,
+    #[serde(default)]
+    labels: Vec<SearchLabel>,
+    created_at: DateTime<Utc>,
+    updated_at: DateTime<Utc>,
+    /// Present only when the item is a pull request
+    pub(super) pull_request: Option<serde_json::Value>,
+    /// Draft state, included for pull requests in search results
+    #[serde(default)]
+    draft: Option<bool>,
+    /// Reaction rollup, included in search results at no extra cost
+    #[serde(default)]
+    reactions: Option<SearchReactions>,
+    /// Conversation comment count, also included at no extra cost
+    #[serde(default)]
+    comments: u32,
+}
+
+#[derive(Debug, Deserialize)]
+struct SearchUser {
+    login: String,
+}
+
+#[derive(Debug, Deserialize)]
+struct SearchLabel {
+    name: String,
+}
+
+#[derive(Debug, Deserialize)]
+struct SearchReactions {
+    total_count: u32,
+}
+
+impl SearchItem {
+    pub(super) fn into_pull_request(self) -> PullRequest {
+        // Extract owner/repo from html_url
+        // Format: "https://github.com/owner/repo/pull/123"
+        let path = self
+            .html_url
+            .parse::<http::Uri>()
+            .map(|uri| uri.path().to_string())
+            .unwrap_or_default();
+        let parts: Vec<&str> = path.split('/').filter(|s| !s.is_empty()).collect();
+        let repo = if parts.len() >= 2 {
+            format!("{}/{}", parts[0], parts[1])
+        } else {
+            "unknown/unknown".to_string()
+        };
+
+        PullRequest {
+            title: self.title,
+            number: self.number,
+            author: self.user.login,
+            repo,
+            url: self.html_url,
+            created_at: self.created_at,
+            updated_at: self.updated_at,
+            additions: 0,                       // Search API doesn't include these
+            deletions: 0,                       // Will be populated by enrichment
+            approvals: 0,                       // Requires separate API call
+            draft: self.draft.unwrap_or(false), // Overwritten by Pulls API on enrichment
+            is_issue: self.pull_request.is_none(),
+            labels: self.labels.into_iter().map(|l| l.name).collect(),
+            user_has_reviewed: false,   // Will be populated by enrichment
+            approved_by_trusted: false, // Will be populated by enrichment
+            my_last_review_at: None,    // Will be populated by enrichment
+            updated_since_my_review: false, // Will be populated by enrichment
+            re_review_requested: false, // Only populated by GraphQL enrichment
+            review_decision: None,      // Only populated by GraphQL enrichment
+            changes_requested_count: 0, // Will be populated by enrichment
+            requested_reviewers: None,  // Will be populated by enrichment
+            changed_files: None,        // Will be populated by enrichment
+            hunks: None,                // Only counted for the `hunks` size metric
+            review_request_withdrawn: false, // Set after fetch by review request tracking
+            is_author: false,           // Set by search_and_enrich_prs
+            reactions: self.reactions.map_or(0, |r| r.total_count),
+            comments: self.comments,
+            team_review_requested: false, // Will be populated by enrichment
+            ci_status: None,              // Only fetched for `ci_status` scoring
+            commits_behind: None,         // Only compared for `commits_behind` scoring
+            review_requested_at: None,    // Only fetched for `age_basis: review_requested`
+            filtered_size: None,          // Will be set by enrich_pr if exclude patterns configured
+            group: None,                  // Set by fetch_and_score_prs
+            repo_archived: None,          // Set by enrich_pr from the PR details
+        }
+    }
+}
 
 /// Search GitHub for pull requests matching the given query.
+/// With `include_issues`, issues matching the query are kept too.
 /// Auth errors (401 / Bad credentials) fail immediately as a typed AuthError.
 /// Rate limit and permission errors also fail immediately.
 /// Transient/network errors are retried up to 3 times with exponential backoff.
-pub async fn search_prs(client: &Octocrab, query: &str) -> Result<Vec<PullRequest>> {
-    // Ensure the query only returns PRs, not issues
-    let query = if query.contains("is:pr") {
+pub async fn search_prs(
+    client: &Octocrab,
+    query: &str,
+    include_issues: bool,
+) -> Result<Vec<PullRequest>> {
+    // Ensure the query only returns PRs, not issues (unless issues are wanted)
+    let query = if include_issues || query.contains("is:pr") {
         query.to_string()
     } else {
         format!("{} is:pr", query)
@@ -24,45 +212,26 @@ pub async fn search_prs(client: &Octocrab, query: &str) -> Result<Vec<PullReques
 
     loop {
         attempt += 1;
+        // Raw request rather than octocrab's typed search so the `draft` flag,
+        // which octocrab's Issue model drops, survives deserialization
         match client
-            .search()
-            .issues_and_pull_requests(&query)
-            .send()
+            .get::<serde_json::Value, _, _>("/search/issues", Some(&[("q", query.as_str())]))
             .await
         {
-            Ok(results) => {
+            Ok(raw) => {
+                let seq = SEARCH_DUMP_SEQ.fetch_add(1, Ordering::Relaxed) + 1;
+                dump_raw(
+                    &format!("search-{}.json", seq),
+                    &format!("GET /search/issues?q={}", query),
+                    &raw,
+                );
+                let results: SearchResponse =
+                    serde_json::from_value(raw).context("Failed to parse search response")?;
                 let prs: Vec<PullRequest> = results
                     .items
                     .into_iter()
-                    .filter(|issue| issue.pull_request.is_some()) // Only PRs, not issues
-                    .map(|issue| {
-                        // Extract owner/repo from html_url
-                        // Format: "https://github.com/owner/repo/pull/123"
-                        let path = issue.html_url.path();
-                        let parts: Vec<&str> = path.split('/').filter(|s| !s.is_empty()).collect();
-                        let repo = if parts.len() >= 2 {
-                            format!("{}/{}", parts[0], parts[1])
-                        } else {
-                            "unknown/unknown".to_string()
-                        };
-
-                        PullRequest {
-                            title: issue.title,
-                            number: issue.number,
-                            author: issue.user.login.clone(),
-                            repo,
-                            url: issue.html_url.to_string(),
-                            created_at: issue.created_at,
-                            updated_at: issue.updated_at,
-                            additions: 0, // Search API doesn't include these
-                            deletions: 0, // Will be populated by enrichment
-                            approvals: 0, // Requires separate API call
-                            draft: false, // Populated during enrichment from Pulls API
-                            labels: issue.labels.iter().map(|l| l.name.clone()).collect(),
-                            user_has_reviewed: false, // Will be populated by enrichment
-                            filtered_size: None, // Will be set by enrich_pr if exclude patterns configured
-                        }
-                    })
+                    .filter(|item| include_issues || item.pull_request.is_some())
+                    .map(SearchItem::into_pull_request)
                     .collect();
                 return Ok(prs);
             }
@@ -109,72 +278,377 @@ pub async fn search_prs(client: &Octocrab, query: &str) -> Result<Vec<PullReques
     }
 }
 
-/// Fetch PR details (additions, deletions) from the GitHub API
+/// Size, draft flag, requested reviewer logins, and the branch tips to
+/// compare for `commits_behind`, from the Pulls API
+#[derive(Debug)]
+pub(super) struct PrDetails {
+    pub(super) additions: u64,
+    pub(super) deletions: u64,
+    pub(super) draft: bool,
+    pub(super) changed_files: Option<u64>,
+    pub(super) requested_reviewers: Vec<String>,
+    /// Slugs of requested teams
+    pub(super) requested_teams: Vec<String>,
+    pub(super) base_ref: String,
+    pub(super) head_sha: String,
+    /// The base repo is archived (None when the response doesn't say)
+    pub(super) repo_archived: Option<bool>,
+}
+
+/// Whether a repo ("owner/name") is archived, for PRs whose details didn't say
+pub async fn fetch_repo_archived(client: &Octocrab, repo: &str) -> Result<bool> {
+    let (owner, name) = repo
+        .split_once('/')
+        .ok_or_else(|| anyhow!("Invalid repo format: {}", repo))?;
+    let repository = client
+        .repos(owner, name)
+        .get()
+        .await
+        .context("Failed to fetch repository")?;
+    Ok(repository.archived.unwrap_or(false))
+}
+
+/// Fetch PR details (additions, deletions, draft, requested reviewers) from the GitHub API
 async fn fetch_pr_details(
     client: &Octocrab,
     owner: &str,
     repo: &str,
     number: u64,
-) -> Result<(u64, u64, bool)> {
-    let pr = client
-        .pulls(owner, repo)
-        .get(number)
+) -> Result<PrDetails> {
+    // Raw request rather than `pulls().get()` so the rate limit headers can
+    // be read off the response instead of costing a call of their own
+    let route = format!("/repos/{}/{}/pulls/{}", owner, repo, number);
+    let response = client
+        ._get(route.as_str())
+        .await
+        .context("Failed to fetch PR details")?;
+    let response = octocrab::map_github_error(response)
         .await
         .context("Failed to fetch PR details")?;
+    if let Some(remaining) = core_remaining_from_headers(response.headers()) {
+        OBSERVED_CORE_REMAINING.fetch_min(remaining, Ordering::Relaxed);
+    }
+    let body = client
+        .body_to_string(response)
+        .await
+        .context("Failed to fetch PR details")?;
+    let pr: octocrab::models::pulls::PullRequest =
+        serde_json::from_str(&body).context("Failed to parse PR details")?;
+    dump_raw(
+        &pr_dump_name(owner, repo, number, "details"),
+        &format!("GET /repos/{}/{}/pulls/{}", owner, repo, number),
+        &pr,
+    );
+
+    Ok(PrDetails {
+        additions: pr.additions.unwrap_or(0),
+        deletions: pr.deletions.unwrap_or(0),
+        draft: pr.draft.unwrap_or(false),
+        changed_files: pr.changed_files,
+        requested_reviewers: pr
+            .requested_reviewers
+            .unwrap_or_default()
+            .into_iter()
+            .map(|user| user.login)
+            .collect(),
+        requested_teams: pr
+            .requested_teams
+            .unwrap_or_default()
+            .into_iter()
+            .map(|team| team.slug)
+            .collect(),
+        repo_archived: pr.base.repo.as_ref().and_then(|repo| repo.archived),
+        base_ref: pr.base.ref_field,
+        head_sha: pr.head.sha,
+    })
+}
+
+#[derive(Debug, Deserialize)]
+pub(super) struct CompareResponse {
+    pub(super) behind_by: u32,
+}
+
+/// How many commits `base_ref` has that `head_sha` doesn't, from the compare
+/// API. Fails with a 404 when the base branch no longer exists.
+async fn fetch_commits_behind(
+    client: &Octocrab,
+    owner: &str,
+    repo: &str,
+    number: u64,
+    base_ref: &str,
+    head_sha: &str,
+) -> Result<u32> {
+    let route = format!(
+        "/repos/{}/{}/compare/{}...{}",
+        owner, repo, base_ref, head_sha
+    );
+    // Only the counts are needed; keep the commit list in the response short
+    let raw: serde_json::Value = client
+        .get(&route, Some(&[("per_page", "1")]))
+        .await
+        .context("Failed to compare PR with its base branch")?;
+    dump_raw(
+        &pr_dump_name(owner, repo, number, "compare"),
+        &format!("GET {}", route),
+        &raw,
+    );
+    let compare: CompareResponse =
+        serde_json::from_value(raw).context("Failed to parse compare response")?;
+    Ok(compare.behind_by)
+}
+
+#[derive(Debug, Deserialize)]
+pub(super) struct CheckRunsResponse {
+    /// All runs on the commit, including any past the first page
+    #[serde(default)]
+    pub(super) total_count: u64,
+    pub(super) check_runs: Vec<CheckRun>,
+}
+
+#[derive(Debug, Deserialize)]
+pub(super) struct CheckRun {
+    status: String,
+    conclusion: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+pub(super) struct CombinedStatus {
+    state: String,
+    total_count: u64,
+}
+
+/// One CI result from a commit's check runs and its legacy commit statuses:
+/// any failure wins, then anything still running; None when neither has
+/// anything to report. Only the first page of check runs is fetched, so
+/// runs past it count as pending: one of them may have failed.
+pub(super) fn combine_ci_status(
+    checks: &CheckRunsResponse,
+    status: &CombinedStatus,
+) -> Option<CiStatus> {
+    let unseen = (checks.total_count > checks.check_runs.len() as u64).then_some(CiStatus::Pending);
+    let runs = checks.check_runs.iter().map(|run| {
+        if run.status != "completed" {
+            return CiStatus::Pending;
+        }
+        match run.conclusion.as_deref() {
+            Some("failure" | "timed_out" | "cancelled" | "action_required") => CiStatus::Failure,
+            Some("success" | "neutral" | "skipped") => CiStatus::Success,
+            _ => CiStatus::Pending,
+        }
+    });
+    // A commit without statuses reports "pending"; only count real ones
+    let statuses = (status.total_count > 0).then_some(match status.state.as_str() {
+        "success" => CiStatus::Success,
+        "failure" | "error" => CiStatus::Failure,
+        _ => CiStatus::Pending,
+    });
+    let all: Vec<CiStatus> = runs.chain(unseen).chain(statuses).collect();
+    [CiStatus::Failure, CiStatus::Pending, CiStatus::Success]
+        .into_iter()
+        .find(|status| all.contains(status))
+}
 
-    let additions = pr.additions.unwrap_or(0);
-    let deletions = pr.deletions.unwrap_or(0);
-    let draft = pr.draft.unwrap_or(false);
+/// CI result for the PR's head commit, from its check runs and combined
+/// commit status
+async fn fetch_ci_status(
+    client: &Octocrab,
+    owner: &str,
+    repo: &str,
+    number: u64,
+    head_sha: &str,
+) -> Result<Option<CiStatus>> {
+    let checks_route = format!("/repos/{}/{}/commits/{}/check-runs", owner, repo, head_sha);
+    let status_route = format!("/repos/{}/{}/commits/{}/status", owner, repo, head_sha);
+    let (checks, status): (serde_json::Value, serde_json::Value) = tokio::try_join!(
+        client.get(&checks_route, Some(&[("per_page", "100")])),
+        client.get(&status_route, None::<&()>),
+    )
+    .context("Failed to fetch CI status")?;
+    dump_raw(
+        &pr_dump_name(owner, repo, number, "checks"),
+        &format!("GET {}", checks_route),
+        &checks,
+    );
+    dump_raw(
+        &pr_dump_name(owner, repo, number, "status"),
+        &format!("GET {}", status_route),
+        &status,
+    );
+    let checks: CheckRunsResponse =
+        serde_json::from_value(checks).context("Failed to parse check runs")?;
+    let status: CombinedStatus =
+        serde_json::from_value(status).context("Failed to parse commit status")?;
+    Ok(combine_ci_status(&checks, &status))
+}
 
-    Ok((additions, deletions, draft))
+/// Whose reviews count for what: trusted reviewers trigger
+/// `approved_by_trusted`, bots' approvals are left out of the approval
+/// count unless `count_bot_approvals` is set, and requests to your `teams`
+/// trigger `team_review_requested`
+#[derive(Debug, Clone, Default)]
+pub struct ReviewerRules {
+    pub trusted: Vec<String>,
+    /// Logins to treat as bots besides GitHub Apps (`[bot]` logins)
+    pub bots: Vec<String>,
+    pub count_bot_approvals: bool,
+    /// Your teams, as "org/slug" or a bare slug matching in any org
+    pub teams: Vec<String>,
 }
 
-/// Fetch PR review count (approved reviews) and check if authenticated user has reviewed
+impl ReviewerRules {
+    /// Whether a team requested on a PR in `repo` ("owner/name") is yours
+    pub(super) fn is_my_team(&self, repo: &str, slug: &str) -> bool {
+        let owner = repo.split_once('/').map_or(repo, |(owner, _)| owner);
+        self.teams.iter().any(|team| match team.split_once('/') {
+            Some((org, team)) => org.eq_ignore_ascii_case(owner) && team.eq_ignore_ascii_case(slug),
+            None => team.eq_ignore_ascii_case(slug),
+        })
+    }
+
+    pub(super) fn is_trusted(&self, login: &str) -> bool {
+        self.trusted.iter().any(|t| login.eq_ignore_ascii_case(t))
+    }
+
+    /// `is_app` is GitHub's own account type; apps show up as `name[bot]`
+    /// in REST responses but as a bare `name` in GraphQL
+    pub(super) fn is_bot(&self, login: &str, is_app: bool) -> bool {
+        is_app
+            || login.to_ascii_lowercase().ends_with("[bot]")
+            || self.bots.iter().any(|b| login.eq_ignore_ascii_case(b))
+    }
+
+    /// Whether an approval from this reviewer goes into the approval count
+    pub(super) fn counts_approval(&self, login: &str, is_app: bool) -> bool {
+        self.count_bot_approvals || !self.is_bot(login, is_app)
+    }
+}
+
+/// Review-derived facts about a PR, collected from a single reviews fetch
+#[derive(Debug, Default)]
+pub(super) struct ReviewSummary {
+    pub(super) approvals: u32,
+    pub(super) changes_requested: u32,
+    pub(super) user_has_reviewed: bool,
+    pub(super) approved_by_trusted: bool,
+    pub(super) my_last_review_at: Option<DateTime<Utc>>,
+    /// Head commit of your latest review, when that review requested changes
+    pub(super) my_changes_requested_commit: Option<String>,
+}
+
+/// Fetch PR reviews and summarize them
 async fn fetch_pr_reviews(
     client: &Octocrab,
     owner: &str,
     repo: &str,
     number: u64,
     auth_username: Option<&str>,
-) -> Result<(u32, bool)> {
+    reviewers: &ReviewerRules,
+) -> Result<ReviewSummary> {
     let reviews = client
         .pulls(owner, repo)
         .list_reviews(number)
         .send()
         .await
         .context("Failed to fetch PR reviews")?;
+    dump_raw(
+        &pr_dump_name(owner, repo, number, "reviews"),
+        &format!("GET /repos/{}/{}/pulls/{}/reviews", owner, repo, number),
+        &reviews.items,
+    );
+
+    Ok(summarize_reviews(&reviews.items, auth_username, reviewers))
+}
 
+/// Count approvals (leaving out bots' unless configured) and others' change
+/// requests, check if the authenticated user has reviewed (and when they last
+/// did), and check whether any trusted reviewer has approved
+pub(super) fn summarize_reviews(
+    reviews: &[Review],
+    auth_username: Option<&str>,
+    reviewers: &ReviewerRules,
+) -> ReviewSummary {
     let approved_count = reviews
-        .items
         .iter()
+        .filter(|review| matches!(review.state, Some(ReviewState::Approved)))
         .filter(|review| {
-            matches!(
-                review.state,
-                Some(octocrab::models::pulls::ReviewState::Approved)
-            )
+            review.user.as_ref().is_none_or(|u| {
+                reviewers.counts_approval(&u.login, u.r#type.eq_ignore_ascii_case("bot"))
+            })
         })
         .count() as u32;
 
-    // Check if authenticated user has reviewed (any review state counts)
-    let user_has_reviewed = auth_username.is_some_and(|username| {
-        reviews.items.iter().any(|r| {
+    let is_me = |r: &Review| {
+        auth_username.is_some_and(|username| {
             r.user
                 .as_ref()
                 .is_some_and(|u| u.login.eq_ignore_ascii_case(username))
         })
+    };
+
+    // Your own change requests don't block you from reviewing
+    let changes_requested = reviews
+        .iter()
+        .filter(|r| matches!(r.state, Some(ReviewState::ChangesRequested)) && !is_me(r))
+        .count() as u32;
+
+    // Check if authenticated user has reviewed (any review state counts)
+    let user_has_reviewed = reviews.iter().any(is_me);
+    let my_last_review_at = reviews
+        .iter()
+        .filter(|r| is_me(r))
+        .filter_map(|r| r.submitted_at)
+        .max();
+    // Comments don't replace your verdict, as on GitHub: approving and then
+    // commenting still leaves you approved
+    let my_changes_requested_commit = reviews
+        .iter()
+        .filter(|r| is_me(r))
+        .filter(|r| {
+            !matches!(
+                r.state,
+                None | Some(ReviewState::Commented | ReviewState::Pending)
+            )
+        })
+        .max_by_key(|r| r.submitted_at)
+        .filter(|r| matches!(r.state, Some(ReviewState::ChangesRequested)))
+        .and_then(|r| r.commit_id.clone());
+
+    let approved_by_trusted = reviews.iter().any(|r| {
+        matches!(r.state, Some(ReviewState::Approved))
+            && r.user
+                .as_ref()
+                .is_some_and(|u| reviewers.is_trusted(&u.login))
     });
 
-    Ok((approved_count, user_has_reviewed))
+    ReviewSummary {
+        approvals: approved_count,
+        changes_requested,
+        user_has_reviewed,
+        approved_by_trusted,
+        my_last_review_at,
+        my_changes_requested_commit,
+    }
+}
+
+/// One changed file from the PR file list
+#[derive(Debug, Clone)]
+pub struct ChangedFile {
+    pub filename: String,
+    pub additions: u64,
+    pub deletions: u64,
+    /// Diff hunks in the file's patch; only counted when requested
+    pub hunks: Option<u64>,
 }
 
 /// Fetch per-file diff data for a PR with pagination.
-/// Returns a list of (filename, additions, deletions) tuples.
+/// With `count_hunks`, each file's patch is parsed for its hunk count.
 async fn fetch_pr_file_list(
     client: &Octocrab,
     owner: &str,
     repo: &str,
     number: u64,
-) -> Result<Vec<(String, u64, u64)>> {
+    count_hunks: bool,
+) -> Result<Vec<ChangedFile>> {
     let page = client
         .pulls(owner, repo)
         .list_files(number)
@@ -185,87 +659,272 @@ async fn fetch_pr_file_list(
         .all_pages(page)
         .await
         .context("Failed to paginate PR file list")?;
+    dump_raw(
+        &pr_dump_name(owner, repo, number, "files"),
+        &format!("GET /repos/{}/{}/pulls/{}/files", owner, repo, number),
+        &all_files,
+    );
 
     Ok(all_files
         .into_iter()
-        .map(|f| (f.filename, f.additions, f.deletions))
+        .map(|f| ChangedFile {
+            hunks: count_hunks.then(|| f.patch.as_deref().map_or(1, count_patch_hunks)),
+            filename: f.filename,
+            additions: f.additions,
+            deletions: f.deletions,
+        })
         .collect())
 }
 
-/// Filter files by basename glob matching and compute total size of non-excluded files.
-fn apply_size_exclusions(files: &[(String, u64, u64)], exclude_patterns: &[String]) -> Result<u64> {
-    let compiled: Vec<glob::Pattern> = exclude_patterns
+/// Fetch a PR's changed files on demand (for the TUI's changed files popup)
+pub async fn fetch_changed_files(client: &Octocrab, pr: &PullRequest) -> Result<Vec<ChangedFile>> {
+    let (owner, repo_name) = pr
+        .repo
+        .split_once('/')
+        .ok_or_else(|| anyhow!("Invalid repo format: {}", pr.repo))?;
+    fetch_pr_file_list(client, owner, repo_name, pr.number, false).await
+}
+
+/// Fetch all timeline events for a PR
+async fn fetch_timeline(
+    client: &Octocrab,
+    owner: &str,
+    repo: &str,
+    number: u64,
+) -> Result<Vec<TimelineEvent>> {
+    let page = client
+        .issues(owner, repo)
+        .list_timeline_events(number)
+        .per_page(100)
+        .send()
+        .await
+        .context("Failed to fetch PR timeline")?;
+
+    let events = client
+        .all_pages(page)
+        .await
+        .context("Failed to paginate PR timeline")?;
+    dump_raw(
+        &pr_dump_name(owner, repo, number, "timeline"),
+        &format!("GET /repos/{}/{}/issues/{}/timeline", owner, repo, number),
+        &events,
+    );
+    Ok(events)
+}
+
+/// When review was first requested from `auth_username`. Falls back to the
+/// first review request of anyone (e.g. a team you're on) when you were
+/// never requested individually.
+pub(super) fn first_review_request(
+    events: &[TimelineEvent],
+    auth_username: Option<&str>,
+) -> Option<DateTime<Utc>> {
+    let requests: Vec<&TimelineEvent> = events
         .iter()
-        .map(|p| glob::Pattern::new(p).context(format!("Invalid glob pattern: {}", p)))
+        .filter(|e| e.event == Event::ReviewRequested)
+        .collect();
+
+    let mine = auth_username.and_then(|username| {
+        requests
+            .iter()
+            .filter(|e| {
+                e.requested_reviewer
+                    .as_ref()
+                    .is_some_and(|r| r.login.eq_ignore_ascii_case(username))
+            })
+            .filter_map(|e| e.created_at)
+            .min()
+    });
+    mine.or_else(|| requests.iter().filter_map(|e| e.created_at).min())
+}
+
+/// Count `@@` hunk headers in a unified diff patch.
+/// GitHub omits the patch for binary and very large files; callers treat
+/// those as a single hunk.
+pub(super) fn count_patch_hunks(patch: &str) -> u64 {
+    patch.lines().filter(|line| line.starts_with("@@ ")).count() as u64
+}
+
+/// Size totals over the files that survive exclusion
+#[derive(Debug, PartialEq)]
+pub(super) struct FileTotals {
+    lines: u64,
+    files: u64,
+    /// None unless hunks were counted
+    hunks: Option<u64>,
+}
+
+/// Filter files by exclude patterns (basename glob, path glob, or `re:` regex)
+/// and total the lines, files, and hunks of non-excluded files.
+pub(super) fn apply_size_exclusions(
+    files: &[ChangedFile],
+    exclude_patterns: &[String],
+) -> Result<FileTotals> {
+    let compiled: Vec<ExcludePattern> = exclude_patterns
+        .iter()
+        .map(|p| ExcludePattern::parse(p))
         .collect::<Result<Vec<_>>>()?;
 
-    let total = files
+    let kept: Vec<&ChangedFile> = files
         .iter()
-        .filter(|(filename, _, _)| {
-            let basename = std::path::Path::new(filename)
-                .file_name()
-                .and_then(|n| n.to_str())
-                .unwrap_or(filename);
-            !compiled.iter().any(|pat| pat.matches(basename))
-        })
-        .map(|(_, additions, deletions)| additions + deletions)
-        .sum();
+        .filter(|f| !compiled.iter().any(|pat| pat.matches(&f.filename)))
+        .collect();
 
-    Ok(total)
+    Ok(FileTotals {
+        lines: kept.iter().map(|f| f.additions + f.deletions).sum(),
+        files: kept.len() as u64,
+        hunks: kept.iter().map(|f| f.hunks).sum(),
+    })
 }
 
 /// Enrich a PR with detailed information (size and approvals)
+#[allow(clippy::too_many_arguments)]
 async fn enrich_pr(
     client: &Octocrab,
     pr: &mut PullRequest,
     auth_username: Option<&str>,
     exclude_patterns: &Option<Vec<String>>,
+    size_metric: SizeMetric,
+    age_basis: AgeBasis,
+    compare_base: bool,
+    fetch_ci: bool,
+    reviewers: &ReviewerRules,
+    use_graphql: bool,
 ) -> Result<()> {
-    // Parse owner/repo from pr.repo field
-    let parts: Vec<&str> = pr.repo.split('/').collect();
+    // Parse owner/repo from pr.repo field (a copy, as the PR is updated below)
+    let repo_path = pr.repo.clone();
+    let parts: Vec<&str> = repo_path.split('/').collect();
     if parts.len() != 2 {
         return Err(anyhow!("Invalid repo format: {}", pr.repo));
     }
     let owner = parts[0];
     let repo_name = parts[1];
 
-    // Fetch details and reviews in parallel
-    let details_fut = fetch_pr_details(client, owner, repo_name, pr.number);
-    let reviews_fut = fetch_pr_reviews(client, owner, repo_name, pr.number, auth_username);
-
-    match tokio::try_join!(details_fut, reviews_fut) {
-        Ok(((additions, deletions, draft), (approvals, user_has_reviewed))) => {
-            pr.additions = additions;
-            pr.deletions = deletions;
-            pr.draft = draft;
-            pr.approvals = approvals;
-            pr.user_has_reviewed = user_has_reviewed;
-
-            // Conditionally fetch per-file data and apply size exclusions
-            if let Some(ref patterns) = exclude_patterns {
-                if !patterns.is_empty() {
-                    match fetch_pr_file_list(client, owner, repo_name, pr.number).await {
-                        Ok(files) => {
-                            match apply_size_exclusions(&files, patterns) {
-                                Ok(filtered) => pr.filtered_size = Some(filtered),
-                                Err(e) => {
-                                    buffered_eprintln!(
-                                        "Warning: Failed to apply size exclusions for PR {}: {}",
-                                        pr.number,
-                                        e
-                                    );
-                                    // Leave filtered_size as None — fallback to aggregate size
-                                }
-                            }
-                        }
+    // With `use_graphql`, one query covers details, reviews, and review
+    // requests; if it fails, fall back to the REST calls below
+    let graphql = if use_graphql {
+        match graphql::fetch_pr_review_data(
+            client,
+            owner,
+            repo_name,
+            pr.number,
+            auth_username,
+            reviewers,
+        )
+        .await
+        {
+            Ok(data) => Some(data),
+            Err(e) => {
+                log::warn!(
+                    "GraphQL enrichment failed for PR {}, using REST: {:#}",
+                    pr.number,
+                    e
+                );
+                None
+            }
+        }
+    } else {
+        None
+    };
+    let have_review_requests = graphql.is_some();
+    let have_ci_status = graphql.is_some();
+
+    let fetched = match graphql {
+        Some(data) => {
+            pr.review_decision = data.review_decision;
+            pr.re_review_requested = data.re_review_requested;
+            pr.ci_status = data.ci_status;
+            if age_basis == AgeBasis::ReviewRequested {
+                pr.review_requested_at = data.first_review_request;
+            }
+            Ok((data.details, data.reviews))
+        }
+        // Fetch details and reviews in parallel
+        None => tokio::try_join!(
+            fetch_pr_details(client, owner, repo_name, pr.number),
+            fetch_pr_reviews(
+                client,
+                owner,
+                repo_name,
+                pr.number,
+                auth_username,
+                reviewers,
+            )
+        ),
+    };
+
+    match fetched {
+        Ok((details, reviews)) => {
+            apply_details_and_reviews(pr, &details, &reviews, reviewers);
+
+            // Conditionally fetch per-file data: needed to apply size
+            // exclusions, and to count hunks for the `hunks` size metric
+            let patterns = exclude_patterns.as_deref().unwrap_or_default();
+            let count_hunks = size_metric == SizeMetric::Hunks;
+            if !patterns.is_empty() || count_hunks {
+                match fetch_pr_file_list(client, owner, repo_name, pr.number, count_hunks).await {
+                    Ok(files) => match apply_size_exclusions(&files, patterns) {
+                        Ok(totals) => apply_file_totals(pr, totals, !patterns.is_empty()),
                         Err(e) => {
-                            buffered_eprintln!(
-                                "Warning: Failed to fetch file list for PR {}: {}",
+                            log::warn!(
+                                "Failed to apply size exclusions for PR {}: {}",
                                 pr.number,
                                 e
                             );
                             // Leave filtered_size as None — fallback to aggregate size
                         }
+                    },
+                    Err(e) => {
+                        log::warn!("Failed to fetch file list for PR {}: {}", pr.number, e);
+                        // Leave filtered_size as None — fallback to aggregate size
+                    }
+                }
+            }
+
+            // One extra call per PR, so only when `commits_behind` is scored
+            if compare_base {
+                match fetch_commits_behind(
+                    client,
+                    owner,
+                    repo_name,
+                    pr.number,
+                    &details.base_ref,
+                    &details.head_sha,
+                )
+                .await
+                {
+                    Ok(behind) => pr.commits_behind = Some(behind),
+                    Err(e) => {
+                        log::warn!(
+                            "Failed to compare PR {} with base '{}': {}",
+                            pr.number,
+                            details.base_ref,
+                            e
+                        );
+                        // Leave commits_behind as None — the factor has no effect
+                    }
+                }
+            }
+
+            if fetch_ci && !have_ci_status {
+                match fetch_ci_status(client, owner, repo_name, pr.number, &details.head_sha).await
+                {
+                    Ok(status) => pr.ci_status = status,
+                    Err(e) => {
+                        log::warn!("Failed to fetch CI status for PR {}: {}", pr.number, e);
+                        // Leave ci_status as None — the factor is skipped
+                    }
+                }
+            }
+
+            if age_basis == AgeBasis::ReviewRequested && !have_review_requests {
+                match fetch_timeline(client, owner, repo_name, pr.number).await {
+                    Ok(events) => {
+                        pr.review_requested_at = first_review_request(&events, auth_username);
+                    }
+                    Err(e) => {
+                        log::warn!("Failed to fetch timeline for PR {}: {}", pr.number, e);
+                        // Leave review_requested_at as None — age falls back to created_at
                     }
                 }
             }
@@ -274,29 +933,85 @@ async fn enrich_pr(
         }
         Err(e) => {
             // If enrichment fails, log but don't fail the whole operation
-            buffered_eprintln!("Warning: Failed to enrich PR {}: {}", pr.number, e);
+            log::warn!("Failed to enrich PR {}: {}", pr.number, e);
             Ok(())
         }
     }
 }
 
+/// Copy fetched details and the review summary onto the PR
+pub(super) fn apply_details_and_reviews(
+    pr: &mut PullRequest,
+    details: &PrDetails,
+    reviews: &ReviewSummary,
+    reviewers: &ReviewerRules,
+) {
+    pr.additions = details.additions;
+    pr.deletions = details.deletions;
+    pr.draft = details.draft;
+    pr.changed_files = details.changed_files;
+    pr.requested_reviewers = Some(details.requested_reviewers.clone());
+    pr.team_review_requested = details
+        .requested_teams
+        .iter()
+        .any(|slug| reviewers.is_my_team(&pr.repo, slug));
+    pr.repo_archived = details.repo_archived;
+    pr.approvals = reviews.approvals;
+    pr.changes_requested_count = reviews.changes_requested;
+    pr.user_has_reviewed = reviews.user_has_reviewed;
+    pr.approved_by_trusted = reviews.approved_by_trusted;
+    pr.my_last_review_at = reviews.my_last_review_at;
+    // Pushed to since your latest review requested changes: the head moved
+    // past the commit you reviewed (comments and other activity don't count)
+    pr.updated_since_my_review = reviews
+        .my_changes_requested_commit
+        .as_ref()
+        .is_some_and(|reviewed| *reviewed != details.head_sha);
+}
+
+/// Record file list totals: the filtered size and file count when exclude
+/// patterns were applied, and the hunk count when it was taken
+pub(super) fn apply_file_totals(pr: &mut PullRequest, totals: FileTotals, excluding: bool) {
+    if excluding {
+        pr.filtered_size = Some(totals.lines);
+        pr.changed_files = Some(totals.files);
+    }
+    pr.hunks = totals.hunks;
+}
+
 /// Helper function for concurrent PR enrichment
+#[allow(clippy::too_many_arguments)]
 async fn enrich_pr_with_rate_limit_check(
     client: Octocrab,
     mut pr: PullRequest,
     rate_limited: Arc<AtomicBool>,
     auth_username: Option<String>,
     exclude_patterns: Option<Vec<String>>,
+    size_metric: SizeMetric,
+    age_basis: AgeBasis,
+    compare_base: bool,
+    fetch_ci: bool,
+    reviewers: Arc<ReviewerRules>,
+    use_graphql: bool,
 ) -> PullRequest {
     if rate_limited.load(Ordering::Relaxed) {
         return pr; // Skip enrichment if rate limited
     }
+    if pr.is_issue {
+        return pr; // Issues have no diff or reviews to fetch
+    }
 
     match enrich_pr(
         &client,
         &mut pr,
         auth_username.as_deref(),
         &exclude_patterns,
+        size_metric,
+        age_basis,
+        compare_base,
+        fetch_ci,
+        &reviewers,
+        use_graphql,
     )
     .await
     {
@@ -304,26 +1019,162 @@ async fn enrich_pr_with_rate_limit_check(
         Err(e) => {
             let err_str = e.to_string();
             if err_str.contains("rate limit") || err_str.contains("403") {
-                buffered_eprintln!(
-                    "Warning: Rate limit hit during enrichment. Returning partial results."
-                );
+                log::warn!("Rate limit hit during enrichment. Returning partial results.");
                 rate_limited.store(true, Ordering::Relaxed);
             } else {
-                buffered_eprintln!("Warning: Failed to enrich PR {}: {}", pr.number, e);
+                log::warn!("Failed to enrich PR {}: {}", pr.number, e);
             }
         }
     }
     pr
 }
 
+/// Time spent in each phase of `search_and_enrich_prs`
+#[derive(Debug, Clone, Copy, Default)]
+pub struct SearchTiming {
+    pub search: Duration,
+    pub enrichment: Duration,
+}
+
+/// Estimated REST calls `enrich_pr` makes per PR, for the pre-flight rate
+/// limit check. GraphQL queries draw on a separate limit and aren't counted;
+/// paginated file lists count as one call.
+fn core_calls_per_pr(
+    use_graphql: bool,
+    file_list: bool,
+    compare_base: bool,
+    fetch_ci: bool,
+    timeline: bool,
+) -> u64 {
+    let mut calls = if use_graphql { 0 } else { 2 }; // Details and reviews
+    if file_list {
+        calls += 1;
+    }
+    if compare_base {
+        calls += 1;
+    }
+    if fetch_ci && !use_graphql {
+        calls += 2; // Check runs and commit statuses
+    }
+    if timeline && !use_graphql {
+        calls += 1;
+    }
+    calls
+}
+
+/// How many of `pr_count` PRs can be enriched with `remaining` calls, or
+/// None when they all fit
+fn enrichment_budget(pr_count: usize, calls_per_pr: u64, remaining: u64) -> Option<usize> {
+    if calls_per_pr == 0 || pr_count as u64 * calls_per_pr <= remaining {
+        return None;
+    }
+    Some((remaining / calls_per_pr) as usize)
+}
+
+/// Compare the calls needed to enrich `pr_count` PRs with the remaining rate
+/// limit before starting, so a large result set doesn't run into a 403
+/// halfway through. Returns how many PRs to enrich when `guard` is
+/// `Degrade` and the limit is short; None means enrich them all.
+async fn check_rate_limit(
+    client: &Octocrab,
+    query: &str,
+    pr_count: usize,
+    calls_per_pr: u64,
+    guard: RateLimitGuard,
+) -> Option<usize> {
+    if guard == RateLimitGuard::Off || pr_count == 0 || calls_per_pr == 0 {
+        return None;
+    }
+    // Best-effort: if the limit can't be read, enrich as usual
+    let core = match client.ratelimit().get().await {
+        Ok(rate_limit) => rate_limit.resources.core,
+        Err(e) => {
+            log::debug!("Rate limit check failed for {}: {}", query, e);
+            return None;
+        }
+    };
+    let remaining = core.remaining as u64;
+    let affordable = enrichment_budget(pr_count, calls_per_pr, remaining)?;
+    let resets = DateTime::from_timestamp(core.reset as i64, 0)
+        .map(|t| t.with_timezone(&chrono::Local).format("%H:%M").to_string())
+        .unwrap_or_else(|| "soon".to_string());
+
+    match guard {
+        RateLimitGuard::Degrade => {
+            log::warn!(
+                "Only {} API requests left (resets {}); enriching {} of {} PRs for {}, the rest are scored incomplete",
+                remaining,
+                resets,
+                affordable,
+                pr_count,
+                query
+            );
+            Some(affordable)
+        }
+        _ => {
+            log::warn!(
+                "Enriching {} PRs for {} needs about {} API requests but only {} are left (resets {}); scores may be partial",
+                pr_count,
+                query,
+                pr_count as u64 * calls_per_pr,
+                remaining,
+                resets
+            );
+            None
+        }
+    }
+}
+
 /// Search and enrich PRs with full details
+#[allow(clippy::too_many_arguments)]
 pub async fn search_and_enrich_prs(
     client: &Octocrab,
     query: &str,
     auth_username: Option<&str>,
     exclude_patterns: Option<Vec<String>>,
-) -> Result<Vec<PullRequest>> {
-    let prs = search_prs(client, query).await?;
+    size_metric: SizeMetric,
+    age_basis: AgeBasis,
+    compare_base: bool,
+    fetch_ci: bool,
+    include_issues: bool,
+    reviewers: &ReviewerRules,
+    use_graphql: bool,
+    rate_limit_guard: RateLimitGuard,
+) -> Result<(Vec<PullRequest>, SearchTiming)> {
+    let search_start = Instant::now();
+    let mut prs = search_prs(client, query, include_issues).await?;
+    if let Some(username) = auth_username {
+        for pr in &mut prs {
+            pr.is_author = pr.author.eq_ignore_ascii_case(username);
+        }
+    }
+    let search_elapsed = search_start.elapsed();
+
+    // Issues aren't enriched, so they cost nothing and are never held back
+    let calls_per_pr = core_calls_per_pr(
+        use_graphql,
+        exclude_patterns.as_ref().is_some_and(|p| !p.is_empty())
+            || size_metric == SizeMetric::Hunks,
+        compare_base,
+        fetch_ci,
+        age_basis == AgeBasis::ReviewRequested,
+    );
+    let pr_count = prs.iter().filter(|pr| !pr.is_issue).count();
+    let mut held_back = Vec::new();
+    if let Some(affordable) =
+        check_rate_limit(client, query, pr_count, calls_per_pr, rate_limit_guard).await
+    {
+        let mut kept = 0;
+        (prs, held_back) = prs.into_iter().partition(|pr| {
+            pr.is_issue || {
+                kept += 1;
+                kept <= affordable
+            }
+        });
+    }
+
+    let enrich_start = Instant::now();
+    let reviewers = Arc::new(reviewers.clone());
 
     // Enrich PRs with bounded concurrency
     const MAX_CONCURRENT_ENRICHMENTS: usize = 10;
@@ -344,6 +1195,12 @@ pub async fn search_and_enrich_prs(
                 rate_limited.clone(),
                 auth_username.map(|s| s.to_string()),
                 exclude_patterns.clone(),
+                size_metric,
+                age_basis,
+                compare_base,
+                fetch_ci,
+                reviewers.clone(),
+                use_graphql,
             ));
         }
     }
@@ -361,6 +1218,12 @@ pub async fn search_and_enrich_prs(
                     rate_limited.clone(),
                     auth_username.map(|s| s.to_string()),
                     exclude_patterns.clone(),
+                    size_metric,
+                    age_basis,
+                    compare_base,
+                    fetch_ci,
+                    reviewers.clone(),
+                    use_graphql,
                 ));
             }
         }
@@ -368,6 +1231,487 @@ pub async fn search_and_enrich_prs(
 
     // Add any remaining unenriched PRs (if rate limited, remaining weren't submitted)
     enriched_prs.extend(prs_iter);
+    enriched_prs.extend(held_back);
+
+    let timing = SearchTiming {
+        search: search_elapsed,
+        enrichment: enrich_start.elapsed(),
+    };
+    Ok((enriched_prs, timing))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SEARCH_RESPONSE: &str = r#"{
+        "total_count": 3,
+        "incomplete_results": false,
+        "items": [
+            {
+                "number": 42,
+                "title": "WIP: new parser",
+                "html_url": "https://github.com/acme/widgets/pull/42",
+                "user": { "login": "alice" },
+                "labels": [{ "name": "wip" }],
+                "created_at": "2024-01-01T00:00:00Z",
+                "updated_at": "2024-01-02T00:00:00Z",
+                "pull_request": { "url": "https://api.github.com/repos/acme/widgets/pulls/42" },
+                "draft": true,
+                "reactions": { "total_count": 7, "+1": 5, "heart": 2 },
+                "comments": 12
+            },
+            {
+                "number": 43,
+                "title": "Fix typo",
+                "html_url": "https://github.com/acme/widgets/pull/43",
+                "user": { "login": "bob" },
+                "labels": [],
+                "created_at": "2024-01-01T00:00:00Z",
+                "updated_at": "2024-01-01T00:00:00Z",
+                "pull_request": { "url": "https://api.github.com/repos/acme/widgets/pulls/43" }
+            },
+            {
+                "number": 44,
+                "title": "An issue",
+                "html_url": "https://github.com/acme/widgets/issues/44",
+                "user": { "login": "carol" },
+                "labels": [],
+                "created_at": "2024-01-01T00:00:00Z",
+                "updated_at": "2024-01-01T00:00:00Z"
+            }
+        ]
+    }"#;
+
+    #[test]
+    fn test_write_dump_wraps_response_with_request() {
+        let dir = std::env::temp_dir().join(format!("pr-bro-test-dump-{}", std::process::id()));
+        std::fs::create_dir_all(&dir).unwrap();
+        let name = pr_dump_name("acme", "widgets", 42, "reviews");
+        assert_eq!(name, "acme-widgets-42-reviews.json");
+
+        let response = serde_json::json!([{ "state": "APPROVED" }]);
+        write_dump(
+            &dir,
+            &name,
+            "GET /repos/acme/widgets/pulls/42/reviews",
+            &response,
+        )
+        .unwrap();
+
+        let written: serde_json::Value =
+            serde_json::from_str(&std::fs::read_to_string(dir.join(&name)).unwrap()).unwrap();
+        assert_eq!(
+            written["request"],
+            "GET /repos/acme/widgets/pulls/42/reviews"
+        );
+        assert_eq!(written["response"], response);
+        std::fs::remove_dir_all(&dir).ok();
+    }
+
+    #[test]
+    fn test_core_remaining_from_headers() {
+        let headers = |resource: &str, remaining: &str| {
+            let mut map = http::HeaderMap::new();
+            map.insert("x-ratelimit-resource", resource.parse().unwrap());
+            map.insert("x-ratelimit-remaining", remaining.parse().unwrap());
+            map
+        };
+        assert_eq!(
+            core_remaining_from_headers(&headers("core", "4711")),
+            Some(4711)
+        );
+        // The search limit is separate and much smaller
+        assert_eq!(core_remaining_from_headers(&headers("search", "29")), None);
+        assert_eq!(core_remaining_from_headers(&headers("core", "n/a")), None);
+        assert_eq!(core_remaining_from_headers(&http::HeaderMap::new()), None);
+    }
+
+    #[test]
+    fn test_search_item_reads_draft_flag() {
+        let response: SearchResponse = serde_json::from_str(SEARCH_RESPONSE).unwrap();
+        let prs: Vec<PullRequest> = response
+            .items
+            .into_iter()
+            .filter(|item| item.pull_request.is_some())
+            .map(SearchItem::into_pull_request)
+            .collect();
+
+        assert_eq!(prs.len(), 2);
+        assert_eq!(prs[0].number, 42);
+        assert!(prs[0].draft);
+        assert_eq!(prs[0].repo, "acme/widgets");
+        assert_eq!(prs[0].author, "alice");
+        assert_eq!(prs[0].labels, vec!["wip".to_string()]);
+        // Missing draft field defaults to not-draft
+        assert!(!prs[1].draft);
+        assert!(!prs[0].is_issue);
+        assert_eq!(prs[0].reactions, 7);
+        // Missing reactions rollup counts as none
+        assert_eq!(prs[1].reactions, 0);
+        assert_eq!(prs[0].comments, 12);
+        assert_eq!(prs[1].comments, 0);
+    }
+
+    #[test]
+    fn test_search_item_marks_issues() {
+        let response: SearchResponse = serde_json::from_str(SEARCH_RESPONSE).unwrap();
+        let items: Vec<PullRequest> = response
+            .items
+            .into_iter()
+            .map(SearchItem::into_pull_request)
+            .collect();
+
+        assert_eq!(items.len(), 3);
+        assert_eq!(
+            items.iter().map(|i| i.is_issue).collect::<Vec<_>>(),
+            vec![false, false, true]
+        );
+    }
+
+    fn review(login: &str, state: &str, submitted_at: &str) -> Review {
+        serde_json::from_value(serde_json::json!({
+            "id": 1,
+            "node_id": "R_1",
+            "html_url": "https://github.com/acme/widgets/pull/42#pullrequestreview-1",
+            "user": {
+                "login": login,
+                "id": 1,
+                "node_id": "U_1",
+                "avatar_url": "https://avatars.githubusercontent.com/u/1",
+                "gravatar_id": "",
+                "url": "https://api.github.com/users/x",
+                "html_url": "https://github.com/x",
+                "followers_url": "https://api.github.com/users/x/followers",
+                "following_url": "https://api.github.com/users/x/following",
+                "gists_url": "https://api.github.com/users/x/gists",
+                "starred_url": "https://api.github.com/users/x/starred",
+                "subscriptions_url": "https://api.github.com/users/x/subscriptions",
+                "organizations_url": "https://api.github.com/users/x/orgs",
+                "repos_url": "https://api.github.com/users/x/repos",
+                "events_url": "https://api.github.com/users/x/events",
+                "received_events_url": "https://api.github.com/users/x/received_events",
+                "type": "User",
+                "site_admin": false
+            },
+            "state": state,
+            "submitted_at": submitted_at
+        }))
+        .unwrap()
+    }
+
+    #[test]
+    fn test_is_my_team() {
+        let rules = ReviewerRules {
+            teams: vec!["acme/Platform".to_string(), "security".to_string()],
+            ..ReviewerRules::default()
+        };
+        assert!(rules.is_my_team("acme/widgets", "platform"));
+        // An org-qualified team only matches in that org
+        assert!(!rules.is_my_team("initech/widgets", "platform"));
+        // A bare slug matches in any org
+        assert!(rules.is_my_team("initech/widgets", "security"));
+        assert!(!rules.is_my_team("acme/widgets", "docs"));
+    }
+
+    fn trusted(logins: &[&str]) -> ReviewerRules {
+        ReviewerRules {
+            trusted: logins.iter().map(|l| l.to_string()).collect(),
+            ..ReviewerRules::default()
+        }
+    }
+
+    #[test]
+    fn test_summarize_reviews_tracks_my_last_review() {
+        let reviews = vec![
+            review("me", "CHANGES_REQUESTED", "2024-01-01T10:00:00Z"),
+            review("alice", "APPROVED", "2024-01-02T10:00:00Z"),
+            review("Me", "COMMENTED", "2024-01-03T10:00:00Z"),
+        ];
+        let summary = summarize_reviews(&reviews, Some("me"), &trusted(&["ALICE"]));
+
+        assert_eq!(summary.approvals, 1);
+        // Only others' change requests count
+        assert_eq!(summary.changes_requested, 0);
+        assert!(summary.user_has_reviewed);
+        assert!(summary.approved_by_trusted);
+        assert_eq!(
+            summary.my_last_review_at,
+            Some("2024-01-03T10:00:00Z".parse().unwrap())
+        );
+    }
+
+    #[test]
+    fn test_summarize_reviews_counts_others_change_requests() {
+        let reviews = vec![
+            review("alice", "CHANGES_REQUESTED", "2024-01-01T10:00:00Z"),
+            review("bob", "CHANGES_REQUESTED", "2024-01-01T11:00:00Z"),
+            review("me", "CHANGES_REQUESTED", "2024-01-01T12:00:00Z"),
+            review("carol", "COMMENTED", "2024-01-01T13:00:00Z"),
+        ];
+        let summary = summarize_reviews(&reviews, Some("me"), &ReviewerRules::default());
+
+        assert_eq!(summary.changes_requested, 2);
+        assert_eq!(summary.approvals, 0);
+    }
+
+    #[test]
+    fn test_summarize_reviews_without_my_review() {
+        let reviews = vec![review("alice", "APPROVED", "2024-01-02T10:00:00Z")];
+        let summary = summarize_reviews(&reviews, Some("me"), &ReviewerRules::default());
+
+        assert!(!summary.user_has_reviewed);
+        assert!(!summary.approved_by_trusted);
+        assert_eq!(summary.my_last_review_at, None);
+    }
 
-    Ok(enriched_prs)
+    #[test]
+    fn test_updated_since_my_review_needs_a_push_after_changes_requested() {
+        let on = |login: &str, state: &str, submitted_at: &str, commit: &str| {
+            let mut review = review(login, state, submitted_at);
+            review.commit_id = Some(commit.to_string());
+            review
+        };
+        let updated = |reviews: &[Review], head_sha: &str| {
+            let details = PrDetails {
+                additions: 0,
+                deletions: 0,
+                draft: false,
+                changed_files: None,
+                requested_reviewers: vec![],
+                requested_teams: vec![],
+                base_ref: "main".to_string(),
+                head_sha: head_sha.to_string(),
+                repo_archived: None,
+            };
+            let summary = summarize_reviews(reviews, Some("me"), &ReviewerRules::default());
+            let mut pr = PullRequest::test_default();
+            apply_details_and_reviews(&mut pr, &details, &summary, &ReviewerRules::default());
+            pr.updated_since_my_review
+        };
+
+        // Changes requested on c1: a push moves the head, anything else doesn't
+        let requested = [
+            on("me", "CHANGES_REQUESTED", "2024-01-01T10:00:00Z", "c1"),
+            on("me", "COMMENTED", "2024-01-01T11:00:00Z", "c1"),
+        ];
+        assert!(updated(&requested, "c2"));
+        assert!(!updated(&requested, "c1"));
+
+        // Approved, then commented: your verdict is still an approval
+        let approved = [
+            on("me", "CHANGES_REQUESTED", "2024-01-01T10:00:00Z", "c1"),
+            on("me", "APPROVED", "2024-01-02T10:00:00Z", "c2"),
+            on("me", "COMMENTED", "2024-01-02T11:00:00Z", "c2"),
+        ];
+        assert!(!updated(&approved, "c3"));
+
+        // Others' change requests are theirs to re-review
+        let others = [on(
+            "alice",
+            "CHANGES_REQUESTED",
+            "2024-01-01T10:00:00Z",
+            "c1",
+        )];
+        assert!(!updated(&others, "c2"));
+    }
+
+    #[test]
+    fn test_summarize_reviews_leaves_out_bot_approvals() {
+        let mut app_review = review("renovate", "APPROVED", "2024-01-01T09:00:00Z");
+        app_review.user.as_mut().unwrap().r#type = "Bot".to_string();
+        let reviews = vec![
+            review("alice", "APPROVED", "2024-01-01T10:00:00Z"),
+            review("dependabot[bot]", "APPROVED", "2024-01-01T10:00:00Z"),
+            review("ci-approver", "APPROVED", "2024-01-01T11:00:00Z"),
+            app_review,
+        ];
+        let mut rules = ReviewerRules {
+            bots: vec!["CI-Approver".to_string()],
+            ..ReviewerRules::default()
+        };
+
+        // Only the human approval counts
+        assert_eq!(summarize_reviews(&reviews, None, &rules).approvals, 1);
+
+        rules.count_bot_approvals = true;
+        assert_eq!(summarize_reviews(&reviews, None, &rules).approvals, 4);
+
+        // Unlisted bots without the [bot] suffix or Bot type count as humans
+        rules.bots.clear();
+        rules.count_bot_approvals = false;
+        assert_eq!(summarize_reviews(&reviews, None, &rules).approvals, 2);
+    }
+
+    #[test]
+    fn test_apply_size_exclusions_by_path() {
+        let files = vec![
+            file("src/main.rs", 10, 5, None),
+            file("vendor/lib/dep.go", 500, 0, None),
+            file("Cargo.lock", 200, 100, None),
+            file("gen/api.pb.go", 300, 0, None),
+        ];
+        let patterns = vec![
+            "vendor/**".to_string(),
+            "*.lock".to_string(),
+            r"re:\.pb\.go$".to_string(),
+        ];
+        let totals = apply_size_exclusions(&files, &patterns).unwrap();
+        assert_eq!(totals.lines, 15);
+        assert_eq!(totals.files, 1);
+        assert_eq!(totals.hunks, None);
+    }
+
+    fn file(name: &str, additions: u64, deletions: u64, hunks: Option<u64>) -> ChangedFile {
+        ChangedFile {
+            filename: name.to_string(),
+            additions,
+            deletions,
+            hunks,
+        }
+    }
+
+    #[test]
+    fn test_count_patch_hunks() {
+        let patch = "@@ -1,3 +1,4 @@\n fn main() {\n+    init();\n@@ -20,2 +21,2 @@ fn run()\n-    old();\n+    new();";
+        assert_eq!(count_patch_hunks(patch), 2);
+        // A line containing "@@" that isn't a header doesn't count
+        assert_eq!(count_patch_hunks("@@ -1 +1 @@\n+let s = \"@@ x\";"), 1);
+    }
+
+    #[test]
+    fn test_apply_size_exclusions_sums_hunks_of_kept_files() {
+        let files = vec![
+            file("src/lib.rs", 10, 0, Some(3)),
+            file("Cargo.lock", 100, 0, Some(9)),
+            file("src/main.rs", 2, 2, Some(1)),
+        ];
+        let totals = apply_size_exclusions(&files, &["*.lock".to_string()]).unwrap();
+        assert_eq!(
+            totals,
+            FileTotals {
+                lines: 14,
+                files: 2,
+                hunks: Some(4),
+            }
+        );
+    }
+
+    fn timeline(json: &str) -> Vec<TimelineEvent> {
+        serde_json::from_str(json).unwrap()
+    }
+
+    #[test]
+    fn test_first_review_request_prefers_own_request() {
+        let events = timeline(
+            r#"[
+            { "event": "review_requested", "created_at": "2024-01-02T00:00:00Z",
+              "requested_reviewer": { "login": "alice", "id": 1, "node_id": "", "avatar_url": "https://a", "gravatar_id": "", "url": "https://a", "html_url": "https://a", "followers_url": "https://a", "following_url": "https://a", "gists_url": "https://a", "starred_url": "https://a", "subscriptions_url": "https://a", "organizations_url": "https://a", "repos_url": "https://a", "events_url": "https://a", "received_events_url": "https://a", "type": "User", "site_admin": false } },
+            { "event": "review_requested", "created_at": "2024-01-05T00:00:00Z",
+              "requested_reviewer": { "login": "Me", "id": 2, "node_id": "", "avatar_url": "https://a", "gravatar_id": "", "url": "https://a", "html_url": "https://a", "followers_url": "https://a", "following_url": "https://a", "gists_url": "https://a", "starred_url": "https://a", "subscriptions_url": "https://a", "organizations_url": "https://a", "repos_url": "https://a", "events_url": "https://a", "received_events_url": "https://a", "type": "User", "site_admin": false } },
+            { "event": "labeled", "created_at": "2024-01-01T00:00:00Z" }
+        ]"#,
+        );
+        let at = |s: &str| s.parse::<DateTime<Utc>>().unwrap();
+
+        assert_eq!(
+            first_review_request(&events, Some("me")),
+            Some(at("2024-01-05T00:00:00Z"))
+        );
+        // Never requested individually (e.g. via a team): earliest request of anyone
+        assert_eq!(
+            first_review_request(&events, Some("bob")),
+            Some(at("2024-01-02T00:00:00Z"))
+        );
+        assert_eq!(first_review_request(&events[2..], Some("me")), None);
+    }
+
+    #[test]
+    fn test_core_calls_per_pr() {
+        assert_eq!(core_calls_per_pr(false, false, false, false, false), 2);
+        assert_eq!(core_calls_per_pr(false, true, true, false, true), 5);
+        assert_eq!(core_calls_per_pr(false, false, false, true, false), 4);
+        // GraphQL covers details, reviews, review requests, and CI
+        assert_eq!(core_calls_per_pr(true, false, false, true, true), 0);
+        assert_eq!(core_calls_per_pr(true, true, true, true, true), 2);
+    }
+
+    #[test]
+    fn test_combine_ci_status() {
+        let run = |status: &str, conclusion: Option<&str>| CheckRun {
+            status: status.to_string(),
+            conclusion: conclusion.map(str::to_string),
+        };
+        let checks = |check_runs: Vec<CheckRun>| CheckRunsResponse {
+            total_count: check_runs.len() as u64,
+            check_runs,
+        };
+        let statuses = |state: &str, total_count| CombinedStatus {
+            state: state.to_string(),
+            total_count,
+        };
+        let none = statuses("pending", 0);
+
+        // No checks and no statuses: unknown, not pending
+        assert_eq!(combine_ci_status(&checks(vec![]), &none), None);
+        let green = checks(vec![
+            run("completed", Some("success")),
+            run("completed", Some("skipped")),
+        ]);
+        assert_eq!(combine_ci_status(&green, &none), Some(CiStatus::Success));
+        // Any failure wins, then anything still running
+        let running = checks(vec![
+            run("completed", Some("success")),
+            run("in_progress", None),
+        ]);
+        assert_eq!(combine_ci_status(&running, &none), Some(CiStatus::Pending));
+        assert_eq!(
+            combine_ci_status(&running, &statuses("error", 1)),
+            Some(CiStatus::Failure)
+        );
+        assert_eq!(
+            combine_ci_status(&checks(vec![]), &statuses("success", 2)),
+            Some(CiStatus::Success)
+        );
+    }
+
+    #[test]
+    fn test_combine_ci_status_with_unfetched_check_runs() {
+        let green_page = || {
+            (0..100)
+                .map(|_| CheckRun {
+                    status: "completed".to_string(),
+                    conclusion: Some("success".to_string()),
+                })
+                .collect::<Vec<_>>()
+        };
+        let none = CombinedStatus {
+            state: "pending".to_string(),
+            total_count: 0,
+        };
+
+        // 150 runs, only the first 100 seen: the rest could have failed
+        let partial = CheckRunsResponse {
+            total_count: 150,
+            check_runs: green_page(),
+        };
+        assert_eq!(combine_ci_status(&partial, &none), Some(CiStatus::Pending));
+
+        // A failure on the first page still wins
+        let mut runs = green_page();
+        runs[0].conclusion = Some("failure".to_string());
+        let failed = CheckRunsResponse {
+            total_count: 150,
+            check_runs: runs,
+        };
+        assert_eq!(combine_ci_status(&failed, &none), Some(CiStatus::Failure));
+    }
+
+    #[test]
+    fn test_enrichment_budget() {
+        assert_eq!(enrichment_budget(100, 2, 200), None);
+        assert_eq!(enrichment_budget(100, 2, 150), Some(75));
+        assert_eq!(enrichment_budget(100, 3, 0), Some(0));
+        assert_eq!(enrichment_budget(100, 0, 0), None);
+    }
 }
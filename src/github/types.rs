@@ -1,4 +1,5 @@
 use chrono::{DateTime, Utc};
+use serde::Deserialize;
 
 #[derive(Debug, Clone)]
 pub struct PullRequest {
@@ -13,15 +14,132 @@ pub struct PullRequest {
     pub deletions: u64, // Lines deleted
     pub approvals: u32, // Approval count (will need separate API call)
     pub draft: bool,
-    pub labels: Vec<String>,        // GitHub label names on this PR
-    pub user_has_reviewed: bool,    // Whether the authenticated user has submitted a review
-    pub filtered_size: Option<u64>, // Size after applying exclude patterns (if configured)
+    pub is_issue: bool, // An issue from a query with `include_issues` (no diff or reviews)
+    pub labels: Vec<String>, // GitHub label names on this PR
+    pub user_has_reviewed: bool, // Whether the authenticated user has submitted a review
+    pub approved_by_trusted: bool, // Whether a configured trusted reviewer has approved
+    pub my_last_review_at: Option<DateTime<Utc>>, // When the authenticated user last reviewed
+    pub updated_since_my_review: bool, // Pushed to after the authenticated user's latest review requested changes
+    pub re_review_requested: bool, // Your review was requested again after you reviewed (GraphQL only)
+    pub review_decision: Option<ReviewDecision>, // GitHub's overall verdict (GraphQL only)
+    pub changes_requested_count: u32, // Change-request reviews from reviewers other than you
+    pub requested_reviewers: Option<Vec<String>>, // Pending reviewer logins (None if not enriched)
+    pub review_request_withdrawn: bool, // Your review request was removed without you reviewing
+    pub is_author: bool,           // Authored by the authenticated user
+    pub reactions: u32,            // Total reactions on the PR description (from search results)
+    pub comments: u32, // Conversation comments, not inline review comments (from search results)
+    pub team_review_requested: bool, // Review requested from one of the configured `teams`
+    pub ci_status: Option<CiStatus>, // Combined CI result for the head commit (only fetched for `ci_status` scoring)
+    pub commits_behind: Option<u32>, // Commits on the base branch not in the PR (only fetched for `commits_behind` scoring)
+    pub review_requested_at: Option<DateTime<Utc>>, // First review request (for `age_basis`)
+    pub changed_files: Option<u64>,  // Files changed (after exclude patterns, if configured)
+    pub hunks: Option<u64>,          // Diff hunks, counted only for the `hunks` size metric
+    pub filtered_size: Option<u64>,  // Size after applying exclude patterns (if configured)
+    pub group: Option<String>,       // `group` tag of the query the PR is scored under
+    pub repo_archived: Option<bool>, // Repo is archived (read-only); None until known
 }
 
+#[cfg(test)]
 impl PullRequest {
-    /// Calculate PR age from creation time
+    /// A fresh PR with no reviews, diff, or enrichment; tests override the
+    /// fields they care about with `..PullRequest::test_default()`
+    pub fn test_default() -> Self {
+        let now = Utc::now();
+        PullRequest {
+            title: "Test PR".to_string(),
+            number: 1,
+            author: "octocat".to_string(),
+            repo: "owner/repo".to_string(),
+            url: "https://github.com/owner/repo/pull/1".to_string(),
+            created_at: now,
+            updated_at: now,
+            additions: 0,
+            deletions: 0,
+            approvals: 0,
+            draft: false,
+            is_issue: false,
+            labels: vec![],
+            user_has_reviewed: false,
+            approved_by_trusted: false,
+            my_last_review_at: None,
+            updated_since_my_review: false,
+            re_review_requested: false,
+            review_decision: None,
+            changes_requested_count: 0,
+            requested_reviewers: None,
+            review_request_withdrawn: false,
+            is_author: false,
+            reactions: 0,
+            comments: 0,
+            team_review_requested: false,
+            ci_status: None,
+            commits_behind: None,
+            review_requested_at: None,
+            changed_files: None,
+            hunks: None,
+            filtered_size: None,
+            group: None,
+            repo_archived: None,
+        }
+    }
+}
+
+/// GitHub's overall review verdict for a PR, from the branch protection rules.
+/// Only fetched with `use_graphql`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum ReviewDecision {
+    Approved,
+    ChangesRequested,
+    ReviewRequired,
+}
+
+impl ReviewDecision {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            ReviewDecision::Approved => "approved",
+            ReviewDecision::ChangesRequested => "changes_requested",
+            ReviewDecision::ReviewRequired => "review_required",
+        }
+    }
+}
+
+/// Combined result of the checks and commit statuses on a PR's head commit.
+/// Only fetched for `ci_status` scoring; None when there is no CI to report.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CiStatus {
+    Success,
+    Failure,
+    Pending,
+}
+
+impl CiStatus {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            CiStatus::Success => "success",
+            CiStatus::Failure => "failure",
+            CiStatus::Pending => "pending",
+        }
+    }
+}
+
+impl PullRequest {
+    /// Calculate PR age from creation time.
+    /// Clamped to zero so a future `created_at` (clock skew) never yields a negative age.
     pub fn age(&self) -> chrono::Duration {
-        Utc::now() - self.created_at
+        self.age_at(Utc::now())
+    }
+
+    /// `age` as of `now`, for callers that need one instant across many PRs
+    pub fn age_at(&self, now: DateTime<Utc>) -> chrono::Duration {
+        (now - self.created_at).max(chrono::Duration::zero())
+    }
+
+    /// Time since review was first requested, or since creation when unknown.
+    /// Clamped to zero like `age`.
+    pub fn review_age(&self) -> chrono::Duration {
+        let since = self.review_requested_at.unwrap_or(self.created_at);
+        (Utc::now() - since).max(chrono::Duration::zero())
     }
 
     /// Calculate total size, using filtered size if available (exclude patterns applied)
@@ -0,0 +1,129 @@
+//! `pr-bro check`: one fetch summarized as a status object for dashboards
+//! and monitoring. Failures are reported in the object rather than through
+//! the exit code, so callers always get something to parse.
+
+use chrono::{DateTime, Utc};
+use serde::Serialize;
+
+use crate::config::Config;
+use crate::fetch::fetch_and_score_prs;
+use crate::github::cache::CacheConfig;
+use crate::snooze::SnoozeState;
+
+/// Result of a health check. Counts and rate limit fields are null when the
+/// check failed before they were known.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct HealthReport {
+    pub ok: bool,
+    pub error: Option<String>,
+    pub active_count: Option<usize>,
+    pub snoozed_count: Option<usize>,
+    pub rate_limit_remaining: Option<u64>,
+    pub rate_limit_reset: Option<DateTime<Utc>>,
+}
+
+impl HealthReport {
+    /// A failed check, with the full error chain as the message
+    pub fn failed(error: &anyhow::Error) -> Self {
+        Self {
+            ok: false,
+            error: Some(format!("{:#}", error)),
+            active_count: None,
+            snoozed_count: None,
+            rate_limit_remaining: None,
+            rate_limit_reset: None,
+        }
+    }
+
+    /// One line for people: "ok: 4 active, 1 snoozed, ..." or the error
+    pub fn summary(&self) -> String {
+        if let Some(ref error) = self.error {
+            return format!("error: {}", error);
+        }
+        let mut summary = format!(
+            "ok: {} active, {} snoozed",
+            self.active_count.unwrap_or(0),
+            self.snoozed_count.unwrap_or(0)
+        );
+        if let Some(remaining) = self.rate_limit_remaining {
+            summary.push_str(&format!(", {} API requests left", remaining));
+            if let Some(reset) = self.rate_limit_reset {
+                summary.push_str(&format!(
+                    " (resets {})",
+                    reset.with_timezone(&chrono::Local).format("%H:%M")
+                ));
+            }
+        }
+        summary
+    }
+}
+
+/// Fetch and score every query once, the same way a list or TUI refresh
+/// does, and report the outcome. Never fails: errors end up in the report.
+pub async fn run_health_check(
+    client: &octocrab::Octocrab,
+    config: &Config,
+    snooze_state: &SnoozeState,
+    cache_config: &CacheConfig,
+    auth_username: Option<&str>,
+) -> HealthReport {
+    let (active, snoozed, _, _) = match fetch_and_score_prs(
+        client,
+        config,
+        snooze_state,
+        cache_config,
+        auth_username,
+    )
+    .await
+    {
+        Ok(result) => result,
+        Err(e) => return HealthReport::failed(&e),
+    };
+
+    // Best-effort, as in the fetch itself; a missing rate limit isn't unhealthy
+    let core = client
+        .ratelimit()
+        .get()
+        .await
+        .ok()
+        .map(|r| r.resources.core);
+    HealthReport {
+        ok: true,
+        error: None,
+        active_count: Some(active.len()),
+        snoozed_count: Some(snoozed.len()),
+        rate_limit_remaining: core.as_ref().map(|c| c.remaining as u64),
+        rate_limit_reset: core.and_then(|c| DateTime::from_timestamp(c.reset as i64, 0)),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_failed_report_serializes_nulls() {
+        let report = HealthReport::failed(&anyhow::anyhow!("Bad credentials"));
+        assert_eq!(
+            serde_json::to_string(&report).unwrap(),
+            r#"{"ok":false,"error":"Bad credentials","active_count":null,"snoozed_count":null,"rate_limit_remaining":null,"rate_limit_reset":null}"#
+        );
+        assert_eq!(report.summary(), "error: Bad credentials");
+    }
+
+    #[test]
+    fn test_healthy_summary() {
+        let report = HealthReport {
+            ok: true,
+            error: None,
+            active_count: Some(4),
+            snoozed_count: Some(1),
+            rate_limit_remaining: Some(4990),
+            rate_limit_reset: None,
+        };
+        assert_eq!(
+            report.summary(),
+            "ok: 4 active, 1 snoozed, 4990 API requests left"
+        );
+    }
+}
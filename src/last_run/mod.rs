@@ -0,0 +1,156 @@
+//! What changed since the last non-interactive run: the active list's URLs
+//! and scores are saved after each listing and compared on the next one.
+
+pub mod storage;
+
+pub use storage::{get_last_run_path, load_last_run_state, save_last_run_state};
+
+use serde::{Deserialize, Serialize};
+use std::collections::{HashMap, HashSet};
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct LastRunState {
+    pub version: u32,
+    /// None until the first run is saved, so that run doesn't report
+    /// every PR as new
+    #[serde(default)]
+    pub saved_at: Option<chrono::DateTime<chrono::Utc>>,
+    /// Score of each listed PR, by URL
+    #[serde(default)]
+    pub scores: HashMap<String, f64>,
+}
+
+impl Default for LastRunState {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// A PR listed both times whose score changed
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct ScoreChange {
+    pub url: String,
+    pub old: f64,
+    pub new: f64,
+}
+
+/// Difference between the last run's list and this one
+#[derive(Debug, Clone, Default, PartialEq, Serialize)]
+pub struct RunDelta {
+    pub new: Vec<String>,
+    pub removed: Vec<String>,
+    pub score_changes: Vec<ScoreChange>,
+}
+
+impl RunDelta {
+    pub fn is_empty(&self) -> bool {
+        self.new.is_empty() && self.removed.is_empty() && self.score_changes.is_empty()
+    }
+
+    /// One line, e.g. "2 new, 1 removed, 3 score changes"
+    pub fn summary(&self) -> String {
+        if self.is_empty() {
+            return "no changes".to_string();
+        }
+        let changes = self.score_changes.len();
+        format!(
+            "{} new, {} removed, {} score {}",
+            self.new.len(),
+            self.removed.len(),
+            changes,
+            if changes == 1 { "change" } else { "changes" }
+        )
+    }
+}
+
+impl LastRunState {
+    pub fn new() -> Self {
+        Self {
+            version: 1,
+            saved_at: None,
+            scores: HashMap::new(),
+        }
+    }
+
+    /// Compare `current` (URL and score, in list order) with the saved run.
+    /// None when there is no saved run yet. Scores count as changed when they
+    /// would print differently, so float noise doesn't show up.
+    pub fn diff(&self, current: &[(&str, f64)]) -> Option<RunDelta> {
+        self.saved_at?;
+        let mut delta = RunDelta::default();
+        for &(url, score) in current {
+            match self.scores.get(url) {
+                None => delta.new.push(url.to_string()),
+                Some(&old) if old.round() != score.round() => {
+                    delta.score_changes.push(ScoreChange {
+                        url: url.to_string(),
+                        old,
+                        new: score,
+                    })
+                }
+                Some(_) => {}
+            }
+        }
+        let listed: HashSet<&str> = current.iter().map(|(url, _)| *url).collect();
+        delta.removed = self
+            .scores
+            .keys()
+            .filter(|url| !listed.contains(url.as_str()))
+            .cloned()
+            .collect();
+        delta.removed.sort();
+        Some(delta)
+    }
+
+    /// Replace the saved run with `current`
+    pub fn record(&mut self, current: &[(&str, f64)], now: chrono::DateTime<chrono::Utc>) {
+        self.saved_at = Some(now);
+        self.scores = current
+            .iter()
+            .map(|&(url, score)| (url.to_string(), score))
+            .collect();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_first_run_has_no_delta() {
+        let state = LastRunState::new();
+        assert_eq!(state.diff(&[("https://github.com/o/r/pull/1", 10.0)]), None);
+    }
+
+    #[test]
+    fn test_diff_against_last_run() {
+        let mut state = LastRunState::new();
+        state.record(
+            &[
+                ("https://github.com/o/r/pull/1", 10.0),
+                ("https://github.com/o/r/pull/2", 20.0),
+                ("https://github.com/o/r/pull/3", 30.0),
+            ],
+            chrono::Utc::now(),
+        );
+
+        let delta = state
+            .diff(&[
+                ("https://github.com/o/r/pull/1", 10.2),
+                ("https://github.com/o/r/pull/2", 25.0),
+                ("https://github.com/o/r/pull/4", 5.0),
+            ])
+            .unwrap();
+        assert_eq!(delta.new, vec!["https://github.com/o/r/pull/4"]);
+        assert_eq!(delta.removed, vec!["https://github.com/o/r/pull/3"]);
+        assert_eq!(
+            delta.score_changes,
+            vec![ScoreChange {
+                url: "https://github.com/o/r/pull/2".to_string(),
+                old: 20.0,
+                new: 25.0,
+            }]
+        );
+        assert_eq!(delta.summary(), "1 new, 1 removed, 1 score change");
+    }
+}
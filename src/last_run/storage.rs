@@ -0,0 +1,46 @@
+use super::LastRunState;
+use anyhow::Result;
+use std::path::{Path, PathBuf};
+
+/// Get the default last-run snapshot path (~/.config/pr-bro/last_run.json)
+pub fn get_last_run_path() -> PathBuf {
+    crate::config::get_config_dir().join("last_run.json")
+}
+
+/// Load the last-run snapshot from a JSON file
+///
+/// The snapshot is only a comparison point, so a missing, unreadable, or
+/// unsupported file starts over with an empty state (with a warning)
+/// instead of failing the run.
+pub fn load_last_run_state(path: &Path) -> LastRunState {
+    crate::state_file::load_state(path, "last-run snapshot", &[]).unwrap_or_else(|e| {
+        log::warn!("{}. Starting the since-last-run comparison fresh.", e);
+        LastRunState::new()
+    })
+}
+
+/// Save the last-run snapshot to a JSON file atomically
+pub fn save_last_run_state(path: &Path, state: &LastRunState) -> Result<()> {
+    crate::state_file::save_state(path, "last-run snapshot", state)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::env;
+
+    #[test]
+    fn test_unreadable_or_newer_snapshot_resets_to_empty() {
+        let path = env::temp_dir().join("pr_bro_test_last_run_reset.json");
+
+        std::fs::write(&path, "not json").unwrap();
+        assert!(load_last_run_state(&path).saved_at.is_none());
+
+        std::fs::write(&path, r#"{ "version": 99, "scores": { "u": 1.0 } }"#).unwrap();
+        let state = load_last_run_state(&path);
+        assert!(state.saved_at.is_none());
+        assert!(state.scores.is_empty());
+
+        let _ = std::fs::remove_file(&path);
+    }
+}
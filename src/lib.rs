@@ -3,9 +3,19 @@ pub mod config;
 pub mod credentials;
 pub mod fetch;
 pub mod github;
+pub mod health;
+pub mod last_run;
+pub mod logging;
+pub mod marks;
+pub mod notify;
 pub mod output;
+pub mod pins;
+pub mod plan;
+pub mod review_requests;
 pub mod scoring;
 pub mod snooze;
+pub mod state_file;
 pub mod stderr_buffer;
 pub mod tui;
 pub mod version_check;
+pub mod workload;
@@ -0,0 +1,172 @@
+//! Leveled diagnostics through the `log` facade.
+//!
+//! Records go to stderr (through the TUI stderr buffer, so they never corrupt
+//! the screen) at a level set by `--verbose`/`--quiet`, and optionally to a
+//! `--log-file` at debug level regardless of those flags.
+
+use anyhow::{Context, Result};
+use log::{Level, LevelFilter, Log, Metadata, Record};
+use std::fs::{File, OpenOptions};
+use std::io::Write;
+use std::path::Path;
+use std::sync::Mutex;
+
+/// Level written to the log file
+const FILE_LEVEL: LevelFilter = LevelFilter::Debug;
+
+/// Level for dependencies' records in the log file; their debug output is
+/// mostly noise. They never reach stderr, which stays as it always was.
+const DEPENDENCY_LEVEL: LevelFilter = LevelFilter::Warn;
+
+/// Stderr level for the CLI flags: quiet shows nothing, verbose adds info
+pub fn stderr_level(verbose: bool, quiet: bool) -> LevelFilter {
+    if quiet {
+        LevelFilter::Off
+    } else if verbose {
+        LevelFilter::Info
+    } else {
+        LevelFilter::Warn
+    }
+}
+
+struct Logger {
+    stderr_level: LevelFilter,
+    file: Option<Mutex<File>>,
+}
+
+impl Logger {
+    fn file_enabled(&self, metadata: &Metadata) -> bool {
+        let level = if is_own(metadata.target()) {
+            FILE_LEVEL
+        } else {
+            DEPENDENCY_LEVEL
+        };
+        self.file.is_some() && metadata.level() <= level
+    }
+
+    fn stderr_enabled(&self, metadata: &Metadata) -> bool {
+        is_own(metadata.target()) && metadata.level() <= self.stderr_level
+    }
+}
+
+impl Log for Logger {
+    fn enabled(&self, metadata: &Metadata) -> bool {
+        self.stderr_enabled(metadata) || self.file_enabled(metadata)
+    }
+
+    fn log(&self, record: &Record) {
+        if self.stderr_enabled(record.metadata()) {
+            crate::stderr_buffer::write(stderr_line(record.level(), &record.args().to_string()));
+        }
+        if self.file_enabled(record.metadata()) {
+            if let Some(file) = &self.file {
+                let line = file_line(
+                    &chrono::Local::now().format("%Y-%m-%dT%H:%M:%S%.3f%:z"),
+                    record.level(),
+                    record.target(),
+                    &record.args().to_string(),
+                );
+                // Logging must never take the program down
+                let _ = writeln!(file.lock().unwrap(), "{}", line);
+            }
+        }
+    }
+
+    fn flush(&self) {
+        if let Some(file) = &self.file {
+            let _ = file.lock().unwrap().flush();
+        }
+    }
+}
+
+/// Whether a record comes from this crate rather than a dependency
+fn is_own(target: &str) -> bool {
+    target == "pr_bro" || target.starts_with("pr_bro::")
+}
+
+/// Stderr keeps the plain messages the CLI has always printed, with a
+/// prefix for problems
+fn stderr_line(level: Level, message: &str) -> String {
+    match level {
+        Level::Error => format!("Error: {}", message),
+        Level::Warn => format!("Warning: {}", message),
+        Level::Info | Level::Debug | Level::Trace => message.to_string(),
+    }
+}
+
+fn file_line(
+    timestamp: &dyn std::fmt::Display,
+    level: Level,
+    target: &str,
+    message: &str,
+) -> String {
+    format!("{} {:<5} {}: {}", timestamp, level, target, message)
+}
+
+/// Install the logger. Call once, before anything logs. `log_file` is
+/// appended to, so several runs can be captured in one file.
+pub fn init(stderr_level: LevelFilter, log_file: Option<&Path>) -> Result<()> {
+    let file = log_file
+        .map(|path| {
+            OpenOptions::new()
+                .create(true)
+                .append(true)
+                .open(path)
+                .with_context(|| format!("Failed to open log file {}", path.display()))
+        })
+        .transpose()?;
+
+    let max_level = if file.is_some() {
+        stderr_level.max(FILE_LEVEL)
+    } else {
+        stderr_level
+    };
+    log::set_boxed_logger(Box::new(Logger {
+        stderr_level,
+        file: file.map(Mutex::new),
+    }))
+    .context("Logger already initialized")?;
+    log::set_max_level(max_level);
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_stderr_level_follows_flags() {
+        assert_eq!(stderr_level(false, false), LevelFilter::Warn);
+        assert_eq!(stderr_level(true, false), LevelFilter::Info);
+        // Quiet wins over verbose
+        assert_eq!(stderr_level(true, true), LevelFilter::Off);
+    }
+
+    #[test]
+    fn test_dependency_records_only_go_to_file() {
+        let logger = Logger {
+            stderr_level: LevelFilter::Info,
+            file: None,
+        };
+        let meta = |level, target| Metadata::builder().level(level).target(target).build();
+        assert!(logger.stderr_enabled(&meta(Level::Info, "pr_bro::fetch")));
+        assert!(!logger.stderr_enabled(&meta(Level::Debug, "pr_bro::fetch")));
+        assert!(!logger.stderr_enabled(&meta(Level::Error, "octocrab")));
+        assert!(!logger.file_enabled(&meta(Level::Error, "pr_bro")));
+    }
+
+    #[test]
+    fn test_line_formats() {
+        assert_eq!(stderr_line(Level::Warn, "disk full"), "Warning: disk full");
+        assert_eq!(stderr_line(Level::Info, "Cache: enabled"), "Cache: enabled");
+        assert_eq!(
+            file_line(
+                &"2026-01-02T03:04:05.000+00:00",
+                Level::Info,
+                "pr_bro::fetch",
+                "hi"
+            ),
+            "2026-01-02T03:04:05.000+00:00 INFO  pr_bro::fetch: hi"
+        );
+    }
+}
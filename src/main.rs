@@ -1,4 +1,4 @@
-use clap::{Parser, Subcommand};
+use clap::{CommandFactory, Parser, Subcommand};
 use std::io::{BufRead, IsTerminal, Write as _};
 use std::path::PathBuf;
 use std::time::Instant;
@@ -21,26 +21,104 @@ enum Commands {
     },
     /// Open a PR in browser by its index number
     Open {
-        /// Index number of the PR to open (1-based, as shown in list)
-        index: usize,
+        /// Index of the PR to open, as shown in list (see `index_style`)
+        index: String,
+        /// Open the Files changed tab
+        #[arg(long, group = "tab")]
+        files: bool,
+        /// Open the Commits tab
+        #[arg(long, group = "tab")]
+        commits: bool,
+        /// Open the Checks tab
+        #[arg(long, group = "tab")]
+        checks: bool,
+        /// Open the PR's repository instead
+        #[arg(long, group = "tab")]
+        repo: bool,
+        /// Open the PR author's profile instead
+        #[arg(long, group = "tab")]
+        author: bool,
     },
     /// Snooze a PR by its index number
+    #[command(args_conflicts_with_subcommands = true, subcommand_negates_reqs = true)]
     Snooze {
-        /// Index number of the PR to snooze (1-based, as shown in list)
-        index: usize,
+        /// Index of the PR to snooze, as shown in list (see `index_style`)
+        #[arg(required = true)]
+        index: Option<String>,
         /// Duration to snooze (e.g., "2h", "3d", "1w"). Omit for indefinite.
         #[arg(long, value_name = "DURATION")]
         r#for: Option<String>,
+        #[command(subcommand)]
+        action: Option<SnoozeAction>,
     },
     /// Unsnooze a PR by its index in the snoozed list
     Unsnooze {
-        /// Index number of the snoozed PR to unsnooze (1-based, as shown in --show-snoozed list)
-        index: usize,
+        /// Index of the snoozed PR to unsnooze, as shown in --show-snoozed list
+        index: String,
+    },
+    /// Show the highest-priority PR in detail and offer to open it
+    #[command(alias = "focus")]
+    Next {
+        /// Number of top PRs to show
+        #[arg(default_value_t = 1)]
+        count: usize,
+    },
+    /// Show how many PRs each requested reviewer has outstanding
+    Workload {
+        /// Search query to use instead of the configured queries
+        #[arg(long)]
+        query: Option<String>,
+    },
+    /// Plan a review session: the highest-scoring PRs that fit in a changed-line budget
+    Plan {
+        /// Maximum total changed lines (additions + deletions) to review
+        #[arg(long)]
+        budget: u64,
+    },
+    /// Fetch once and report whether PR Bro can reach GitHub, for monitoring.
+    /// Always exits 0; failures are reported in the output.
+    Check {
+        /// Print a JSON object instead of a summary line
+        #[arg(long)]
+        json: bool,
+    },
+    /// Print a shell completion script to stdout
+    Completions {
+        /// Shell to generate completions for
+        #[arg(value_enum)]
+        shell: clap_complete::Shell,
+    },
+    /// Print the effective scoring config for each query (global merged with overrides)
+    ExplainConfig,
+    /// Score PRs saved by --dump-raw with the current config, without
+    /// fetching, to try out scoring changes
+    ScoreTest {
+        /// Dump directory, or one search-N.json file in it
+        #[arg(long, value_name = "PATH")]
+        input: PathBuf,
+        /// Your GitHub login, for the factors about your own reviews and PRs
+        #[arg(long, value_name = "LOGIN")]
+        user: Option<String>,
     },
     /// Initialize a new config file with an interactive wizard
     Init,
 }
 
+#[derive(Subcommand, Debug)]
+enum SnoozeAction {
+    /// Snooze the PR if it's active, unsnooze it if it's snoozed
+    Toggle {
+        /// PR URL, or index of a PR as shown in list (see --snoozed)
+        target: String,
+        /// Duration when snoozing (e.g., "2h", "3d", "1w"). Omit for indefinite.
+        #[arg(long, value_name = "DURATION")]
+        r#for: Option<String>,
+        /// Read an index from the snoozed list (list --show-snoozed), to unsnooze by index
+        #[arg(long)]
+        snoozed: bool,
+    },
+}
+
 #[derive(Parser, Debug)]
 #[command(name = "pr-bro")]
 #[command(about = "GitHub PR review prioritization CLI", long_about = None)]
@@ -50,30 +128,130 @@ struct Cli {
     #[arg(short, long, global = true)]
     verbose: bool,
 
+    /// Append debug-level logs to FILE (handy while the TUI owns the terminal)
+    #[arg(long, global = true, value_name = "FILE")]
+    log_file: Option<PathBuf>,
+
     /// Path to config file (defaults to ~/.config/pr-bro/config.yaml)
     #[arg(short, long, global = true)]
     config: Option<String>,
 
+    /// Ignore any .pr-bro.yaml found in the current directory or its parents
+    #[arg(long, global = true)]
+    no_local_config: bool,
+
     /// Force plain text output even in a terminal
     #[arg(long, global = true)]
     non_interactive: bool,
 
-    /// Output format when non-interactive (table or tsv)
-    #[arg(long, global = true, default_value = "table")]
+    /// Output format when non-interactive (table, tsv, json, json-array: the
+    /// PRs as a bare array, or jsonl: one JSON record per line)
+    #[arg(long, global = true, default_value = "table", value_parser = ["table", "tsv", "json", "json-array", "jsonl"])]
     format: String,
 
+    /// Prepend a column header line to TSV output
+    #[arg(long, global = true)]
+    header: bool,
+
+    /// Pretty-print JSON output (implies --format json)
+    #[arg(long, global = true)]
+    json_pretty: bool,
+
+    /// Cut titles to N chars in table, TSV, and CSV output, even when piped
+    /// (overrides `max_title_width`)
+    #[arg(long, global = true, value_name = "N", value_parser = clap::value_parser!(u16).range(1..))]
+    max_title_width: Option<u16>,
+
+    /// Order of the list: "score", or "efficiency" (score per changed line).
+    /// List numbers follow it, so pass the same --sort to open and snooze
+    #[arg(long, global = true, default_value = "score", value_parser = ["score", "efficiency"])]
+    sort: String,
+
+    /// Leave out PRs scoring below SCORE (raw, before --normalize); pinned
+    /// PRs always show. List numbers follow the shorter list, so pass the
+    /// same --min-score to open and snooze
+    #[arg(long, global = true, value_name = "SCORE", default_value_t = 0.0, value_parser = pr_bro::fetch::parse_min_score)]
+    min_score: f64,
+
+    /// Split the table into sections by query `group` tag or repo owner
+    #[arg(long, global = true, value_enum, value_name = "KEY")]
+    group_by: Option<pr_bro::output::GroupBy>,
+
+    /// Also write the list as JSON to FILE, whatever --format prints
+    #[arg(long, global = true, value_name = "FILE")]
+    json_file: Option<PathBuf>,
+
+    /// Also write the list as TSV to FILE (with --header, a header line too)
+    #[arg(long, global = true, value_name = "FILE")]
+    tsv_file: Option<PathBuf>,
+
+    /// Also write the list as CSV, with a header line, to FILE
+    #[arg(long, global = true, value_name = "FILE")]
+    csv_file: Option<PathBuf>,
+
     /// Disable HTTP response caching for this run
     #[arg(long, global = true)]
     no_cache: bool,
 
-    /// Remove cached GitHub API responses and exit
+    /// Fetch fresh data for one non-interactive run, still refreshing the cache
+    #[arg(long, global = true, conflicts_with = "no_cache")]
+    refresh_once: bool,
+
+    /// Debug only: write raw search and enrichment responses to DIR
+    #[arg(long, global = true, hide = true, value_name = "DIR")]
+    dump_raw: Option<std::path::PathBuf>,
+
+    /// Remove cached GitHub API responses (asks first in a terminal). Exits
+    /// afterwards unless a subcommand is given, which then runs as usual
     #[arg(long, global = true)]
     clear_cache: bool,
 
+    /// With --clear-cache, only remove responses for this repo (owner/name)
+    #[arg(
+        long,
+        global = true,
+        requires = "clear_cache",
+        value_name = "OWNER/NAME"
+    )]
+    cache_repo: Option<String>,
+
+    /// Answer yes to confirmation prompts (e.g. --clear-cache)
+    #[arg(short, long, global = true)]
+    yes: bool,
+
     /// Disable version update checking
     #[arg(long, global = true)]
     no_version_check: bool,
 
+    /// Print a breakdown of where time was spent (non-interactive mode)
+    #[arg(long, global = true)]
+    profile_timing: bool,
+
+    /// Don't compare the list with the last run, or save it for the next one
+    #[arg(long, global = true)]
+    no_diff: bool,
+
+    /// Include PRs older than the configured max_age cutoff
+    #[arg(long, global = true)]
+    include_old: bool,
+
+    /// Leave out PRs you authored (same as `exclude_own: true`)
+    #[arg(long, global = true)]
+    exclude_own: bool,
+
+    /// Show scores as 0-100 relative to the top PR in the list (JSON and
+    /// --*-file outputs keep raw scores)
+    #[arg(long, global = true)]
+    normalize: bool,
+
+    /// In the TUI, open PRs in the browser as they are selected (toggle with 'O')
+    #[arg(long)]
+    open_on_select: bool,
+
+    /// Suppress logs and warnings; print only results (errors still go to stderr)
+    #[arg(short, long, global = true)]
+    quiet: bool,
+
     #[command(subcommand)]
     command: Option<Commands>,
 }
@@ -85,33 +263,74 @@ async fn main() {
         .install_default()
         .expect("Failed to install rustls crypto provider");
 
-    let cli = Cli::parse();
+    let mut cli = Cli::parse();
     let config_path_str = cli.config.clone();
-    let command = cli.command.unwrap_or(Commands::List {
+    let explicit_command = cli.command.is_some();
+    let command = cli.command.take().unwrap_or(Commands::List {
         show_snoozed: false,
     });
     let start_time = Instant::now();
 
-    // Handle --clear-cache flag (early exit before credential setup)
+    // Quiet mode wins over verbose and silences logged warnings on stderr
+    let verbose = cli.verbose && !cli.quiet;
+    let stderr_level = pr_bro::logging::stderr_level(cli.verbose, cli.quiet);
+    if let Err(e) = pr_bro::logging::init(stderr_level, cli.log_file.as_deref()) {
+        eprintln!("{:#}", e);
+        std::process::exit(EXIT_CONFIG);
+    }
+
+    // Handle completions subcommand (no config, cache, or network needed)
+    if let Commands::Completions { shell } = command {
+        clap_complete::generate(shell, &mut Cli::command(), "pr-bro", &mut std::io::stdout());
+        std::process::exit(EXIT_SUCCESS);
+    }
+
+    // Handle --clear-cache: report what is cached and confirm before removing
+    // it. On its own it exits afterwards; with an explicit subcommand the run
+    // continues on the emptied cache, so status goes to stderr.
     if cli.clear_cache {
-        let cache_path = pr_bro::github::get_cache_path();
-        println!("Clearing cache at: {}", cache_path.display());
-        match pr_bro::github::clear_cache() {
-            Ok(()) => {
-                println!("Cache cleared.");
-                std::process::exit(EXIT_SUCCESS);
-            }
+        let repo = cli.cache_repo.as_deref();
+        let stats = match pr_bro::github::cache_stats(repo) {
+            Ok(stats) => stats,
             Err(e) => {
-                eprintln!("Failed to clear cache: {}", e);
+                eprintln!("Failed to clear cache: {:#}", e);
+                std::process::exit(EXIT_CONFIG);
+            }
+        };
+        let target = match repo {
+            Some(repo) => format!("cached responses for {}", repo),
+            None => format!("cache at {}", pr_bro::github::get_cache_path().display()),
+        };
+
+        if stats.entries == 0 {
+            if !cli.quiet {
+                eprintln!("Nothing to clear: no {}.", target);
+            }
+        } else if cli.yes || confirm(&format!("Clear {} ({})?", target, stats.describe())) {
+            let result = match repo {
+                Some(repo) => pr_bro::github::clear_repo_cache(repo).map(|_| ()),
+                None => pr_bro::github::clear_cache(),
+            };
+            if let Err(e) = result {
+                eprintln!("Failed to clear {}: {:#}", target, e);
                 std::process::exit(EXIT_CONFIG);
             }
+            if !cli.quiet {
+                eprintln!("Cleared {} ({}).", target, stats.describe());
+            }
+        } else if !cli.quiet {
+            eprintln!("Cache left untouched.");
+        }
+
+        if !explicit_command {
+            std::process::exit(EXIT_SUCCESS);
         }
     }
 
     // Evict stale cache entries (older than 7 days)
     let evicted = pr_bro::github::evict_stale_entries();
-    if cli.verbose && evicted > 0 {
-        eprintln!(
+    if evicted > 0 {
+        log::info!(
             "Evicted {} stale cache entries (older than 7 days)",
             evicted
         );
@@ -129,12 +348,50 @@ async fn main() {
         }
     }
 
+    // A repo-committed .pr-bro.yaml is merged under the user's config
+    let local_config_path = if cli.no_local_config {
+        None
+    } else {
+        std::env::current_dir()
+            .ok()
+            .and_then(|dir| pr_bro::config::find_local_config(&dir))
+    };
+    if let Some(ref path) = local_config_path {
+        log::info!("Using local config {}", path.display());
+    }
+
+    // Handle check subcommand: it loads the config itself so that every
+    // failure, config errors included, is reported rather than exited on
+    if let Commands::Check { json } = command {
+        let report = match health_check(
+            config_path_str.map(PathBuf::from),
+            local_config_path.as_deref(),
+            !cli.no_cache,
+        )
+        .await
+        {
+            Ok(report) => report,
+            Err(e) => pr_bro::health::HealthReport::failed(&e),
+        };
+        if json || cli.format.starts_with("json") || cli.json_pretty {
+            let output = if cli.json_pretty {
+                serde_json::to_string_pretty(&report)
+            } else {
+                serde_json::to_string(&report)
+            };
+            println!("{}", output.expect("health report serializes"));
+        } else {
+            println!("{}", report.summary());
+        }
+        std::process::exit(EXIT_SUCCESS);
+    }
+
     // Load config (with missing-config wizard prompt)
     let config_path = config_path_str.as_ref().map(PathBuf::from);
     let resolved_path = config_path
         .clone()
         .unwrap_or_else(pr_bro::config::get_config_path);
-    let config = if !resolved_path.exists() {
+    let mut config = if !resolved_path.exists() {
         // Config missing -- offer wizard if interactive terminal
         if std::io::stdin().is_terminal() && std::io::stdout().is_terminal() {
             eprintln!("No config found at {}", resolved_path.display());
@@ -148,7 +405,8 @@ async fn main() {
                     Ok(()) => {
                         // Re-load the config that was just written
                         let reload_path = config_path_str.map(PathBuf::from);
-                        match pr_bro::config::load_config(reload_path) {
+                        match pr_bro::config::load_config(reload_path, local_config_path.as_deref())
+                        {
                             Ok(c) => c,
                             Err(e) => {
                                 eprintln!("Config error after init: {:#}", e);
@@ -174,7 +432,7 @@ async fn main() {
             std::process::exit(EXIT_CONFIG);
         }
     } else {
-        match pr_bro::config::load_config(config_path) {
+        match pr_bro::config::load_config(config_path, local_config_path.as_deref()) {
             Ok(c) => c,
             Err(e) => {
                 eprintln!("Config error: {:#}", e);
@@ -183,16 +441,14 @@ async fn main() {
         }
     };
 
-    if cli.verbose {
-        eprintln!("Loaded {} queries from config", config.queries.len());
-        for (i, query) in config.queries.iter().enumerate() {
-            eprintln!(
-                "  Query {}: {} ({})",
-                i + 1,
-                query.name.as_deref().unwrap_or("(unnamed)"),
-                query.query
-            );
-        }
+    log::info!("Loaded {} queries from config", config.queries.len());
+    for (i, query) in config.queries.iter().enumerate() {
+        log::info!(
+            "  Query {}: {} ({})",
+            i + 1,
+            query.name.as_deref().unwrap_or("(unnamed)"),
+            query.query
+        );
     }
 
     // Validate global scoring config
@@ -221,12 +477,80 @@ async fn main() {
         }
     }
 
+    // Validate non-scoring config
+    if let Err(errors) = config.validate() {
+        eprintln!("Config errors:");
+        for error in errors {
+            eprintln!("  - {}", error);
+        }
+        std::process::exit(EXIT_CONFIG);
+    }
+    apply_cli_overrides(&cli, &mut config);
+    if let Commands::Workload { query: Some(query) } = &command {
+        config.queries = vec![pr_bro::config::QueryConfig {
+            name: None,
+            query: query.clone(),
+            scoring: None,
+            max_age: None,
+            include_issues: false,
+            enrich: true,
+            group: None,
+            weight: None,
+        }];
+    }
+
+    // Handle explain-config subcommand (after validation, no network needed)
+    if matches!(command, Commands::ExplainConfig) {
+        match pr_bro::config::explain_config(&config) {
+            Ok(output) => {
+                print!("{}", output);
+                std::process::exit(EXIT_SUCCESS);
+            }
+            Err(e) => {
+                eprintln!("Failed to explain config: {:#}", e);
+                std::process::exit(EXIT_CONFIG);
+            }
+        }
+    }
+
+    // Handle score-test subcommand (after validation, no network needed)
+    if let Commands::ScoreTest { input, user } = &command {
+        let mut scored = match pr_bro::fetch::score_dump(&config, input, user.as_deref()) {
+            Ok(scored) => scored,
+            Err(e) => {
+                eprintln!("Failed to score dump: {:#}", e);
+                std::process::exit(EXIT_CONFIG);
+            }
+        };
+        let total = scored.len();
+        pr_bro::fetch::drop_below_min_score(
+            &mut scored,
+            cli.min_score,
+            &pr_bro::pins::PinState::new(),
+        );
+        if cli.sort == "efficiency" {
+            pr_bro::fetch::sort_by_efficiency(&mut scored);
+        }
+        let scored_refs: Vec<pr_bro::output::ScoredPr> = scored
+            .iter()
+            .map(|(pr, result)| pr_bro::output::ScoredPr {
+                pr,
+                score: result.score,
+                incomplete: result.incomplete,
+                snooze: None,
+            })
+            .collect();
+        print_list(&cli, &config, &scored_refs, total, None, verbose);
+        std::process::exit(EXIT_SUCCESS);
+    }
+    let config_load_elapsed = start_time.elapsed();
+
     // Load snooze state (before credential setup - no network required)
     let snooze_path = pr_bro::snooze::get_snooze_path();
     let mut snooze_state = match pr_bro::snooze::load_snooze_state(&snooze_path) {
         Ok(s) => s,
         Err(e) => {
-            eprintln!("Warning: Could not load snooze state: {}", e);
+            log::warn!("Could not load snooze state: {}", e);
             pr_bro::snooze::SnoozeState::new()
         }
     };
@@ -247,6 +571,7 @@ async fn main() {
     }
 
     // Setup credentials (prompts for token on first run)
+    let token_start = Instant::now();
     let token = match pr_bro::credentials::setup_token_if_missing() {
         Ok(t) => t,
         Err(e) => {
@@ -254,35 +579,44 @@ async fn main() {
             std::process::exit(EXIT_AUTH);
         }
     };
+    let token_elapsed = token_start.elapsed();
 
-    if cli.verbose {
-        if pr_bro::credentials::get_token_from_env().is_some() {
-            eprintln!(
-                "Token retrieved from {} env var",
-                pr_bro::credentials::ENV_TOKEN_VAR
-            );
-        } else {
-            eprintln!("Token provided via prompt");
+    if pr_bro::credentials::get_token_from_env().is_some() {
+        log::info!(
+            "Token retrieved from {} env var",
+            pr_bro::credentials::ENV_TOKEN_VAR
+        );
+    } else {
+        log::info!("Token provided via prompt");
+    }
+
+    if let Some(dir) = cli.dump_raw.clone() {
+        if let Err(e) = pr_bro::github::set_dump_dir(dir) {
+            eprintln!("Error: {:#}", e);
+            std::process::exit(EXIT_CONFIG);
         }
+        eprintln!("Debug: dumping raw API responses (may include private repo data)");
     }
 
     // Create cache config
     let cache_config = pr_bro::github::CacheConfig {
         enabled: !cli.no_cache,
+        compress: config.cache_compression,
+        bypass_reads: cli.refresh_once,
     };
 
-    if cli.verbose {
-        let status = if cache_config.enabled {
-            "enabled"
-        } else {
-            "disabled (--no-cache)"
-        };
-        eprintln!(
-            "Cache: {} ({})",
-            status,
-            pr_bro::github::get_cache_path().display()
-        );
-    }
+    let status = if cache_config.bypass_reads {
+        "write-only (--refresh-once)"
+    } else if cache_config.enabled {
+        "enabled"
+    } else {
+        "disabled (--no-cache)"
+    };
+    log::info!(
+        "Cache: {} ({})",
+        status,
+        pr_bro::github::get_cache_path().display()
+    );
 
     // Create GitHub client and get cache handle
     let (client, cache_handle) = match pr_bro::github::create_client(&token, &cache_config) {
@@ -296,21 +630,23 @@ async fn main() {
     // Fetch authenticated username once at startup
     let auth_username: Option<String> = match client.current().user().await {
         Ok(user) => {
-            if cli.verbose {
-                eprintln!("Authenticated as: {}", user.login);
-            }
+            log::info!("Authenticated as: {}", user.login);
             Some(user.login)
         }
         Err(e) => {
-            if cli.verbose {
-                eprintln!("Warning: Could not fetch authenticated user: {}", e);
-            }
+            log::info!("Could not fetch authenticated user: {}", e);
             None
         }
     };
 
     // Detect TTY for interactive mode
-    let is_interactive = std::io::stdout().is_terminal() && !cli.non_interactive;
+    // --refresh-once is a single fetch-and-exit, so it never launches the TUI,
+    // and neither does writing the list to files
+    let writes_files = cli.json_file.is_some() || cli.tsv_file.is_some() || cli.csv_file.is_some();
+    let is_interactive = std::io::stdout().is_terminal()
+        && !cli.non_interactive
+        && !cli.refresh_once
+        && !writes_files;
 
     // If interactive and default list command (not explicit subcommand), launch TUI
     if is_interactive
@@ -321,22 +657,23 @@ async fn main() {
             }
         )
     {
-        if cli.verbose {
-            eprintln!("Launching TUI mode...");
-        }
+        log::info!("Launching TUI mode...");
 
         // Create App in loading state (empty PR lists)
-        let app = pr_bro::tui::App::new_loading(
+        let mut app = pr_bro::tui::App::new_loading(
             snooze_state,
             snooze_path,
             config,
             cache_config,
             cache_handle,
-            cli.verbose,
             auth_username.clone(),
             cli.no_version_check,
             theme,
         );
+        app.open_on_select |= cli.open_on_select;
+        app.normalize_scores = cli.normalize;
+        app.sort_by_efficiency = cli.sort == "efficiency";
+        app.min_score = cli.min_score;
 
         // Launch TUI immediately - it will trigger initial fetch in background
         if let Err(e) = pr_bro::tui::run_tui(app, client).await {
@@ -347,16 +684,22 @@ async fn main() {
         std::process::exit(EXIT_SUCCESS);
     }
 
+    // The TUI flashes this itself; plain listings mention it on stderr
+    if matches!(command, Commands::List { .. }) && !cli.quiet {
+        if let Some(notice) = config.snooze_reminder(&snooze_state) {
+            eprintln!("{}", notice);
+        }
+    }
+
     // Non-interactive path: fetch and score PRs, with auth re-prompt on failure
     let mut current_client = client;
     let mut current_auth_username = auth_username;
-    let (active_scored, snoozed_scored, _rate_limit) = loop {
+    let (mut active_scored, mut snoozed_scored, _rate_limit, fetch_timings) = loop {
         match pr_bro::fetch::fetch_and_score_prs(
             &current_client,
             &config,
             &snooze_state,
             &cache_config,
-            cli.verbose,
             current_auth_username.as_deref(),
         )
         .await
@@ -389,15 +732,11 @@ async fn main() {
                     // Re-fetch authenticated username
                     current_auth_username = match current_client.current().user().await {
                         Ok(user) => {
-                            if cli.verbose {
-                                eprintln!("Re-authenticated as: {}", user.login);
-                            }
+                            log::info!("Re-authenticated as: {}", user.login);
                             Some(user.login)
                         }
                         Err(e) => {
-                            if cli.verbose {
-                                eprintln!("Warning: Could not fetch authenticated user: {}", e);
-                            }
+                            log::info!("Could not fetch authenticated user: {}", e);
                             None
                         }
                     };
@@ -413,16 +752,35 @@ async fn main() {
         }
     };
 
+    // Pinned PRs go first, so list indices match the TUI order
+    let pin_state = match pr_bro::pins::load_pin_state(&pr_bro::pins::get_pins_path()) {
+        Ok(state) => state,
+        Err(e) => {
+            log::warn!("Could not load pins: {}", e);
+            pr_bro::pins::PinState::new()
+        }
+    };
+    let (active_total, snoozed_total) = (active_scored.len(), snoozed_scored.len());
+    pr_bro::fetch::drop_below_min_score(&mut active_scored, cli.min_score, &pin_state);
+    pr_bro::fetch::drop_below_min_score(&mut snoozed_scored, cli.min_score, &pin_state);
+    if cli.sort == "efficiency" {
+        pr_bro::fetch::sort_by_efficiency(&mut active_scored);
+        pr_bro::fetch::sort_by_efficiency(&mut snoozed_scored);
+    }
+    pr_bro::fetch::float_pinned(&mut active_scored, &pin_state);
+    pr_bro::fetch::float_pinned(&mut snoozed_scored, &pin_state);
+
     // Non-interactive path: use existing CLI behavior
     // Select which list to use based on command
-    let scored_prs = match &command {
-        Commands::List { show_snoozed: true } | Commands::Unsnooze { .. } => snoozed_scored,
-        _ => active_scored,
+    let scored_prs: &[_] = match &command {
+        Commands::List { show_snoozed: true } | Commands::Unsnooze { .. } => &snoozed_scored,
+        _ => &active_scored,
     };
 
     // Route based on subcommand
+    let output_start = Instant::now();
     match command {
-        Commands::List { show_snoozed: _ } => {
+        Commands::List { show_snoozed } => {
             // Build ScoredPr references for formatter
             let scored_refs: Vec<pr_bro::output::ScoredPr> = scored_prs
                 .iter()
@@ -430,137 +788,152 @@ async fn main() {
                     pr,
                     score: result.score,
                     incomplete: result.incomplete,
+                    snooze: if show_snoozed {
+                        snooze_state.snoozed_entries().get(&pr.url)
+                    } else {
+                        None
+                    },
                 })
                 .collect();
 
-            // Output results
-            let use_colors = pr_bro::output::should_use_colors();
-
-            if cli.format == "tsv" {
-                // TSV mode: machine-readable tab-separated output
-                let output = pr_bro::output::format_tsv(&scored_refs);
-                if !output.is_empty() {
-                    println!("{}", output);
-                }
-            } else if cli.verbose && !scored_refs.is_empty() {
-                // Verbose mode: detailed output with scores
-                for scored in &scored_refs {
-                    println!(
-                        "{}",
-                        pr_bro::output::format_pr_detail(scored.pr, use_colors)
-                    );
-                    println!(
-                        "  Score: {}",
-                        pr_bro::output::format_score(scored.score, scored.incomplete)
-                    );
-                    println!();
-                }
+            // Only the active list is compared and saved; --show-snoozed
+            // listings leave the snapshot alone
+            let delta = if show_snoozed || cli.no_diff {
+                None
             } else {
-                // Normal mode: scored table
-                let output = pr_bro::output::format_scored_table(&scored_refs, use_colors);
-                println!("{}", output);
-            }
+                since_last_run(scored_prs)
+            };
+            let total = if show_snoozed {
+                snoozed_total
+            } else {
+                active_total
+            };
+            print_list(&cli, &config, &scored_refs, total, delta.as_ref(), verbose);
 
-            if cli.verbose {
-                eprintln!();
-                eprintln!(
-                    "Total: {} PRs in {:?}",
-                    scored_prs.len(),
-                    start_time.elapsed()
-                );
-            }
+            log::info!(
+                "Total: {} PRs in {:?}",
+                scored_prs.len(),
+                start_time.elapsed()
+            );
         }
-        Commands::Open { index } => {
-            // Handle empty result case
-            if scored_prs.is_empty() {
-                eprintln!("No pull requests found. Nothing to open.");
-                std::process::exit(EXIT_SUCCESS);
-            }
+        Commands::Open {
+            index,
+            files,
+            commits,
+            checks,
+            repo,
+            author,
+        } => {
+            let (pr, _result) = select_by_index(scored_prs, &index, config.index_style, "open");
 
-            // Validate index bounds (1-based)
-            if index < 1 || index > scored_prs.len() {
-                eprintln!(
-                    "Invalid index {}. Must be between 1 and {}.",
-                    index,
-                    scored_prs.len()
-                );
-                std::process::exit(EXIT_CONFIG);
+            // clap allows at most one tab or page flag
+            let tab = [
+                (files, pr_bro::browser::PrTab::Files),
+                (commits, pr_bro::browser::PrTab::Commits),
+                (checks, pr_bro::browser::PrTab::Checks),
+            ]
+            .into_iter()
+            .find_map(|(set, tab)| set.then_some(tab));
+            if tab.is_some() && pr.is_issue {
+                log::warn!("#{} is an issue, which has no tabs", pr.number);
             }
-
-            // Get PR at index (convert to 0-based)
-            let (pr, _result) = &scored_prs[index - 1];
+            let (url, what) = if repo {
+                (
+                    pr_bro::browser::pr_page_url(
+                        &pr.url,
+                        pr_bro::browser::PrPage::Repo,
+                        &pr.repo,
+                        &pr.author,
+                    ),
+                    pr.repo.clone(),
+                )
+            } else if author {
+                (
+                    pr_bro::browser::pr_page_url(
+                        &pr.url,
+                        pr_bro::browser::PrPage::Author,
+                        &pr.repo,
+                        &pr.author,
+                    ),
+                    format!("@{}'s profile", pr.author),
+                )
+            } else {
+                (
+                    pr_bro::browser::pr_tab_url(&pr.url, tab),
+                    format!("PR #{}", pr.number),
+                )
+            };
 
             // Open in browser
-            if let Err(e) = pr_bro::browser::open_url(&pr.url) {
+            if let Err(e) = pr_bro::browser::open_url(&url, config.browser.as_deref()) {
                 eprintln!("Failed to open browser: {}", e);
                 std::process::exit(EXIT_NETWORK);
             }
 
-            println!("Opening PR #{} in browser: {}", pr.number, pr.url);
+            println!("Opening {} in browser: {}", what, url);
         }
         Commands::Snooze {
-            index,
-            r#for: duration,
+            action:
+                Some(SnoozeAction::Toggle {
+                    target,
+                    r#for: duration,
+                    snoozed,
+                }),
+            ..
         } => {
-            if scored_prs.is_empty() {
-                eprintln!("No pull requests found. Nothing to snooze.");
-                std::process::exit(EXIT_SUCCESS);
-            }
-            if index < 1 || index > scored_prs.len() {
-                eprintln!(
-                    "Invalid index {}. Must be between 1 and {}.",
-                    index,
-                    scored_prs.len()
-                );
-                std::process::exit(EXIT_CONFIG);
-            }
-
-            let (pr, _) = &scored_prs[index - 1];
-            let snooze_until = if let Some(dur_str) = duration {
-                let std_duration = humantime::parse_duration(&dur_str).unwrap_or_else(|_| {
-                    eprintln!(
-                        "Invalid duration '{}'. Use formats like: 2h, 3d, 1w",
-                        dur_str
-                    );
-                    std::process::exit(EXIT_CONFIG);
-                });
-                let chrono_duration =
-                    chrono::Duration::from_std(std_duration).unwrap_or_else(|_| {
-                        eprintln!("Duration '{}' is too large.", dur_str);
+            // A URL is stable across runs; an index addresses the active list,
+            // or the snoozed one with --snoozed
+            let pr_bro::snooze::ToggleTarget { url, label } =
+                match pr_bro::snooze::resolve_toggle_target(
+                    &target,
+                    config.index_style,
+                    &active_scored,
+                    &snoozed_scored,
+                    snoozed,
+                ) {
+                    Ok(resolved) => resolved,
+                    Err(e) => {
+                        eprintln!("{}", e);
                         std::process::exit(EXIT_CONFIG);
-                    });
-                Some(chrono::Utc::now() + chrono_duration)
+                    }
+                };
+
+            if snooze_state.is_snoozed(&url) {
+                snooze_state.unsnooze(&url);
+                save_snooze_or_exit(&snooze_path, &snooze_state);
+                println!("Unsnoozed {}", label);
             } else {
-                None
-            };
+                let snooze_until = parse_snooze_until(duration);
+                snooze_state.snooze(url, snooze_until);
+                save_snooze_or_exit(&snooze_path, &snooze_state);
+                println!("Snoozed {}{}", label, format_snooze_until(snooze_until));
+            }
+        }
+        Commands::Snooze {
+            index,
+            r#for: duration,
+            ..
+        } => {
+            let index = index.expect("clap requires an index without a subcommand");
+            let (pr, _) = select_by_index(scored_prs, &index, config.index_style, "snooze");
+            let snooze_until = parse_snooze_until(duration);
 
             snooze_state.snooze(pr.url.clone(), snooze_until);
-            if let Err(e) = pr_bro::snooze::save_snooze_state(&snooze_path, &snooze_state) {
-                eprintln!("Failed to save snooze state: {}", e);
-                std::process::exit(EXIT_CONFIG);
-            }
+            save_snooze_or_exit(&snooze_path, &snooze_state);
 
-            let duration_msg = match snooze_until {
-                Some(until) => format!(" until {}", until.format("%Y-%m-%d %H:%M UTC")),
-                None => " indefinitely".to_string(),
-            };
-            println!("Snoozed PR #{}{}: {}", pr.number, duration_msg, pr.title);
+            println!(
+                "Snoozed PR #{}{}: {}",
+                pr.number,
+                format_snooze_until(snooze_until),
+                pr.title
+            );
         }
         Commands::Unsnooze { index } => {
             if scored_prs.is_empty() {
                 eprintln!("No snoozed pull requests found. Nothing to unsnooze.");
                 std::process::exit(EXIT_SUCCESS);
             }
-            if index < 1 || index > scored_prs.len() {
-                eprintln!(
-                    "Invalid index {}. Must be between 1 and {}.",
-                    index,
-                    scored_prs.len()
-                );
-                std::process::exit(EXIT_CONFIG);
-            }
-
-            let (pr, _) = &scored_prs[index - 1];
+            let (pr, _) = select_by_index(scored_prs, &index, config.index_style, "unsnooze");
             let removed = snooze_state.unsnooze(&pr.url);
             if removed {
                 if let Err(e) = pr_bro::snooze::save_snooze_state(&snooze_path, &snooze_state) {
@@ -569,11 +942,510 @@ async fn main() {
                 }
                 println!("Unsnoozed PR #{}: {}", pr.number, pr.title);
             } else {
-                eprintln!("PR #{} was not snoozed.", pr.number);
+                log::warn!("PR #{} was not snoozed.", pr.number);
             }
         }
+        Commands::Next { count } => {
+            if scored_prs.is_empty() {
+                println!("No pull requests to review.");
+                std::process::exit(EXIT_SUCCESS);
+            }
+
+            let use_colors = pr_bro::output::should_use_colors();
+            let top = &scored_prs[..count.clamp(1, scored_prs.len())];
+            for (i, (pr, result)) in top.iter().enumerate() {
+                if i > 0 {
+                    println!();
+                }
+                if top.len() > 1 {
+                    print!("{}. ", i + 1);
+                }
+                println!(
+                    "{}",
+                    pr_bro::output::format_pr_detail(pr, use_colors, config.display_timezone())
+                );
+                println!(
+                    "  Score: {}",
+                    pr_bro::output::format_score(result.score, result.incomplete)
+                );
+            }
+
+            // Only prompt when someone is there to answer
+            if std::io::stdin().is_terminal() && std::io::stdout().is_terminal() {
+                println!();
+                if top.len() == 1 {
+                    print!("Open in browser? [Y/n] ");
+                } else {
+                    print!("Open which PR? [1-{}, Enter to skip] ", top.len());
+                }
+                let _ = std::io::stdout().flush();
+                let mut answer = String::new();
+                let _ = std::io::stdin().lock().read_line(&mut answer);
+                let answer = answer.trim().to_lowercase();
+
+                let choice = if top.len() == 1 {
+                    (answer.is_empty() || answer == "y" || answer == "yes").then_some(0)
+                } else {
+                    answer
+                        .parse::<usize>()
+                        .ok()
+                        .filter(|n| (1..=top.len()).contains(n))
+                        .map(|n| n - 1)
+                };
+                if let Some(idx) = choice {
+                    let pr = &top[idx].0;
+                    if let Err(e) = pr_bro::browser::open_url(&pr.url, config.browser.as_deref()) {
+                        eprintln!("Failed to open browser: {}", e);
+                        std::process::exit(EXIT_NETWORK);
+                    }
+                    println!("Opening PR #{} in browser: {}", pr.number, pr.url);
+                }
+            }
+        }
+        Commands::Workload { .. } => {
+            // Snoozing is personal; a reviewer's outstanding requests include both lists
+            let all_prs = active_scored.iter().chain(&snoozed_scored);
+            let (loads, unknown) = pr_bro::workload::tally_reviewers(all_prs.map(|(pr, _)| pr));
+            if unknown > 0 {
+                log::warn!(
+                    "reviewer data unavailable for {} PR{}; not counted",
+                    unknown,
+                    if unknown == 1 { "" } else { "s" }
+                );
+            }
+
+            // The report is one compact JSON line, so jsonl prints it too
+            if cli.format.starts_with("json") || cli.json_pretty {
+                println!(
+                    "{}",
+                    pr_bro::output::format_workload_json(&loads, cli.json_pretty)
+                );
+            } else {
+                println!(
+                    "{}",
+                    pr_bro::output::format_workload_table(
+                        &loads,
+                        pr_bro::output::should_use_colors()
+                    )
+                );
+            }
+        }
+        Commands::Plan { budget } => {
+            let selected: Vec<(usize, pr_bro::output::ScoredPr)> =
+                pr_bro::plan::select_within_budget(scored_prs, budget)
+                    .into_iter()
+                    .map(|idx| {
+                        let (pr, result) = &scored_prs[idx];
+                        (
+                            idx + 1,
+                            pr_bro::output::ScoredPr {
+                                pr,
+                                score: result.score,
+                                incomplete: result.incomplete,
+                                snooze: None,
+                            },
+                        )
+                    })
+                    .collect();
+
+            // The plan is one compact JSON line, so jsonl prints it too
+            if cli.format.starts_with("json") || cli.json_pretty {
+                println!(
+                    "{}",
+                    pr_bro::output::format_plan_json(&selected, budget, cli.json_pretty)
+                );
+            } else {
+                println!(
+                    "{}",
+                    pr_bro::output::format_plan_table(
+                        &selected,
+                        budget,
+                        pr_bro::output::should_use_colors()
+                    )
+                );
+            }
+        }
+        Commands::Check { .. } => unreachable!("Check is handled before config loading"),
+        Commands::Completions { .. } => {
+            unreachable!("Completions is handled before config loading")
+        }
+        Commands::ExplainConfig => unreachable!("ExplainConfig is handled after config loading"),
+        Commands::ScoreTest { .. } => unreachable!("ScoreTest is handled after config loading"),
         Commands::Init => unreachable!("Init is handled before config loading"),
     }
 
+    if cli.profile_timing {
+        let mut entries = vec![
+            ("config load".to_string(), config_load_elapsed),
+            ("token retrieval".to_string(), token_elapsed),
+        ];
+        for query in &fetch_timings.queries {
+            entries.push((format!("search: {}", query.name), query.timing.search));
+            entries.push((
+                format!("enrichment: {}", query.name),
+                query.timing.enrichment,
+            ));
+        }
+        entries.push((
+            "queries (parallel, wall)".to_string(),
+            fetch_timings.queries_total,
+        ));
+        entries.push(("scoring".to_string(), fetch_timings.scoring));
+        entries.push(("output".to_string(), output_start.elapsed()));
+        entries.push(("total".to_string(), start_time.elapsed()));
+        eprintln!("{}", pr_bro::output::format_timing_report(&entries));
+    }
+
     std::process::exit(EXIT_SUCCESS);
 }
+
+/// The steps of a normal run up to the first fetch, for `pr-bro check`. Never
+/// prompts: the token must come from the environment.
+async fn health_check(
+    config_path: Option<PathBuf>,
+    local_config_path: Option<&std::path::Path>,
+    use_cache: bool,
+) -> anyhow::Result<pr_bro::health::HealthReport> {
+    let config = pr_bro::config::load_config(config_path, local_config_path)?;
+    let mut errors = Vec::new();
+    let global_scoring = config.scoring.clone().unwrap_or_default();
+    for scoring in std::iter::once(&global_scoring).chain(
+        config
+            .queries
+            .iter()
+            .filter_map(|query| query.scoring.as_ref()),
+    ) {
+        if let Err(e) = pr_bro::scoring::validate_scoring(scoring) {
+            errors.extend(e);
+        }
+    }
+    if let Err(e) = config.validate() {
+        errors.extend(e);
+    }
+    if !errors.is_empty() {
+        anyhow::bail!("Config errors: {}", errors.join("; "));
+    }
+    if config.queries.is_empty() {
+        anyhow::bail!("No queries configured");
+    }
+
+    let token = pr_bro::credentials::get_token_from_env().ok_or_else(|| {
+        anyhow::anyhow!(
+            "No GitHub token: set {}",
+            pr_bro::credentials::ENV_TOKEN_VAR
+        )
+    })?;
+    let cache_config = pr_bro::github::CacheConfig {
+        enabled: use_cache,
+        compress: config.cache_compression,
+        bypass_reads: false,
+    };
+    let (client, _cache) = pr_bro::github::create_client(&token, &cache_config)?;
+    let auth_username = client.current().user().await.ok().map(|user| user.login);
+
+    let snooze_path = pr_bro::snooze::get_snooze_path();
+    let mut snooze_state = pr_bro::snooze::load_snooze_state(&snooze_path)?;
+    snooze_state.clean_expired();
+
+    Ok(pr_bro::health::run_health_check(
+        &client,
+        &config,
+        &snooze_state,
+        &cache_config,
+        auth_username.as_deref(),
+    )
+    .await)
+}
+
+/// Look up a PR by its list index label (a 1-based number, or a letter or
+/// ID with `index_style`), exiting with a message if nothing matches
+fn select_by_index<'a>(
+    prs: &'a [(
+        pr_bro::github::types::PullRequest,
+        pr_bro::scoring::ScoreResult,
+    )],
+    index: &str,
+    index_style: pr_bro::config::IndexStyle,
+    action: &str,
+) -> &'a (
+    pr_bro::github::types::PullRequest,
+    pr_bro::scoring::ScoreResult,
+) {
+    if prs.is_empty() {
+        eprintln!("No pull requests found. Nothing to {}.", action);
+        std::process::exit(EXIT_SUCCESS);
+    }
+    let urls = prs.iter().map(|(pr, _)| pr.url.as_str());
+    match pr_bro::output::resolve_index(index_style, index, urls) {
+        Some(position) => &prs[position],
+        None => {
+            eprintln!(
+                "Invalid index {}. Must be between 1 and {}{}.",
+                index,
+                prs.len(),
+                match index_style {
+                    pr_bro::config::IndexStyle::Number => "",
+                    pr_bro::config::IndexStyle::Letter => ", or a letter as shown in list",
+                    pr_bro::config::IndexStyle::Id => ", or an ID as shown in list",
+                }
+            );
+            std::process::exit(EXIT_CONFIG);
+        }
+    }
+}
+
+/// Apply the flags that override config settings (--include-old,
+/// --exclude-own); an unset flag leaves the config alone
+fn apply_cli_overrides(cli: &Cli, config: &mut pr_bro::config::Config) {
+    if cli.include_old {
+        config.clear_max_age();
+    }
+    if cli.exclude_own {
+        config.exclude_own = true;
+    }
+}
+
+/// Parse a `--for` duration into a snooze end time (None = indefinite)
+fn parse_snooze_until(duration: Option<String>) -> Option<chrono::DateTime<chrono::Utc>> {
+    let dur_str = duration?;
+    let std_duration = humantime::parse_duration(&dur_str).unwrap_or_else(|_| {
+        eprintln!(
+            "Invalid duration '{}'. Use formats like: 2h, 3d, 1w",
+            dur_str
+        );
+        std::process::exit(EXIT_CONFIG);
+    });
+    let chrono_duration = chrono::Duration::from_std(std_duration).unwrap_or_else(|_| {
+        eprintln!("Duration '{}' is too large.", dur_str);
+        std::process::exit(EXIT_CONFIG);
+    });
+    Some(chrono::Utc::now() + chrono_duration)
+}
+
+fn format_snooze_until(until: Option<chrono::DateTime<chrono::Utc>>) -> String {
+    match until {
+        Some(until) => format!(" until {}", until.format("%Y-%m-%d %H:%M UTC")),
+        None => " indefinitely".to_string(),
+    }
+}
+
+/// Print a scored list in the format the flags ask for, and write the
+/// --json-file / --tsv-file / --csv-file copies. `total` is the list's size
+/// before --min-score, reported in JSON. `delta` goes into JSON output, or
+/// on stderr as a one-line summary otherwise.
+fn print_list(
+    cli: &Cli,
+    config: &pr_bro::config::Config,
+    scored_refs: &[pr_bro::output::ScoredPr],
+    total: usize,
+    delta: Option<&pr_bro::last_run::RunDelta>,
+    verbose: bool,
+) {
+    let use_colors = pr_bro::output::should_use_colors();
+
+    // --normalize only changes what's printed for people; JSON and
+    // the --*-file outputs below keep raw scores
+    let mut display_refs = scored_refs.to_vec();
+    let max_title_width = cli
+        .max_title_width
+        .map(usize::from)
+        .or(config.max_title_width);
+    if cli.normalize {
+        pr_bro::output::normalize_scores(&mut display_refs);
+    }
+
+    let json = cli.format == "json" || cli.json_pretty;
+    if let Some(delta) = delta.filter(|_| !json && !cli.quiet) {
+        eprintln!("Since last run: {}", delta.summary());
+    }
+
+    if cli.format == "json-array" {
+        // Bare array of the same records, for `jq '.[]'`
+        println!(
+            "{}",
+            pr_bro::output::format_json_array(scored_refs, cli.json_pretty)
+        );
+    } else if json {
+        // JSON mode: stable field order, compact unless --json-pretty
+        println!(
+            "{}",
+            pr_bro::output::format_json_document(scored_refs, total, delta, cli.json_pretty)
+        );
+    } else if cli.format == "jsonl" {
+        // JSON lines: print each record as it's formatted
+        let now = chrono::Utc::now();
+        for (idx, scored) in scored_refs.iter().enumerate() {
+            println!(
+                "{}",
+                pr_bro::output::format_jsonl_record(idx + 1, scored, now)
+            );
+        }
+    } else if cli.format == "tsv" {
+        // TSV mode: machine-readable tab-separated output
+        let output = pr_bro::output::format_tsv(&display_refs, cli.header, max_title_width);
+        if !output.is_empty() {
+            println!("{}", output);
+        }
+    } else if verbose && !display_refs.is_empty() {
+        // Verbose mode: detailed output with scores
+        for scored in &display_refs {
+            println!(
+                "{}",
+                pr_bro::output::format_pr_detail(scored.pr, use_colors, config.display_timezone())
+            );
+            println!(
+                "  Score: {}",
+                pr_bro::output::format_score(scored.score, scored.incomplete)
+            );
+            println!();
+        }
+    } else {
+        // Normal mode: scored table, in sections with --group-by
+        let output = match cli.group_by {
+            Some(group_by) => pr_bro::output::format_grouped_table(
+                &display_refs,
+                group_by,
+                &config.columns,
+                use_colors,
+                config.wrap_titles,
+                config.index_style,
+                max_title_width,
+            ),
+            None => pr_bro::output::format_scored_table(
+                &display_refs,
+                &config.columns,
+                use_colors,
+                config.wrap_titles,
+                config.index_style,
+                max_title_width,
+            ),
+        };
+        println!("{}", output);
+    }
+
+    // Machine-readable copies, independent of what went to stdout
+    if let Some(ref path) = cli.json_file {
+        write_output_file(
+            path,
+            &pr_bro::output::format_json_document(scored_refs, total, None, cli.json_pretty),
+        );
+    }
+    if let Some(ref path) = cli.tsv_file {
+        write_output_file(
+            path,
+            &pr_bro::output::format_tsv(scored_refs, cli.header, max_title_width),
+        );
+    }
+    if let Some(ref path) = cli.csv_file {
+        write_output_file(
+            path,
+            &pr_bro::output::format_csv(scored_refs, max_title_width),
+        );
+    }
+}
+
+/// Compare the listed PRs with the last run's, then save them for the next
+/// one. None on the first run, or when the snapshot had to be reset.
+fn since_last_run(
+    prs: &[(
+        pr_bro::github::types::PullRequest,
+        pr_bro::scoring::ScoreResult,
+    )],
+) -> Option<pr_bro::last_run::RunDelta> {
+    let path = pr_bro::last_run::get_last_run_path();
+    let mut state = pr_bro::last_run::load_last_run_state(&path);
+    let current: Vec<(&str, f64)> = prs
+        .iter()
+        .map(|(pr, result)| (pr.url.as_str(), result.score))
+        .collect();
+    let delta = state.diff(&current);
+    state.record(&current, chrono::Utc::now());
+    if let Err(e) = pr_bro::last_run::save_last_run_state(&path, &state) {
+        log::warn!("Could not save last run: {}", e);
+    }
+    delta
+}
+
+/// Write one `--*-file` output, ending with a newline like stdout would
+fn write_output_file(path: &std::path::Path, contents: &str) {
+    let mut contents = contents.to_string();
+    if !contents.is_empty() {
+        contents.push('\n');
+    }
+    if let Err(e) = std::fs::write(path, contents) {
+        eprintln!("Failed to write {}: {}", path.display(), e);
+        std::process::exit(EXIT_CONFIG);
+    }
+    log::info!("Wrote {}", path.display());
+}
+
+fn save_snooze_or_exit(path: &std::path::Path, state: &pr_bro::snooze::SnoozeState) {
+    if let Err(e) = pr_bro::snooze::save_snooze_state(path, state) {
+        eprintln!("Failed to save snooze state: {}", e);
+        std::process::exit(EXIT_CONFIG);
+    }
+}
+
+/// Ask a yes/no question on stderr, defaulting to no. Without a terminal to
+/// answer, proceed so scripts keep working as before.
+fn confirm(question: &str) -> bool {
+    if !std::io::stdin().is_terminal() || !std::io::stderr().is_terminal() {
+        return true;
+    }
+    eprint!("{} [y/N] ", question);
+    let _ = std::io::stderr().flush();
+    let mut answer = String::new();
+    let _ = std::io::stdin().lock().read_line(&mut answer);
+    let answer = answer.trim().to_lowercase();
+    answer == "y" || answer == "yes"
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_cli_definition_is_consistent() {
+        Cli::command().debug_assert();
+    }
+
+    #[test]
+    fn test_open_parses_with_page_flags() {
+        let open = |args: &[&str]| {
+            let cli = Cli::try_parse_from(["pr-bro", "open", "1"].iter().chain(args)).unwrap();
+            match cli.command {
+                Some(Commands::Open {
+                    index,
+                    repo,
+                    author,
+                    ..
+                }) => (index, repo, author),
+                other => panic!("expected open, got {:?}", other),
+            }
+        };
+        assert_eq!(open(&[]), ("1".to_string(), false, false));
+        assert_eq!(open(&["--repo"]), ("1".to_string(), true, false));
+        assert_eq!(open(&["--author"]), ("1".to_string(), false, true));
+    }
+
+    #[test]
+    fn test_clear_cache_for_one_repo() {
+        let cli = Cli::try_parse_from(["pr-bro", "--clear-cache", "--cache-repo", "acme/widgets"])
+            .unwrap();
+        assert!(cli.clear_cache);
+        assert_eq!(cli.cache_repo.as_deref(), Some("acme/widgets"));
+    }
+
+    #[test]
+    fn test_exclude_own_flag_overrides_config() {
+        let mut config: pr_bro::config::Config = serde_saphyr::from_str("queries: []").unwrap();
+
+        let cli = Cli::try_parse_from(["pr-bro", "list"]).unwrap();
+        apply_cli_overrides(&cli, &mut config);
+        assert!(!config.exclude_own);
+
+        let cli = Cli::try_parse_from(["pr-bro", "--exclude-own", "list"]).unwrap();
+        apply_cli_overrides(&cli, &mut config);
+        assert!(config.exclude_own);
+    }
+}
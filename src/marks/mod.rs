@@ -0,0 +1,118 @@
+//! Local "reviewed for now" marks, independent of GitHub review state.
+//!
+//! A mark remembers the PR's `updated_at` when it was set, and lapses as soon
+//! as the PR is updated again (new push, comment, etc.).
+
+pub mod storage;
+
+pub use storage::{get_marks_path, load_mark_state, save_mark_state};
+
+use crate::github::types::PullRequest;
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MarkState {
+    pub version: u32,
+    #[serde(default)]
+    pub marked: HashMap<String, MarkEntry>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MarkEntry {
+    pub marked_at: DateTime<Utc>,
+    /// The PR's `updated_at` when it was marked
+    pub pr_updated_at: DateTime<Utc>,
+}
+
+impl Default for MarkState {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl MarkState {
+    pub fn new() -> Self {
+        Self {
+            version: 1,
+            marked: HashMap::new(),
+        }
+    }
+
+    /// Whether the PR is marked and hasn't been updated since
+    pub fn is_marked(&self, pr: &PullRequest) -> bool {
+        self.marked
+            .get(&pr.url)
+            .is_some_and(|entry| pr.updated_at <= entry.pr_updated_at)
+    }
+
+    /// Mark a PR as reviewed as of its current `updated_at`
+    pub fn mark(&mut self, pr: &PullRequest) {
+        self.marked.insert(
+            pr.url.clone(),
+            MarkEntry {
+                marked_at: Utc::now(),
+                pr_updated_at: pr.updated_at,
+            },
+        );
+    }
+
+    /// Remove a mark. Returns true if the PR was marked.
+    pub fn unmark(&mut self, pr_url: &str) -> bool {
+        self.marked.remove(pr_url).is_some()
+    }
+
+    /// Drop marks on PRs that were updated after being marked.
+    /// PRs not in `prs` keep their marks. Returns true if anything was removed.
+    pub fn clear_updated<'a>(&mut self, prs: impl IntoIterator<Item = &'a PullRequest>) -> bool {
+        let before = self.marked.len();
+        for pr in prs {
+            if self
+                .marked
+                .get(&pr.url)
+                .is_some_and(|entry| pr.updated_at > entry.pr_updated_at)
+            {
+                self.marked.remove(&pr.url);
+            }
+        }
+        self.marked.len() != before
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::Duration;
+
+    fn pr(updated_at: DateTime<Utc>) -> PullRequest {
+        PullRequest {
+            created_at: updated_at,
+            updated_at,
+            ..PullRequest::test_default()
+        }
+    }
+
+    #[test]
+    fn test_mark_lapses_when_pr_updated() {
+        let t0 = Utc::now() - Duration::hours(1);
+        let mut state = MarkState::new();
+        state.mark(&pr(t0));
+        assert!(state.is_marked(&pr(t0)));
+
+        let updated = pr(t0 + Duration::minutes(10));
+        assert!(!state.is_marked(&updated));
+        assert!(state.clear_updated([&updated]));
+        assert!(state.marked.is_empty());
+    }
+
+    #[test]
+    fn test_clear_updated_keeps_unchanged_and_unseen() {
+        let t0 = Utc::now() - Duration::hours(1);
+        let mut state = MarkState::new();
+        state.mark(&pr(t0));
+        assert!(!state.clear_updated([&pr(t0)]));
+        assert!(!state.clear_updated([]));
+        assert!(state.unmark("https://github.com/owner/repo/pull/1"));
+    }
+}
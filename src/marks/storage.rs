@@ -0,0 +1,20 @@
+use super::MarkState;
+use anyhow::Result;
+use std::path::{Path, PathBuf};
+
+/// Get the default marks file path (~/.config/pr-bro/marks.json)
+pub fn get_marks_path() -> PathBuf {
+    crate::config::get_config_dir().join("marks.json")
+}
+
+/// Load reviewed marks from a JSON file
+///
+/// If the file doesn't exist, returns a new empty state.
+pub fn load_mark_state(path: &Path) -> Result<MarkState> {
+    crate::state_file::load_state(path, "marks", &[])
+}
+
+/// Save reviewed marks to a JSON file atomically
+pub fn save_mark_state(path: &Path, state: &MarkState) -> Result<()> {
+    crate::state_file::save_state(path, "marks", state)
+}
@@ -0,0 +1,29 @@
+use anyhow::Result;
+use std::io::Write;
+
+/// Ring the terminal bell
+pub fn ring_bell() {
+    let mut stdout = std::io::stdout();
+    let _ = stdout.write_all(b"\x07");
+    let _ = stdout.flush();
+}
+
+/// Show a desktop notification
+///
+/// # Errors
+/// Returns error if the notification service is unavailable, or if pr-bro was
+/// built without the `desktop-notifications` feature
+#[cfg(feature = "desktop-notifications")]
+pub fn desktop_notify(summary: &str, body: &str) -> Result<()> {
+    notify_rust::Notification::new()
+        .appname("pr-bro")
+        .summary(summary)
+        .body(body)
+        .show()?;
+    Ok(())
+}
+
+#[cfg(not(feature = "desktop-notifications"))]
+pub fn desktop_notify(_summary: &str, _body: &str) -> Result<()> {
+    anyhow::bail!("pr-bro was built without the desktop-notifications feature")
+}
@@ -1,9 +1,14 @@
-use chrono::Duration;
+use chrono::{DateTime, Duration, Utc};
 use owo_colors::OwoColorize;
+use serde::Serialize;
 use std::io::IsTerminal;
 use terminal_size::{terminal_size, Width};
 
+use crate::config::{DisplayTimezone, IndexStyle, TableColumn};
 use crate::github::types::PullRequest;
+use crate::last_run::RunDelta;
+use crate::snooze::SnoozeEntry;
+use crate::workload::ReviewerLoad;
 
 /// Format a list of PRs as one line per PR
 /// Format: "{title} | {repo} | {author} | {url}"
@@ -39,37 +44,57 @@ fn format_pr_line(pr: &PullRequest, use_colors: bool) -> String {
     }
 }
 
-/// Format a single PR with detailed multi-line output (for verbose mode)
-pub fn format_pr_detail(pr: &PullRequest, use_colors: bool) -> String {
-    let age = format_age(pr.age());
+/// Format a single PR with detailed multi-line output (for verbose mode).
+/// Created and updated times are shown in `timezone` next to the relative age.
+pub fn format_pr_detail(pr: &PullRequest, use_colors: bool, timezone: DisplayTimezone) -> String {
+    let age = format!(
+        "{} (created {})",
+        format_age(pr.age()),
+        timezone.format(pr.created_at)
+    );
+    let updated = format!(
+        "{} ({})",
+        format_ago(Utc::now() - pr.updated_at),
+        timezone.format(pr.updated_at)
+    );
     let total_size = pr.size();
 
-    if use_colors {
+    let detail = if use_colors {
         format!(
-            "{}\n  Repo: {}\n  Author: {}\n  Age: {}\n  Size: +{}/{} ({} lines)\n  Approvals: {}\n  URL: {}",
+            "{}\n  Repo: {}\n  Author: {}\n  Age: {}\n  Updated: {}\n  Size: +{}/{} ({} lines)\n  Approvals: {}\n  Changes requested: {}\n  URL: {}",
             pr.title.bold(),
             pr.repo.cyan(),
             pr.author.yellow(),
             age,
+            updated,
             pr.additions.green(),
             pr.deletions.red(),
             total_size,
             pr.approvals,
+            pr.changes_requested_count,
             pr.url.underline()
         )
     } else {
         format!(
-            "{}\n  Repo: {}\n  Author: {}\n  Age: {}\n  Size: +{}/{} ({} lines)\n  Approvals: {}\n  URL: {}",
+            "{}\n  Repo: {}\n  Author: {}\n  Age: {}\n  Updated: {}\n  Size: +{}/{} ({} lines)\n  Approvals: {}\n  Changes requested: {}\n  URL: {}",
             pr.title,
             pr.repo,
             pr.author,
             age,
+            updated,
             pr.additions,
             pr.deletions,
             total_size,
             pr.approvals,
+            pr.changes_requested_count,
             pr.url
         )
+    };
+
+    if pr.review_request_withdrawn {
+        format!("{}\n  Your review request was withdrawn", detail)
+    } else {
+        detail
     }
 }
 
@@ -99,11 +124,45 @@ pub fn format_score(score: f64, incomplete: bool) -> String {
     }
 }
 
+/// Score per changed line as shown in tables: two decimals for the small
+/// values most PRs have, compact like scores from 100 up
+pub fn format_efficiency(value: f64) -> String {
+    if value < 100.0 {
+        format!("{:.2}", value)
+    } else {
+        format_score(value, false)
+    }
+}
+
+/// `score` rescaled to 0-100 relative to `max_score` (the top score in the
+/// list), for `--normalize`. Lists whose top score isn't positive are left as is.
+pub fn normalized_score(score: f64, max_score: f64) -> f64 {
+    if max_score > 0.0 {
+        score / max_score * 100.0
+    } else {
+        score
+    }
+}
+
+/// Rescale display scores to 0-100 relative to the top one (`--normalize`)
+pub fn normalize_scores(prs: &mut [ScoredPr]) {
+    let max_score = prs
+        .iter()
+        .map(|scored| scored.score)
+        .fold(0.0_f64, f64::max);
+    for scored in prs {
+        scored.score = normalized_score(scored.score, max_score);
+    }
+}
+
 /// A PR with its calculated score for display
+#[derive(Clone, Copy)]
 pub struct ScoredPr<'a> {
     pub pr: &'a PullRequest,
     pub score: f64,
     pub incomplete: bool,
+    /// Snooze metadata, set when listing snoozed PRs
+    pub snooze: Option<&'a SnoozeEntry>,
 }
 
 /// Get terminal width, defaulting to None for pipes (unlimited)
@@ -123,100 +182,727 @@ fn truncate_title(title: &str, max_width: usize) -> String {
     }
 }
 
-/// Format PRs as scored table with columns: Index, Score, Title, URL
-/// No headers (minimal format per CONTEXT.md)
-/// Index column: 3 chars (fits "99."), right-aligned
+/// Title cut to `max_width` chars if given, else whole
+fn capped_title(title: &str, max_width: Option<usize>) -> String {
+    match max_width {
+        Some(width) => truncate_title(title, width),
+        None => title.to_string(),
+    }
+}
+
+/// Split a title across two lines of at most `max_width` chars each.
+/// Breaks at the last space that fits when possible; the second line is
+/// truncated with "..." if the title still doesn't fit.
+fn wrap_title(title: &str, max_width: usize) -> (String, Option<String>) {
+    let chars: Vec<char> = title.chars().collect();
+    if chars.len() <= max_width {
+        return (title.to_string(), None);
+    }
+
+    let break_at = chars[..=max_width]
+        .iter()
+        .rposition(|c| *c == ' ')
+        .filter(|&pos| pos > 0)
+        .unwrap_or(max_width);
+    let first: String = chars[..break_at].iter().collect();
+    let rest: String = chars[break_at..].iter().collect();
+
+    (
+        first.trim_end().to_string(),
+        Some(truncate_title(rest.trim_start(), max_width)),
+    )
+}
+
+/// Author logins longer than this are truncated in the author column
+pub const AUTHOR_MAX_WIDTH: usize = 12;
+
+/// Plain text of one table cell (before padding and colors)
+fn column_text(
+    column: TableColumn,
+    idx: usize,
+    scored: &ScoredPr,
+    index_style: IndexStyle,
+) -> String {
+    let pr = scored.pr;
+    match column {
+        // Index label (1-based number by default) with trailing dot
+        TableColumn::Index => format!("{}.", super::index_label(index_style, idx, &pr.url)),
+        TableColumn::Score => format_score(scored.score, scored.incomplete),
+        TableColumn::Age => format_age(pr.age()),
+        TableColumn::Repo => pr.repo.clone(),
+        TableColumn::Author => truncate_title(&pr.author, AUTHOR_MAX_WIDTH),
+        TableColumn::Title => pr.title.clone(),
+        TableColumn::Ref => pr.short_ref(),
+        TableColumn::Url => pr.url.clone(),
+        TableColumn::Efficiency => {
+            format_efficiency(crate::scoring::efficiency(scored.score, pr.size()))
+        }
+    }
+}
+
+/// Gap placed before the column at `pos`: one space after the index, two elsewhere
+fn column_separator(columns: &[TableColumn], pos: usize) -> &'static str {
+    match pos.checked_sub(1).map(|prev| columns[prev]) {
+        None => "",
+        Some(TableColumn::Index) => " ",
+        Some(_) => "  ",
+    }
+}
+
+/// Format PRs as a scored table using the configured `columns` (no headers,
+/// minimal format per CONTEXT.md). The default layout is Index, Score, Title, Ref.
+/// Index column: 3 chars (fits "99."), or 5 for stable IDs, right-aligned
 /// Score column is right-aligned, 7 chars wide (fits "9999.9M")
-pub fn format_scored_table(prs: &[ScoredPr], use_colors: bool) -> String {
+/// Other columns are as wide as their longest value; the title takes whatever
+/// terminal width is left and is never padded unless it wraps.
+/// With `wrap_titles`, long titles continue on a second line instead of being truncated.
+/// `max_title_width` caps titles even when piped, where they are otherwise left whole.
+pub fn format_scored_table(
+    prs: &[ScoredPr],
+    columns: &[TableColumn],
+    use_colors: bool,
+    wrap_titles: bool,
+    index_style: IndexStyle,
+    max_title_width: Option<usize>,
+) -> String {
     if prs.is_empty() {
         return "No pull requests found.".to_string();
     }
+    table_rows(
+        prs,
+        columns,
+        use_colors,
+        wrap_titles,
+        index_style,
+        max_title_width,
+    )
+    .join("\n")
+}
 
-    let term_width = get_terminal_width();
+/// Which sections `--group-by` splits the table into
+#[derive(Debug, Clone, Copy, PartialEq, Eq, clap::ValueEnum)]
+pub enum GroupBy {
+    /// The `group` tag of the PR's query
+    Group,
+    /// The repository owner
+    Org,
+}
 
-    // Index column: 3 chars + 1 space = 4
-    // Score column: 7 chars + 2 spaces = 9
-    // URL: varies, ~50 chars typical for GitHub
-    // Leave rest for title
-    let index_width = 3;
-    let score_width = 7;
-    let separator = "  ";
+impl GroupBy {
+    /// Section heading for a PR
+    pub fn key(self, pr: &PullRequest) -> String {
+        match self {
+            GroupBy::Group => pr.group.clone().unwrap_or_else(|| "(no group)".to_string()),
+            GroupBy::Org => pr.repo.split('/').next().unwrap_or(&pr.repo).to_string(),
+        }
+    }
+}
 
-    prs.iter()
+/// Format PRs as a scored table split into sections by `group_by`, each under
+/// a "name (count)" heading. Sections are ordered by their first PR in the
+/// list. Rows keep their list index and the widths of the whole table, so
+/// `pr-bro open N` still matches.
+pub fn format_grouped_table(
+    prs: &[ScoredPr],
+    group_by: GroupBy,
+    columns: &[TableColumn],
+    use_colors: bool,
+    wrap_titles: bool,
+    index_style: IndexStyle,
+    max_title_width: Option<usize>,
+) -> String {
+    if prs.is_empty() {
+        return "No pull requests found.".to_string();
+    }
+    let rows = table_rows(
+        prs,
+        columns,
+        use_colors,
+        wrap_titles,
+        index_style,
+        max_title_width,
+    );
+
+    let mut sections: Vec<(String, Vec<String>)> = Vec::new();
+    for (scored, row) in prs.iter().zip(rows) {
+        let key = group_by.key(scored.pr);
+        match sections.iter_mut().find(|(name, _)| *name == key) {
+            Some((_, rows)) => rows.push(row),
+            None => sections.push((key, vec![row])),
+        }
+    }
+
+    sections
+        .into_iter()
+        .map(|(name, rows)| {
+            let heading = format!("{} ({})", name, rows.len());
+            let heading = if use_colors {
+                heading.bold().to_string()
+            } else {
+                heading
+            };
+            format!("{}\n{}", heading, rows.join("\n"))
+        })
+        .collect::<Vec<_>>()
+        .join("\n\n")
+}
+
+/// One formatted line per PR (two when its title wraps) for `format_scored_table`
+fn table_rows(
+    prs: &[ScoredPr],
+    columns: &[TableColumn],
+    use_colors: bool,
+    wrap_titles: bool,
+    index_style: IndexStyle,
+    max_title_width: Option<usize>,
+) -> Vec<String> {
+    let term_width = get_terminal_width();
+
+    let rows: Vec<Vec<String>> = prs
+        .iter()
         .enumerate()
         .map(|(idx, scored)| {
-            // 1-based index, right-aligned with trailing dot
-            let index_str = format!("{:>2}.", idx + 1);
-            let score_str = format_score(scored.score, scored.incomplete);
-            let score_padded = format!("{:>width$}", score_str, width = score_width);
+            columns
+                .iter()
+                .map(|&column| column_text(column, idx, scored, index_style))
+                .collect()
+        })
+        .collect();
 
-            // Calculate available title width (accounting for index column)
-            let ref_len = scored.pr.short_ref().len();
-            let fixed_width = index_width + 1 + score_width + separator.len() * 2 + ref_len;
+    let widths: Vec<usize> = columns
+        .iter()
+        .enumerate()
+        .map(|(pos, column)| match column {
+            TableColumn::Index => super::index_width(index_style),
+            TableColumn::Score => 7,
+            _ => rows
+                .iter()
+                .map(|row| row[pos].chars().count())
+                .max()
+                .unwrap_or(0),
+        })
+        .collect();
 
-            let title = if let Some(width) = term_width {
-                if width > fixed_width + 10 {
-                    truncate_title(&scored.pr.title, width - fixed_width)
+    // Everything except the title is fixed width; the title gets the rest
+    let title_pos = columns.iter().position(|c| *c == TableColumn::Title);
+    let fixed_width: usize = (0..columns.len())
+        .filter(|&pos| Some(pos) != title_pos)
+        .map(|pos| widths[pos])
+        .sum::<usize>()
+        + (0..columns.len())
+            .map(|pos| column_separator(columns, pos).len())
+            .sum::<usize>();
+    let title_width = term_width.map(|width| {
+        if width > fixed_width + 10 {
+            width - fixed_width
+        } else {
+            // Very narrow terminal, show truncated
+            20
+        }
+    });
+    let title_width = match (title_width, max_title_width) {
+        (Some(width), Some(max)) => Some(width.min(max)),
+        (width, max) => width.or(max),
+    };
+    // Continuation lines start under the title column
+    let title_indent: usize = title_pos.map_or(0, |title_pos| {
+        (0..title_pos).map(|pos| widths[pos]).sum::<usize>()
+            + (0..=title_pos)
+                .map(|pos| column_separator(columns, pos).len())
+                .sum::<usize>()
+    });
+
+    rows.into_iter()
+        .zip(prs)
+        .map(|(row, scored)| {
+            let mut line = String::new();
+            let mut continuation = None;
+
+            for (pos, (column, text)) in columns.iter().zip(row).enumerate() {
+                let is_last = pos + 1 == columns.len();
+                line.push_str(column_separator(columns, pos));
+
+                let cell = match column {
+                    // No terminal (pipe) and no max_title_width: don't truncate
+                    TableColumn::Title => match title_width {
+                        Some(width) if wrap_titles => {
+                            let (first, rest) = wrap_title(&text, width);
+                            // Pad so later columns stay aligned when the title wraps
+                            let first = if rest.is_some() && !is_last {
+                                format!("{:<width$}", first, width = width)
+                            } else {
+                                first
+                            };
+                            continuation = rest;
+                            first
+                        }
+                        Some(width) => truncate_title(&text, width),
+                        None => text,
+                    },
+                    TableColumn::Index
+                    | TableColumn::Score
+                    | TableColumn::Age
+                    | TableColumn::Efficiency => {
+                        format!("{:>width$}", text, width = widths[pos])
+                    }
+                    _ if is_last => text,
+                    _ => format!("{:<width$}", text, width = widths[pos]),
+                };
+
+                if use_colors {
+                    match column {
+                        TableColumn::Index | TableColumn::Age => {
+                            line.push_str(&cell.dimmed().to_string())
+                        }
+                        TableColumn::Score => line.push_str(&cell.bold().to_string()),
+                        TableColumn::Ref | TableColumn::Url => {
+                            line.push_str(&cell.underline().to_string())
+                        }
+                        // Same author, same color, so runs of one person's PRs stand out
+                        TableColumn::Author => line.push_str(
+                            &cell
+                                .color(super::palette::ansi_color(&scored.pr.author))
+                                .to_string(),
+                        ),
+                        _ => line.push_str(&cell),
+                    }
                 } else {
-                    // Very narrow terminal, show truncated
-                    truncate_title(&scored.pr.title, 20)
+                    line.push_str(&cell);
                 }
-            } else {
-                // No terminal (pipe), don't truncate
-                scored.pr.title.clone()
-            };
+            }
 
-            if use_colors {
-                format!(
-                    "{} {}{}{}{}{}",
-                    index_str.dimmed(),
-                    score_padded.bold(),
-                    separator,
-                    title,
-                    separator,
-                    scored.pr.short_ref().underline()
-                )
-            } else {
-                format!(
-                    "{} {}{}{}{}{}",
-                    index_str,
-                    score_padded,
-                    separator,
-                    title,
-                    separator,
-                    scored.pr.short_ref()
-                )
+            if let Some(rest) = continuation {
+                line.push('\n');
+                line.push_str(&" ".repeat(title_indent));
+                line.push_str(&rest);
             }
+            line
         })
-        .collect::<Vec<_>>()
-        .join("\n")
+        .collect()
 }
 
 /// Format PRs as tab-separated values for scripting
 /// Columns: score, title, repo, pr_ref (no headers, no colors)
-pub fn format_tsv(prs: &[ScoredPr]) -> String {
+/// Titles are cut to `max_title_width` chars when set.
+pub fn format_tsv(prs: &[ScoredPr], header: bool, max_title_width: Option<usize>) -> String {
+    // The header names the columns for spreadsheet import; rows alone stay
+    // the default so existing scripts keep working
+    let header_line = header.then(|| {
+        if prs.iter().any(|scored| scored.snooze.is_some()) {
+            "score\ttitle\trepo\tpr\tremaining\tsnooze_until"
+        } else {
+            "score\ttitle\trepo\tpr"
+        }
+    });
     if prs.is_empty() {
-        return String::new();
+        return header_line.unwrap_or_default().to_string();
     }
 
-    prs.iter()
-        .map(|scored| {
+    header_line
+        .map(str::to_string)
+        .into_iter()
+        .chain(prs.iter().map(|scored| {
             let score = scored.score.round() as i64;
-            format!(
+            let line = format!(
                 "{}\t{}\t{}\t{}",
                 score,
-                scored.pr.title,
+                capped_title(&scored.pr.title, max_title_width),
                 scored.pr.repo,
                 scored.pr.short_ref()
-            )
-        })
+            );
+            // Snoozed listings append remaining time and expiry (empty when indefinite)
+            match scored.snooze {
+                Some(entry) => format!(
+                    "{}\t{}\t{}",
+                    line,
+                    entry.format_remaining(),
+                    entry
+                        .snooze_until
+                        .map(|until| until.to_rfc3339())
+                        .unwrap_or_default()
+                ),
+                None => line,
+            }
+        }))
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+/// Format PRs as CSV (RFC 4180) for spreadsheets: a header line, then
+/// score, title, repo, pr_ref, and url, plus the snooze columns for snoozed
+/// listings like `format_tsv`. Titles are cut to `max_title_width` chars when set.
+pub fn format_csv(prs: &[ScoredPr], max_title_width: Option<usize>) -> String {
+    let snoozed = prs.iter().any(|scored| scored.snooze.is_some());
+    let header = if snoozed {
+        "score,title,repo,pr,url,remaining,snooze_until"
+    } else {
+        "score,title,repo,pr,url"
+    };
+
+    std::iter::once(header.to_string())
+        .chain(prs.iter().map(|scored| {
+            let mut fields = vec![
+                (scored.score.round() as i64).to_string(),
+                capped_title(&scored.pr.title, max_title_width),
+                scored.pr.repo.clone(),
+                scored.pr.short_ref(),
+                scored.pr.url.clone(),
+            ];
+            if snoozed {
+                let entry = scored.snooze;
+                fields.push(entry.map(|e| e.format_remaining()).unwrap_or_default());
+                fields.push(
+                    entry
+                        .and_then(|e| e.snooze_until)
+                        .map(|until| until.to_rfc3339())
+                        .unwrap_or_default(),
+                );
+            }
+            fields
+                .iter()
+                .map(|field| csv_field(field))
+                .collect::<Vec<_>>()
+                .join(",")
+        }))
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+/// Quote a CSV field when it contains a comma, quote, or line break
+fn csv_field(field: &str) -> String {
+    if field.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", field.replace('"', "\"\""))
+    } else {
+        field.to_string()
+    }
+}
+
+/// Top-level JSON document. `total` is the number of PRs matched before
+/// `--min-score` dropped any, so consumers can tell when `prs` is a partial list.
+#[derive(Serialize)]
+struct JsonOutput<'a> {
+    generated_at: DateTime<Utc>,
+    total: usize,
+    prs: Vec<JsonPr<'a>>,
+    /// Changes since the last run; only present when there was one to compare
+    #[serde(skip_serializing_if = "Option::is_none")]
+    since_last_run: Option<&'a RunDelta>,
+}
+
+/// A single PR in JSON output. Fields serialize in declaration order.
+#[derive(Serialize)]
+struct JsonPr<'a> {
+    index: usize,
+    score: f64,
+    incomplete: bool,
+    title: &'a str,
+    repo: &'a str,
+    number: u64,
+    author: &'a str,
+    url: &'a str,
+    created_at: DateTime<Utc>,
+    updated_at: DateTime<Utc>,
+    /// Whole hours since `created_at`, so filters need no date math
+    age_hours: i64,
+    additions: u64,
+    deletions: u64,
+    size: u64,
+    approvals: u32,
+    changes_requested: u32,
+    reactions: u32,
+    comments: u32,
+    /// Commits the base branch is ahead; only present with `commits_behind` scoring
+    #[serde(skip_serializing_if = "Option::is_none")]
+    commits_behind: Option<u32>,
+    /// Head commit CI result; only present with `ci_status` scoring
+    #[serde(skip_serializing_if = "Option::is_none")]
+    ci_status: Option<&'static str>,
+    /// GitHub's review verdict; only present with `use_graphql`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    review_decision: Option<&'static str>,
+    draft: bool,
+    is_issue: bool,
+    is_author: bool,
+    review_request_withdrawn: bool,
+    labels: &'a [String],
+    /// The query's `group` tag; only present when set
+    #[serde(skip_serializing_if = "Option::is_none")]
+    group: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    snooze: Option<JsonSnooze>,
+}
+
+/// Snooze metadata for a PR in JSON output (snoozed listings only)
+#[derive(Serialize)]
+struct JsonSnooze {
+    snoozed_at: DateTime<Utc>,
+    /// `null` for indefinite snoozes
+    snooze_until: Option<DateTime<Utc>>,
+    remaining: String,
+}
+
+/// JSON representation of one PR; `index` is its 1-based position in the list
+/// and `now` is the instant `age_hours` is measured from
+fn json_pr<'a>(index: usize, scored: &ScoredPr<'a>, now: DateTime<Utc>) -> JsonPr<'a> {
+    JsonPr {
+        index,
+        score: scored.score,
+        incomplete: scored.incomplete,
+        title: &scored.pr.title,
+        repo: &scored.pr.repo,
+        number: scored.pr.number,
+        author: &scored.pr.author,
+        url: &scored.pr.url,
+        created_at: scored.pr.created_at,
+        updated_at: scored.pr.updated_at,
+        age_hours: scored.pr.age_at(now).num_hours(),
+        additions: scored.pr.additions,
+        deletions: scored.pr.deletions,
+        size: scored.pr.size(),
+        approvals: scored.pr.approvals,
+        changes_requested: scored.pr.changes_requested_count,
+        reactions: scored.pr.reactions,
+        comments: scored.pr.comments,
+        commits_behind: scored.pr.commits_behind,
+        ci_status: scored.pr.ci_status.map(|status| status.as_str()),
+        review_decision: scored.pr.review_decision.map(|d| d.as_str()),
+        draft: scored.pr.draft,
+        is_issue: scored.pr.is_issue,
+        is_author: scored.pr.is_author,
+        review_request_withdrawn: scored.pr.review_request_withdrawn,
+        labels: &scored.pr.labels,
+        group: scored.pr.group.as_deref(),
+        snooze: scored.snooze.map(|entry| JsonSnooze {
+            snoozed_at: entry.snoozed_at,
+            snooze_until: entry.snooze_until,
+            remaining: entry.format_remaining(),
+        }),
+    }
+}
+
+fn to_json<T: Serialize>(value: &T, pretty: bool) -> String {
+    let result = if pretty {
+        serde_json::to_string_pretty(value)
+    } else {
+        serde_json::to_string(value)
+    };
+    result.expect("JSON output contains only serializable fields")
+}
+
+/// Format PRs as a JSON document for scripting
+/// Compact by default; `pretty` enables indented output for diffing across runs
+pub fn format_json(prs: &[ScoredPr], pretty: bool) -> String {
+    format_json_document(prs, prs.len(), None, pretty)
+}
+
+/// `format_json` for a list filtered down from `total` matches, plus a
+/// `since_last_run` object when `delta` is given
+pub fn format_json_document(
+    prs: &[ScoredPr],
+    total: usize,
+    delta: Option<&RunDelta>,
+    pretty: bool,
+) -> String {
+    let now = Utc::now();
+    let output = JsonOutput {
+        generated_at: now,
+        total,
+        prs: prs
+            .iter()
+            .enumerate()
+            .map(|(idx, scored)| json_pr(idx + 1, scored, now))
+            .collect(),
+        since_last_run: delta,
+    };
+    to_json(&output, pretty)
+}
+
+/// Format PRs as a bare JSON array (`--format json-array`): the entries of
+/// `format_json`'s `prs`, without the wrapper, and `[]` when empty
+pub fn format_json_array(prs: &[ScoredPr], pretty: bool) -> String {
+    let now = Utc::now();
+    let records: Vec<JsonPr> = prs
+        .iter()
+        .enumerate()
+        .map(|(idx, scored)| json_pr(idx + 1, scored, now))
+        .collect();
+    to_json(&records, pretty)
+}
+
+/// One PR as a single-line JSON record for JSON-lines output. Same fields as
+/// the entries of `format_json`'s `prs` array, so each line stands alone.
+/// `now` is shared across a listing so ages line up.
+pub fn format_jsonl_record(index: usize, scored: &ScoredPr, now: DateTime<Utc>) -> String {
+    to_json(&json_pr(index, scored, now), false)
+}
+
+/// Format PRs as JSON lines (NDJSON): one record per line, no wrapper
+pub fn format_jsonl(prs: &[ScoredPr]) -> String {
+    let now = Utc::now();
+    prs.iter()
+        .enumerate()
+        .map(|(idx, scored)| format_jsonl_record(idx + 1, scored, now))
         .collect::<Vec<_>>()
         .join("\n")
 }
 
+/// JSON document for `pr-bro plan`
+#[derive(Serialize)]
+struct JsonPlan<'a> {
+    generated_at: DateTime<Utc>,
+    budget: u64,
+    total_lines: u64,
+    prs: Vec<JsonPr<'a>>,
+}
+
+/// Format a review plan as JSON. Each entry is `(list_index, pr)` where
+/// `list_index` is the PR's 1-based index in `pr-bro list`, so it can be
+/// passed straight to `open` or `snooze`.
+pub fn format_plan_json(selected: &[(usize, ScoredPr)], budget: u64, pretty: bool) -> String {
+    let now = Utc::now();
+    let output = JsonPlan {
+        generated_at: now,
+        budget,
+        total_lines: selected.iter().map(|(_, scored)| scored.pr.size()).sum(),
+        prs: selected
+            .iter()
+            .map(|(index, scored)| json_pr(*index, scored, now))
+            .collect(),
+    };
+    to_json(&output, pretty)
+}
+
+/// Format a review plan as a table: list index, score, size, title, ref,
+/// followed by a total line. Indices match `pr-bro list`.
+pub fn format_plan_table(selected: &[(usize, ScoredPr)], budget: u64, use_colors: bool) -> String {
+    if selected.is_empty() {
+        return format!("No pull requests fit within {} lines.", budget);
+    }
+
+    let size_width = selected
+        .iter()
+        .map(|(_, scored)| scored.pr.size().to_string().len())
+        .max()
+        .unwrap_or(0);
+    let term_width = get_terminal_width();
+
+    let mut lines: Vec<String> = selected
+        .iter()
+        .map(|(index, scored)| {
+            let index_str = format!("{:>2}.", index);
+            let score_str = format!("{:>7}", format_score(scored.score, scored.incomplete));
+            let size_str = format!("{:>width$}", scored.pr.size(), width = size_width);
+            let short_ref = scored.pr.short_ref();
+
+            let fixed_width = index_str.len() + 1 + 7 + 2 + size_width + 2 + 2 + short_ref.len();
+            let title = match term_width {
+                Some(width) if width > fixed_width + 10 => {
+                    truncate_title(&scored.pr.title, width - fixed_width)
+                }
+                Some(_) => truncate_title(&scored.pr.title, 20),
+                None => scored.pr.title.clone(),
+            };
+
+            if use_colors {
+                format!(
+                    "{} {}  {}  {}  {}",
+                    index_str.dimmed(),
+                    score_str.bold(),
+                    size_str,
+                    title,
+                    short_ref.underline()
+                )
+            } else {
+                format!(
+                    "{} {}  {}  {}  {}",
+                    index_str, score_str, size_str, title, short_ref
+                )
+            }
+        })
+        .collect();
+
+    let total_lines: u64 = selected.iter().map(|(_, scored)| scored.pr.size()).sum();
+    lines.push(String::new());
+    lines.push(format!(
+        "Total: {} of {} lines across {} PR{}",
+        total_lines,
+        budget,
+        selected.len(),
+        if selected.len() == 1 { "" } else { "s" }
+    ));
+    lines.join("\n")
+}
+
+/// JSON document for `pr-bro workload`
+#[derive(Serialize)]
+struct JsonWorkload<'a> {
+    generated_at: DateTime<Utc>,
+    reviewers: &'a [ReviewerLoad],
+}
+
+/// Format reviewer workload as JSON, busiest reviewer first
+pub fn format_workload_json(loads: &[ReviewerLoad], pretty: bool) -> String {
+    let output = JsonWorkload {
+        generated_at: Utc::now(),
+        reviewers: loads,
+    };
+    to_json(&output, pretty)
+}
+
+/// Format reviewer workload as a two-column table: reviewer, outstanding PRs
+pub fn format_workload_table(loads: &[ReviewerLoad], use_colors: bool) -> String {
+    if loads.is_empty() {
+        return "No outstanding review requests.".to_string();
+    }
+
+    let name_width = loads
+        .iter()
+        .map(|load| load.reviewer.len())
+        .max()
+        .unwrap_or(0)
+        .max("Reviewer".len());
+
+    let header = format!("{:<width$}  PRs", "Reviewer", width = name_width);
+    let mut lines = vec![if use_colors {
+        header.bold().to_string()
+    } else {
+        header
+    }];
+    lines.extend(loads.iter().map(|load| {
+        format!(
+            "{:<width$}  {:>3}",
+            load.reviewer,
+            load.outstanding,
+            width = name_width
+        )
+    }));
+    lines.join("\n")
+}
+
+/// Format a `--profile-timing` report: one "label  elapsed" line per phase,
+/// labels left-aligned to the widest one
+pub fn format_timing_report(entries: &[(String, std::time::Duration)]) -> String {
+    let label_width = entries.iter().map(|(l, _)| l.len()).max().unwrap_or(0);
+    let mut lines = vec!["Timing:".to_string()];
+    lines.extend(entries.iter().map(|(label, elapsed)| {
+        format!(
+            "  {:<width$}  {:>8.1}ms",
+            label,
+            elapsed.as_secs_f64() * 1000.0,
+            width = label_width
+        )
+    }));
+    lines.join("\n")
+}
+
 /// Format a duration into a human-readable age string
 /// "2h" for hours, "3d" for days, "1w" for weeks
+/// Negative durations (future timestamps from clock skew) display as "now"
 pub fn format_age(duration: Duration) -> String {
+    if duration < Duration::minutes(1) {
+        return "now".to_string();
+    }
+
     let hours = duration.num_hours();
     let days = duration.num_days();
     let weeks = days / 7;
@@ -228,12 +914,15 @@ pub fn format_age(duration: Duration) -> String {
     } else if hours >= 1 {
         format!("{}h", hours)
     } else {
-        let minutes = duration.num_minutes();
-        if minutes >= 1 {
-            format!("{}m", minutes)
-        } else {
-            "now".to_string()
-        }
+        format!("{}m", duration.num_minutes())
+    }
+}
+
+/// `format_age` as a phrase: "3d ago", or "now"
+pub fn format_ago(duration: Duration) -> String {
+    match format_age(duration).as_str() {
+        "now" => "now".to_string(),
+        age => format!("{} ago", age),
     }
 }
 
@@ -246,18 +935,13 @@ mod tests {
         PullRequest {
             title: "Fix login bug".to_string(),
             number: 123,
-            author: "octocat".to_string(),
-            repo: "owner/repo".to_string(),
             url: "https://github.com/owner/repo/pull/123".to_string(),
             created_at: Utc::now() - Duration::hours(5),
             updated_at: Utc::now() - Duration::hours(1),
             additions: 50,
             deletions: 10,
             approvals: 1,
-            draft: false,
-            labels: vec![],
-            user_has_reviewed: false,
-            filtered_size: None,
+            ..PullRequest::test_default()
         }
     }
 
@@ -281,12 +965,18 @@ mod tests {
     #[test]
     fn test_format_pr_detail() {
         let pr = sample_pr();
-        let result = format_pr_detail(&pr, false);
+        let result = format_pr_detail(&pr, false, DisplayTimezone::Utc);
         assert!(result.contains("Fix login bug"));
         assert!(result.contains("Repo: owner/repo"));
         assert!(result.contains("Author: octocat"));
         assert!(result.contains("Size: +50/10 (60 lines)"));
         assert!(result.contains("Approvals: 1"));
+        assert!(result.contains("Changes requested: 0"));
+        assert!(result.contains(&format!(
+            "(created {})",
+            DisplayTimezone::Utc.format(pr.created_at)
+        )));
+        assert!(result.contains("Updated: 1h ago ("));
     }
 
     #[test]
@@ -319,6 +1009,21 @@ mod tests {
         assert_eq!(format_age(duration), "now");
     }
 
+    #[test]
+    fn test_format_age_negative() {
+        assert_eq!(format_age(Duration::seconds(-30)), "now");
+        assert_eq!(format_age(Duration::days(-3)), "now");
+    }
+
+    #[test]
+    fn test_future_created_at_clamps_age() {
+        // Clock skew: GitHub reports a creation time slightly ahead of ours
+        let mut pr = sample_pr();
+        pr.created_at = Utc::now() + Duration::minutes(5);
+        assert_eq!(pr.age(), Duration::zero());
+        assert!(format_pr_detail(&pr, false, DisplayTimezone::Utc).contains("Age: now"));
+    }
+
     // format_score tests
     #[test]
     fn test_format_score_small() {
@@ -396,7 +1101,14 @@ mod tests {
     #[test]
     fn test_format_scored_table_empty() {
         let prs: Vec<ScoredPr> = vec![];
-        let result = format_scored_table(&prs, false);
+        let result = format_scored_table(
+            &prs,
+            &TableColumn::default_layout(),
+            false,
+            false,
+            IndexStyle::Number,
+            None,
+        );
         assert_eq!(result, "No pull requests found.");
     }
 
@@ -407,8 +1119,16 @@ mod tests {
             pr: &pr,
             score: 1500.0,
             incomplete: false,
+            snooze: None,
         }];
-        let result = format_scored_table(&scored_prs, false);
+        let result = format_scored_table(
+            &scored_prs,
+            &TableColumn::default_layout(),
+            false,
+            false,
+            IndexStyle::Number,
+            None,
+        );
         // Index should be 1-based
         assert!(result.contains(" 1."));
         // Score should be right-aligned in 7-char column
@@ -424,8 +1144,16 @@ mod tests {
             pr: &pr,
             score: 847.0,
             incomplete: true,
+            snooze: None,
         }];
-        let result = format_scored_table(&scored_prs, false);
+        let result = format_scored_table(
+            &scored_prs,
+            &TableColumn::default_layout(),
+            false,
+            false,
+            IndexStyle::Number,
+            None,
+        );
         assert!(result.contains(" 1."));
         assert!(result.contains("847*"));
     }
@@ -443,14 +1171,23 @@ mod tests {
                 pr: &pr1,
                 score: 2000.0,
                 incomplete: false,
+                snooze: None,
             },
             ScoredPr {
                 pr: &pr2,
                 score: 500.0,
                 incomplete: false,
+                snooze: None,
             },
         ];
-        let result = format_scored_table(&scored_prs, false);
+        let result = format_scored_table(
+            &scored_prs,
+            &TableColumn::default_layout(),
+            false,
+            false,
+            IndexStyle::Number,
+            None,
+        );
         let lines: Vec<&str> = result.lines().collect();
         assert_eq!(lines.len(), 2);
         // Check indices are sequential
@@ -467,7 +1204,7 @@ mod tests {
     #[test]
     fn test_format_tsv_empty() {
         let prs: Vec<ScoredPr> = vec![];
-        let result = format_tsv(&prs);
+        let result = format_tsv(&prs, false, None);
         assert_eq!(result, "");
     }
 
@@ -478,8 +1215,9 @@ mod tests {
             pr: &pr,
             score: 1500.7,
             incomplete: false,
+            snooze: None,
         }];
-        let result = format_tsv(&scored_prs);
+        let result = format_tsv(&scored_prs, false, None);
         assert_eq!(result, "1501\tFix login bug\towner/repo\towner/repo#123");
     }
 
@@ -495,14 +1233,16 @@ mod tests {
                 pr: &pr1,
                 score: 2000.0,
                 incomplete: false,
+                snooze: None,
             },
             ScoredPr {
                 pr: &pr2,
                 score: 500.0,
                 incomplete: true,
+                snooze: None,
             },
         ];
-        let result = format_tsv(&scored_prs);
+        let result = format_tsv(&scored_prs, false, None);
         let lines: Vec<&str> = result.lines().collect();
         assert_eq!(lines.len(), 2);
         // Verify tab-separated format
@@ -512,6 +1252,240 @@ mod tests {
         assert!(lines[1].starts_with("500\t"));
     }
 
+    // format_json tests
+    #[test]
+    fn test_format_json_empty() {
+        let prs: Vec<ScoredPr> = vec![];
+        let result = format_json(&prs, false);
+        let value: serde_json::Value = serde_json::from_str(&result).unwrap();
+        assert_eq!(value["total"], 0);
+        assert!(value["prs"].as_array().unwrap().is_empty());
+        assert!(value["generated_at"].is_string());
+    }
+
+    #[test]
+    fn test_format_json_total_counts_prs_before_filtering() {
+        let pr = sample_pr();
+        let scored_prs = vec![ScoredPr {
+            pr: &pr,
+            score: 1500.0,
+            incomplete: false,
+            snooze: None,
+        }];
+        let result = format_json_document(&scored_prs, 3, None, false);
+        let value: serde_json::Value = serde_json::from_str(&result).unwrap();
+        assert_eq!(value["total"], 3);
+        assert_eq!(value["prs"].as_array().unwrap().len(), 1);
+    }
+
+    #[test]
+    fn test_format_json_array_is_bare() {
+        assert_eq!(format_json_array(&[], false), "[]");
+
+        let pr = sample_pr();
+        let scored_prs = vec![ScoredPr {
+            pr: &pr,
+            score: 1500.25,
+            incomplete: false,
+            snooze: None,
+        }];
+        let value: serde_json::Value =
+            serde_json::from_str(&format_json_array(&scored_prs, false)).unwrap();
+        let records = value.as_array().unwrap();
+        assert_eq!(records.len(), 1);
+        assert_eq!(records[0]["score"], 1500.25);
+        assert_eq!(records[0]["age_hours"], 5);
+    }
+
+    #[test]
+    fn test_format_json_single() {
+        let pr = sample_pr();
+        let scored_prs = vec![ScoredPr {
+            pr: &pr,
+            score: 1500.0,
+            incomplete: true,
+            snooze: None,
+        }];
+        let result = format_json(&scored_prs, false);
+        let value: serde_json::Value = serde_json::from_str(&result).unwrap();
+        assert_eq!(value["total"], 1);
+        let first = &value["prs"][0];
+        assert_eq!(first["index"], 1);
+        assert_eq!(first["score"], 1500.0);
+        assert_eq!(first["incomplete"], true);
+        assert_eq!(first["title"], "Fix login bug");
+        assert_eq!(first["repo"], "owner/repo");
+        assert_eq!(first["number"], 123);
+        assert_eq!(first["size"], 60);
+        assert_eq!(first["age_hours"], 5);
+    }
+
+    #[test]
+    fn test_json_age_hours_measured_from_given_now() {
+        let pr = sample_pr();
+        let scored = ScoredPr {
+            pr: &pr,
+            score: 1.0,
+            incomplete: false,
+            snooze: None,
+        };
+        let age_hours = |now| {
+            let record: serde_json::Value =
+                serde_json::from_str(&format_jsonl_record(1, &scored, now)).unwrap();
+            record["age_hours"].clone()
+        };
+        assert_eq!(age_hours(pr.created_at + Duration::hours(30)), 30);
+        // Clock skew clamps to zero, as in the age column
+        assert_eq!(age_hours(pr.created_at - Duration::hours(1)), 0);
+    }
+
+    #[test]
+    fn test_format_jsonl_one_record_per_line() {
+        let pr = sample_pr();
+        let scored_prs = vec![
+            ScoredPr {
+                pr: &pr,
+                score: 200.0,
+                incomplete: false,
+                snooze: None,
+            },
+            ScoredPr {
+                pr: &pr,
+                score: 100.0,
+                incomplete: true,
+                snooze: None,
+            },
+        ];
+        let result = format_jsonl(&scored_prs);
+        let lines: Vec<&str> = result.lines().collect();
+        assert_eq!(lines.len(), 2);
+        for (i, line) in lines.iter().enumerate() {
+            let value: serde_json::Value = serde_json::from_str(line).unwrap();
+            assert_eq!(value["index"], i + 1);
+            assert_eq!(value["title"], "Fix login bug");
+            assert!(value.get("total").is_none());
+        }
+        assert!(format_jsonl(&[]).is_empty());
+    }
+
+    #[test]
+    fn test_format_json_stable_field_order() {
+        let pr = sample_pr();
+        let scored_prs = vec![ScoredPr {
+            pr: &pr,
+            score: 100.0,
+            incomplete: false,
+            snooze: None,
+        }];
+        let result = format_json(&scored_prs, false);
+        // Wrapper fields first, then PR fields in declaration order
+        let generated = result.find("\"generated_at\"").unwrap();
+        let total = result.find("\"total\"").unwrap();
+        let prs = result.find("\"prs\"").unwrap();
+        assert!(generated < total && total < prs);
+        let index = result.find("\"index\"").unwrap();
+        let score = result.find("\"score\"").unwrap();
+        let title = result.find("\"title\"").unwrap();
+        let labels = result.find("\"labels\"").unwrap();
+        assert!(index < score && score < title && title < labels);
+    }
+
+    #[test]
+    fn test_format_plan_keeps_list_indices_and_totals() {
+        let pr1 = sample_pr(); // 60 lines
+        let mut pr2 = sample_pr();
+        pr2.additions = 30;
+        pr2.deletions = 0;
+        let selected = vec![
+            (
+                1,
+                ScoredPr {
+                    pr: &pr1,
+                    score: 900.0,
+                    incomplete: false,
+                    snooze: None,
+                },
+            ),
+            (
+                3,
+                ScoredPr {
+                    pr: &pr2,
+                    score: 400.0,
+                    incomplete: false,
+                    snooze: None,
+                },
+            ),
+        ];
+
+        let table = format_plan_table(&selected, 100, false);
+        let lines: Vec<&str> = table.lines().collect();
+        assert!(lines[0].starts_with(" 1."));
+        assert!(lines[1].starts_with(" 3."));
+        assert!(lines[1].contains("  30  "));
+        assert_eq!(lines[3], "Total: 90 of 100 lines across 2 PRs");
+
+        let json: serde_json::Value =
+            serde_json::from_str(&format_plan_json(&selected, 100, false)).unwrap();
+        assert_eq!(json["budget"], 100);
+        assert_eq!(json["total_lines"], 90);
+        assert_eq!(json["prs"][1]["index"], 3);
+    }
+
+    #[test]
+    fn test_format_json_compact_vs_pretty() {
+        let pr = sample_pr();
+        let scored_prs = vec![ScoredPr {
+            pr: &pr,
+            score: 100.0,
+            incomplete: false,
+            snooze: None,
+        }];
+        let compact = format_json(&scored_prs, false);
+        let pretty = format_json(&scored_prs, true);
+        assert_eq!(compact.lines().count(), 1);
+        assert!(pretty.lines().count() > 1);
+        assert!(pretty.contains("\n  \"total\": 1"));
+    }
+
+    #[test]
+    fn test_format_grouped_table_keeps_list_indices() {
+        let mut prs: Vec<PullRequest> = (1..=3).map(|_| sample_pr()).collect();
+        prs[0].repo = "acme/api".to_string();
+        prs[0].group = Some("Acme".to_string());
+        prs[1].repo = "other/app".to_string();
+        prs[2].repo = "acme/web".to_string();
+        prs[2].group = Some("Acme".to_string());
+        let scored_prs: Vec<ScoredPr> = prs
+            .iter()
+            .map(|pr| ScoredPr {
+                pr,
+                score: 100.0,
+                incomplete: false,
+                snooze: None,
+            })
+            .collect();
+
+        let table = |group_by| {
+            format_grouped_table(
+                &scored_prs,
+                group_by,
+                &[TableColumn::Index, TableColumn::Repo],
+                false,
+                false,
+                IndexStyle::Number,
+                None,
+            )
+        };
+        assert_eq!(
+            table(GroupBy::Org),
+            "acme (2)\n 1. acme/api\n 3. acme/web\n\nother (1)\n 2. other/app"
+        );
+        assert_eq!(
+            table(GroupBy::Group),
+            "Acme (2)\n 1. acme/api\n 3. acme/web\n\n(no group) (1)\n 2. other/app"
+        );
+    }
+
     #[test]
     fn test_format_scored_table_index_format() {
         // Verify index format: right-aligned, 1-based, with trailing dot
@@ -520,9 +1494,285 @@ mod tests {
             pr: &pr,
             score: 100.0,
             incomplete: false,
+            snooze: None,
         }];
-        let result = format_scored_table(&scored_prs, false);
+        let result = format_scored_table(
+            &scored_prs,
+            &TableColumn::default_layout(),
+            false,
+            false,
+            IndexStyle::Number,
+            None,
+        );
         // Should start with " 1." (space for alignment, then index)
         assert!(result.starts_with(" 1."));
+
+        let result = format_scored_table(
+            &scored_prs,
+            &TableColumn::default_layout(),
+            false,
+            false,
+            IndexStyle::Letter,
+            None,
+        );
+        assert!(result.starts_with(" a."));
+
+        let result = format_scored_table(
+            &scored_prs,
+            &TableColumn::default_layout(),
+            false,
+            false,
+            IndexStyle::Id,
+            None,
+        );
+        assert!(result.starts_with(&format!("{}.", crate::output::index::stable_id(&pr.url))));
+    }
+
+    #[test]
+    fn test_format_scored_table_custom_columns() {
+        let pr1 = sample_pr();
+        let mut pr2 = sample_pr();
+        pr2.author = "a".to_string();
+        let scored_prs = vec![
+            ScoredPr {
+                pr: &pr1,
+                score: 100.0,
+                incomplete: false,
+                snooze: None,
+            },
+            ScoredPr {
+                pr: &pr2,
+                score: 2000.0,
+                incomplete: false,
+                snooze: None,
+            },
+        ];
+        let columns = [TableColumn::Author, TableColumn::Age, TableColumn::Url];
+        let result = format_scored_table(
+            &scored_prs,
+            &columns,
+            false,
+            false,
+            IndexStyle::Number,
+            None,
+        );
+        let lines: Vec<&str> = result.lines().collect();
+        // Author is padded to the longest value, age is right-aligned, no score/title
+        assert_eq!(
+            lines[0],
+            "octocat  5h  https://github.com/owner/repo/pull/123"
+        );
+        assert_eq!(
+            lines[1],
+            "a        5h  https://github.com/owner/repo/pull/123"
+        );
+    }
+
+    #[test]
+    fn test_author_column_truncates_long_logins() {
+        let mut pr = sample_pr();
+        pr.author = "a-very-long-login-name".to_string();
+        let scored = ScoredPr {
+            pr: &pr,
+            score: 1.0,
+            incomplete: false,
+            snooze: None,
+        };
+        assert_eq!(
+            column_text(TableColumn::Author, 0, &scored, IndexStyle::Number),
+            "a-very-lo..."
+        );
+    }
+
+    #[test]
+    fn test_format_json_includes_snooze_metadata() {
+        let pr = sample_pr();
+        let entry = SnoozeEntry {
+            snoozed_at: Utc::now() - Duration::hours(1),
+            snooze_until: Some(Utc::now() + Duration::hours(3) + Duration::minutes(5)),
+        };
+        let scored_prs = vec![ScoredPr {
+            pr: &pr,
+            score: 10.0,
+            incomplete: false,
+            snooze: Some(&entry),
+        }];
+        let value: serde_json::Value =
+            serde_json::from_str(&format_json(&scored_prs, false)).unwrap();
+        let snooze = &value["prs"][0]["snooze"];
+        assert_eq!(snooze["remaining"], "3h left");
+        assert!(snooze["snoozed_at"].is_string());
+        assert!(snooze["snooze_until"].is_string());
+    }
+
+    #[test]
+    fn test_format_json_omits_snooze_for_active_prs() {
+        let pr = sample_pr();
+        let scored_prs = vec![ScoredPr {
+            pr: &pr,
+            score: 10.0,
+            incomplete: false,
+            snooze: None,
+        }];
+        let value: serde_json::Value =
+            serde_json::from_str(&format_json(&scored_prs, false)).unwrap();
+        assert!(value["prs"][0].get("snooze").is_none());
+    }
+
+    #[test]
+    fn test_max_title_width_applies_when_piped() {
+        let pr = sample_pr();
+        let scored_prs = vec![ScoredPr {
+            pr: &pr,
+            score: 1500.7,
+            incomplete: false,
+            snooze: None,
+        }];
+        // Tests have no terminal, so without a cap the title stays whole
+        let table = |max| {
+            format_scored_table(
+                &scored_prs,
+                &TableColumn::default_layout(),
+                false,
+                false,
+                IndexStyle::Number,
+                max,
+            )
+        };
+        assert!(table(None).contains("Fix login bug"));
+        assert!(table(Some(8)).contains("Fix l..."));
+        assert!(!table(Some(8)).contains("Fix login"));
+
+        assert_eq!(
+            format_tsv(&scored_prs, false, Some(8)),
+            "1501\tFix l...\towner/repo\towner/repo#123"
+        );
+        assert!(format_csv(&scored_prs, Some(8)).contains(",Fix l...,"));
+    }
+
+    #[test]
+    fn test_normalize_scores() {
+        let pr = sample_pr();
+        let scored = |score| ScoredPr {
+            pr: &pr,
+            score,
+            incomplete: false,
+            snooze: None,
+        };
+        let mut prs = vec![scored(12_300.0), scored(3_075.0), scored(0.0)];
+        normalize_scores(&mut prs);
+        let scores: Vec<f64> = prs.iter().map(|s| s.score).collect();
+        assert_eq!(scores, vec![100.0, 25.0, 0.0]);
+
+        // Nothing to scale against
+        assert_eq!(normalized_score(-5.0, 0.0), -5.0);
+    }
+
+    #[test]
+    fn test_format_csv_quotes_fields() {
+        let mut pr = sample_pr();
+        pr.title = "Fix \"login\", again".to_string();
+        let scored_prs = vec![ScoredPr {
+            pr: &pr,
+            score: 1500.7,
+            incomplete: false,
+            snooze: None,
+        }];
+        assert_eq!(
+            format_csv(&scored_prs, None),
+            "score,title,repo,pr,url\n\
+             1501,\"Fix \"\"login\"\", again\",owner/repo,owner/repo#123,https://github.com/owner/repo/pull/123"
+        );
+        assert_eq!(format_csv(&[], None), "score,title,repo,pr,url");
+    }
+
+    #[test]
+    fn test_format_tsv_header() {
+        let pr = sample_pr();
+        let scored_prs = vec![ScoredPr {
+            pr: &pr,
+            score: 42.0,
+            incomplete: false,
+            snooze: None,
+        }];
+        let result = format_tsv(&scored_prs, true, None);
+        let lines: Vec<&str> = result.lines().collect();
+        assert_eq!(lines[0], "score\ttitle\trepo\tpr");
+        assert_eq!(lines.len(), 2);
+
+        // An empty listing still gets the header
+        assert_eq!(format_tsv(&[], true, None), "score\ttitle\trepo\tpr");
+    }
+
+    #[test]
+    fn test_format_tsv_indefinite_snooze() {
+        let pr = sample_pr();
+        let entry = SnoozeEntry {
+            snoozed_at: Utc::now(),
+            snooze_until: None,
+        };
+        let scored_prs = vec![ScoredPr {
+            pr: &pr,
+            score: 10.0,
+            incomplete: false,
+            snooze: Some(&entry),
+        }];
+        let result = format_tsv(&scored_prs, false, None);
+        assert!(result.ends_with("\towner/repo#123\tindefinite\t"));
+    }
+
+    #[test]
+    fn test_wrap_title_fits() {
+        assert_eq!(wrap_title("Short", 10), ("Short".to_string(), None));
+    }
+
+    #[test]
+    fn test_wrap_title_breaks_at_space() {
+        let (first, rest) = wrap_title("Fix login bug on mobile", 12);
+        assert_eq!(first, "Fix login");
+        assert_eq!(rest, Some("bug on mo...".to_string()));
+    }
+
+    #[test]
+    fn test_wrap_title_without_spaces() {
+        let (first, rest) = wrap_title("abcdefghij", 4);
+        assert_eq!(first, "abcd");
+        assert_eq!(rest, Some("e...".to_string()));
+    }
+
+    #[test]
+    fn test_format_timing_report() {
+        let entries = vec![
+            (
+                "config load".to_string(),
+                std::time::Duration::from_micros(1500),
+            ),
+            ("scoring".to_string(), std::time::Duration::from_millis(12)),
+        ];
+        let report = format_timing_report(&entries);
+        let lines: Vec<&str> = report.lines().collect();
+        assert_eq!(lines[0], "Timing:");
+        assert_eq!(lines[1], "  config load       1.5ms");
+        assert_eq!(lines[2], "  scoring          12.0ms");
+    }
+
+    #[test]
+    fn test_format_workload_table() {
+        let loads = vec![
+            ReviewerLoad {
+                reviewer: "alexandra".to_string(),
+                outstanding: 12,
+            },
+            ReviewerLoad {
+                reviewer: "bo".to_string(),
+                outstanding: 3,
+            },
+        ];
+        let output = format_workload_table(&loads, false);
+        assert_eq!(output, "Reviewer   PRs\nalexandra   12\nbo           3");
+        assert_eq!(
+            format_workload_table(&[], false),
+            "No outstanding review requests."
+        );
     }
 }
@@ -0,0 +1,140 @@
+//! List index labels (`index_style`): 1-based numbers by default, letters,
+//! or short IDs hashed from the PR URL that stay the same across refreshes.
+//! `open`, `snooze`, and `unsnooze` resolve labels back to list positions.
+
+use crate::config::IndexStyle;
+
+/// Characters in a stable ID; 36^4 (about 1.7M) values keeps collisions
+/// unlikely for lists of a few hundred PRs
+const ID_LEN: usize = 4;
+
+const BASE36: &[u8; 36] = b"0123456789abcdefghijklmnopqrstuvwxyz";
+
+/// Label for the PR at 0-based `position` with the given `url`
+pub fn index_label(style: IndexStyle, position: usize, url: &str) -> String {
+    match style {
+        IndexStyle::Number => (position + 1).to_string(),
+        IndexStyle::Letter => letters(position),
+        IndexStyle::Id => stable_id(url),
+    }
+}
+
+/// Widest label plus its trailing dot, for column sizing
+pub fn index_width(style: IndexStyle) -> usize {
+    match style {
+        // "99." and "zz."; longer lists just push the row over
+        IndexStyle::Number | IndexStyle::Letter => 3,
+        IndexStyle::Id => ID_LEN + 1,
+    }
+}
+
+/// Spreadsheet-style letters: a..z, then aa, ab, ...
+fn letters(position: usize) -> String {
+    let mut n = position + 1;
+    let mut label = Vec::new();
+    while n > 0 {
+        n -= 1;
+        label.push(b'a' + (n % 26) as u8);
+        n /= 26;
+    }
+    label.reverse();
+    String::from_utf8(label).unwrap()
+}
+
+/// Inverse of `letters`; None unless the label is all ASCII letters
+fn letters_position(label: &str) -> Option<usize> {
+    if label.is_empty() || !label.bytes().all(|b| b.is_ascii_lowercase()) {
+        return None;
+    }
+    let mut n = 0usize;
+    for b in label.bytes() {
+        n = n.checked_mul(26)?.checked_add((b - b'a') as usize + 1)?;
+    }
+    Some(n - 1)
+}
+
+/// Base36 of a 64-bit FNV-1a hash of the URL. Hand-rolled rather than std's
+/// `DefaultHasher`, whose output may change between Rust releases.
+pub fn stable_id(url: &str) -> String {
+    let mut hash: u64 = 0xcbf29ce484222325;
+    for b in url.bytes() {
+        hash ^= b as u64;
+        hash = hash.wrapping_mul(0x100000001b3);
+    }
+    (0..ID_LEN)
+        .map(|_| {
+            let c = BASE36[(hash % 36) as usize] as char;
+            hash /= 36;
+            c
+        })
+        .collect()
+}
+
+/// Resolve a label typed on the command line to a 0-based position in
+/// `urls` (the list as displayed). Plain 1-based numbers always work; with
+/// `Id`, an ID made only of digits wins over the number.
+pub fn resolve_index<'a>(
+    style: IndexStyle,
+    label: &str,
+    urls: impl IntoIterator<Item = &'a str>,
+) -> Option<usize> {
+    let label = label.trim().trim_end_matches('.').to_lowercase();
+    let urls: Vec<&str> = urls.into_iter().collect();
+
+    let by_style = match style {
+        IndexStyle::Number => None,
+        IndexStyle::Letter => letters_position(&label),
+        IndexStyle::Id => urls.iter().position(|url| stable_id(url) == label),
+    };
+    by_style
+        .or_else(|| label.parse::<usize>().ok().and_then(|n| n.checked_sub(1)))
+        .filter(|&position| position < urls.len())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_letters_round_trip() {
+        assert_eq!(letters(0), "a");
+        assert_eq!(letters(25), "z");
+        assert_eq!(letters(26), "aa");
+        assert_eq!(letters(27), "ab");
+        for position in [0, 25, 26, 51, 52, 701, 702] {
+            assert_eq!(letters_position(&letters(position)), Some(position));
+        }
+        assert_eq!(letters_position("a1"), None);
+    }
+
+    #[test]
+    fn test_stable_id_is_short_and_deterministic() {
+        let id = stable_id("https://github.com/acme/api/pull/42");
+        assert_eq!(id.len(), ID_LEN);
+        assert_eq!(id, stable_id("https://github.com/acme/api/pull/42"));
+        assert_ne!(id, stable_id("https://github.com/acme/api/pull/43"));
+    }
+
+    #[test]
+    fn test_resolve_index() {
+        let urls = [
+            "https://github.com/acme/api/pull/42",
+            "https://github.com/acme/web/pull/7",
+        ];
+
+        assert_eq!(resolve_index(IndexStyle::Number, "2", urls), Some(1));
+        assert_eq!(resolve_index(IndexStyle::Number, "3", urls), None);
+        assert_eq!(resolve_index(IndexStyle::Number, "0", urls), None);
+        assert_eq!(resolve_index(IndexStyle::Letter, "B", urls), Some(1));
+        assert_eq!(resolve_index(IndexStyle::Letter, "c", urls), None);
+        // Numbers keep working whatever the style
+        assert_eq!(resolve_index(IndexStyle::Letter, "1", urls), Some(0));
+
+        let id = stable_id(urls[1]);
+        assert_eq!(resolve_index(IndexStyle::Id, &id, urls), Some(1));
+        assert_eq!(
+            resolve_index(IndexStyle::Id, &format!("{}.", id.to_uppercase()), urls),
+            Some(1)
+        );
+    }
+}
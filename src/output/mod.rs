@@ -1,6 +1,12 @@
 pub mod formatter;
+pub mod index;
+pub mod palette;
 
 pub use formatter::{
-    format_age, format_pr_detail, format_pr_list, format_score, format_scored_table, format_tsv,
-    should_use_colors, ScoredPr,
+    format_age, format_ago, format_csv, format_efficiency, format_grouped_table, format_json,
+    format_json_array, format_json_document, format_jsonl, format_jsonl_record, format_plan_json,
+    format_plan_table, format_pr_detail, format_pr_list, format_score, format_scored_table,
+    format_timing_report, format_tsv, format_workload_json, format_workload_table,
+    normalize_scores, normalized_score, should_use_colors, GroupBy, ScoredPr,
 };
+pub use index::{index_label, index_width, resolve_index};
@@ -0,0 +1,47 @@
+use owo_colors::AnsiColors;
+
+/// Number of colors names (authors, labels) are spread across
+pub const PALETTE_LEN: usize = 6;
+
+/// Plain-output colors for each palette slot
+const ANSI_PALETTE: [AnsiColors; PALETTE_LEN] = [
+    AnsiColors::Cyan,
+    AnsiColors::Green,
+    AnsiColors::Yellow,
+    AnsiColors::Blue,
+    AnsiColors::Magenta,
+    AnsiColors::Red,
+];
+
+/// Palette slot for a name, stable across runs and platforms (FNV-1a over
+/// the lowercased name, so `Octocat` and `octocat` match)
+pub fn palette_index(name: &str) -> usize {
+    let mut hash: u32 = 0x811c_9dc5;
+    for byte in name.to_lowercase().bytes() {
+        hash ^= byte as u32;
+        hash = hash.wrapping_mul(0x0100_0193);
+    }
+    hash as usize % PALETTE_LEN
+}
+
+/// Terminal color for a name in plain output
+pub fn ansi_color(name: &str) -> AnsiColors {
+    ANSI_PALETTE[palette_index(name)]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_palette_index_is_stable_and_case_insensitive() {
+        // Fixed values: changing the hash would recolor everyone's authors
+        assert_eq!(palette_index("octocat"), palette_index("OctoCat"));
+        assert_eq!(palette_index(""), 0x811c_9dc5_usize % PALETTE_LEN);
+        let spread: std::collections::HashSet<usize> = ["alice", "bob", "carol", "dave", "erin"]
+            .iter()
+            .map(|n| palette_index(n))
+            .collect();
+        assert!(spread.len() > 1);
+    }
+}
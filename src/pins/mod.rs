@@ -0,0 +1,74 @@
+//! Pinned PRs: ones the user must get to, floated to the top of every list
+//! regardless of score until unpinned.
+
+pub mod storage;
+
+pub use storage::{get_pins_path, load_pin_state, save_pin_state};
+
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeSet;
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PinState {
+    pub version: u32,
+    /// Pinned PR URLs
+    #[serde(default)]
+    pub pinned: BTreeSet<String>,
+}
+
+impl Default for PinState {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl PinState {
+    pub fn new() -> Self {
+        Self {
+            version: 1,
+            pinned: BTreeSet::new(),
+        }
+    }
+
+    pub fn is_pinned(&self, pr_url: &str) -> bool {
+        self.pinned.contains(pr_url)
+    }
+
+    /// Pin the PR if it isn't pinned, unpin it otherwise.
+    /// Returns true if the PR is now pinned.
+    pub fn toggle(&mut self, pr_url: &str) -> bool {
+        if self.pinned.remove(pr_url) {
+            false
+        } else {
+            self.pinned.insert(pr_url.to_string());
+            true
+        }
+    }
+
+    /// Remove every pin. Returns how many there were.
+    pub fn clear(&mut self) -> usize {
+        let count = self.pinned.len();
+        self.pinned.clear();
+        count
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_toggle_and_clear() {
+        let url = "https://github.com/owner/repo/pull/1";
+        let mut state = PinState::new();
+        assert!(state.toggle(url));
+        assert!(state.is_pinned(url));
+        assert!(!state.toggle(url));
+        assert!(!state.is_pinned(url));
+
+        state.toggle(url);
+        state.toggle("https://github.com/owner/repo/pull/2");
+        assert_eq!(state.clear(), 2);
+        assert!(!state.is_pinned(url));
+    }
+}
@@ -0,0 +1,20 @@
+use super::PinState;
+use anyhow::Result;
+use std::path::{Path, PathBuf};
+
+/// Get the default pins file path (~/.config/pr-bro/pins.json)
+pub fn get_pins_path() -> PathBuf {
+    crate::config::get_config_dir().join("pins.json")
+}
+
+/// Load pinned PRs from a JSON file
+///
+/// If the file doesn't exist, returns a new empty state.
+pub fn load_pin_state(path: &Path) -> Result<PinState> {
+    crate::state_file::load_state(path, "pins", &[])
+}
+
+/// Save pinned PRs to a JSON file atomically
+pub fn save_pin_state(path: &Path, state: &PinState) -> Result<()> {
+    crate::state_file::save_state(path, "pins", state)
+}
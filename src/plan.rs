@@ -0,0 +1,67 @@
+use crate::github::types::PullRequest;
+use crate::scoring::ScoreResult;
+
+/// Pick PRs for a review session of at most `budget` changed lines.
+///
+/// Greedy by score: walks the list in order (already sorted by score
+/// descending) and takes each PR whose size still fits, skipping ones that
+/// don't. Returns indices into `prs`, in list order.
+pub fn select_within_budget(prs: &[(PullRequest, ScoreResult)], budget: u64) -> Vec<usize> {
+    let mut remaining = budget;
+    prs.iter()
+        .enumerate()
+        .filter_map(|(idx, (pr, _))| {
+            let size = pr.size();
+            if size <= remaining {
+                remaining -= size;
+                Some(idx)
+            } else {
+                None
+            }
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::scoring::ScoreBreakdown;
+
+    fn scored(size: u64, score: f64) -> (PullRequest, ScoreResult) {
+        let pr = PullRequest {
+            additions: size,
+            ..PullRequest::test_default()
+        };
+        let result = ScoreResult {
+            score,
+            incomplete: false,
+            sla_breached: false,
+            oldest_alert: false,
+            breakdown: ScoreBreakdown {
+                base_score: score,
+                factors: vec![],
+                disabled: vec![],
+            },
+        };
+        (pr, result)
+    }
+
+    #[test]
+    fn test_select_skips_prs_that_do_not_fit() {
+        let prs = vec![
+            scored(300, 900.0),
+            scored(400, 800.0), // would exceed 500 after the first
+            scored(150, 700.0),
+            scored(100, 600.0), // 300 + 150 + 100 > 500
+            scored(50, 500.0),
+        ];
+        assert_eq!(select_within_budget(&prs, 500), vec![0, 2, 4]);
+    }
+
+    #[test]
+    fn test_select_zero_budget_takes_only_empty_prs() {
+        let prs = vec![scored(10, 900.0), scored(0, 800.0)];
+        assert_eq!(select_within_budget(&prs, 0), vec![1]);
+        assert!(select_within_budget(&[], 100).is_empty());
+    }
+}
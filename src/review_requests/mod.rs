@@ -0,0 +1,168 @@
+//! Detect when your review request on a PR is withdrawn.
+//!
+//! GitHub has no flag for "you were un-requested", so the requested-reviewer
+//! set seen on each refresh is persisted and compared with the current one.
+
+pub mod storage;
+
+pub use storage::{get_review_requests_path, load_review_request_state, save_review_request_state};
+
+use crate::github::types::PullRequest;
+use chrono::{DateTime, Duration, Utc};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+
+/// Entries not seen for this long are dropped (closed/merged PRs, removed queries)
+const STALE_AFTER_DAYS: i64 = 30;
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ReviewRequestState {
+    pub version: u32,
+    #[serde(default)]
+    pub prs: HashMap<String, ReviewRequestEntry>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ReviewRequestEntry {
+    /// Requested reviewer logins as of `seen_at`
+    pub requested_reviewers: Vec<String>,
+    /// Sticky until you are requested again
+    #[serde(default)]
+    pub withdrawn: bool,
+    pub seen_at: DateTime<Utc>,
+}
+
+impl Default for ReviewRequestState {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl ReviewRequestState {
+    pub fn new() -> Self {
+        Self {
+            version: 1,
+            prs: HashMap::new(),
+        }
+    }
+
+    /// Compare each PR's requested reviewers with the last-seen set, set
+    /// `review_request_withdrawn` on PRs where `username` was un-requested, and
+    /// record the current sets.
+    ///
+    /// Dropping off the list because you submitted a review doesn't count:
+    /// GitHub removes the request then too, so a review since the last refresh
+    /// clears the flag. PRs whose details weren't fetched are left untouched.
+    pub fn update(&mut self, prs: &mut [PullRequest], username: &str, now: DateTime<Utc>) {
+        for pr in prs.iter_mut() {
+            let Some(ref current) = pr.requested_reviewers else {
+                continue;
+            };
+            let requested_now = current.iter().any(|r| r.eq_ignore_ascii_case(username));
+
+            let withdrawn = match self.prs.get(&pr.url) {
+                _ if requested_now => false,
+                None => false,
+                Some(prev) => {
+                    let was_requested = prev
+                        .requested_reviewers
+                        .iter()
+                        .any(|r| r.eq_ignore_ascii_case(username));
+                    let reviewed_since = pr
+                        .my_last_review_at
+                        .is_some_and(|reviewed_at| reviewed_at >= prev.seen_at);
+                    (prev.withdrawn || was_requested) && !reviewed_since
+                }
+            };
+
+            pr.review_request_withdrawn = withdrawn;
+            self.prs.insert(
+                pr.url.clone(),
+                ReviewRequestEntry {
+                    requested_reviewers: current.clone(),
+                    withdrawn,
+                    seen_at: now,
+                },
+            );
+        }
+
+        self.prs
+            .retain(|_, entry| now - entry.seen_at < Duration::days(STALE_AFTER_DAYS));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn pr(requested: Option<&[&str]>) -> PullRequest {
+        PullRequest {
+            requested_reviewers: requested.map(|r| r.iter().map(|s| s.to_string()).collect()),
+            ..PullRequest::test_default()
+        }
+    }
+
+    #[test]
+    fn test_withdrawn_after_unrequest_and_sticky() {
+        let mut state = ReviewRequestState::new();
+        let t0 = Utc::now() - Duration::hours(2);
+
+        let mut prs = vec![pr(Some(&["me", "alice"]))];
+        state.update(&mut prs, "me", t0);
+        assert!(!prs[0].review_request_withdrawn);
+
+        let mut prs = vec![pr(Some(&["alice"]))];
+        state.update(&mut prs, "me", t0 + Duration::hours(1));
+        assert!(prs[0].review_request_withdrawn);
+
+        // Stays flagged on later refreshes until re-requested
+        let mut prs = vec![pr(Some(&["alice"]))];
+        state.update(&mut prs, "me", t0 + Duration::hours(2));
+        assert!(prs[0].review_request_withdrawn);
+
+        let mut prs = vec![pr(Some(&["Me"]))];
+        state.update(&mut prs, "me", t0 + Duration::hours(3));
+        assert!(!prs[0].review_request_withdrawn);
+    }
+
+    #[test]
+    fn test_reviewing_is_not_withdrawal() {
+        let mut state = ReviewRequestState::new();
+        let t0 = Utc::now() - Duration::hours(2);
+
+        let mut prs = vec![pr(Some(&["me"]))];
+        state.update(&mut prs, "me", t0);
+
+        let mut prs = vec![pr(Some(&[]))];
+        prs[0].my_last_review_at = Some(t0 + Duration::minutes(30));
+        state.update(&mut prs, "me", t0 + Duration::hours(1));
+        assert!(!prs[0].review_request_withdrawn);
+    }
+
+    #[test]
+    fn test_unenriched_pr_keeps_previous_entry() {
+        let mut state = ReviewRequestState::new();
+        let t0 = Utc::now() - Duration::hours(2);
+
+        let mut prs = vec![pr(Some(&["me"]))];
+        state.update(&mut prs, "me", t0);
+
+        // Details not fetched (e.g. rate limited): no false positive, entry kept
+        let mut prs = vec![pr(None)];
+        state.update(&mut prs, "me", t0 + Duration::hours(1));
+        assert!(!prs[0].review_request_withdrawn);
+        assert_eq!(
+            state.prs["https://github.com/owner/repo/pull/1"].requested_reviewers,
+            vec!["me".to_string()]
+        );
+    }
+
+    #[test]
+    fn test_stale_entries_pruned() {
+        let mut state = ReviewRequestState::new();
+        let mut prs = vec![pr(Some(&["me"]))];
+        state.update(&mut prs, "me", Utc::now() - Duration::days(40));
+        state.update(&mut [], "me", Utc::now());
+        assert!(state.prs.is_empty());
+    }
+}
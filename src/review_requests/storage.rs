@@ -0,0 +1,20 @@
+use super::ReviewRequestState;
+use anyhow::Result;
+use std::path::{Path, PathBuf};
+
+/// Get the default review request state file path (~/.config/pr-bro/review_requests.json)
+pub fn get_review_requests_path() -> PathBuf {
+    crate::config::get_config_dir().join("review_requests.json")
+}
+
+/// Load review request state from a JSON file
+///
+/// If the file doesn't exist, returns a new empty state.
+pub fn load_review_request_state(path: &Path) -> Result<ReviewRequestState> {
+    crate::state_file::load_state(path, "review request state", &[])
+}
+
+/// Save review request state to a JSON file atomically
+pub fn save_review_request_state(path: &Path, state: &ReviewRequestState) -> Result<()> {
+    crate::state_file::save_state(path, "review request state", state)
+}
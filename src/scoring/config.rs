@@ -1,9 +1,13 @@
 use serde::{Deserialize, Serialize};
-use std::collections::HashMap;
+use std::borrow::Cow;
+
+use crate::github::types::CiStatus;
 
 /// Label-based scoring effect.
 ///
 /// Maps label names to score effects. Multiple matching labels compound.
+/// A label may also set the base score (`set_base`), replacing `base_score`
+/// before any other factor is applied; `effect` can then be omitted.
 ///
 /// Example YAML:
 /// ```yaml
@@ -12,12 +16,22 @@ use std::collections::HashMap;
 ///     effect: "+10"
 ///   - name: "wip"
 ///     effect: "x0.5"
+///   - name: "hotfix"
+///     set_base: 1000
 /// ```
 #[derive(Debug, Clone, Deserialize, Serialize, PartialEq)]
 #[serde(deny_unknown_fields)]
 pub struct LabelEffect {
     pub name: String,
+
+    /// Effect applied when the label matches (may be empty when `set_base` is set)
+    #[serde(default, skip_serializing_if = "String::is_empty")]
     pub effect: String,
+
+    /// Base score override when the label matches. If several matching labels
+    /// set a base, the first one in config order wins.
+    #[serde(default)]
+    pub set_base: Option<f64>,
 }
 
 /// Main scoring configuration.
@@ -49,11 +63,17 @@ pub struct ScoringConfig {
     #[serde(default)]
     pub age: Option<String>,
 
+    /// What the age factor measures from: PR creation (default) or when your
+    /// review was first requested. The latter costs an extra API call per PR.
+    #[serde(default)]
+    pub age_basis: Option<AgeBasis>,
+
     /// Approval factor: effect string applied based on approval count
     /// Format: "+N per 1", "xN per 1", "+N", or "xN"
     /// Example: "+10 per 1" adds 10 points per approval
+    /// Or a curve: `{ peak: 2, boost: "x2", decay: "x0.7 per 1" }`
     #[serde(default)]
-    pub approvals: Option<String>,
+    pub approvals: Option<ApprovalsConfig>,
 
     /// Size factor: bucket-based with optional file exclusions
     #[serde(default)]
@@ -73,6 +93,82 @@ pub struct ScoringConfig {
     /// Example: "x0.1" to deprioritize draft PRs
     #[serde(default)]
     pub draft: Option<String>,
+
+    /// Trusted approval factor: effect applied when a reviewer listed in
+    /// `trusted_reviewers` has approved the PR
+    /// Example: "x0.5" to skim PRs the lead already approved
+    #[serde(default)]
+    pub approved_by_trusted: Option<String>,
+
+    /// Updated-since-my-review factor: effect applied when the PR was updated
+    /// after the authenticated user's last review (the ball is back in your court)
+    /// Example: "x3" to strongly boost PRs the author has pushed to since you reviewed
+    #[serde(default)]
+    pub updated_since_my_review: Option<String>,
+
+    /// Re-review factor: effect applied when your review was requested again
+    /// after you last reviewed. Needs `use_graphql: true`.
+    /// Example: "x3" to put explicit re-review requests first
+    #[serde(default)]
+    pub re_review_requested: Option<String>,
+
+    /// Changes-requested factor: effect applied when reviewers other than you
+    /// have requested changes. Format like approvals: "+N per 1", "xN per 1", "+N", or "xN"
+    /// Example: "x0.5" to wait for the author to address feedback first
+    #[serde(default)]
+    pub changes_requested: Option<String>,
+
+    /// Reactions factor: effect applied based on the total reaction count on
+    /// the PR description. Format like approvals: "+N per 1", "xN per 1", "+N", or "xN"
+    /// Example: "+1 per 5" adds a point for every 5 reactions
+    #[serde(default)]
+    pub reactions: Option<String>,
+
+    /// Comments factor: effect applied based on the number of comments in the
+    /// PR's conversation. Format like approvals: "+N per 1", "xN per 1", "+N", or "xN"
+    /// Example: "+1 per 1" floats long discussions up
+    #[serde(default)]
+    pub comments: Option<String>,
+
+    /// Team review factor: effect applied when review was requested from
+    /// one of the `teams` you belong to (not from you personally)
+    /// Example: "x1.5" to put team-wide asks above org-wide noise
+    #[serde(default)]
+    pub team_review_requested: Option<String>,
+
+    /// CI factor: effect per combined CI result of the PR's head commit.
+    /// Costs two extra API calls per PR (none with `use_graphql`), made only
+    /// when this is set.
+    /// Example: `{ failure: "x0.3", pending: "x0.7" }` to wait for green builds
+    #[serde(default)]
+    pub ci_status: Option<CiStatusConfig>,
+
+    /// Commits-behind factor: effect applied based on how many commits the
+    /// base branch has that the PR doesn't. Format like approvals. Costs one
+    /// extra API call per PR, made only when this is set.
+    /// Example: "x0.9 per 10" to sink PRs that have fallen far behind
+    #[serde(default)]
+    pub commits_behind: Option<String>,
+
+    /// Authored factor: effect applied to PRs you opened yourself
+    /// Example: "x0.2" to keep your own PRs below review requests
+    #[serde(default)]
+    pub authored: Option<String>,
+
+    /// Base score for PRs you opened yourself, replacing `base_score` for them
+    /// Example: 20 so tracking queries like `author:@me` start low
+    #[serde(default)]
+    pub authored_base_score: Option<f64>,
+
+    /// Review SLA: PRs waiting longer than this are marked as breaching, and
+    /// its `effect` (if any) applies to them
+    #[serde(default)]
+    pub sla: Option<SlaConfig>,
+
+    /// Factors to skip while keeping their settings, by config key
+    /// Example: ["size", "labels"] to see how PRs rank without them
+    #[serde(default)]
+    pub disabled_factors: Option<Vec<String>>,
 }
 
 impl Default for ScoringConfig {
@@ -80,9 +176,12 @@ impl Default for ScoringConfig {
         Self {
             base_score: Some(100.0),
             age: Some("+1 per 1h".to_string()),
-            approvals: Some("+10 per 1".to_string()),
+            age_basis: None,
+            approvals: Some(ApprovalsConfig::Effect("+10 per 1".to_string())),
             size: Some(SizeConfig {
                 exclude: None,
+                metric: None,
+                on_exclude_failure: None,
                 buckets: Some(vec![
                     SizeBucket {
                         range: "<100".to_string(),
@@ -101,10 +200,88 @@ impl Default for ScoringConfig {
             labels: None,
             previously_reviewed: None,
             draft: None,
+            approved_by_trusted: None,
+            updated_since_my_review: None,
+            re_review_requested: None,
+            changes_requested: None,
+            reactions: None,
+            comments: None,
+            team_review_requested: None,
+            ci_status: None,
+            commits_behind: None,
+            authored: None,
+            authored_base_score: None,
+            sla: None,
+            disabled_factors: None,
         }
     }
 }
 
+/// Factor names accepted in `disabled_factors`, spelled as their config keys
+pub const FACTOR_NAMES: &[&str] = &[
+    "age",
+    "approvals",
+    "size",
+    "labels",
+    "previously_reviewed",
+    "draft",
+    "approved_by_trusted",
+    "updated_since_my_review",
+    "re_review_requested",
+    "changes_requested",
+    "reactions",
+    "comments",
+    "team_review_requested",
+    "ci_status",
+    "commits_behind",
+    "authored",
+    "sla",
+];
+
+impl ScoringConfig {
+    /// This config with the factors in `disabled_factors` cleared, plus the
+    /// names of those that were actually configured (for the breakdown).
+    /// `labels` covers label base overrides and `authored` covers
+    /// `authored_base_score`.
+    pub fn without_disabled(&self) -> (Cow<'_, ScoringConfig>, Vec<String>) {
+        let Some(disabled) = self.disabled_factors.as_ref().filter(|d| !d.is_empty()) else {
+            return (Cow::Borrowed(self), Vec::new());
+        };
+
+        let mut config = self.clone();
+        let mut skipped = Vec::new();
+        for name in disabled {
+            let was_set = match name.as_str() {
+                "age" => config.age.take().is_some(),
+                "approvals" => config.approvals.take().is_some(),
+                "size" => config.size.take().is_some(),
+                "labels" => config.labels.take().is_some(),
+                "previously_reviewed" => config.previously_reviewed.take().is_some(),
+                "draft" => config.draft.take().is_some(),
+                "approved_by_trusted" => config.approved_by_trusted.take().is_some(),
+                "updated_since_my_review" => config.updated_since_my_review.take().is_some(),
+                "re_review_requested" => config.re_review_requested.take().is_some(),
+                "changes_requested" => config.changes_requested.take().is_some(),
+                "reactions" => config.reactions.take().is_some(),
+                "comments" => config.comments.take().is_some(),
+                "team_review_requested" => config.team_review_requested.take().is_some(),
+                "ci_status" => config.ci_status.take().is_some(),
+                "commits_behind" => config.commits_behind.take().is_some(),
+                "authored" => {
+                    let effect = config.authored.take().is_some();
+                    config.authored_base_score.take().is_some() || effect
+                }
+                "sla" => config.sla.take().is_some(),
+                _ => false, // Rejected by validation
+            };
+            if was_set && !skipped.contains(name) {
+                skipped.push(name.clone());
+            }
+        }
+        (Cow::Owned(config), skipped)
+    }
+}
+
 /// Merge per-query scoring config with global config at field level.
 /// Per-query `Some` values override global values.
 /// Per-query `None` values fall through to global values.
@@ -120,6 +297,7 @@ pub fn merge_scoring_configs(
     ScoringConfig {
         base_score: query.base_score.or(global.base_score),
         age: query.age.clone().or_else(|| global.age.clone()),
+        age_basis: query.age_basis.or(global.age_basis),
         approvals: query.approvals.clone().or_else(|| global.approvals.clone()),
         size: merge_size_configs(global.size.as_ref(), query.size.as_ref()),
         labels: merge_label_configs(global.labels.as_ref(), query.labels.as_ref()),
@@ -128,12 +306,48 @@ pub fn merge_scoring_configs(
             .clone()
             .or_else(|| global.previously_reviewed.clone()),
         draft: query.draft.clone().or_else(|| global.draft.clone()),
+        approved_by_trusted: query
+            .approved_by_trusted
+            .clone()
+            .or_else(|| global.approved_by_trusted.clone()),
+        updated_since_my_review: query
+            .updated_since_my_review
+            .clone()
+            .or_else(|| global.updated_since_my_review.clone()),
+        re_review_requested: query
+            .re_review_requested
+            .clone()
+            .or_else(|| global.re_review_requested.clone()),
+        changes_requested: query
+            .changes_requested
+            .clone()
+            .or_else(|| global.changes_requested.clone()),
+        reactions: query.reactions.clone().or_else(|| global.reactions.clone()),
+        comments: query.comments.clone().or_else(|| global.comments.clone()),
+        team_review_requested: query
+            .team_review_requested
+            .clone()
+            .or_else(|| global.team_review_requested.clone()),
+        ci_status: merge_ci_status_configs(global.ci_status.as_ref(), query.ci_status.as_ref()),
+        commits_behind: query
+            .commits_behind
+            .clone()
+            .or_else(|| global.commits_behind.clone()),
+        authored: query.authored.clone().or_else(|| global.authored.clone()),
+        authored_base_score: query.authored_base_score.or(global.authored_base_score),
+        sla: query.sla.clone().or_else(|| global.sla.clone()),
+        disabled_factors: query
+            .disabled_factors
+            .clone()
+            .or_else(|| global.disabled_factors.clone()),
     }
 }
 
 /// Merge SizeConfig with leaf-level field handling.
 /// When both global and query have SizeConfig:
 /// - exclude: per-query overrides global (or falls through if None)
+/// - metric: per-query overrides global (or falls through if None)
+/// - on_exclude_failure: per-query overrides global (or falls through if None)
 /// - buckets: per-query overrides global (or falls through if None)
 ///
 /// Absent field (None) means inherit from global; explicitly set field means override.
@@ -144,6 +358,8 @@ fn merge_size_configs(
     match (query, global) {
         (Some(q), Some(g)) => Some(SizeConfig {
             exclude: q.exclude.clone().or_else(|| g.exclude.clone()),
+            metric: q.metric.or(g.metric),
+            on_exclude_failure: q.on_exclude_failure.or(g.on_exclude_failure),
             buckets: q.buckets.clone().or_else(|| g.buckets.clone()),
         }),
         (Some(q), None) => Some(q.clone()),
@@ -151,6 +367,23 @@ fn merge_size_configs(
     }
 }
 
+/// Merge CiStatusConfig per status: a query setting only `failure` keeps
+/// the global `pending` and `success` effects.
+fn merge_ci_status_configs(
+    global: Option<&CiStatusConfig>,
+    query: Option<&CiStatusConfig>,
+) -> Option<CiStatusConfig> {
+    match (query, global) {
+        (Some(q), Some(g)) => Some(CiStatusConfig {
+            success: q.success.clone().or_else(|| g.success.clone()),
+            failure: q.failure.clone().or_else(|| g.failure.clone()),
+            pending: q.pending.clone().or_else(|| g.pending.clone()),
+        }),
+        (Some(q), None) => Some(q.clone()),
+        (None, g) => g.cloned(),
+    }
+}
+
 /// Merge label configs by name (case-insensitive).
 /// Query labels override global labels with same name.
 /// Global labels not in query are preserved.
@@ -162,20 +395,55 @@ fn merge_label_configs(
         (None, g) => g.cloned(),
         (Some(q), None) => Some(q.clone()),
         (Some(q), Some(g)) => {
-            let mut merged: HashMap<String, LabelEffect> = HashMap::new();
-            // Add global labels first (lowercase keys for case-insensitive dedup)
-            for label in g {
-                merged.insert(label.name.to_lowercase(), label.clone());
+            // Global order, with query labels replacing same-named (case-insensitive)
+            // ones in place and new ones appended, so "first `set_base` wins" holds
+            let mut merged: Vec<LabelEffect> = Vec::with_capacity(g.len() + q.len());
+            for label in g.iter().chain(q) {
+                match merged
+                    .iter_mut()
+                    .find(|existing| existing.name.eq_ignore_ascii_case(&label.name))
+                {
+                    Some(existing) => *existing = label.clone(),
+                    None => merged.push(label.clone()),
+                }
             }
-            // Override with query labels (query wins on collision)
-            for label in q {
-                merged.insert(label.name.to_lowercase(), label.clone());
-            }
-            Some(merged.into_values().collect())
+            Some(merged)
         }
     }
 }
 
+/// Approvals factor: a single effect, or a curve that rewards approvals up to
+/// a peak and penalizes each one past it.
+///
+/// Example YAML:
+/// ```yaml
+/// approvals: "+10 per 1"
+/// # or
+/// approvals: { peak: 2, boost: "x2", decay: "x0.7 per 1" }
+/// ```
+#[derive(Debug, Clone, Deserialize, Serialize, PartialEq)]
+#[serde(untagged)]
+pub enum ApprovalsConfig {
+    Effect(String),
+    Curve(ApprovalsCurve),
+}
+
+/// Approvals curve: `boost` counts the first `peak` approvals, `decay` the
+/// approvals past the peak. Both take approvals effect strings, so "per N"
+/// means per N approvals and flat effects apply once when any approval counts.
+#[derive(Debug, Clone, Deserialize, Serialize, PartialEq)]
+#[serde(deny_unknown_fields)]
+pub struct ApprovalsCurve {
+    /// Approval count where the boost stops and the decay begins
+    pub peak: u32,
+
+    /// Effect for approvals up to the peak (e.g. "x2" or "+10 per 1")
+    pub boost: String,
+
+    /// Effect for approvals past the peak (e.g. "x0.7 per 1")
+    pub decay: String,
+}
+
 /// Size factor configuration.
 ///
 /// Supports file exclusion patterns and size-based buckets.
@@ -187,11 +455,125 @@ pub struct SizeConfig {
     #[serde(default)]
     pub exclude: Option<Vec<String>>,
 
+    /// What the buckets measure: "lines" (default), "files", or "hunks"
+    #[serde(default)]
+    pub metric: Option<SizeMetric>,
+
+    /// What to do when `exclude` is set but the file list couldn't be
+    /// fetched: "aggregate" (default), "skip", or "incomplete"
+    #[serde(default)]
+    pub on_exclude_failure: Option<ExcludeFailure>,
+
     /// Size buckets mapping line count ranges to effects
     #[serde(default)]
     pub buckets: Option<Vec<SizeBucket>>,
 }
 
+/// Review SLA: how long a PR may wait for review.
+///
+/// Waiting time is measured like the age factor (see `age_basis`).
+///
+/// Example YAML:
+/// ```yaml
+/// sla:
+///   within: 1d
+///   business_days: true
+///   effect: "x10"
+/// ```
+#[derive(Debug, Clone, Deserialize, Serialize, PartialEq)]
+#[serde(deny_unknown_fields)]
+pub struct SlaConfig {
+    /// Allowed waiting time (humantime, e.g. "1d", "4h")
+    pub within: String,
+
+    /// Effect applied to PRs breaching the SLA (e.g. "x10")
+    #[serde(default)]
+    pub effect: Option<String>,
+
+    /// Count only weekday time (Mon-Fri, local time) toward the SLA
+    #[serde(default)]
+    pub business_days: bool,
+}
+
+/// Effects for each CI result, e.g.
+/// ```yaml
+/// ci_status:
+///   failure: "x0.3"
+///   pending: "x0.7"
+/// ```
+/// A result with no effect leaves the score alone.
+#[derive(Debug, Clone, Default, Deserialize, Serialize, PartialEq)]
+#[serde(deny_unknown_fields)]
+pub struct CiStatusConfig {
+    #[serde(default)]
+    pub success: Option<String>,
+    #[serde(default)]
+    pub failure: Option<String>,
+    #[serde(default)]
+    pub pending: Option<String>,
+}
+
+impl CiStatusConfig {
+    /// The effect configured for `status`, if any
+    pub fn effect_for(&self, status: CiStatus) -> Option<&str> {
+        match status {
+            CiStatus::Success => self.success.as_deref(),
+            CiStatus::Failure => self.failure.as_deref(),
+            CiStatus::Pending => self.pending.as_deref(),
+        }
+    }
+}
+
+/// Start time the age factor counts from
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Deserialize, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum AgeBasis {
+    /// When the PR was opened
+    #[default]
+    Created,
+    /// When your review was first requested, falling back to creation time
+    ReviewRequested,
+}
+
+/// Quantity that size buckets are matched against
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Deserialize, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum SizeMetric {
+    /// Changed lines (additions + deletions)
+    #[default]
+    Lines,
+    /// Changed files
+    Files,
+    /// Diff hunks, a proxy for how many places a reviewer has to look.
+    /// Needs the PR file list, which costs an extra API call per PR.
+    Hunks,
+}
+
+impl SizeMetric {
+    /// Unit name used in score breakdowns
+    pub fn unit(self) -> &'static str {
+        match self {
+            SizeMetric::Lines => "lines",
+            SizeMetric::Files => "files",
+            SizeMetric::Hunks => "hunks",
+        }
+    }
+}
+
+/// Size factor behavior when exclude patterns are configured but couldn't be
+/// applied, because the PR's file list fetch failed or was never made
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Deserialize, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum ExcludeFailure {
+    /// Match buckets against the unfiltered size, as if nothing were excluded
+    #[default]
+    Aggregate,
+    /// Leave the size factor out
+    Skip,
+    /// Leave the size factor out and mark the score incomplete
+    Incomplete,
+}
+
 /// Size factor bucket.
 ///
 /// Maps line count ranges to score effects.
@@ -216,7 +598,10 @@ mod tests {
 
         assert_eq!(config.base_score, Some(100.0));
         assert_eq!(config.age, Some("+1 per 1h".to_string()));
-        assert_eq!(config.approvals, Some("+10 per 1".to_string()));
+        assert_eq!(
+            config.approvals,
+            Some(ApprovalsConfig::Effect("+10 per 1".to_string()))
+        );
         assert!(config.size.is_some());
         assert!(config.labels.is_none());
         assert!(config.previously_reviewed.is_none());
@@ -264,13 +649,36 @@ size:
         let config: ScoringConfig = serde_saphyr::from_str(yaml).unwrap();
         assert_eq!(config.base_score, Some(100.0));
         assert_eq!(config.age, Some("+1 per 1h".to_string()));
-        assert_eq!(config.approvals, Some("x2 per 1".to_string()));
+        assert_eq!(
+            config.approvals,
+            Some(ApprovalsConfig::Effect("x2 per 1".to_string()))
+        );
 
         let size = config.size.unwrap();
         assert_eq!(size.exclude.unwrap().len(), 2);
         assert_eq!(size.buckets.as_ref().unwrap().len(), 2);
     }
 
+    #[test]
+    fn test_approvals_curve_parse() {
+        let yaml = r#"
+approvals: { peak: 2, boost: "x2", decay: "x0.7 per 1" }
+"#;
+        let config: ScoringConfig = serde_saphyr::from_str(yaml).unwrap();
+        assert_eq!(
+            config.approvals,
+            Some(ApprovalsConfig::Curve(ApprovalsCurve {
+                peak: 2,
+                boost: "x2".to_string(),
+                decay: "x0.7 per 1".to_string(),
+            }))
+        );
+
+        // A curve needs all three keys
+        let yaml = "approvals: { peak: 2, boost: \"x2\" }";
+        assert!(serde_saphyr::from_str::<ScoringConfig>(yaml).is_err());
+    }
+
     #[test]
     fn test_empty_scoring_config_parse() {
         let yaml = "{}";
@@ -343,6 +751,49 @@ draft: "x0.1"
         assert_eq!(config.draft, Some("x0.1".to_string()));
     }
 
+    #[test]
+    fn test_approved_by_trusted_config_parse() {
+        let yaml = r#"
+approved_by_trusted: "x0.5"
+"#;
+        let config: ScoringConfig = serde_saphyr::from_str(yaml).unwrap();
+        assert_eq!(config.approved_by_trusted, Some("x0.5".to_string()));
+    }
+
+    #[test]
+    fn test_updated_since_my_review_config_parse() {
+        let yaml = r#"
+updated_since_my_review: "x3"
+"#;
+        let config: ScoringConfig = serde_saphyr::from_str(yaml).unwrap();
+        assert_eq!(config.updated_since_my_review, Some("x3".to_string()));
+    }
+
+    #[test]
+    fn test_label_set_base_parse() {
+        let yaml = r#"
+labels:
+  - name: "hotfix"
+    set_base: 1000
+  - name: "urgent"
+    effect: "+10"
+"#;
+        let config: ScoringConfig = serde_saphyr::from_str(yaml).unwrap();
+        let labels = config.labels.unwrap();
+        assert_eq!(labels[0].set_base, Some(1000.0));
+        assert_eq!(labels[0].effect, "");
+        assert_eq!(labels[1].set_base, None);
+    }
+
+    #[test]
+    fn test_changes_requested_config_parse() {
+        let yaml = r#"
+changes_requested: "x0.5"
+"#;
+        let config: ScoringConfig = serde_saphyr::from_str(yaml).unwrap();
+        assert_eq!(config.changes_requested, Some("x0.5".to_string()));
+    }
+
     #[test]
     fn test_full_config_with_all_factors() {
         let yaml = r#"
@@ -362,7 +813,10 @@ draft: "x0.1"
         let config: ScoringConfig = serde_saphyr::from_str(yaml).unwrap();
         assert_eq!(config.base_score, Some(100.0));
         assert_eq!(config.age, Some("+1 per 1h".to_string()));
-        assert_eq!(config.approvals, Some("x2 per 1".to_string()));
+        assert_eq!(
+            config.approvals,
+            Some(ApprovalsConfig::Effect("x2 per 1".to_string()))
+        );
         assert!(config.size.is_some());
         assert_eq!(config.labels.as_ref().unwrap().len(), 1);
         assert_eq!(config.previously_reviewed, Some("x0.5".to_string()));
@@ -378,14 +832,35 @@ draft: "x0.1"
         assert_eq!(result, global);
     }
 
+    #[test]
+    fn test_merge_ci_status_per_result() {
+        let yaml = r#"
+ci_status:
+  failure: "x0.3"
+  pending: "x0.7"
+"#;
+        let global: ScoringConfig = serde_saphyr::from_str(yaml).unwrap();
+        let query: ScoringConfig =
+            serde_saphyr::from_str("ci_status: { failure: \"x0.1\" }").unwrap();
+        let merged = merge_scoring_configs(&global, Some(&query))
+            .ci_status
+            .unwrap();
+        assert_eq!(merged.failure.as_deref(), Some("x0.1"));
+        assert_eq!(merged.pending.as_deref(), Some("x0.7"));
+        assert_eq!(merged.effect_for(CiStatus::Success), None);
+    }
+
     #[test]
     fn test_merge_partial_query_preserves_global_fields() {
         let global = ScoringConfig {
             base_score: Some(100.0),
             age: Some("+1 per 1h".to_string()),
-            approvals: Some("+10 per 1".to_string()),
+            age_basis: None,
+            approvals: Some(ApprovalsConfig::Effect("+10 per 1".to_string())),
             size: Some(SizeConfig {
                 exclude: Some(vec!["*.lock".to_string()]),
+                metric: None,
+                on_exclude_failure: None,
                 buckets: Some(vec![SizeBucket {
                     range: "<100".to_string(),
                     effect: "x5".to_string(),
@@ -394,26 +869,57 @@ draft: "x0.1"
             labels: Some(vec![LabelEffect {
                 name: "urgent".to_string(),
                 effect: "+10".to_string(),
+                set_base: None,
             }]),
             previously_reviewed: Some("x0.5".to_string()),
             draft: None,
+            approved_by_trusted: None,
+            updated_since_my_review: None,
+            re_review_requested: None,
+            changes_requested: None,
+            reactions: None,
+            comments: None,
+            team_review_requested: None,
+            ci_status: None,
+            commits_behind: None,
+            authored: None,
+            authored_base_score: None,
+            sla: None,
+            disabled_factors: None,
         };
 
         // Query only sets age — everything else should come from global
         let query = ScoringConfig {
             base_score: None,
             age: Some("+5 per 1h".to_string()),
+            age_basis: None,
             approvals: None,
             size: None,
             labels: None,
             previously_reviewed: None,
             draft: None,
+            approved_by_trusted: None,
+            updated_since_my_review: None,
+            re_review_requested: None,
+            changes_requested: None,
+            reactions: None,
+            comments: None,
+            team_review_requested: None,
+            ci_status: None,
+            commits_behind: None,
+            authored: None,
+            authored_base_score: None,
+            sla: None,
+            disabled_factors: None,
         };
 
         let result = merge_scoring_configs(&global, Some(&query));
         assert_eq!(result.base_score, Some(100.0)); // from global
         assert_eq!(result.age, Some("+5 per 1h".to_string())); // from query
-        assert_eq!(result.approvals, Some("+10 per 1".to_string())); // from global
+        assert_eq!(
+            result.approvals,
+            Some(ApprovalsConfig::Effect("+10 per 1".to_string()))
+        ); // from global
         assert!(result.size.is_some()); // from global
         assert_eq!(
             result.size.as_ref().unwrap().exclude,
@@ -428,21 +934,49 @@ draft: "x0.1"
         let global = ScoringConfig {
             base_score: Some(100.0),
             age: Some("+1 per 1h".to_string()),
+            age_basis: None,
             approvals: None,
             size: None,
             labels: None,
             previously_reviewed: None,
             draft: None,
+            approved_by_trusted: None,
+            updated_since_my_review: None,
+            re_review_requested: None,
+            changes_requested: None,
+            reactions: None,
+            comments: None,
+            team_review_requested: None,
+            ci_status: None,
+            commits_behind: None,
+            authored: None,
+            authored_base_score: None,
+            sla: None,
+            disabled_factors: None,
         };
 
         let query = ScoringConfig {
             base_score: Some(200.0),
             age: Some("+5 per 1h".to_string()),
+            age_basis: None,
             approvals: None,
             size: None,
             labels: None,
             previously_reviewed: None,
             draft: None,
+            approved_by_trusted: None,
+            updated_since_my_review: None,
+            re_review_requested: None,
+            changes_requested: None,
+            reactions: None,
+            comments: None,
+            team_review_requested: None,
+            ci_status: None,
+            commits_behind: None,
+            authored: None,
+            authored_base_score: None,
+            sla: None,
+            disabled_factors: None,
         };
 
         let result = merge_scoring_configs(&global, Some(&query));
@@ -455,9 +989,12 @@ draft: "x0.1"
         let global = ScoringConfig {
             base_score: None,
             age: None,
+            age_basis: None,
             approvals: None,
             size: Some(SizeConfig {
                 exclude: Some(vec!["*.lock".to_string()]),
+                metric: None,
+                on_exclude_failure: None,
                 buckets: Some(vec![SizeBucket {
                     range: "<100".to_string(),
                     effect: "x5".to_string(),
@@ -466,15 +1003,31 @@ draft: "x0.1"
             labels: None,
             previously_reviewed: None,
             draft: None,
+            approved_by_trusted: None,
+            updated_since_my_review: None,
+            re_review_requested: None,
+            changes_requested: None,
+            reactions: None,
+            comments: None,
+            team_review_requested: None,
+            ci_status: None,
+            commits_behind: None,
+            authored: None,
+            authored_base_score: None,
+            sla: None,
+            disabled_factors: None,
         };
 
         // Query has size with new buckets but no exclude
         let query = ScoringConfig {
             base_score: None,
             age: None,
+            age_basis: None,
             approvals: None,
             size: Some(SizeConfig {
                 exclude: None,
+                metric: None,
+                on_exclude_failure: None,
                 buckets: Some(vec![SizeBucket {
                     range: "<50".to_string(),
                     effect: "x10".to_string(),
@@ -483,6 +1036,19 @@ draft: "x0.1"
             labels: None,
             previously_reviewed: None,
             draft: None,
+            approved_by_trusted: None,
+            updated_since_my_review: None,
+            re_review_requested: None,
+            changes_requested: None,
+            reactions: None,
+            comments: None,
+            team_review_requested: None,
+            ci_status: None,
+            commits_behind: None,
+            authored: None,
+            authored_base_score: None,
+            sla: None,
+            disabled_factors: None,
         };
 
         let result = merge_scoring_configs(&global, Some(&query));
@@ -500,9 +1066,12 @@ draft: "x0.1"
         let global = ScoringConfig {
             base_score: None,
             age: None,
+            age_basis: None,
             approvals: None,
             size: Some(SizeConfig {
                 exclude: None,
+                metric: None,
+                on_exclude_failure: None,
                 buckets: Some(vec![SizeBucket {
                     range: "<100".to_string(),
                     effect: "x5".to_string(),
@@ -511,20 +1080,49 @@ draft: "x0.1"
             labels: None,
             previously_reviewed: None,
             draft: None,
+            approved_by_trusted: None,
+            updated_since_my_review: None,
+            re_review_requested: None,
+            changes_requested: None,
+            reactions: None,
+            comments: None,
+            team_review_requested: None,
+            ci_status: None,
+            commits_behind: None,
+            authored: None,
+            authored_base_score: None,
+            sla: None,
+            disabled_factors: None,
         };
 
         // Query has size with absent buckets (None = inherit)
         let query = ScoringConfig {
             base_score: None,
             age: None,
+            age_basis: None,
             approvals: None,
             size: Some(SizeConfig {
                 exclude: None,
+                metric: None,
+                on_exclude_failure: None,
                 buckets: None,
             }),
             labels: None,
             previously_reviewed: None,
             draft: None,
+            approved_by_trusted: None,
+            updated_since_my_review: None,
+            re_review_requested: None,
+            changes_requested: None,
+            reactions: None,
+            comments: None,
+            team_review_requested: None,
+            ci_status: None,
+            commits_behind: None,
+            authored: None,
+            authored_base_score: None,
+            sla: None,
+            disabled_factors: None,
         };
 
         let result = merge_scoring_configs(&global, Some(&query));
@@ -540,27 +1138,59 @@ draft: "x0.1"
         let global = ScoringConfig {
             base_score: None,
             age: None,
+            age_basis: None,
             approvals: None,
             size: Some(SizeConfig {
                 exclude: Some(vec!["*.lock".to_string()]),
+                metric: None,
+                on_exclude_failure: None,
                 buckets: None,
             }),
             labels: None,
             previously_reviewed: None,
             draft: None,
+            approved_by_trusted: None,
+            updated_since_my_review: None,
+            re_review_requested: None,
+            changes_requested: None,
+            reactions: None,
+            comments: None,
+            team_review_requested: None,
+            ci_status: None,
+            commits_behind: None,
+            authored: None,
+            authored_base_score: None,
+            sla: None,
+            disabled_factors: None,
         };
 
         let query = ScoringConfig {
             base_score: None,
             age: None,
+            age_basis: None,
             approvals: None,
             size: Some(SizeConfig {
                 exclude: Some(vec!["*.json".to_string()]),
+                metric: None,
+                on_exclude_failure: None,
                 buckets: None,
             }),
             labels: None,
             previously_reviewed: None,
             draft: None,
+            approved_by_trusted: None,
+            updated_since_my_review: None,
+            re_review_requested: None,
+            changes_requested: None,
+            reactions: None,
+            comments: None,
+            team_review_requested: None,
+            ci_status: None,
+            commits_behind: None,
+            authored: None,
+            authored_base_score: None,
+            sla: None,
+            disabled_factors: None,
         };
 
         let result = merge_scoring_configs(&global, Some(&query));
@@ -577,11 +1207,25 @@ draft: "x0.1"
         let query = ScoringConfig {
             base_score: None,
             age: None,
+            age_basis: None,
             approvals: None,
             size: None,
             labels: None,
             previously_reviewed: None,
             draft: None,
+            approved_by_trusted: None,
+            updated_since_my_review: None,
+            re_review_requested: None,
+            changes_requested: None,
+            reactions: None,
+            comments: None,
+            team_review_requested: None,
+            ci_status: None,
+            commits_behind: None,
+            authored: None,
+            authored_base_score: None,
+            sla: None,
+            disabled_factors: None,
         };
 
         let result = merge_scoring_configs(&global, Some(&query));
@@ -597,9 +1241,12 @@ draft: "x0.1"
         let global = ScoringConfig {
             base_score: None,
             age: None,
+            age_basis: None,
             approvals: None,
             size: Some(SizeConfig {
                 exclude: None,
+                metric: None,
+                on_exclude_failure: None,
                 buckets: Some(vec![SizeBucket {
                     range: "<100".to_string(),
                     effect: "x5".to_string(),
@@ -608,19 +1255,48 @@ draft: "x0.1"
             labels: None,
             previously_reviewed: None,
             draft: None,
+            approved_by_trusted: None,
+            updated_since_my_review: None,
+            re_review_requested: None,
+            changes_requested: None,
+            reactions: None,
+            comments: None,
+            team_review_requested: None,
+            ci_status: None,
+            commits_behind: None,
+            authored: None,
+            authored_base_score: None,
+            sla: None,
+            disabled_factors: None,
         };
 
         let query = ScoringConfig {
             base_score: None,
             age: None,
+            age_basis: None,
             approvals: None,
             size: Some(SizeConfig {
                 exclude: Some(vec!["*.lock".to_string()]),
+                metric: None,
+                on_exclude_failure: None,
                 buckets: None, // absent = inherit
             }),
             labels: None,
             previously_reviewed: None,
             draft: None,
+            approved_by_trusted: None,
+            updated_since_my_review: None,
+            re_review_requested: None,
+            changes_requested: None,
+            reactions: None,
+            comments: None,
+            team_review_requested: None,
+            ci_status: None,
+            commits_behind: None,
+            authored: None,
+            authored_base_score: None,
+            sla: None,
+            disabled_factors: None,
         };
 
         let result = merge_scoring_configs(&global, Some(&query));
@@ -639,22 +1315,41 @@ draft: "x0.1"
         let global = ScoringConfig {
             base_score: None,
             age: None,
+            age_basis: None,
             approvals: None,
             size: Some(SizeConfig {
                 exclude: Some(vec!["*.lock".to_string()]),
+                metric: None,
+                on_exclude_failure: None,
                 buckets: None,
             }),
             labels: None,
             previously_reviewed: None,
             draft: None,
+            approved_by_trusted: None,
+            updated_since_my_review: None,
+            re_review_requested: None,
+            changes_requested: None,
+            reactions: None,
+            comments: None,
+            team_review_requested: None,
+            ci_status: None,
+            commits_behind: None,
+            authored: None,
+            authored_base_score: None,
+            sla: None,
+            disabled_factors: None,
         };
 
         let query = ScoringConfig {
             base_score: None,
             age: None,
+            age_basis: None,
             approvals: None,
             size: Some(SizeConfig {
                 exclude: None, // absent = inherit
+                metric: None,
+                on_exclude_failure: None,
                 buckets: Some(vec![SizeBucket {
                     range: "<200".to_string(),
                     effect: "x3".to_string(),
@@ -663,6 +1358,19 @@ draft: "x0.1"
             labels: None,
             previously_reviewed: None,
             draft: None,
+            approved_by_trusted: None,
+            updated_since_my_review: None,
+            re_review_requested: None,
+            changes_requested: None,
+            reactions: None,
+            comments: None,
+            team_review_requested: None,
+            ci_status: None,
+            commits_behind: None,
+            authored: None,
+            authored_base_score: None,
+            sla: None,
+            disabled_factors: None,
         };
 
         let result = merge_scoring_configs(&global, Some(&query));
@@ -682,27 +1390,57 @@ draft: "x0.1"
         let global = ScoringConfig {
             base_score: None,
             age: None,
+            age_basis: None,
             approvals: None,
             size: None,
             labels: Some(vec![LabelEffect {
                 name: "foo".to_string(),
                 effect: "x3".to_string(),
+                set_base: None,
             }]),
             previously_reviewed: None,
             draft: None,
+            approved_by_trusted: None,
+            updated_since_my_review: None,
+            re_review_requested: None,
+            changes_requested: None,
+            reactions: None,
+            comments: None,
+            team_review_requested: None,
+            ci_status: None,
+            commits_behind: None,
+            authored: None,
+            authored_base_score: None,
+            sla: None,
+            disabled_factors: None,
         };
 
         let query = ScoringConfig {
             base_score: None,
             age: None,
+            age_basis: None,
             approvals: None,
             size: None,
             labels: Some(vec![LabelEffect {
                 name: "foo".to_string(),
                 effect: "x2".to_string(),
+                set_base: None,
             }]),
             previously_reviewed: None,
             draft: None,
+            approved_by_trusted: None,
+            updated_since_my_review: None,
+            re_review_requested: None,
+            changes_requested: None,
+            reactions: None,
+            comments: None,
+            team_review_requested: None,
+            ci_status: None,
+            commits_behind: None,
+            authored: None,
+            authored_base_score: None,
+            sla: None,
+            disabled_factors: None,
         };
 
         let result = merge_scoring_configs(&global, Some(&query));
@@ -717,43 +1455,120 @@ draft: "x0.1"
         let global = ScoringConfig {
             base_score: None,
             age: None,
+            age_basis: None,
             approvals: None,
             size: None,
             labels: Some(vec![
                 LabelEffect {
                     name: "foo".to_string(),
                     effect: "+5".to_string(),
+                    set_base: None,
                 },
                 LabelEffect {
                     name: "bar".to_string(),
                     effect: "+10".to_string(),
+                    set_base: None,
                 },
             ]),
             previously_reviewed: None,
             draft: None,
+            approved_by_trusted: None,
+            updated_since_my_review: None,
+            re_review_requested: None,
+            changes_requested: None,
+            reactions: None,
+            comments: None,
+            team_review_requested: None,
+            ci_status: None,
+            commits_behind: None,
+            authored: None,
+            authored_base_score: None,
+            sla: None,
+            disabled_factors: None,
         };
 
         let query = ScoringConfig {
             base_score: None,
             age: None,
+            age_basis: None,
             approvals: None,
             size: None,
             labels: Some(vec![LabelEffect {
                 name: "foo".to_string(),
                 effect: "+20".to_string(),
+                set_base: None,
             }]),
             previously_reviewed: None,
             draft: None,
+            approved_by_trusted: None,
+            updated_since_my_review: None,
+            re_review_requested: None,
+            changes_requested: None,
+            reactions: None,
+            comments: None,
+            team_review_requested: None,
+            ci_status: None,
+            commits_behind: None,
+            authored: None,
+            authored_base_score: None,
+            sla: None,
+            disabled_factors: None,
         };
 
         let result = merge_scoring_configs(&global, Some(&query));
         let labels = result.labels.unwrap();
         assert_eq!(labels.len(), 2);
-        // Use find to avoid order dependence (HashMap)
-        let foo = labels.iter().find(|l| l.name == "foo").unwrap();
-        assert_eq!(foo.effect, "+20"); // from query
-        let bar = labels.iter().find(|l| l.name == "bar").unwrap();
-        assert_eq!(bar.effect, "+10"); // preserved from global
+        // Global order is kept, with the query's foo in foo's place
+        assert_eq!(labels[0].name, "foo");
+        assert_eq!(labels[0].effect, "+20"); // from query
+        assert_eq!(labels[1].name, "bar");
+        assert_eq!(labels[1].effect, "+10"); // preserved from global
+    }
+
+    #[test]
+    fn test_merge_labels_keeps_first_set_base_deterministic() {
+        let global: ScoringConfig = serde_saphyr::from_str(
+            r#"
+labels:
+  - { name: hotfix, set_base: 1000 }
+  - { name: security, set_base: 500 }
+  - { name: docs, effect: "x0.5" }
+"#,
+        )
+        .unwrap();
+        let query: ScoringConfig = serde_saphyr::from_str(
+            r#"
+labels:
+  - { name: Security, set_base: 800 }
+  - { name: urgent, set_base: 900 }
+"#,
+        )
+        .unwrap();
+
+        let labels = merge_scoring_configs(&global, Some(&query)).labels.unwrap();
+        let order: Vec<(&str, Option<f64>)> = labels
+            .iter()
+            .map(|l| (l.name.as_str(), l.set_base))
+            .collect();
+        assert_eq!(
+            order,
+            vec![
+                ("hotfix", Some(1000.0)),
+                ("Security", Some(800.0)),
+                ("docs", None),
+                ("urgent", Some(900.0)),
+            ]
+        );
+
+        // A PR with both hotfix and urgent always gets hotfix's base
+        let pr = crate::github::types::PullRequest {
+            labels: vec!["urgent".to_string(), "hotfix".to_string()],
+            ..crate::github::types::PullRequest::test_default()
+        };
+        let merged = merge_scoring_configs(&global, Some(&query));
+        let result = crate::scoring::calculate_score(&pr, &merged);
+        assert_eq!(result.breakdown.factors[0].label, "Label: hotfix");
+        assert_eq!(result.breakdown.factors[0].after, 1000.0);
     }
 
     #[test]
@@ -761,27 +1576,57 @@ draft: "x0.1"
         let global = ScoringConfig {
             base_score: None,
             age: None,
+            age_basis: None,
             approvals: None,
             size: None,
             labels: Some(vec![LabelEffect {
                 name: "Urgent".to_string(),
                 effect: "+10".to_string(),
+                set_base: None,
             }]),
             previously_reviewed: None,
             draft: None,
+            approved_by_trusted: None,
+            updated_since_my_review: None,
+            re_review_requested: None,
+            changes_requested: None,
+            reactions: None,
+            comments: None,
+            team_review_requested: None,
+            ci_status: None,
+            commits_behind: None,
+            authored: None,
+            authored_base_score: None,
+            sla: None,
+            disabled_factors: None,
         };
 
         let query = ScoringConfig {
             base_score: None,
             age: None,
+            age_basis: None,
             approvals: None,
             size: None,
             labels: Some(vec![LabelEffect {
                 name: "urgent".to_string(),
                 effect: "+20".to_string(),
+                set_base: None,
             }]),
             previously_reviewed: None,
             draft: None,
+            approved_by_trusted: None,
+            updated_since_my_review: None,
+            re_review_requested: None,
+            changes_requested: None,
+            reactions: None,
+            comments: None,
+            team_review_requested: None,
+            ci_status: None,
+            commits_behind: None,
+            authored: None,
+            authored_base_score: None,
+            sla: None,
+            disabled_factors: None,
         };
 
         let result = merge_scoring_configs(&global, Some(&query));
@@ -797,24 +1642,53 @@ draft: "x0.1"
         let global = ScoringConfig {
             base_score: None,
             age: None,
+            age_basis: None,
             approvals: None,
             size: None,
             labels: None,
             previously_reviewed: None,
             draft: None,
+            approved_by_trusted: None,
+            updated_since_my_review: None,
+            re_review_requested: None,
+            changes_requested: None,
+            reactions: None,
+            comments: None,
+            team_review_requested: None,
+            ci_status: None,
+            commits_behind: None,
+            authored: None,
+            authored_base_score: None,
+            sla: None,
+            disabled_factors: None,
         };
 
         let query = ScoringConfig {
             base_score: None,
             age: None,
+            age_basis: None,
             approvals: None,
             size: None,
             labels: Some(vec![LabelEffect {
                 name: "foo".to_string(),
                 effect: "+5".to_string(),
+                set_base: None,
             }]),
             previously_reviewed: None,
             draft: None,
+            approved_by_trusted: None,
+            updated_since_my_review: None,
+            re_review_requested: None,
+            changes_requested: None,
+            reactions: None,
+            comments: None,
+            team_review_requested: None,
+            ci_status: None,
+            commits_behind: None,
+            authored: None,
+            authored_base_score: None,
+            sla: None,
+            disabled_factors: None,
         };
 
         let result = merge_scoring_configs(&global, Some(&query));
@@ -828,24 +1702,53 @@ draft: "x0.1"
         let global = ScoringConfig {
             base_score: None,
             age: None,
+            age_basis: None,
             approvals: None,
             size: None,
             labels: Some(vec![LabelEffect {
                 name: "bar".to_string(),
                 effect: "+10".to_string(),
+                set_base: None,
             }]),
             previously_reviewed: None,
             draft: None,
+            approved_by_trusted: None,
+            updated_since_my_review: None,
+            re_review_requested: None,
+            changes_requested: None,
+            reactions: None,
+            comments: None,
+            team_review_requested: None,
+            ci_status: None,
+            commits_behind: None,
+            authored: None,
+            authored_base_score: None,
+            sla: None,
+            disabled_factors: None,
         };
 
         let query = ScoringConfig {
             base_score: None,
             age: None,
+            age_basis: None,
             approvals: None,
             size: None,
             labels: None,
             previously_reviewed: None,
             draft: None,
+            approved_by_trusted: None,
+            updated_since_my_review: None,
+            re_review_requested: None,
+            changes_requested: None,
+            reactions: None,
+            comments: None,
+            team_review_requested: None,
+            ci_status: None,
+            commits_behind: None,
+            authored: None,
+            authored_base_score: None,
+            sla: None,
+            disabled_factors: None,
         };
 
         let result = merge_scoring_configs(&global, Some(&query));
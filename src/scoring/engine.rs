@@ -1,6 +1,11 @@
-use super::config::ScoringConfig;
+use super::config::{
+    AgeBasis, ApprovalsConfig, ApprovalsCurve, ExcludeFailure, ScoringConfig, SizeMetric,
+};
 use super::factors::Effect;
+use super::sla::business_time_between;
 use crate::github::types::PullRequest;
+use crate::output::formatter::format_age;
+use chrono::Local;
 
 #[derive(Debug, Clone)]
 pub struct FactorContribution {
@@ -14,35 +19,116 @@ pub struct FactorContribution {
 pub struct ScoreBreakdown {
     pub base_score: f64,
     pub factors: Vec<FactorContribution>,
+    /// Configured factors skipped via `disabled_factors`, by config key
+    pub disabled: Vec<String>,
 }
 
 #[derive(Debug, Clone)]
 pub struct ScoreResult {
     pub score: f64,
     pub incomplete: bool,
+    /// Waiting longer than the configured review SLA
+    pub sla_breached: bool,
+    /// The oldest PR awaiting your review, past `oldest_alert` (set after scoring)
+    pub oldest_alert: bool,
     pub breakdown: ScoreBreakdown,
 }
 
+impl ScoreResult {
+    /// Scale the final score by the query's `weight`, recorded as the last
+    /// factor. A weight of 1 (or none) leaves the result untouched.
+    pub fn apply_weight(&mut self, weight: Option<f64>) {
+        let Some(weight) = weight.filter(|&w| w != 1.0) else {
+            return;
+        };
+        let before = self.score;
+        self.score *= weight;
+        self.breakdown.factors.push(FactorContribution {
+            label: "Query Weight".to_string(),
+            description: format!("x{} from the query's weight", weight),
+            before,
+            after: self.score,
+        });
+    }
+}
+
+/// Score per changed line, for picking high-value, low-effort PRs. Empty PRs
+/// (and issues) count as one line, so this never divides by zero.
+pub fn efficiency(score: f64, size: u64) -> f64 {
+    score / size.max(1) as f64
+}
+
 pub fn calculate_score(pr: &PullRequest, config: &ScoringConfig) -> ScoreResult {
-    let base_score = config.base_score.unwrap_or(100.0);
+    // Disabled factors keep their settings but are left out entirely
+    let (config, disabled) = config.without_disabled();
+    let config = config.as_ref();
+
+    // Your own PRs can start from a separate base (e.g. for `author:@me` queries)
+    let base_score = config
+        .authored_base_score
+        .filter(|_| pr.is_author)
+        .or(config.base_score)
+        .unwrap_or(100.0);
     let mut score = base_score;
-    let incomplete = false;
+    let mut incomplete = false;
     let mut factors = Vec::new();
 
+    // Label base overrides replace the base before any other factor applies,
+    // so later multipliers scale the overridden base. First match wins.
+    if let Some(ref label_configs) = config.labels {
+        if let Some((label_config, new_base)) = label_configs.iter().find_map(|lc| {
+            lc.set_base
+                .filter(|_| pr.labels.iter().any(|l| l.eq_ignore_ascii_case(&lc.name)))
+                .map(|b| (lc, b))
+        }) {
+            let before = score;
+            score = new_base;
+            factors.push(FactorContribution {
+                label: format!("Label: {}", label_config.name),
+                description: format!(
+                    "matched label '{}' -> base set to {}",
+                    label_config.name, new_base
+                ),
+                before,
+                after: score,
+            });
+        }
+    }
+
     // Apply age factor (always available - created_at always present)
     if let Some(ref age_str) = config.age {
         if let Ok(effect) = Effect::parse(age_str) {
             let before = score;
-            let age = pr.age();
+            let from_review_request = config.age_basis == Some(AgeBasis::ReviewRequested)
+                && pr.review_requested_at.is_some();
+            let age = if from_review_request {
+                pr.review_age()
+            } else {
+                pr.age()
+            };
             let units = calculate_units(&effect, age);
             score = effect.apply(score, units);
 
             // Build description for age factor
             let description = match &effect {
                 Effect::AddPerUnit(n, _) => format!("{:+} per unit ({} units)", n, units),
-                Effect::MultiplyPerUnit(n, _) => format!("x{} per unit ({} units)", n, units),
+                Effect::MultiplyPerUnit(n, _, None) => {
+                    format!("x{} per unit ({} units)", n, units)
+                }
+                Effect::MultiplyPerUnit(n, _, Some(cap)) => {
+                    format!("x{} per unit ({} units, cap x{})", n, units, cap)
+                }
+                Effect::SubtractPerUnit(n, _) => format!("-{} per unit ({} units)", n, units),
+                Effect::DividePerUnit(n, _) => format!("/{} per unit ({} units)", n, units),
                 Effect::Add(n) => format!("{:+}", n),
+                Effect::Subtract(n) => format!("-{}", n),
                 Effect::Multiply(n) => format!("x{}", n),
+                Effect::Divide(n) => format!("/{}", n),
+            };
+            let description = if from_review_request {
+                format!("{} since review requested", description)
+            } else {
+                description
             };
 
             factors.push(FactorContribution {
@@ -54,55 +140,158 @@ pub fn calculate_score(pr: &PullRequest, config: &ScoringConfig) -> ScoreResult
         }
     }
 
-    // Apply approvals factor
-    if let Some(ref approvals_str) = config.approvals {
-        // For approvals, "per N" means "per N approvals", not per time unit
-        // Convert formats like "+10 per 1" or "x2 per 1" to use a dummy time unit for parsing
-        // The time unit is ignored; we use approval count as units instead
-        let parseable_str = if let Some((effect_part, per_part)) = approvals_str.split_once(" per ")
-        {
-            // Check if per_part is just a number (no time unit)
-            if per_part.trim().chars().all(|c| c.is_numeric() || c == '.') {
-                format!("{} per 1sec", effect_part)
-            } else {
-                approvals_str.clone()
-            }
-        } else {
-            approvals_str.clone()
-        };
-
-        if let Ok(effect) = Effect::parse(&parseable_str) {
-            let before = score;
-            let units = pr.approvals as u64;
-            score = effect.apply(score, units);
+    // Issues have no reviews or diff, so PR-only factors skip them. The
+    // review-state factors below are all false for issues and skip on their own.
+    let is_pr = !pr.is_issue;
 
-            let description = format!("{} approvals, effect: {}", pr.approvals, approvals_str);
+    // Apply approvals factor
+    if let Some(ApprovalsConfig::Curve(curve)) = config.approvals.as_ref().filter(|_| is_pr) {
+        if let Some(after) = apply_approvals_curve(score, pr.approvals, curve) {
+            let description = format!(
+                "{} approvals, peak {}: boost {}, decay {}",
+                pr.approvals, curve.peak, curve.boost, curve.decay
+            );
             factors.push(FactorContribution {
                 label: "Approvals".to_string(),
                 description,
-                before,
-                after: score,
+                before: score,
+                after,
+            });
+            score = after;
+        }
+    }
+    if let Some(ApprovalsConfig::Effect(approvals_str)) =
+        config.approvals.as_ref().filter(|_| is_pr)
+    {
+        // For approvals, "per N" means "per N approvals", not per time unit.
+        // Units are whole groups of N approvals (integer division), so
+        // "+5 per 2" with 5 approvals applies twice.
+        if let Some(after) = apply_count_factor(score, approvals_str, pr.approvals as u64) {
+            factors.push(FactorContribution {
+                label: "Approvals".to_string(),
+                description: format!("{} approvals, effect: {}", pr.approvals, approvals_str),
+                before: score,
+                after,
             });
+            score = after;
         }
     }
 
-    // Apply size factor
-    if let Some(ref size_config) = config.size {
-        if let Some(ref buckets) = size_config.buckets {
-            let size = pr.size();
-            let before = score;
-            let result = apply_bucket_effect(score, size, buckets, |b| &b.range, |b| &b.effect);
-            score = result.score;
+    // Apply changes_requested factor (only when someone else has requested changes)
+    if let Some(changes_str) = config.changes_requested.as_deref() {
+        let count = pr.changes_requested_count as u64;
+        if let Some(after) = apply_count_factor(score, changes_str, count) {
+            factors.push(FactorContribution {
+                label: "Changes Requested".to_string(),
+                description: format!("{} change requests, effect: {}", count, changes_str),
+                before: score,
+                after,
+            });
+            score = after;
+        }
+    }
+
+    // Apply reactions factor (only when the PR has reactions; issues count too)
+    if let Some(reactions_str) = config.reactions.as_deref() {
+        if let Some(after) = apply_count_factor(score, reactions_str, pr.reactions as u64) {
+            factors.push(FactorContribution {
+                label: "Reactions".to_string(),
+                description: format!("{} reactions, effect: {}", pr.reactions, reactions_str),
+                before: score,
+                after,
+            });
+            score = after;
+        }
+    }
 
-            // Only add contribution if a bucket matched
-            if let (Some(range), Some(effect)) = (result.matched_range, result.matched_effect) {
-                let description = format!("{} lines, matched '{}' -> {}", size, range, effect);
+    // Apply comments factor (only when the PR has comments; issues count too)
+    if let Some(comments_str) = config.comments.as_deref() {
+        if let Some(after) = apply_count_factor(score, comments_str, pr.comments as u64) {
+            factors.push(FactorContribution {
+                label: "Comments".to_string(),
+                description: format!("{} comments, effect: {}", pr.comments, comments_str),
+                before: score,
+                after,
+            });
+            score = after;
+        }
+    }
+
+    // Apply commits_behind factor (unknown when the compare failed, e.g. the
+    // base branch was deleted; then it has no effect)
+    if let Some(behind_str) = config.commits_behind.as_deref() {
+        if let Some(behind) = pr.commits_behind {
+            if let Some(after) = apply_count_factor(score, behind_str, behind as u64) {
                 factors.push(FactorContribution {
-                    label: "Size".to_string(),
-                    description,
-                    before,
-                    after: score,
+                    label: "Commits Behind".to_string(),
+                    description: format!("{} commits behind base, effect: {}", behind, behind_str),
+                    before: score,
+                    after,
                 });
+                score = after;
+            }
+        }
+    }
+
+    // Apply size factor
+    if let Some(size_config) = config.size.as_ref().filter(|_| is_pr) {
+        if let Some(ref buckets) = size_config.buckets {
+            let metric = size_config.metric.unwrap_or_default();
+            // Exclusions configured but not applied: line and file counts
+            // are unfiltered. (Hunks are only counted from the file list.)
+            let exclude_failure = (metric != SizeMetric::Hunks
+                && size_config.exclude.as_ref().is_some_and(|p| !p.is_empty())
+                && pr.filtered_size.is_none())
+            .then(|| size_config.on_exclude_failure.unwrap_or_default())
+            .filter(|&on_failure| on_failure != ExcludeFailure::Aggregate);
+            let size = match metric {
+                _ if exclude_failure.is_some() => None,
+                SizeMetric::Lines => Some(pr.size()),
+                SizeMetric::Files => pr.changed_files,
+                SizeMetric::Hunks => pr.hunks,
+            };
+
+            // File and hunk counts come from enrichment; without them the size
+            // factor is skipped and the score is marked incomplete
+            match size {
+                Some(size) => {
+                    let before = score;
+                    let result =
+                        apply_bucket_effect(score, size, buckets, |b| &b.range, |b| &b.effect);
+                    score = result.score;
+
+                    // Only add contribution if a bucket matched
+                    if let (Some(range), Some(effect)) =
+                        (result.matched_range, result.matched_effect)
+                    {
+                        let description = format!(
+                            "{} {}, matched '{}' -> {}",
+                            size,
+                            metric.unit(),
+                            range,
+                            effect
+                        );
+                        factors.push(FactorContribution {
+                            label: "Size".to_string(),
+                            description,
+                            before,
+                            after: score,
+                        });
+                    }
+                }
+                None => match exclude_failure {
+                    Some(on_failure) => {
+                        incomplete |= on_failure == ExcludeFailure::Incomplete;
+                        factors.push(FactorContribution {
+                            label: "Size".to_string(),
+                            description: "skipped, exclusions not applied (no file list)"
+                                .to_string(),
+                            before: score,
+                            after: score,
+                        });
+                    }
+                    None => incomplete = true,
+                },
             }
         }
     }
@@ -110,6 +299,9 @@ pub fn calculate_score(pr: &PullRequest, config: &ScoringConfig) -> ScoreResult
     // Apply label factors (multiple matching labels compound)
     if let Some(ref label_configs) = config.labels {
         for label_config in label_configs {
+            if label_config.effect.trim().is_empty() {
+                continue; // Base-override-only label, handled above
+            }
             if pr
                 .labels
                 .iter()
@@ -164,13 +356,163 @@ pub fn calculate_score(pr: &PullRequest, config: &ScoringConfig) -> ScoreResult
         }
     }
 
+    // Apply approved_by_trusted factor
+    if let Some(ref trusted_effect_str) = config.approved_by_trusted {
+        if pr.approved_by_trusted {
+            if let Ok(effect) = Effect::parse(trusted_effect_str) {
+                let before = score;
+                score = effect.apply(score, 1);
+                factors.push(FactorContribution {
+                    label: "Trusted Approval".to_string(),
+                    description: format!(
+                        "Approved by a trusted reviewer -> {}",
+                        trusted_effect_str
+                    ),
+                    before,
+                    after: score,
+                });
+            }
+        }
+    }
+
+    // Apply team_review_requested factor
+    if let Some(ref team_effect_str) = config.team_review_requested {
+        if pr.team_review_requested {
+            if let Ok(effect) = Effect::parse(team_effect_str) {
+                let before = score;
+                score = effect.apply(score, 1);
+                factors.push(FactorContribution {
+                    label: "Team Review Requested".to_string(),
+                    description: format!("Review requested from your team -> {}", team_effect_str),
+                    before,
+                    after: score,
+                });
+            }
+        }
+    }
+
+    // Apply ci_status factor (skipped when the CI result is unknown)
+    if let Some(ref ci_config) = config.ci_status {
+        if let Some(status) = pr.ci_status {
+            if let Some(effect_str) = ci_config.effect_for(status) {
+                if let Ok(effect) = Effect::parse(effect_str) {
+                    let before = score;
+                    score = effect.apply(score, 1);
+                    factors.push(FactorContribution {
+                        label: "CI".to_string(),
+                        description: format!("CI {} -> {}", status.as_str(), effect_str),
+                        before,
+                        after: score,
+                    });
+                }
+            }
+        }
+    }
+
+    // Apply updated_since_my_review factor
+    if let Some(ref updated_effect_str) = config.updated_since_my_review {
+        if pr.updated_since_my_review {
+            if let Ok(effect) = Effect::parse(updated_effect_str) {
+                let before = score;
+                score = effect.apply(score, 1);
+                let reviewed = pr
+                    .my_last_review_at
+                    .map(|t| t.format("%Y-%m-%d %H:%M UTC").to_string())
+                    .unwrap_or_else(|| "unknown".to_string());
+                factors.push(FactorContribution {
+                    label: "Updated Since My Review".to_string(),
+                    description: format!(
+                        "Pushed to since you requested changes ({}) -> {}",
+                        reviewed, updated_effect_str
+                    ),
+                    before,
+                    after: score,
+                });
+            }
+        }
+    }
+
+    // Apply re_review_requested factor (only known with GraphQL enrichment)
+    if let Some(ref re_review_str) = config.re_review_requested {
+        if pr.re_review_requested {
+            if let Ok(effect) = Effect::parse(re_review_str) {
+                let before = score;
+                score = effect.apply(score, 1);
+                factors.push(FactorContribution {
+                    label: "Re-review Requested".to_string(),
+                    description: format!("Your review was requested again -> {}", re_review_str),
+                    before,
+                    after: score,
+                });
+            }
+        }
+    }
+
+    // Apply authored factor
+    if let Some(ref authored_str) = config.authored {
+        if pr.is_author {
+            if let Ok(effect) = Effect::parse(authored_str) {
+                let before = score;
+                score = effect.apply(score, 1);
+                factors.push(FactorContribution {
+                    label: "Authored".to_string(),
+                    description: format!("You opened this PR -> {}", authored_str),
+                    before,
+                    after: score,
+                });
+            }
+        }
+    }
+
+    // Apply review SLA (waiting time is measured like the age factor)
+    let mut sla_breached = false;
+    if let Some(ref sla) = config.sla {
+        if let Ok(within) = humantime::parse_duration(&sla.within) {
+            let since = if config.age_basis == Some(AgeBasis::ReviewRequested) {
+                pr.review_requested_at.unwrap_or(pr.created_at)
+            } else {
+                pr.created_at
+            };
+            let now = chrono::Utc::now();
+            let waited = if sla.business_days {
+                business_time_between(&since.with_timezone(&Local), &now.with_timezone(&Local))
+            } else {
+                now - since
+            };
+            sla_breached = chrono::Duration::from_std(within).is_ok_and(|within| waited > within);
+
+            if let Some(effect_str) = sla.effect.as_ref().filter(|_| sla_breached) {
+                if let Ok(effect) = Effect::parse(effect_str) {
+                    let before = score;
+                    score = effect.apply(score, 1);
+                    let kind = if sla.business_days { " business" } else { "" };
+                    factors.push(FactorContribution {
+                        label: "SLA Breached".to_string(),
+                        description: format!(
+                            "Waiting {}{} time, over the {} SLA -> {}",
+                            format_age(waited),
+                            kind,
+                            sla.within,
+                            effect_str
+                        ),
+                        before,
+                        after: score,
+                    });
+                }
+            }
+        }
+    }
+
     // Floor at zero
     ScoreResult {
         score: score.max(0.0),
         incomplete,
+        sla_breached,
+        oldest_alert: false,
         breakdown: ScoreBreakdown {
             base_score,
             factors,
+            disabled,
         },
     }
 }
@@ -179,16 +521,46 @@ fn calculate_units(effect: &Effect, age: chrono::Duration) -> u64 {
     if let Some(unit_duration) = effect.unit_duration() {
         let age_secs = age.num_seconds().max(0) as u64;
         let unit_secs = unit_duration.as_secs();
-        if unit_secs > 0 {
-            age_secs / unit_secs
-        } else {
-            0
-        }
+        age_secs.checked_div(unit_secs).unwrap_or(0)
     } else {
         1 // Non-per-unit effects apply once
     }
 }
 
+/// Score after an approvals curve: `boost` over the first `peak` approvals,
+/// then `decay` over each approval past it. None when an effect doesn't parse.
+fn apply_approvals_curve(score: f64, approvals: u32, curve: &ApprovalsCurve) -> Option<f64> {
+    let (boost, boost_per) = Effect::parse_per_count(&curve.boost).ok()?;
+    let (decay, decay_per) = Effect::parse_per_count(&curve.decay).ok()?;
+    let boosted = approvals.min(curve.peak) as u64;
+    let past_peak = approvals.saturating_sub(curve.peak) as u64;
+    let score = apply_count_effect(score, &boost, boost_per, boosted);
+    Some(apply_count_effect(score, &decay, decay_per, past_peak))
+}
+
+/// Score after a count factor (approvals, change requests, reactions,
+/// comments, commits behind). None when the count is zero, so the factor is
+/// skipped, or when the effect doesn't parse.
+fn apply_count_factor(score: f64, effect_str: &str, count: u64) -> Option<f64> {
+    if count == 0 {
+        return None;
+    }
+    let (effect, per) = Effect::parse_per_count(effect_str).ok()?;
+    Some(apply_count_effect(score, &effect, per, count))
+}
+
+/// Apply a count-based effect: per-unit effects once per whole `per` items,
+/// flat effects once when there is at least one item
+fn apply_count_effect(score: f64, effect: &Effect, per: u64, count: u64) -> f64 {
+    if effect.unit_duration().is_some() {
+        effect.apply(score, count / per)
+    } else if count > 0 {
+        effect.apply(score, 1)
+    } else {
+        score
+    }
+}
+
 struct BucketResult {
     score: f64,
     matched_range: Option<String>,
@@ -233,25 +605,18 @@ where
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::scoring::{LabelEffect, SizeBucket, SizeConfig};
+    use crate::github::types::CiStatus;
+    use crate::scoring::{CiStatusConfig, LabelEffect, SizeBucket, SizeConfig, SlaConfig};
     use chrono::{Duration as ChronoDuration, Utc};
 
     fn sample_pr(age_hours: i64, approvals: u32, size: u64) -> PullRequest {
         PullRequest {
-            title: "Test PR".to_string(),
-            number: 1,
             author: "user".to_string(),
-            repo: "owner/repo".to_string(),
-            url: "https://github.com/owner/repo/pull/1".to_string(),
             created_at: Utc::now() - ChronoDuration::hours(age_hours),
-            updated_at: Utc::now(),
             additions: size / 2,
             deletions: size / 2,
             approvals,
-            draft: false,
-            labels: vec![],
-            user_has_reviewed: false,
-            filtered_size: None,
+            ..PullRequest::test_default()
         }
     }
 
@@ -263,17 +628,49 @@ mod tests {
             &ScoringConfig {
                 base_score: Some(100.0),
                 age: None,
+                age_basis: None,
                 approvals: None,
                 size: None,
                 labels: None,
                 previously_reviewed: None,
                 draft: None,
+                approved_by_trusted: None,
+                updated_since_my_review: None,
+                re_review_requested: None,
+                changes_requested: None,
+                reactions: None,
+                comments: None,
+                team_review_requested: None,
+                ci_status: None,
+                commits_behind: None,
+                authored: None,
+                authored_base_score: None,
+                sla: None,
+                disabled_factors: None,
             },
         );
         assert_eq!(result.score, 100.0);
         assert!(!result.incomplete);
     }
 
+    #[test]
+    fn test_query_weight_scales_final_score() {
+        let mut result = calculate_score(&sample_pr(1, 0, 100), &ScoringConfig::default());
+        let unweighted = result.clone();
+        result.apply_weight(Some(1.0));
+        assert_eq!(result.score, unweighted.score);
+        assert_eq!(
+            result.breakdown.factors.len(),
+            unweighted.breakdown.factors.len()
+        );
+
+        result.apply_weight(Some(1.5));
+        assert_eq!(result.score, unweighted.score * 1.5);
+        let factor = result.breakdown.factors.last().unwrap();
+        assert_eq!(factor.label, "Query Weight");
+        assert_eq!(factor.before, unweighted.score);
+    }
+
     #[test]
     fn test_age_factor_additive() {
         let pr = sample_pr(5, 0, 100);
@@ -282,16 +679,70 @@ mod tests {
             &ScoringConfig {
                 base_score: Some(100.0),
                 age: Some("+1 per 1h".to_string()),
+                age_basis: None,
                 approvals: None,
                 size: None,
                 labels: None,
                 previously_reviewed: None,
                 draft: None,
+                approved_by_trusted: None,
+                updated_since_my_review: None,
+                re_review_requested: None,
+                changes_requested: None,
+                reactions: None,
+                comments: None,
+                team_review_requested: None,
+                ci_status: None,
+                commits_behind: None,
+                authored: None,
+                authored_base_score: None,
+                sla: None,
+                disabled_factors: None,
             },
         );
         assert_eq!(result.score, 105.0); // 100 + 5*1
     }
 
+    #[test]
+    fn test_age_basis_review_requested() {
+        let config = ScoringConfig {
+            base_score: Some(100.0),
+            age: Some("+1 per 1h".to_string()),
+            age_basis: Some(AgeBasis::ReviewRequested),
+            approvals: None,
+            size: None,
+            labels: None,
+            previously_reviewed: None,
+            draft: None,
+            approved_by_trusted: None,
+            updated_since_my_review: None,
+            re_review_requested: None,
+            changes_requested: None,
+            reactions: None,
+            comments: None,
+            team_review_requested: None,
+            ci_status: None,
+            commits_behind: None,
+            authored: None,
+            authored_base_score: None,
+            sla: None,
+            disabled_factors: None,
+        };
+
+        // Opened 10h ago, review requested 2h ago
+        let mut pr = sample_pr(10, 0, 100);
+        pr.review_requested_at = Some(Utc::now() - ChronoDuration::hours(2));
+        let result = calculate_score(&pr, &config);
+        assert_eq!(result.score, 102.0);
+        assert!(result.breakdown.factors[0]
+            .description
+            .ends_with("since review requested"));
+
+        // No request event known: falls back to created_at
+        pr.review_requested_at = None;
+        assert_eq!(calculate_score(&pr, &config).score, 110.0);
+    }
+
     #[test]
     fn test_score_floors_at_zero() {
         let pr = sample_pr(1, 0, 100);
@@ -300,11 +751,25 @@ mod tests {
             &ScoringConfig {
                 base_score: Some(10.0),
                 age: Some("+-20 per 1h".to_string()), // Would go negative
+                age_basis: None,
                 approvals: None,
                 size: None,
                 labels: None,
                 previously_reviewed: None,
                 draft: None,
+                approved_by_trusted: None,
+                updated_since_my_review: None,
+                re_review_requested: None,
+                changes_requested: None,
+                reactions: None,
+                comments: None,
+                team_review_requested: None,
+                ci_status: None,
+                commits_behind: None,
+                authored: None,
+                authored_base_score: None,
+                sla: None,
+                disabled_factors: None,
             },
         );
         assert_eq!(result.score, 0.0);
@@ -312,20 +777,120 @@ mod tests {
 
     #[test]
     fn test_approvals_flat_effect() {
-        let pr = sample_pr(1, 0, 100);
-        let result = calculate_score(
-            &pr,
-            &ScoringConfig {
-                base_score: Some(100.0),
-                age: None,
-                approvals: Some("x0.5".to_string()),
-                size: None,
-                labels: None,
-                previously_reviewed: None,
-                draft: None,
-            },
-        );
+        // A flat effect applies once there is an approval, like the other
+        // count factors, and is skipped (no breakdown entry) at zero
+        let config = approvals_only("x0.5");
+        let result = calculate_score(&sample_pr(1, 1, 100), &config);
         assert_eq!(result.score, 50.0);
+
+        let result = calculate_score(&sample_pr(1, 0, 100), &config);
+        assert_eq!(result.score, 100.0);
+        assert!(result.breakdown.factors.is_empty());
+    }
+
+    fn approvals_only(approvals: &str) -> ScoringConfig {
+        ScoringConfig {
+            base_score: Some(100.0),
+            age: None,
+            age_basis: None,
+            approvals: Some(ApprovalsConfig::Effect(approvals.to_string())),
+            size: None,
+            labels: None,
+            previously_reviewed: None,
+            draft: None,
+            approved_by_trusted: None,
+            updated_since_my_review: None,
+            re_review_requested: None,
+            changes_requested: None,
+            reactions: None,
+            comments: None,
+            team_review_requested: None,
+            ci_status: None,
+            commits_behind: None,
+            authored: None,
+            authored_base_score: None,
+            sla: None,
+            disabled_factors: None,
+        }
+    }
+
+    fn approvals_curve(peak: u32, boost: &str, decay: &str) -> ScoringConfig {
+        ScoringConfig {
+            approvals: Some(ApprovalsConfig::Curve(ApprovalsCurve {
+                peak,
+                boost: boost.to_string(),
+                decay: decay.to_string(),
+            })),
+            ..approvals_only("x1")
+        }
+    }
+
+    #[test]
+    fn test_approvals_curve_boosts_then_decays() {
+        let config = approvals_curve(2, "x2", "x0.5 per 1");
+        let scores: Vec<f64> = (0..=4)
+            .map(|approvals| calculate_score(&sample_pr(1, approvals, 100), &config).score)
+            .collect();
+        // None yet, boosted at 1 and at the peak, then halved per approval past it
+        assert_eq!(scores, vec![100.0, 200.0, 200.0, 100.0, 50.0]);
+    }
+
+    #[test]
+    fn test_approvals_curve_per_count_boost() {
+        // +10 per approval up to 3, then -15 per 2 past it
+        let config = approvals_curve(3, "+10 per 1", "-15 per 2");
+        let score = |approvals| calculate_score(&sample_pr(1, approvals, 100), &config).score;
+        assert_eq!(score(2), 120.0);
+        assert_eq!(score(3), 130.0);
+        assert_eq!(score(4), 130.0); // 1 past the peak: no whole group of 2
+        assert_eq!(score(7), 100.0);
+
+        let result = calculate_score(&sample_pr(1, 4, 100), &config);
+        let factor = &result.breakdown.factors[0];
+        assert_eq!(factor.label, "Approvals");
+        assert_eq!(
+            factor.description,
+            "4 approvals, peak 3: boost +10 per 1, decay -15 per 2"
+        );
+    }
+
+    #[test]
+    fn test_approvals_per_one() {
+        let pr = sample_pr(1, 3, 100);
+        let result = calculate_score(&pr, &approvals_only("+10 per 1"));
+        assert_eq!(result.score, 130.0);
+    }
+
+    #[test]
+    fn test_approvals_per_two() {
+        // 4 approvals / 2 = 2 units -> +10
+        let pr = sample_pr(1, 4, 100);
+        let result = calculate_score(&pr, &approvals_only("+5 per 2"));
+        assert_eq!(result.score, 110.0);
+    }
+
+    #[test]
+    fn test_approvals_per_two_odd_count_rounds_down() {
+        // 3 approvals / 2 = 1 unit (integer division) -> +5
+        let pr = sample_pr(1, 3, 100);
+        let result = calculate_score(&pr, &approvals_only("+5 per 2"));
+        assert_eq!(result.score, 105.0);
+    }
+
+    #[test]
+    fn test_approvals_per_three_below_threshold() {
+        // 2 approvals / 3 = 0 units -> no change
+        let pr = sample_pr(1, 2, 100);
+        let result = calculate_score(&pr, &approvals_only("x2 per 3"));
+        assert_eq!(result.score, 100.0);
+    }
+
+    #[test]
+    fn test_approvals_per_three_multiplicative() {
+        // 7 approvals / 3 = 2 units -> x2^2
+        let pr = sample_pr(1, 7, 100);
+        let result = calculate_score(&pr, &approvals_only("x2 per 3"));
+        assert_eq!(result.score, 400.0);
     }
 
     #[test]
@@ -336,9 +901,12 @@ mod tests {
             &ScoringConfig {
                 base_score: Some(100.0),
                 age: None,
+                age_basis: None,
                 approvals: None,
                 size: Some(SizeConfig {
                     exclude: None,
+                    metric: None,
+                    on_exclude_failure: None,
                     buckets: Some(vec![SizeBucket {
                         range: "<100".to_string(),
                         effect: "x2".to_string(),
@@ -347,6 +915,19 @@ mod tests {
                 labels: None,
                 previously_reviewed: None,
                 draft: None,
+                approved_by_trusted: None,
+                updated_since_my_review: None,
+                re_review_requested: None,
+                changes_requested: None,
+                reactions: None,
+                comments: None,
+                team_review_requested: None,
+                ci_status: None,
+                commits_behind: None,
+                authored: None,
+                authored_base_score: None,
+                sla: None,
+                disabled_factors: None,
             },
         );
         assert_eq!(result.score, 200.0);
@@ -360,9 +941,12 @@ mod tests {
         let config = ScoringConfig {
             base_score: Some(100.0),
             age: Some("+1 per 1h".to_string()), // +24 for age
-            approvals: Some("x1.5 per 1".to_string()), // x1.5 for 1 approval
+            age_basis: None,
+            approvals: Some(ApprovalsConfig::Effect("x1.5 per 1".to_string())), // x1.5 for 1 approval
             size: Some(SizeConfig {
                 exclude: None,
+                metric: None,
+                on_exclude_failure: None,
                 buckets: Some(vec![
                     SizeBucket {
                         range: "<100".to_string(),
@@ -377,6 +961,19 @@ mod tests {
             labels: None,
             previously_reviewed: None,
             draft: None,
+            approved_by_trusted: None,
+            updated_since_my_review: None,
+            re_review_requested: None,
+            changes_requested: None,
+            reactions: None,
+            comments: None,
+            team_review_requested: None,
+            ci_status: None,
+            commits_behind: None,
+            authored: None,
+            authored_base_score: None,
+            sla: None,
+            disabled_factors: None,
         };
 
         let result = calculate_score(&pr, &config);
@@ -403,11 +1000,25 @@ mod tests {
         let config = ScoringConfig {
             base_score: Some(100.0),
             age: Some("x1.1 per 1h".to_string()),
+            age_basis: None,
             approvals: None,
             size: None,
             labels: None,
             previously_reviewed: None,
             draft: None,
+            approved_by_trusted: None,
+            updated_since_my_review: None,
+            re_review_requested: None,
+            changes_requested: None,
+            reactions: None,
+            comments: None,
+            team_review_requested: None,
+            ci_status: None,
+            commits_behind: None,
+            authored: None,
+            authored_base_score: None,
+            sla: None,
+            disabled_factors: None,
         };
 
         let result = calculate_score(&pr, &config);
@@ -422,9 +1033,12 @@ mod tests {
         let config = ScoringConfig {
             base_score: Some(100.0),
             age: None,
+            age_basis: None,
             approvals: None,
             size: Some(SizeConfig {
                 exclude: None,
+                metric: None,
+                on_exclude_failure: None,
                 buckets: Some(vec![
                     SizeBucket {
                         range: "<100".to_string(),
@@ -439,6 +1053,19 @@ mod tests {
             labels: None,
             previously_reviewed: None,
             draft: None,
+            approved_by_trusted: None,
+            updated_since_my_review: None,
+            re_review_requested: None,
+            changes_requested: None,
+            reactions: None,
+            comments: None,
+            team_review_requested: None,
+            ci_status: None,
+            commits_behind: None,
+            authored: None,
+            authored_base_score: None,
+            sla: None,
+            disabled_factors: None,
         };
 
         let result = calculate_score(&pr, &config);
@@ -453,14 +1080,29 @@ mod tests {
         let config = ScoringConfig {
             base_score: Some(100.0),
             age: None,
+            age_basis: None,
             approvals: None,
             size: None,
             labels: Some(vec![LabelEffect {
                 name: "urgent".to_string(),
                 effect: "+10".to_string(),
+                set_base: None,
             }]),
             previously_reviewed: None,
             draft: None,
+            approved_by_trusted: None,
+            updated_since_my_review: None,
+            re_review_requested: None,
+            changes_requested: None,
+            reactions: None,
+            comments: None,
+            team_review_requested: None,
+            ci_status: None,
+            commits_behind: None,
+            authored: None,
+            authored_base_score: None,
+            sla: None,
+            disabled_factors: None,
         };
 
         let result = calculate_score(&pr, &config);
@@ -475,14 +1117,29 @@ mod tests {
         let config = ScoringConfig {
             base_score: Some(100.0),
             age: None,
+            age_basis: None,
             approvals: None,
             size: None,
             labels: Some(vec![LabelEffect {
                 name: "wip".to_string(),
                 effect: "x0.5".to_string(),
+                set_base: None,
             }]),
             previously_reviewed: None,
             draft: None,
+            approved_by_trusted: None,
+            updated_since_my_review: None,
+            re_review_requested: None,
+            changes_requested: None,
+            reactions: None,
+            comments: None,
+            team_review_requested: None,
+            ci_status: None,
+            commits_behind: None,
+            authored: None,
+            authored_base_score: None,
+            sla: None,
+            disabled_factors: None,
         };
 
         let result = calculate_score(&pr, &config);
@@ -497,16 +1154,31 @@ mod tests {
         let config = ScoringConfig {
             base_score: Some(100.0),
             age: None,
+            age_basis: None,
             approvals: None,
             size: None,
             labels: Some(vec![
                 LabelEffect {
                     name: "urgent".to_string(),
                     effect: "+10".to_string(),
+                    set_base: None,
                 }, // lowercase
             ]),
             previously_reviewed: None,
             draft: None,
+            approved_by_trusted: None,
+            updated_since_my_review: None,
+            re_review_requested: None,
+            changes_requested: None,
+            reactions: None,
+            comments: None,
+            team_review_requested: None,
+            ci_status: None,
+            commits_behind: None,
+            authored: None,
+            authored_base_score: None,
+            sla: None,
+            disabled_factors: None,
         };
 
         let result = calculate_score(&pr, &config);
@@ -521,20 +1193,36 @@ mod tests {
         let config = ScoringConfig {
             base_score: Some(100.0),
             age: None,
+            age_basis: None,
             approvals: None,
             size: None,
             labels: Some(vec![
                 LabelEffect {
                     name: "urgent".to_string(),
                     effect: "+10".to_string(),
+                    set_base: None,
                 },
                 LabelEffect {
                     name: "critical".to_string(),
                     effect: "x2".to_string(),
+                    set_base: None,
                 },
             ]),
             previously_reviewed: None,
             draft: None,
+            approved_by_trusted: None,
+            updated_since_my_review: None,
+            re_review_requested: None,
+            changes_requested: None,
+            reactions: None,
+            comments: None,
+            team_review_requested: None,
+            ci_status: None,
+            commits_behind: None,
+            authored: None,
+            authored_base_score: None,
+            sla: None,
+            disabled_factors: None,
         };
 
         let result = calculate_score(&pr, &config);
@@ -550,14 +1238,29 @@ mod tests {
         let config = ScoringConfig {
             base_score: Some(100.0),
             age: None,
+            age_basis: None,
             approvals: None,
             size: None,
             labels: Some(vec![LabelEffect {
                 name: "urgent".to_string(),
                 effect: "+10".to_string(),
+                set_base: None,
             }]),
             previously_reviewed: None,
             draft: None,
+            approved_by_trusted: None,
+            updated_since_my_review: None,
+            re_review_requested: None,
+            changes_requested: None,
+            reactions: None,
+            comments: None,
+            team_review_requested: None,
+            ci_status: None,
+            commits_behind: None,
+            authored: None,
+            authored_base_score: None,
+            sla: None,
+            disabled_factors: None,
         };
 
         let result = calculate_score(&pr, &config);
@@ -572,11 +1275,25 @@ mod tests {
         let config = ScoringConfig {
             base_score: Some(100.0),
             age: None,
+            age_basis: None,
             approvals: None,
             size: None,
             labels: None,
             previously_reviewed: Some("x0.5".to_string()),
             draft: None,
+            approved_by_trusted: None,
+            updated_since_my_review: None,
+            re_review_requested: None,
+            changes_requested: None,
+            reactions: None,
+            comments: None,
+            team_review_requested: None,
+            ci_status: None,
+            commits_behind: None,
+            authored: None,
+            authored_base_score: None,
+            sla: None,
+            disabled_factors: None,
         };
 
         let result = calculate_score(&pr, &config);
@@ -591,11 +1308,25 @@ mod tests {
         let config = ScoringConfig {
             base_score: Some(100.0),
             age: None,
+            age_basis: None,
             approvals: None,
             size: None,
             labels: None,
             previously_reviewed: Some("x0.5".to_string()),
             draft: None,
+            approved_by_trusted: None,
+            updated_since_my_review: None,
+            re_review_requested: None,
+            changes_requested: None,
+            reactions: None,
+            comments: None,
+            team_review_requested: None,
+            ci_status: None,
+            commits_behind: None,
+            authored: None,
+            authored_base_score: None,
+            sla: None,
+            disabled_factors: None,
         };
 
         let result = calculate_score(&pr, &config);
@@ -610,9 +1341,12 @@ mod tests {
         let config = ScoringConfig {
             base_score: Some(100.0),
             age: None,
+            age_basis: None,
             approvals: None,
             size: Some(SizeConfig {
                 exclude: None,
+                metric: None,
+                on_exclude_failure: None,
                 buckets: Some(vec![
                     SizeBucket {
                         range: "<100".to_string(),
@@ -627,6 +1361,19 @@ mod tests {
             labels: None,
             previously_reviewed: None,
             draft: None,
+            approved_by_trusted: None,
+            updated_since_my_review: None,
+            re_review_requested: None,
+            changes_requested: None,
+            reactions: None,
+            comments: None,
+            team_review_requested: None,
+            ci_status: None,
+            commits_behind: None,
+            authored: None,
+            authored_base_score: None,
+            sla: None,
+            disabled_factors: None,
         };
 
         let result = calculate_score(&pr, &config);
@@ -634,6 +1381,168 @@ mod tests {
         assert_eq!(result.score, 500.0);
     }
 
+    #[test]
+    fn test_size_on_exclude_failure() {
+        let pr = sample_pr(1, 0, 50); // file list unavailable: no filtered_size
+        let mut config = ScoringConfig {
+            size: Some(SizeConfig {
+                exclude: Some(vec!["*.lock".to_string()]),
+                metric: None,
+                on_exclude_failure: None,
+                buckets: Some(vec![SizeBucket {
+                    range: "<100".to_string(),
+                    effect: "x5".to_string(),
+                }]),
+            }),
+            ..ScoringConfig::default()
+        };
+        config.age = None;
+        config.approvals = None;
+
+        // Default: the aggregate size stands in
+        let result = calculate_score(&pr, &config);
+        assert_eq!(result.score, 500.0);
+        assert!(!result.incomplete);
+
+        config.size.as_mut().unwrap().on_exclude_failure = Some(ExcludeFailure::Skip);
+        let result = calculate_score(&pr, &config);
+        assert_eq!(result.score, 100.0);
+        assert!(!result.incomplete);
+        assert!(result.breakdown.factors[0]
+            .description
+            .contains("exclusions not applied"));
+
+        config.size.as_mut().unwrap().on_exclude_failure = Some(ExcludeFailure::Incomplete);
+        let result = calculate_score(&pr, &config);
+        assert_eq!(result.score, 100.0);
+        assert!(result.incomplete);
+
+        // Once exclusions were applied the option has no effect
+        let mut filtered = pr.clone();
+        filtered.filtered_size = Some(40);
+        let result = calculate_score(&filtered, &config);
+        assert_eq!(result.score, 500.0);
+        assert!(!result.incomplete);
+    }
+
+    #[test]
+    fn test_size_metric_hunks() {
+        let mut pr = sample_pr(1, 0, 1000); // 1000 lines, but only a few hunks
+        pr.hunks = Some(3);
+
+        let mut config = ScoringConfig {
+            base_score: Some(100.0),
+            age: None,
+            age_basis: None,
+            approvals: None,
+            size: Some(SizeConfig {
+                exclude: None,
+                metric: Some(SizeMetric::Hunks),
+                on_exclude_failure: None,
+                buckets: Some(vec![
+                    SizeBucket {
+                        range: "<5".to_string(),
+                        effect: "x2".to_string(),
+                    },
+                    SizeBucket {
+                        range: ">=5".to_string(),
+                        effect: "x0.5".to_string(),
+                    },
+                ]),
+            }),
+            labels: None,
+            previously_reviewed: None,
+            draft: None,
+            approved_by_trusted: None,
+            updated_since_my_review: None,
+            re_review_requested: None,
+            changes_requested: None,
+            reactions: None,
+            comments: None,
+            team_review_requested: None,
+            ci_status: None,
+            commits_behind: None,
+            authored: None,
+            authored_base_score: None,
+            sla: None,
+            disabled_factors: None,
+        };
+
+        let result = calculate_score(&pr, &config);
+        assert_eq!(result.score, 200.0);
+        assert!(!result.incomplete);
+        assert!(result.breakdown.factors[0]
+            .description
+            .starts_with("3 hunks"));
+
+        // Without a hunk count (enrichment failed) the factor is skipped
+        pr.hunks = None;
+        let result = calculate_score(&pr, &config);
+        assert_eq!(result.score, 100.0);
+        assert!(result.incomplete);
+
+        // Files metric reads changed_files
+        pr.changed_files = Some(12);
+        config.size.as_mut().unwrap().metric = Some(SizeMetric::Files);
+        let result = calculate_score(&pr, &config);
+        assert_eq!(result.score, 50.0);
+    }
+
+    #[test]
+    fn test_issue_skips_pr_only_factors() {
+        let mut issue = sample_pr(10, 0, 0);
+        issue.is_issue = true;
+        issue.labels = vec!["urgent".to_string()];
+
+        let config = ScoringConfig {
+            base_score: Some(100.0),
+            age: Some("+1 per 1h".to_string()),
+            age_basis: None,
+            approvals: Some(ApprovalsConfig::Effect("x2 per 1".to_string())),
+            size: Some(SizeConfig {
+                exclude: None,
+                metric: Some(SizeMetric::Hunks),
+                on_exclude_failure: None,
+                buckets: Some(vec![SizeBucket {
+                    range: "<100".to_string(),
+                    effect: "x5".to_string(),
+                }]),
+            }),
+            labels: Some(vec![LabelEffect {
+                name: "urgent".to_string(),
+                effect: "+50".to_string(),
+                set_base: None,
+            }]),
+            previously_reviewed: None,
+            draft: None,
+            approved_by_trusted: None,
+            updated_since_my_review: None,
+            re_review_requested: None,
+            changes_requested: None,
+            reactions: None,
+            comments: None,
+            team_review_requested: None,
+            ci_status: None,
+            commits_behind: None,
+            authored: None,
+            authored_base_score: None,
+            sla: None,
+            disabled_factors: None,
+        };
+
+        let result = calculate_score(&issue, &config);
+        // base 100 + 10h age + urgent label; no size/approvals, not incomplete
+        assert!((result.score - 160.0).abs() < 0.5);
+        assert!(!result.incomplete);
+        let labels: Vec<&str> = result
+            .breakdown
+            .factors
+            .iter()
+            .map(|f| f.label.as_str())
+            .collect();
+        assert_eq!(labels, vec!["Age", "Label: urgent"]);
+    }
+
     #[test]
     fn test_full_scoring_with_all_factors() {
         let mut pr = sample_pr(5, 2, 50); // 5h old, 2 approvals, 50 lines
@@ -643,9 +1552,12 @@ mod tests {
         let config = ScoringConfig {
             base_score: Some(100.0),
             age: Some("+1 per 1h".to_string()),
-            approvals: Some("+10 per 1".to_string()),
+            age_basis: None,
+            approvals: Some(ApprovalsConfig::Effect("+10 per 1".to_string())),
             size: Some(SizeConfig {
                 exclude: None,
+                metric: None,
+                on_exclude_failure: None,
                 buckets: Some(vec![SizeBucket {
                     range: "<100".to_string(),
                     effect: "x2".to_string(),
@@ -654,9 +1566,23 @@ mod tests {
             labels: Some(vec![LabelEffect {
                 name: "urgent".to_string(),
                 effect: "+20".to_string(),
+                set_base: None,
             }]),
             previously_reviewed: Some("x0.5".to_string()),
             draft: None,
+            approved_by_trusted: None,
+            updated_since_my_review: None,
+            re_review_requested: None,
+            changes_requested: None,
+            reactions: None,
+            comments: None,
+            team_review_requested: None,
+            ci_status: None,
+            commits_behind: None,
+            authored: None,
+            authored_base_score: None,
+            sla: None,
+            disabled_factors: None,
         };
 
         let result = calculate_score(&pr, &config);
@@ -673,11 +1599,25 @@ mod tests {
         let config = ScoringConfig {
             base_score: Some(100.0),
             age: None,
+            age_basis: None,
             approvals: None,
             size: None,
             labels: None,
             previously_reviewed: None,
             draft: Some("x0.1".to_string()),
+            approved_by_trusted: None,
+            updated_since_my_review: None,
+            re_review_requested: None,
+            changes_requested: None,
+            reactions: None,
+            comments: None,
+            team_review_requested: None,
+            ci_status: None,
+            commits_behind: None,
+            authored: None,
+            authored_base_score: None,
+            sla: None,
+            disabled_factors: None,
         };
 
         let result = calculate_score(&pr, &config);
@@ -692,14 +1632,491 @@ mod tests {
         let config = ScoringConfig {
             base_score: Some(100.0),
             age: None,
+            age_basis: None,
             approvals: None,
             size: None,
             labels: None,
             previously_reviewed: None,
             draft: Some("x0.1".to_string()),
+            approved_by_trusted: None,
+            updated_since_my_review: None,
+            re_review_requested: None,
+            changes_requested: None,
+            reactions: None,
+            comments: None,
+            team_review_requested: None,
+            ci_status: None,
+            commits_behind: None,
+            authored: None,
+            authored_base_score: None,
+            sla: None,
+            disabled_factors: None,
+        };
+
+        let result = calculate_score(&pr, &config);
+        assert_eq!(result.score, 100.0);
+    }
+
+    #[test]
+    fn test_approved_by_trusted_applies() {
+        let mut pr = sample_pr(1, 1, 100);
+        pr.approved_by_trusted = true;
+
+        let config = ScoringConfig {
+            base_score: Some(100.0),
+            age: None,
+            age_basis: None,
+            approvals: None,
+            size: None,
+            labels: None,
+            previously_reviewed: None,
+            draft: None,
+            approved_by_trusted: Some("x0.5".to_string()),
+            updated_since_my_review: None,
+            re_review_requested: None,
+            changes_requested: None,
+            reactions: None,
+            comments: None,
+            team_review_requested: None,
+            ci_status: None,
+            commits_behind: None,
+            authored: None,
+            authored_base_score: None,
+            sla: None,
+            disabled_factors: None,
+        };
+
+        let result = calculate_score(&pr, &config);
+        assert_eq!(result.score, 50.0);
+        assert_eq!(result.breakdown.factors[0].label, "Trusted Approval");
+    }
+
+    #[test]
+    fn test_team_review_requested_applies() {
+        let config = ScoringConfig {
+            changes_requested: None,
+            team_review_requested: Some("x1.5".to_string()),
+            ..changes_requested_only("x0.5")
+        };
+
+        let mut pr = sample_pr(1, 0, 100);
+        assert!(calculate_score(&pr, &config).breakdown.factors.is_empty());
+
+        pr.team_review_requested = true;
+        let result = calculate_score(&pr, &config);
+        assert_eq!(result.score, 150.0);
+        assert_eq!(result.breakdown.factors[0].label, "Team Review Requested");
+    }
+
+    #[test]
+    fn test_ci_status_effects() {
+        let config = ScoringConfig {
+            changes_requested: None,
+            ci_status: Some(CiStatusConfig {
+                success: None,
+                failure: Some("x0.3".to_string()),
+                pending: Some("x0.7".to_string()),
+            }),
+            ..changes_requested_only("x0.5")
+        };
+
+        let mut pr = sample_pr(1, 0, 100);
+        pr.ci_status = Some(CiStatus::Failure);
+        let result = calculate_score(&pr, &config);
+        assert!((result.score - 30.0).abs() < 1e-9);
+        assert_eq!(result.breakdown.factors[0].label, "CI");
+
+        // No effect for green builds, and unknown CI is not a failure
+        for status in [Some(CiStatus::Success), None] {
+            pr.ci_status = status;
+            let result = calculate_score(&pr, &config);
+            assert_eq!(result.score, 100.0);
+            assert!(result.breakdown.factors.is_empty());
+        }
+    }
+
+    #[test]
+    fn test_approved_by_trusted_not_applied_without_trusted_approval() {
+        let pr = sample_pr(1, 1, 100);
+
+        let config = ScoringConfig {
+            base_score: Some(100.0),
+            age: None,
+            age_basis: None,
+            approvals: None,
+            size: None,
+            labels: None,
+            previously_reviewed: None,
+            draft: None,
+            approved_by_trusted: Some("x0.5".to_string()),
+            updated_since_my_review: None,
+            re_review_requested: None,
+            changes_requested: None,
+            reactions: None,
+            comments: None,
+            team_review_requested: None,
+            ci_status: None,
+            commits_behind: None,
+            authored: None,
+            authored_base_score: None,
+            sla: None,
+            disabled_factors: None,
+        };
+
+        let result = calculate_score(&pr, &config);
+        assert_eq!(result.score, 100.0);
+    }
+
+    #[test]
+    fn test_updated_since_my_review_applies() {
+        let mut pr = sample_pr(1, 0, 100);
+        pr.user_has_reviewed = true;
+        pr.my_last_review_at = Some(Utc::now() - ChronoDuration::hours(3));
+        pr.updated_since_my_review = true;
+
+        let config = ScoringConfig {
+            base_score: Some(100.0),
+            age: None,
+            age_basis: None,
+            approvals: None,
+            size: None,
+            labels: None,
+            previously_reviewed: None,
+            draft: None,
+            approved_by_trusted: None,
+            updated_since_my_review: Some("x3".to_string()),
+            re_review_requested: None,
+            changes_requested: None,
+            reactions: None,
+            comments: None,
+            team_review_requested: None,
+            ci_status: None,
+            commits_behind: None,
+            authored: None,
+            authored_base_score: None,
+            sla: None,
+            disabled_factors: None,
+        };
+
+        let result = calculate_score(&pr, &config);
+        assert_eq!(result.score, 300.0);
+        assert_eq!(result.breakdown.factors[0].label, "Updated Since My Review");
+    }
+
+    #[test]
+    fn test_updated_since_my_review_not_applied_when_not_updated() {
+        let mut pr = sample_pr(1, 0, 100);
+        pr.user_has_reviewed = true;
+        pr.my_last_review_at = Some(Utc::now());
+
+        let config = ScoringConfig {
+            base_score: Some(100.0),
+            age: None,
+            age_basis: None,
+            approvals: None,
+            size: None,
+            labels: None,
+            previously_reviewed: None,
+            draft: None,
+            approved_by_trusted: None,
+            updated_since_my_review: Some("x3".to_string()),
+            re_review_requested: None,
+            changes_requested: None,
+            reactions: None,
+            comments: None,
+            team_review_requested: None,
+            ci_status: None,
+            commits_behind: None,
+            authored: None,
+            authored_base_score: None,
+            sla: None,
+            disabled_factors: None,
+        };
+
+        let result = calculate_score(&pr, &config);
+        assert_eq!(result.score, 100.0);
+    }
+
+    fn set_base_config(labels: Vec<LabelEffect>) -> ScoringConfig {
+        ScoringConfig {
+            base_score: Some(100.0),
+            age: None,
+            age_basis: None,
+            approvals: Some(ApprovalsConfig::Effect("x2".to_string())),
+            size: None,
+            labels: Some(labels),
+            previously_reviewed: None,
+            draft: Some("x0.5".to_string()),
+            approved_by_trusted: None,
+            updated_since_my_review: None,
+            re_review_requested: None,
+            changes_requested: None,
+            reactions: None,
+            comments: None,
+            team_review_requested: None,
+            ci_status: None,
+            commits_behind: None,
+            authored: None,
+            authored_base_score: None,
+            sla: None,
+            disabled_factors: None,
+        }
+    }
+
+    #[test]
+    fn test_label_set_base_applies_before_multipliers() {
+        let mut pr = sample_pr(1, 1, 100);
+        pr.labels = vec!["Hotfix".to_string()];
+        pr.draft = true;
+
+        let config = set_base_config(vec![LabelEffect {
+            name: "hotfix".to_string(),
+            effect: String::new(),
+            set_base: Some(1000.0),
+        }]);
+
+        let result = calculate_score(&pr, &config);
+        // base 100 -> set to 1000, approvals x2 = 2000, draft x0.5 = 1000
+        assert_eq!(result.score, 1000.0);
+        assert_eq!(result.breakdown.base_score, 100.0);
+        assert_eq!(result.breakdown.factors[0].label, "Label: hotfix");
+        assert_eq!(result.breakdown.factors[0].after, 1000.0);
+    }
+
+    #[test]
+    fn test_label_set_base_with_effect_compounds() {
+        let mut pr = sample_pr(1, 1, 100);
+        pr.labels = vec!["hotfix".to_string(), "urgent".to_string()];
+
+        let config = set_base_config(vec![
+            LabelEffect {
+                name: "hotfix".to_string(),
+                effect: "x1.5".to_string(),
+                set_base: Some(1000.0),
+            },
+            LabelEffect {
+                name: "urgent".to_string(),
+                effect: "+10".to_string(),
+                set_base: Some(500.0),
+            },
+        ]);
+
+        let result = calculate_score(&pr, &config);
+        // First set_base wins: 1000, approvals x2 = 2000, hotfix x1.5 = 3000, urgent +10 = 3010
+        assert_eq!(result.score, 3010.0);
+    }
+
+    #[test]
+    fn test_label_set_base_ignored_without_label() {
+        let pr = sample_pr(1, 1, 100);
+
+        let config = set_base_config(vec![LabelEffect {
+            name: "hotfix".to_string(),
+            effect: String::new(),
+            set_base: Some(1000.0),
+        }]);
+
+        let result = calculate_score(&pr, &config);
+        // 100 x2 (approvals flat effect, one approval)
+        assert_eq!(result.score, 200.0);
+    }
+
+    fn changes_requested_only(effect: &str) -> ScoringConfig {
+        ScoringConfig {
+            base_score: Some(100.0),
+            age: None,
+            age_basis: None,
+            approvals: None,
+            size: None,
+            labels: None,
+            previously_reviewed: None,
+            draft: None,
+            approved_by_trusted: None,
+            updated_since_my_review: None,
+            re_review_requested: None,
+            changes_requested: Some(effect.to_string()),
+            reactions: None,
+            comments: None,
+            team_review_requested: None,
+            ci_status: None,
+            commits_behind: None,
+            authored: None,
+            authored_base_score: None,
+            sla: None,
+            disabled_factors: None,
+        }
+    }
+
+    #[test]
+    fn test_changes_requested_flat_effect() {
+        let mut pr = sample_pr(1, 0, 100);
+        pr.changes_requested_count = 2;
+
+        let result = calculate_score(&pr, &changes_requested_only("x0.5"));
+        assert_eq!(result.score, 50.0);
+        assert!(result.breakdown.factors[0]
+            .description
+            .contains("2 change requests"));
+    }
+
+    #[test]
+    fn test_changes_requested_per_count() {
+        let mut pr = sample_pr(1, 0, 100);
+        pr.changes_requested_count = 3;
+
+        let result = calculate_score(&pr, &changes_requested_only("+-10 per 1"));
+        assert_eq!(result.score, 70.0);
+    }
+
+    #[test]
+    fn test_changes_requested_not_applied_without_requests() {
+        let pr = sample_pr(1, 0, 100);
+
+        let result = calculate_score(&pr, &changes_requested_only("x0.5"));
+        assert_eq!(result.score, 100.0);
+        assert!(result.breakdown.factors.is_empty());
+    }
+
+    #[test]
+    fn test_reactions_per_count() {
+        let config = ScoringConfig {
+            changes_requested: None,
+            reactions: Some("+1 per 5".to_string()),
+            ..changes_requested_only("x0.5")
+        };
+
+        let mut pr = sample_pr(1, 0, 100);
+        pr.reactions = 12;
+        let result = calculate_score(&pr, &config);
+        // Two whole groups of 5 reactions
+        assert_eq!(result.score, 102.0);
+        assert_eq!(result.breakdown.factors[0].label, "Reactions");
+
+        pr.reactions = 0;
+        let result = calculate_score(&pr, &config);
+        assert_eq!(result.score, 100.0);
+        assert!(result.breakdown.factors.is_empty());
+    }
+
+    #[test]
+    fn test_comments_per_count() {
+        let config = ScoringConfig {
+            changes_requested: None,
+            comments: Some("+1 per 1".to_string()),
+            ..changes_requested_only("x0.5")
+        };
+
+        // A stale PR with a long discussion floats up
+        let mut pr = sample_pr(1, 0, 100);
+        pr.comments = 40;
+        let result = calculate_score(&pr, &config);
+        assert_eq!(result.score, 140.0);
+        assert_eq!(result.breakdown.factors[0].label, "Comments");
+
+        pr.comments = 0;
+        assert!(calculate_score(&pr, &config).breakdown.factors.is_empty());
+    }
+
+    #[test]
+    fn test_commits_behind_per_count() {
+        let config = ScoringConfig {
+            changes_requested: None,
+            commits_behind: Some("x0.5 per 10".to_string()),
+            ..changes_requested_only("x0.5")
+        };
+
+        let mut pr = sample_pr(1, 0, 100);
+        pr.commits_behind = Some(25);
+        let result = calculate_score(&pr, &config);
+        // Two whole groups of 10 commits
+        assert_eq!(result.score, 25.0);
+        assert_eq!(result.breakdown.factors[0].label, "Commits Behind");
+
+        // Base branch gone or compare failed: no effect
+        pr.commits_behind = None;
+        let result = calculate_score(&pr, &config);
+        assert_eq!(result.score, 100.0);
+        assert!(result.breakdown.factors.is_empty());
+    }
+
+    #[test]
+    fn test_disabled_factors_are_skipped_and_listed() {
+        let config = ScoringConfig {
+            reactions: Some("+10".to_string()),
+            disabled_factors: Some(vec![
+                "changes_requested".to_string(),
+                "draft".to_string(), // Not configured, so not listed
+            ]),
+            ..changes_requested_only("x0.5")
+        };
+
+        let mut pr = sample_pr(1, 0, 100);
+        pr.changes_requested_count = 1;
+        pr.reactions = 3;
+        let result = calculate_score(&pr, &config);
+        assert_eq!(result.score, 110.0);
+        assert_eq!(result.breakdown.factors.len(), 1);
+        assert_eq!(result.breakdown.disabled, vec!["changes_requested"]);
+    }
+
+    #[test]
+    fn test_re_review_requested_factor() {
+        let config = ScoringConfig {
+            changes_requested: None,
+            re_review_requested: Some("x3".to_string()),
+            ..changes_requested_only("x0.5")
         };
 
+        let mut pr = sample_pr(1, 0, 100);
+        assert_eq!(calculate_score(&pr, &config).score, 100.0);
+
+        pr.re_review_requested = true;
         let result = calculate_score(&pr, &config);
+        assert_eq!(result.score, 300.0);
+        assert_eq!(result.breakdown.factors[0].label, "Re-review Requested");
+    }
+
+    #[test]
+    fn test_authored_base_and_effect_apply_only_to_own_prs() {
+        let config = ScoringConfig {
+            authored: Some("x0.5".to_string()),
+            authored_base_score: Some(20.0),
+            ..changes_requested_only("x0.5")
+        };
+
+        let mut mine = sample_pr(0, 0, 100);
+        mine.is_author = true;
+        let result = calculate_score(&mine, &config);
+        assert_eq!(result.breakdown.base_score, 20.0);
+        assert_eq!(result.score, 10.0);
+        assert_eq!(result.breakdown.factors[0].label, "Authored");
+
+        let theirs = sample_pr(0, 0, 100);
+        let result = calculate_score(&theirs, &config);
+        assert_eq!(result.score, 100.0);
+        assert!(result.breakdown.factors.is_empty());
+    }
+
+    #[test]
+    fn test_sla_breach_marks_and_boosts() {
+        let config = ScoringConfig {
+            sla: Some(SlaConfig {
+                within: "1d".to_string(),
+                effect: Some("x10".to_string()),
+                business_days: false,
+            }),
+            ..changes_requested_only("x0.5")
+        };
+
+        let old = sample_pr(48, 0, 100);
+        let result = calculate_score(&old, &config);
+        assert!(result.sla_breached);
+        assert_eq!(result.score, 1000.0);
+        assert_eq!(result.breakdown.factors[0].label, "SLA Breached");
+
+        let fresh = sample_pr(3, 0, 100);
+        let result = calculate_score(&fresh, &config);
+        assert!(!result.sla_breached);
         assert_eq!(result.score, 100.0);
     }
 }
@@ -0,0 +1,100 @@
+use anyhow::{anyhow, Result};
+
+/// A compiled `size.exclude` pattern.
+///
+/// - Patterns without `/` are globs matched against the file's basename
+///   (`*.lock` matches `Cargo.lock` and `web/package-lock.json`)
+/// - Patterns containing `/` are globs matched against the full path
+///   (`vendor/**` matches everything under `vendor/`)
+/// - Patterns prefixed with `re:` are regular expressions matched against the
+///   full path (`re:^gen/.*\.pb\.go$`)
+#[derive(Debug, Clone)]
+pub enum ExcludePattern {
+    Basename(glob::Pattern),
+    Path(glob::Pattern),
+    Regex(regex::Regex),
+}
+
+impl ExcludePattern {
+    pub fn parse(s: &str) -> Result<Self> {
+        if let Some(re) = s.strip_prefix("re:") {
+            regex::Regex::new(re)
+                .map(ExcludePattern::Regex)
+                .map_err(|e| anyhow!("invalid regex '{}' - {}", re, e))
+        } else {
+            let pattern = glob::Pattern::new(s)
+                .map_err(|e| anyhow!("invalid glob pattern '{}' - {}", s, e))?;
+            if s.contains('/') {
+                Ok(ExcludePattern::Path(pattern))
+            } else {
+                Ok(ExcludePattern::Basename(pattern))
+            }
+        }
+    }
+
+    /// Check whether a repo-relative file path is excluded by this pattern
+    pub fn matches(&self, path: &str) -> bool {
+        match self {
+            ExcludePattern::Basename(pattern) => {
+                let basename = std::path::Path::new(path)
+                    .file_name()
+                    .and_then(|n| n.to_str())
+                    .unwrap_or(path);
+                pattern.matches(basename)
+            }
+            ExcludePattern::Path(pattern) => {
+                // `*` stays within one directory; `**` spans directories
+                let options = glob::MatchOptions {
+                    require_literal_separator: true,
+                    ..Default::default()
+                };
+                pattern.matches_with(path, options)
+            }
+            ExcludePattern::Regex(re) => re.is_match(path),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_basename_pattern_matches_any_directory() {
+        let pattern = ExcludePattern::parse("*.lock").unwrap();
+        assert!(pattern.matches("Cargo.lock"));
+        assert!(pattern.matches("web/yarn.lock"));
+        assert!(!pattern.matches("src/lock.rs"));
+    }
+
+    #[test]
+    fn test_path_pattern_matches_directory() {
+        let pattern = ExcludePattern::parse("vendor/**").unwrap();
+        assert!(pattern.matches("vendor/github.com/foo/bar.go"));
+        assert!(pattern.matches("vendor/modules.txt"));
+        assert!(!pattern.matches("src/vendor/lib.go"));
+        assert!(!pattern.matches("main.go"));
+    }
+
+    #[test]
+    fn test_path_pattern_single_star_stays_in_directory() {
+        let pattern = ExcludePattern::parse("gen/*.go").unwrap();
+        assert!(pattern.matches("gen/api.go"));
+        assert!(!pattern.matches("gen/sub/api.go"));
+    }
+
+    #[test]
+    fn test_regex_pattern_matches_full_path() {
+        let pattern = ExcludePattern::parse(r"re:^proto/.*\.pb\.go$").unwrap();
+        assert!(pattern.matches("proto/v1/service.pb.go"));
+        assert!(!pattern.matches("service.pb.go"));
+    }
+
+    #[test]
+    fn test_invalid_patterns() {
+        let err = ExcludePattern::parse("[invalid").unwrap_err();
+        assert!(err.to_string().contains("invalid glob pattern"));
+        let err = ExcludePattern::parse("re:(unclosed").unwrap_err();
+        assert!(err.to_string().contains("invalid regex"));
+    }
+}
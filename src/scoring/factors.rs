@@ -52,9 +52,14 @@ impl RangeOp {
 #[derive(Debug, Clone)]
 pub enum Effect {
     Add(f64),
+    Subtract(f64),
     Multiply(f64),
+    Divide(f64),
     AddPerUnit(f64, Duration),
-    MultiplyPerUnit(f64, Duration),
+    SubtractPerUnit(f64, Duration),
+    /// Multiplier per unit, with an optional cap on the total multiplier
+    MultiplyPerUnit(f64, Duration, Option<f64>),
+    DividePerUnit(f64, Duration),
 }
 
 impl Effect {
@@ -63,42 +68,136 @@ impl Effect {
 
         // Check for "per" modifier
         if let Some((effect_part, per_part)) = s.split_once(" per ") {
+            let (per_part, cap) = split_cap(per_part)?;
             let duration = humantime::parse_duration(per_part.trim())?;
+            if cap.is_some() && !effect_part.starts_with('x') {
+                bail!("cap is only supported for x effects: {}", s)
+            }
             if let Some(val) = effect_part.strip_prefix('+') {
                 Ok(Effect::AddPerUnit(val.trim().parse()?, duration))
+            } else if let Some(val) = effect_part.strip_prefix('-') {
+                Ok(Effect::SubtractPerUnit(val.trim().parse()?, duration))
             } else if let Some(val) = effect_part.strip_prefix('x') {
-                Ok(Effect::MultiplyPerUnit(val.trim().parse()?, duration))
+                let factor: f64 = val.trim().parse()?;
+                if let Some(cap) = cap {
+                    validate_cap(factor, cap, s)?;
+                }
+                Ok(Effect::MultiplyPerUnit(factor, duration, cap))
+            } else if let Some(val) = effect_part.strip_prefix('/') {
+                Ok(Effect::DividePerUnit(parse_divisor(val, s)?, duration))
             } else {
-                bail!("Effect must start with + or x: {}", s)
+                bail!("Effect must start with +, -, x or /: {}", s)
             }
         } else if let Some(val) = s.strip_prefix('+') {
             Ok(Effect::Add(val.trim().parse()?))
+        } else if let Some(val) = s.strip_prefix('-') {
+            Ok(Effect::Subtract(val.trim().parse()?))
         } else if let Some(val) = s.strip_prefix('x') {
             Ok(Effect::Multiply(val.trim().parse()?))
+        } else if let Some(val) = s.strip_prefix('/') {
+            Ok(Effect::Divide(parse_divisor(val, s)?))
         } else {
-            bail!("Effect must start with + or x: {}", s)
+            bail!("Effect must start with +, -, x or /: {}", s)
+        }
+    }
+
+    /// Parse a count-based effect such as the approvals factor, where "per N"
+    /// means "per N items" rather than per time unit.
+    ///
+    /// Returns the effect together with the per-count divisor (1 for flat effects
+    /// and "per 1"). Callers compute units as `count / per` using integer division,
+    /// so "+5 per 2" with 3 approvals applies once.
+    pub fn parse_per_count(s: &str) -> Result<(Self, u64)> {
+        let s = s.trim();
+        if let Some((effect_part, per_part)) = s.split_once(" per ") {
+            let (per_part, cap) = split_cap(per_part)?;
+            let per_part = per_part.trim();
+            if !per_part.is_empty() && per_part.chars().all(|c| c.is_ascii_digit()) {
+                let per: u64 = per_part.parse()?;
+                if per == 0 {
+                    bail!("per count must be at least 1: {}", s)
+                }
+                // The duration is a placeholder; units come from the count, not time
+                let cap_part = cap.map(|c| format!(" cap x{}", c)).unwrap_or_default();
+                let effect = Effect::parse(&format!("{} per 1sec{}", effect_part, cap_part))?;
+                return Ok((effect, per));
+            }
         }
+        Ok((Effect::parse(s)?, 1))
     }
 
     /// Apply effect to score. `units` is number of time periods for per-unit effects.
     pub fn apply(&self, score: f64, units: u64) -> f64 {
         match self {
             Effect::Add(n) => score + n,
+            Effect::Subtract(n) => score - n,
             Effect::Multiply(n) => score * n,
+            Effect::Divide(n) => score / n,
             Effect::AddPerUnit(n, _) => score + (n * units as f64),
-            Effect::MultiplyPerUnit(n, _) => score * n.powf(units as f64),
+            Effect::SubtractPerUnit(n, _) => score - (n * units as f64),
+            Effect::DividePerUnit(n, _) => score / n.powf(units as f64),
+            Effect::MultiplyPerUnit(n, _, cap) => {
+                let multiplier = n.powf(units as f64);
+                // A growing multiplier is capped from above, a decaying one from below
+                let multiplier = match cap {
+                    Some(cap) if *n >= 1.0 => multiplier.min(*cap),
+                    Some(cap) => multiplier.max(*cap),
+                    None => multiplier,
+                };
+                score * multiplier
+            }
         }
     }
 
     /// Get the duration for per-unit effects (for calculating units from PR age)
     pub fn unit_duration(&self) -> Option<Duration> {
         match self {
-            Effect::AddPerUnit(_, d) | Effect::MultiplyPerUnit(_, d) => Some(*d),
+            Effect::AddPerUnit(_, d)
+            | Effect::SubtractPerUnit(_, d)
+            | Effect::MultiplyPerUnit(_, d, _)
+            | Effect::DividePerUnit(_, d) => Some(*d),
             _ => None,
         }
     }
 }
 
+/// Parse the number after `/`, which must not be zero
+fn parse_divisor(val: &str, s: &str) -> Result<f64> {
+    let divisor: f64 = val.trim().parse()?;
+    if divisor == 0.0 {
+        bail!("cannot divide by zero: {}", s)
+    }
+    Ok(divisor)
+}
+
+/// Split a trailing `cap xN` off the "per" part of an effect string
+fn split_cap(per_part: &str) -> Result<(&str, Option<f64>)> {
+    match per_part.split_once(" cap ") {
+        Some((per, cap)) => {
+            let Some(val) = cap.trim().strip_prefix('x') else {
+                bail!("cap must be a multiplier like x5: {}", cap.trim())
+            };
+            Ok((per, Some(val.trim().parse()?)))
+        }
+        None => Ok((per_part, None)),
+    }
+}
+
+/// A cap must lie on the side of 1x the multiplier moves towards, otherwise it
+/// would apply from the very first unit
+fn validate_cap(factor: f64, cap: f64, s: &str) -> Result<()> {
+    if cap <= 0.0 {
+        bail!("cap must be positive: {}", s)
+    }
+    if factor >= 1.0 && cap < 1.0 {
+        bail!("cap must be at least x1 for a growing multiplier: {}", s)
+    }
+    if factor < 1.0 && cap > 1.0 {
+        bail!("cap must be at most x1 for a decaying multiplier: {}", s)
+    }
+    Ok(())
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -185,9 +284,84 @@ mod tests {
         assert_eq!(effect.apply(100.0, 1), 95.0);
     }
 
+    #[test]
+    fn test_parse_effect_subtract_and_divide() {
+        assert_eq!(Effect::parse("-5").unwrap().apply(100.0, 1), 95.0);
+        assert_eq!(Effect::parse("/2").unwrap().apply(100.0, 1), 50.0);
+
+        // "-5 per 1d" on 3 days = -15, "/2 per 1d" on 3 days = /8
+        assert_eq!(Effect::parse("-5 per 1d").unwrap().apply(100.0, 3), 85.0);
+        assert_eq!(Effect::parse("/2 per 1d").unwrap().apply(100.0, 3), 12.5);
+
+        let (effect, per) = Effect::parse_per_count("-10 per 2").unwrap();
+        assert_eq!(per, 2);
+        assert!(matches!(effect, Effect::SubtractPerUnit(n, _) if n == 10.0));
+    }
+
+    #[test]
+    fn test_parse_effect_rejects_bad_subtract_and_divide() {
+        assert!(Effect::parse("/0").is_err());
+        assert!(Effect::parse("/0 per 1d").is_err());
+        assert!(Effect::parse("-5 per 1d cap x2").is_err());
+        assert!(Effect::parse("/2 per 1d cap x0.5").is_err());
+    }
+
+    #[test]
+    fn test_parse_per_count_flat() {
+        let (effect, per) = Effect::parse_per_count("+20").unwrap();
+        assert_eq!(per, 1);
+        assert_eq!(effect.apply(100.0, 1), 120.0);
+    }
+
+    #[test]
+    fn test_parse_per_count_per_n() {
+        let (effect, per) = Effect::parse_per_count("+5 per 2").unwrap();
+        assert_eq!(per, 2);
+        assert!(matches!(effect, Effect::AddPerUnit(n, _) if n == 5.0));
+    }
+
+    #[test]
+    fn test_parse_per_count_rejects_zero() {
+        assert!(Effect::parse_per_count("+5 per 0").is_err());
+    }
+
+    #[test]
+    fn test_parse_per_count_rejects_bad_effect() {
+        assert!(Effect::parse_per_count("5 per 2").is_err());
+    }
+
     #[test]
     fn test_parse_effect_decimal_multiply() {
         let effect = Effect::parse("x0.5").unwrap();
         assert_eq!(effect.apply(100.0, 1), 50.0);
     }
+
+    #[test]
+    fn test_multiply_per_unit_cap_engages_after_enough_units() {
+        let effect = Effect::parse("x2 per 1d cap x5").unwrap();
+        assert_eq!(effect.apply(100.0, 2), 400.0); // 2^2 = 4, under the cap
+        assert_eq!(effect.apply(100.0, 3), 500.0); // 2^3 = 8, capped at 5
+        assert_eq!(effect.apply(100.0, 30), 500.0);
+
+        // Decaying multipliers are floored instead
+        let effect = Effect::parse("x0.5 per 1d cap x0.25").unwrap();
+        assert_eq!(effect.apply(100.0, 1), 50.0);
+        assert_eq!(effect.apply(100.0, 5), 25.0);
+    }
+
+    #[test]
+    fn test_multiply_per_count_cap() {
+        let (effect, per) = Effect::parse_per_count("x2 per 1 cap x3").unwrap();
+        assert_eq!(per, 1);
+        assert_eq!(effect.apply(10.0, 4), 30.0);
+    }
+
+    #[test]
+    fn test_cap_rejects_invalid() {
+        assert!(Effect::parse("+1 per 1d cap x5").is_err()); // additive
+        assert!(Effect::parse("x1.1 per 1d cap 5").is_err()); // missing x
+        assert!(Effect::parse("x1.1 per 1d cap x0.5").is_err()); // below 1x when growing
+        assert!(Effect::parse("x0.9 per 1d cap x2").is_err()); // above 1x when decaying
+        assert!(Effect::parse("x0.9 per 1d cap x0").is_err());
+    }
 }
@@ -1,9 +1,12 @@
 pub mod config;
 pub mod engine;
+pub mod exclude;
 pub mod factors;
+pub mod sla;
 pub mod validation;
 
 pub use config::*;
-pub use engine::{calculate_score, FactorContribution, ScoreBreakdown, ScoreResult};
+pub use engine::{calculate_score, efficiency, FactorContribution, ScoreBreakdown, ScoreResult};
+pub use exclude::ExcludePattern;
 pub use factors::{Effect, RangeOp};
 pub use validation::validate_scoring;
@@ -0,0 +1,66 @@
+use chrono::{DateTime, Datelike, Duration, NaiveTime, TimeZone, Weekday};
+
+/// Time between `start` and `end` that falls on weekdays (Mon-Fri) in the
+/// timezone of `start`. Zero when `end` is not after `start`.
+pub fn business_time_between<Tz: TimeZone>(start: &DateTime<Tz>, end: &DateTime<Tz>) -> Duration {
+    let tz = start.timezone();
+    let mut total = Duration::zero();
+    let mut cursor = start.clone();
+
+    while cursor < *end {
+        // Walk one calendar day at a time, up to the next local midnight
+        let next_day = cursor.date_naive() + Duration::days(1);
+        let midnight = tz
+            .from_local_datetime(&next_day.and_time(NaiveTime::MIN))
+            .earliest()
+            .unwrap_or_else(|| cursor.clone() + Duration::days(1));
+        let segment_end = if midnight < *end {
+            midnight
+        } else {
+            end.clone()
+        };
+
+        if !matches!(cursor.weekday(), Weekday::Sat | Weekday::Sun) {
+            total += segment_end.clone() - cursor.clone();
+        }
+        cursor = segment_end;
+    }
+
+    total
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::FixedOffset;
+
+    fn at(s: &str) -> DateTime<FixedOffset> {
+        let tz = FixedOffset::east_opt(2 * 3600).unwrap();
+        tz.from_local_datetime(&chrono::NaiveDateTime::parse_from_str(s, "%Y-%m-%d %H:%M").unwrap())
+            .unwrap()
+    }
+
+    #[test]
+    fn test_business_time_skips_weekends() {
+        // Friday 10:00 -> Monday 10:00 is one business day
+        let friday = at("2025-03-07 10:00");
+        let monday = at("2025-03-10 10:00");
+        assert_eq!(business_time_between(&friday, &monday), Duration::hours(24));
+
+        // Entirely within a weekend
+        let saturday = at("2025-03-08 09:00");
+        let sunday = at("2025-03-09 18:00");
+        assert_eq!(business_time_between(&saturday, &sunday), Duration::zero());
+    }
+
+    #[test]
+    fn test_business_time_on_weekdays_matches_calendar_time() {
+        let tuesday = at("2025-03-04 08:30");
+        let thursday = at("2025-03-06 12:00");
+        assert_eq!(
+            business_time_between(&tuesday, &thursday),
+            thursday - tuesday
+        );
+        assert_eq!(business_time_between(&thursday, &tuesday), Duration::zero());
+    }
+}
@@ -1,4 +1,5 @@
-use super::config::{ScoringConfig, SizeBucket};
+use super::config::{ApprovalsConfig, ScoringConfig, SizeBucket, FACTOR_NAMES};
+use super::exclude::ExcludePattern;
 use super::factors::{Effect, RangeOp};
 use anyhow::Result;
 
@@ -14,6 +15,15 @@ pub fn validate_scoring(config: &ScoringConfig) -> Result<(), Vec<String>> {
         }
     }
 
+    if let Some(base) = config.authored_base_score {
+        if !base.is_finite() || base < 0.0 {
+            errors.push(format!(
+                "scoring.authored_base_score: must be a non-negative number, got {}",
+                base
+            ));
+        }
+    }
+
     // Validate age factor syntax
     if let Some(ref age) = config.age {
         if let Err(e) = Effect::parse(age) {
@@ -21,27 +31,31 @@ pub fn validate_scoring(config: &ScoringConfig) -> Result<(), Vec<String>> {
         }
     }
 
-    // Validate approvals effect string
-    if let Some(ref approvals) = config.approvals {
-        // For approvals, "per N" means "per N approvals", not per time unit
-        // Convert formats like "+10 per 1" to "+10 per 1sec" for parsing validation
-        let parseable_str = if let Some((effect_part, per_part)) = approvals.split_once(" per ") {
-            // Check if per_part is just a number (no time unit)
-            if per_part.trim().chars().all(|c| c.is_numeric() || c == '.') {
-                format!("{} per 1sec", effect_part)
-            } else {
-                approvals.clone()
+    // Validate approvals effect string or curve
+    // For approvals, "per N" means "per N approvals", not per time unit
+    match config.approvals {
+        Some(ApprovalsConfig::Effect(ref approvals)) => {
+            if let Err(e) = Effect::parse_per_count(approvals) {
+                errors.push(format!(
+                    "scoring.approvals: invalid format '{}' - {}",
+                    approvals, e
+                ));
             }
-        } else {
-            approvals.clone()
-        };
-
-        if let Err(e) = Effect::parse(&parseable_str) {
-            errors.push(format!(
-                "scoring.approvals: invalid format '{}' - {}",
-                approvals, e
-            ));
         }
+        Some(ApprovalsConfig::Curve(ref curve)) => {
+            if curve.peak == 0 {
+                errors.push("scoring.approvals.peak: must be at least 1".to_string());
+            }
+            for (key, effect) in [("boost", &curve.boost), ("decay", &curve.decay)] {
+                if let Err(e) = Effect::parse_per_count(effect) {
+                    errors.push(format!(
+                        "scoring.approvals.{}: invalid format '{}' - {}",
+                        key, effect, e
+                    ));
+                }
+            }
+        }
+        None => {}
     }
 
     // Validate size buckets
@@ -71,11 +85,8 @@ pub fn validate_scoring(config: &ScoringConfig) -> Result<(), Vec<String>> {
         // Validate size exclude patterns
         if let Some(ref excludes) = size_config.exclude {
             for (i, pattern) in excludes.iter().enumerate() {
-                if let Err(e) = glob::Pattern::new(pattern) {
-                    errors.push(format!(
-                        "scoring.size.exclude[{}]: invalid glob pattern '{}' - {}",
-                        i, pattern, e
-                    ));
+                if let Err(e) = ExcludePattern::parse(pattern) {
+                    errors.push(format!("scoring.size.exclude[{}]: {}", i, e));
                 }
             }
         }
@@ -87,11 +98,28 @@ pub fn validate_scoring(config: &ScoringConfig) -> Result<(), Vec<String>> {
             if label_effect.name.trim().is_empty() {
                 errors.push(format!("scoring.labels[{}].name: must not be empty", i));
             }
-            if let Err(e) = Effect::parse(&label_effect.effect) {
+            // effect may be omitted only when the label sets the base score
+            let effect_omitted = label_effect.effect.trim().is_empty();
+            if effect_omitted && label_effect.set_base.is_none() {
                 errors.push(format!(
-                    "scoring.labels[{}].effect: invalid '{}' - {}",
-                    i, label_effect.effect, e
+                    "scoring.labels[{}]: must have an effect or set_base",
+                    i
                 ));
+            } else if !effect_omitted {
+                if let Err(e) = Effect::parse(&label_effect.effect) {
+                    errors.push(format!(
+                        "scoring.labels[{}].effect: invalid '{}' - {}",
+                        i, label_effect.effect, e
+                    ));
+                }
+            }
+            if let Some(base) = label_effect.set_base {
+                if !base.is_finite() || base < 0.0 {
+                    errors.push(format!(
+                        "scoring.labels[{}].set_base: must be a non-negative number, got {}",
+                        i, base
+                    ));
+                }
             }
         }
     }
@@ -113,6 +141,138 @@ pub fn validate_scoring(config: &ScoringConfig) -> Result<(), Vec<String>> {
         }
     }
 
+    // Validate approved_by_trusted effect
+    if let Some(ref trusted) = config.approved_by_trusted {
+        if let Err(e) = Effect::parse(trusted) {
+            errors.push(format!(
+                "scoring.approved_by_trusted: invalid '{}' - {}",
+                trusted, e
+            ));
+        }
+    }
+
+    // Validate changes_requested effect ("per N" means per N change requests)
+    if let Some(ref changes) = config.changes_requested {
+        if let Err(e) = Effect::parse_per_count(changes) {
+            errors.push(format!(
+                "scoring.changes_requested: invalid '{}' - {}",
+                changes, e
+            ));
+        }
+    }
+
+    // Validate reactions effect ("per N" means per N reactions)
+    if let Some(ref reactions) = config.reactions {
+        if let Err(e) = Effect::parse_per_count(reactions) {
+            errors.push(format!(
+                "scoring.reactions: invalid '{}' - {}",
+                reactions, e
+            ));
+        }
+    }
+
+    // Validate comments effect ("per N" means per N comments)
+    if let Some(ref comments) = config.comments {
+        if let Err(e) = Effect::parse_per_count(comments) {
+            errors.push(format!("scoring.comments: invalid '{}' - {}", comments, e));
+        }
+    }
+
+    // Validate team_review_requested effect
+    if let Some(ref team) = config.team_review_requested {
+        if let Err(e) = Effect::parse(team) {
+            errors.push(format!(
+                "scoring.team_review_requested: invalid '{}' - {}",
+                team, e
+            ));
+        }
+    }
+
+    // Validate ci_status effects
+    if let Some(ref ci) = config.ci_status {
+        for (status, effect) in [
+            ("success", &ci.success),
+            ("failure", &ci.failure),
+            ("pending", &ci.pending),
+        ] {
+            if let Some(effect) = effect {
+                if let Err(e) = Effect::parse(effect) {
+                    errors.push(format!(
+                        "scoring.ci_status.{}: invalid '{}' - {}",
+                        status, effect, e
+                    ));
+                }
+            }
+        }
+    }
+
+    // Validate commits_behind effect ("per N" means per N commits)
+    if let Some(ref behind) = config.commits_behind {
+        if let Err(e) = Effect::parse_per_count(behind) {
+            errors.push(format!(
+                "scoring.commits_behind: invalid '{}' - {}",
+                behind, e
+            ));
+        }
+    }
+
+    // Validate updated_since_my_review effect
+    if let Some(ref updated) = config.updated_since_my_review {
+        if let Err(e) = Effect::parse(updated) {
+            errors.push(format!(
+                "scoring.updated_since_my_review: invalid '{}' - {}",
+                updated, e
+            ));
+        }
+    }
+
+    // Validate re_review_requested effect
+    if let Some(ref re_review) = config.re_review_requested {
+        if let Err(e) = Effect::parse(re_review) {
+            errors.push(format!(
+                "scoring.re_review_requested: invalid '{}' - {}",
+                re_review, e
+            ));
+        }
+    }
+
+    // Validate disabled factor names
+    for name in config.disabled_factors.iter().flatten() {
+        if !FACTOR_NAMES.contains(&name.as_str()) {
+            errors.push(format!(
+                "scoring.disabled_factors: unknown factor '{}' (expected one of: {})",
+                name,
+                FACTOR_NAMES.join(", ")
+            ));
+        }
+    }
+
+    // Validate review SLA
+    if let Some(ref sla) = config.sla {
+        match humantime::parse_duration(&sla.within) {
+            Ok(d) if d.is_zero() => {
+                errors.push("scoring.sla.within: must be greater than zero".to_string())
+            }
+            Ok(_) => {}
+            Err(e) => errors.push(format!(
+                "scoring.sla.within: invalid '{}' - {}",
+                sla.within, e
+            )),
+        }
+        if let Some(ref effect) = sla.effect {
+            if let Err(e) = Effect::parse(effect) {
+                errors.push(format!("scoring.sla.effect: invalid '{}' - {}", effect, e));
+            }
+        }
+    }
+
+    // Validate authored effect
+    if let Some(ref authored) = config.authored {
+        if let Err(e) = Effect::parse(authored) {
+            errors.push(format!("scoring.authored: invalid '{}' - {}", authored, e));
+        }
+    }
+
     if errors.is_empty() {
         Ok(())
     } else {
@@ -182,18 +342,32 @@ fn do_ranges_overlap(r1: &RangeOp, r2: &RangeOp) -> bool {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::scoring::{LabelEffect, SizeBucket, SizeConfig};
+    use crate::scoring::{ApprovalsCurve, LabelEffect, SizeBucket, SizeConfig, SlaConfig};
 
     #[test]
     fn test_valid_config() {
         let config = ScoringConfig {
             base_score: Some(100.0),
             age: Some("+1 per 1h".to_string()),
-            approvals: Some("x0.5".to_string()),
+            age_basis: None,
+            approvals: Some(ApprovalsConfig::Effect("x0.5".to_string())),
             size: None,
             labels: None,
             previously_reviewed: None,
             draft: None,
+            approved_by_trusted: None,
+            updated_since_my_review: None,
+            re_review_requested: None,
+            changes_requested: None,
+            reactions: None,
+            comments: None,
+            team_review_requested: None,
+            ci_status: None,
+            commits_behind: None,
+            authored: None,
+            authored_base_score: None,
+            sla: None,
+            disabled_factors: None,
         };
         assert!(validate_scoring(&config).is_ok());
     }
@@ -203,11 +377,25 @@ mod tests {
         let config = ScoringConfig {
             base_score: None,
             age: None,
+            age_basis: None,
             approvals: None,
             size: None,
             labels: None,
             previously_reviewed: None,
             draft: None,
+            approved_by_trusted: None,
+            updated_since_my_review: None,
+            re_review_requested: None,
+            changes_requested: None,
+            reactions: None,
+            comments: None,
+            team_review_requested: None,
+            ci_status: None,
+            commits_behind: None,
+            authored: None,
+            authored_base_score: None,
+            sla: None,
+            disabled_factors: None,
         };
         assert!(validate_scoring(&config).is_ok());
     }
@@ -217,11 +405,25 @@ mod tests {
         let config = ScoringConfig {
             base_score: None,
             age: Some("invalid".to_string()),
+            age_basis: None,
             approvals: None,
             size: None,
             labels: None,
             previously_reviewed: None,
             draft: None,
+            approved_by_trusted: None,
+            updated_since_my_review: None,
+            re_review_requested: None,
+            changes_requested: None,
+            reactions: None,
+            comments: None,
+            team_review_requested: None,
+            ci_status: None,
+            commits_behind: None,
+            authored: None,
+            authored_base_score: None,
+            sla: None,
+            disabled_factors: None,
         };
         let result = validate_scoring(&config);
         assert!(result.is_err());
@@ -234,11 +436,25 @@ mod tests {
         let config = ScoringConfig {
             base_score: Some(-10.0),
             age: None,
+            age_basis: None,
             approvals: None,
             size: None,
             labels: None,
             previously_reviewed: None,
             draft: None,
+            approved_by_trusted: None,
+            updated_since_my_review: None,
+            re_review_requested: None,
+            changes_requested: None,
+            reactions: None,
+            comments: None,
+            team_review_requested: None,
+            ci_status: None,
+            commits_behind: None,
+            authored: None,
+            authored_base_score: None,
+            sla: None,
+            disabled_factors: None,
         };
         let result = validate_scoring(&config);
         assert!(result.is_err());
@@ -251,11 +467,25 @@ mod tests {
         let config = ScoringConfig {
             base_score: None,
             age: None,
-            approvals: Some("invalid".to_string()),
+            age_basis: None,
+            approvals: Some(ApprovalsConfig::Effect("invalid".to_string())),
             size: None,
             labels: None,
             previously_reviewed: None,
             draft: None,
+            approved_by_trusted: None,
+            updated_since_my_review: None,
+            re_review_requested: None,
+            changes_requested: None,
+            reactions: None,
+            comments: None,
+            team_review_requested: None,
+            ci_status: None,
+            commits_behind: None,
+            authored: None,
+            authored_base_score: None,
+            sla: None,
+            disabled_factors: None,
         };
         let result = validate_scoring(&config);
         assert!(result.is_err());
@@ -263,14 +493,63 @@ mod tests {
         assert!(errors[0].contains("scoring.approvals"));
     }
 
+    #[test]
+    fn test_approvals_per_zero_rejected() {
+        let config = ScoringConfig {
+            base_score: None,
+            age: None,
+            age_basis: None,
+            approvals: Some(ApprovalsConfig::Effect("+5 per 0".to_string())),
+            size: None,
+            labels: None,
+            previously_reviewed: None,
+            draft: None,
+            approved_by_trusted: None,
+            updated_since_my_review: None,
+            re_review_requested: None,
+            changes_requested: None,
+            reactions: None,
+            comments: None,
+            team_review_requested: None,
+            ci_status: None,
+            commits_behind: None,
+            authored: None,
+            authored_base_score: None,
+            sla: None,
+            disabled_factors: None,
+        };
+        let result = validate_scoring(&config);
+        assert!(result.is_err());
+        assert!(result.unwrap_err()[0].contains("scoring.approvals"));
+    }
+
+    #[test]
+    fn test_approvals_curve_validated() {
+        let config = ScoringConfig {
+            approvals: Some(ApprovalsConfig::Curve(ApprovalsCurve {
+                peak: 0,
+                boost: "x2".to_string(),
+                decay: "sideways".to_string(),
+            })),
+            ..ScoringConfig::default()
+        };
+        let errors = validate_scoring(&config).unwrap_err();
+        assert_eq!(errors.len(), 2);
+        assert!(errors[0].starts_with("scoring.approvals.peak:"));
+        assert!(errors[1].starts_with("scoring.approvals.decay: invalid format 'sideways'"));
+    }
+
     #[test]
     fn test_invalid_size_bucket() {
         let config = ScoringConfig {
             base_score: None,
             age: None,
+            age_basis: None,
             approvals: None,
             size: Some(SizeConfig {
                 exclude: None,
+                metric: None,
+                on_exclude_failure: None,
                 buckets: Some(vec![SizeBucket {
                     range: "<100".to_string(),
                     effect: "bad".to_string(),
@@ -279,6 +558,19 @@ mod tests {
             labels: None,
             previously_reviewed: None,
             draft: None,
+            approved_by_trusted: None,
+            updated_since_my_review: None,
+            re_review_requested: None,
+            changes_requested: None,
+            reactions: None,
+            comments: None,
+            team_review_requested: None,
+            ci_status: None,
+            commits_behind: None,
+            authored: None,
+            authored_base_score: None,
+            sla: None,
+            disabled_factors: None,
         };
         let result = validate_scoring(&config);
         assert!(result.is_err());
@@ -291,11 +583,25 @@ mod tests {
         let config = ScoringConfig {
             base_score: Some(-10.0),      // Error 1
             age: Some("bad".to_string()), // Error 2
+            age_basis: None,
             approvals: None,
             size: None,
             labels: None,
             previously_reviewed: None,
             draft: None,
+            approved_by_trusted: None,
+            updated_since_my_review: None,
+            re_review_requested: None,
+            changes_requested: None,
+            reactions: None,
+            comments: None,
+            team_review_requested: None,
+            ci_status: None,
+            commits_behind: None,
+            authored: None,
+            authored_base_score: None,
+            sla: None,
+            disabled_factors: None,
         };
         let result = validate_scoring(&config);
         assert!(result.is_err());
@@ -320,9 +626,12 @@ mod tests {
         let config = ScoringConfig {
             base_score: None,
             age: None,
+            age_basis: None,
             approvals: None,
             size: Some(SizeConfig {
                 exclude: None,
+                metric: None,
+                on_exclude_failure: None,
                 buckets: Some(vec![
                     SizeBucket {
                         range: "<100".to_string(),
@@ -337,6 +646,19 @@ mod tests {
             labels: None,
             previously_reviewed: None,
             draft: None,
+            approved_by_trusted: None,
+            updated_since_my_review: None,
+            re_review_requested: None,
+            changes_requested: None,
+            reactions: None,
+            comments: None,
+            team_review_requested: None,
+            ci_status: None,
+            commits_behind: None,
+            authored: None,
+            authored_base_score: None,
+            sla: None,
+            disabled_factors: None,
         };
         assert!(validate_scoring(&config).is_ok());
     }
@@ -346,9 +668,12 @@ mod tests {
         let config = ScoringConfig {
             base_score: None,
             age: None,
+            age_basis: None,
             approvals: None,
             size: Some(SizeConfig {
                 exclude: None,
+                metric: None,
+                on_exclude_failure: None,
                 buckets: Some(vec![
                     SizeBucket {
                         range: "<=100".to_string(),
@@ -363,6 +688,19 @@ mod tests {
             labels: None,
             previously_reviewed: None,
             draft: None,
+            approved_by_trusted: None,
+            updated_since_my_review: None,
+            re_review_requested: None,
+            changes_requested: None,
+            reactions: None,
+            comments: None,
+            team_review_requested: None,
+            ci_status: None,
+            commits_behind: None,
+            authored: None,
+            authored_base_score: None,
+            sla: None,
+            disabled_factors: None,
         };
         let result = validate_scoring(&config);
         assert!(result.is_err());
@@ -377,9 +715,12 @@ mod tests {
         let config = ScoringConfig {
             base_score: None,
             age: None,
+            age_basis: None,
             approvals: None,
             size: Some(SizeConfig {
                 exclude: None,
+                metric: None,
+                on_exclude_failure: None,
                 buckets: Some(vec![
                     SizeBucket {
                         range: "100-500".to_string(),
@@ -394,6 +735,19 @@ mod tests {
             labels: None,
             previously_reviewed: None,
             draft: None,
+            approved_by_trusted: None,
+            updated_since_my_review: None,
+            re_review_requested: None,
+            changes_requested: None,
+            reactions: None,
+            comments: None,
+            team_review_requested: None,
+            ci_status: None,
+            commits_behind: None,
+            authored: None,
+            authored_base_score: None,
+            sla: None,
+            disabled_factors: None,
         };
         let result = validate_scoring(&config);
         assert!(result.is_err());
@@ -406,9 +760,12 @@ mod tests {
         let config = ScoringConfig {
             base_score: None,
             age: None,
+            age_basis: None,
             approvals: None,
             size: Some(SizeConfig {
                 exclude: None,
+                metric: None,
+                on_exclude_failure: None,
                 buckets: Some(vec![
                     SizeBucket {
                         range: "100-200".to_string(),
@@ -423,6 +780,19 @@ mod tests {
             labels: None,
             previously_reviewed: None,
             draft: None,
+            approved_by_trusted: None,
+            updated_since_my_review: None,
+            re_review_requested: None,
+            changes_requested: None,
+            reactions: None,
+            comments: None,
+            team_review_requested: None,
+            ci_status: None,
+            commits_behind: None,
+            authored: None,
+            authored_base_score: None,
+            sla: None,
+            disabled_factors: None,
         };
         assert!(validate_scoring(&config).is_ok());
     }
@@ -432,9 +802,12 @@ mod tests {
         let config = ScoringConfig {
             base_score: None,
             age: None,
+            age_basis: None,
             approvals: None,
             size: Some(SizeConfig {
                 exclude: None,
+                metric: None,
+                on_exclude_failure: None,
                 buckets: Some(vec![
                     SizeBucket {
                         range: "150".to_string(),
@@ -449,6 +822,19 @@ mod tests {
             labels: None,
             previously_reviewed: None,
             draft: None,
+            approved_by_trusted: None,
+            updated_since_my_review: None,
+            re_review_requested: None,
+            changes_requested: None,
+            reactions: None,
+            comments: None,
+            team_review_requested: None,
+            ci_status: None,
+            commits_behind: None,
+            authored: None,
+            authored_base_score: None,
+            sla: None,
+            disabled_factors: None,
         };
         let result = validate_scoring(&config);
         assert!(result.is_err());
@@ -461,9 +847,12 @@ mod tests {
         let config = ScoringConfig {
             base_score: None,
             age: None,
+            age_basis: None,
             approvals: None,
             size: Some(SizeConfig {
                 exclude: None,
+                metric: None,
+                on_exclude_failure: None,
                 buckets: Some(vec![
                     SizeBucket {
                         range: "<100".to_string(),
@@ -478,6 +867,19 @@ mod tests {
             labels: None,
             previously_reviewed: None,
             draft: None,
+            approved_by_trusted: None,
+            updated_since_my_review: None,
+            re_review_requested: None,
+            changes_requested: None,
+            reactions: None,
+            comments: None,
+            team_review_requested: None,
+            ci_status: None,
+            commits_behind: None,
+            authored: None,
+            authored_base_score: None,
+            sla: None,
+            disabled_factors: None,
         };
         let result = validate_scoring(&config);
         assert!(result.is_err());
@@ -490,9 +892,12 @@ mod tests {
         let config = ScoringConfig {
             base_score: None,
             age: None,
+            age_basis: None,
             approvals: None,
             size: Some(SizeConfig {
                 exclude: None,
+                metric: None,
+                on_exclude_failure: None,
                 buckets: Some(vec![
                     SizeBucket {
                         range: ">200".to_string(),
@@ -507,6 +912,19 @@ mod tests {
             labels: None,
             previously_reviewed: None,
             draft: None,
+            approved_by_trusted: None,
+            updated_since_my_review: None,
+            re_review_requested: None,
+            changes_requested: None,
+            reactions: None,
+            comments: None,
+            team_review_requested: None,
+            ci_status: None,
+            commits_behind: None,
+            authored: None,
+            authored_base_score: None,
+            sla: None,
+            disabled_factors: None,
         };
         assert!(validate_scoring(&config).is_ok());
     }
@@ -516,20 +934,36 @@ mod tests {
         let config = ScoringConfig {
             base_score: None,
             age: None,
+            age_basis: None,
             approvals: None,
             size: None,
             labels: Some(vec![
                 LabelEffect {
                     name: "urgent".to_string(),
                     effect: "+10".to_string(),
+                    set_base: None,
                 },
                 LabelEffect {
                     name: "wip".to_string(),
                     effect: "x0.5".to_string(),
+                    set_base: None,
                 },
             ]),
             previously_reviewed: None,
             draft: None,
+            approved_by_trusted: None,
+            updated_since_my_review: None,
+            re_review_requested: None,
+            changes_requested: None,
+            reactions: None,
+            comments: None,
+            team_review_requested: None,
+            ci_status: None,
+            commits_behind: None,
+            authored: None,
+            authored_base_score: None,
+            sla: None,
+            disabled_factors: None,
         };
         assert!(validate_scoring(&config).is_ok());
     }
@@ -539,14 +973,29 @@ mod tests {
         let config = ScoringConfig {
             base_score: None,
             age: None,
+            age_basis: None,
             approvals: None,
             size: None,
             labels: Some(vec![LabelEffect {
                 name: "urgent".to_string(),
                 effect: "bad".to_string(),
+                set_base: None,
             }]),
             previously_reviewed: None,
             draft: None,
+            approved_by_trusted: None,
+            updated_since_my_review: None,
+            re_review_requested: None,
+            changes_requested: None,
+            reactions: None,
+            comments: None,
+            team_review_requested: None,
+            ci_status: None,
+            commits_behind: None,
+            authored: None,
+            authored_base_score: None,
+            sla: None,
+            disabled_factors: None,
         };
         let result = validate_scoring(&config);
         assert!(result.is_err());
@@ -555,19 +1004,173 @@ mod tests {
         assert!(errors[0].contains("bad"));
     }
 
+    #[test]
+    fn test_label_set_base_without_effect_is_valid() {
+        let config = ScoringConfig {
+            base_score: None,
+            age: None,
+            age_basis: None,
+            approvals: None,
+            size: None,
+            labels: Some(vec![LabelEffect {
+                name: "hotfix".to_string(),
+                effect: String::new(),
+                set_base: Some(1000.0),
+            }]),
+            previously_reviewed: None,
+            draft: None,
+            approved_by_trusted: None,
+            updated_since_my_review: None,
+            re_review_requested: None,
+            changes_requested: None,
+            reactions: None,
+            comments: None,
+            team_review_requested: None,
+            ci_status: None,
+            commits_behind: None,
+            authored: None,
+            authored_base_score: None,
+            sla: None,
+            disabled_factors: None,
+        };
+        assert!(validate_scoring(&config).is_ok());
+    }
+
+    #[test]
+    fn test_invalid_authored_settings() {
+        let config = ScoringConfig {
+            authored: Some("x".to_string()),
+            authored_base_score: Some(-5.0),
+            ..ScoringConfig::default()
+        };
+        let errors = validate_scoring(&config).unwrap_err();
+        assert_eq!(errors.len(), 2);
+        assert!(errors.iter().any(|e| e.starts_with("scoring.authored:")));
+        assert!(errors
+            .iter()
+            .any(|e| e.starts_with("scoring.authored_base_score:")));
+    }
+
+    #[test]
+    fn test_unknown_disabled_factor() {
+        let config = ScoringConfig {
+            disabled_factors: Some(vec!["size".to_string(), "sizes".to_string()]),
+            ..ScoringConfig::default()
+        };
+        let errors = validate_scoring(&config).unwrap_err();
+        assert_eq!(errors.len(), 1);
+        assert!(errors[0].starts_with("scoring.disabled_factors: unknown factor 'sizes'"));
+    }
+
+    #[test]
+    fn test_invalid_sla() {
+        let config = ScoringConfig {
+            sla: Some(SlaConfig {
+                within: "soon".to_string(),
+                effect: Some("x".to_string()),
+                business_days: true,
+            }),
+            ..ScoringConfig::default()
+        };
+        let errors = validate_scoring(&config).unwrap_err();
+        assert!(errors.iter().any(|e| e.starts_with("scoring.sla.within:")));
+        assert!(errors.iter().any(|e| e.starts_with("scoring.sla.effect:")));
+    }
+
+    #[test]
+    fn test_label_without_effect_or_set_base() {
+        let config = ScoringConfig {
+            base_score: None,
+            age: None,
+            age_basis: None,
+            approvals: None,
+            size: None,
+            labels: Some(vec![LabelEffect {
+                name: "hotfix".to_string(),
+                effect: String::new(),
+                set_base: None,
+            }]),
+            previously_reviewed: None,
+            draft: None,
+            approved_by_trusted: None,
+            updated_since_my_review: None,
+            re_review_requested: None,
+            changes_requested: None,
+            reactions: None,
+            comments: None,
+            team_review_requested: None,
+            ci_status: None,
+            commits_behind: None,
+            authored: None,
+            authored_base_score: None,
+            sla: None,
+            disabled_factors: None,
+        };
+        let errors = validate_scoring(&config).unwrap_err();
+        assert!(errors[0].contains("scoring.labels[0]: must have an effect or set_base"));
+    }
+
+    #[test]
+    fn test_label_negative_set_base() {
+        let config = ScoringConfig {
+            base_score: None,
+            age: None,
+            age_basis: None,
+            approvals: None,
+            size: None,
+            labels: Some(vec![LabelEffect {
+                name: "hotfix".to_string(),
+                effect: String::new(),
+                set_base: Some(-5.0),
+            }]),
+            previously_reviewed: None,
+            draft: None,
+            approved_by_trusted: None,
+            updated_since_my_review: None,
+            re_review_requested: None,
+            changes_requested: None,
+            reactions: None,
+            comments: None,
+            team_review_requested: None,
+            ci_status: None,
+            commits_behind: None,
+            authored: None,
+            authored_base_score: None,
+            sla: None,
+            disabled_factors: None,
+        };
+        let errors = validate_scoring(&config).unwrap_err();
+        assert!(errors[0].contains("scoring.labels[0].set_base"));
+    }
+
     #[test]
     fn test_empty_label_name() {
         let config = ScoringConfig {
             base_score: None,
             age: None,
+            age_basis: None,
             approvals: None,
             size: None,
             labels: Some(vec![LabelEffect {
                 name: "  ".to_string(),
                 effect: "+10".to_string(),
+                set_base: None,
             }]),
             previously_reviewed: None,
             draft: None,
+            approved_by_trusted: None,
+            updated_since_my_review: None,
+            re_review_requested: None,
+            changes_requested: None,
+            reactions: None,
+            comments: None,
+            team_review_requested: None,
+            ci_status: None,
+            commits_behind: None,
+            authored: None,
+            authored_base_score: None,
+            sla: None,
+            disabled_factors: None,
         };
         let result = validate_scoring(&config);
         assert!(result.is_err());
@@ -581,11 +1184,25 @@ mod tests {
         let config = ScoringConfig {
             base_score: None,
             age: None,
+            age_basis: None,
             approvals: None,
             size: None,
             labels: None,
             previously_reviewed: Some("x0.5".to_string()),
             draft: None,
+            approved_by_trusted: None,
+            updated_since_my_review: None,
+            re_review_requested: None,
+            changes_requested: None,
+            reactions: None,
+            comments: None,
+            team_review_requested: None,
+            ci_status: None,
+            commits_behind: None,
+            authored: None,
+            authored_base_score: None,
+            sla: None,
+            disabled_factors: None,
         };
         assert!(validate_scoring(&config).is_ok());
     }
@@ -595,11 +1212,25 @@ mod tests {
         let config = ScoringConfig {
             base_score: None,
             age: None,
+            age_basis: None,
             approvals: None,
             size: None,
             labels: None,
             previously_reviewed: Some("invalid".to_string()),
             draft: None,
+            approved_by_trusted: None,
+            updated_since_my_review: None,
+            re_review_requested: None,
+            changes_requested: None,
+            reactions: None,
+            comments: None,
+            team_review_requested: None,
+            ci_status: None,
+            commits_behind: None,
+            authored: None,
+            authored_base_score: None,
+            sla: None,
+            disabled_factors: None,
         };
         let result = validate_scoring(&config);
         assert!(result.is_err());
@@ -613,9 +1244,12 @@ mod tests {
         let config = ScoringConfig {
             base_score: None,
             age: None,
+            age_basis: None,
             approvals: None,
             size: Some(SizeConfig {
                 exclude: Some(vec!["*.lock".to_string(), "*.json".to_string()]),
+                metric: None,
+                on_exclude_failure: None,
                 buckets: Some(vec![SizeBucket {
                     range: "<100".to_string(),
                     effect: "x5".to_string(),
@@ -624,6 +1258,19 @@ mod tests {
             labels: None,
             previously_reviewed: None,
             draft: None,
+            approved_by_trusted: None,
+            updated_since_my_review: None,
+            re_review_requested: None,
+            changes_requested: None,
+            reactions: None,
+            comments: None,
+            team_review_requested: None,
+            ci_status: None,
+            commits_behind: None,
+            authored: None,
+            authored_base_score: None,
+            sla: None,
+            disabled_factors: None,
         };
         assert!(validate_scoring(&config).is_ok());
     }
@@ -633,9 +1280,12 @@ mod tests {
         let config = ScoringConfig {
             base_score: None,
             age: None,
+            age_basis: None,
             approvals: None,
             size: Some(SizeConfig {
                 exclude: Some(vec!["[invalid".to_string()]),
+                metric: None,
+                on_exclude_failure: None,
                 buckets: Some(vec![SizeBucket {
                     range: "<100".to_string(),
                     effect: "x5".to_string(),
@@ -644,6 +1294,19 @@ mod tests {
             labels: None,
             previously_reviewed: None,
             draft: None,
+            approved_by_trusted: None,
+            updated_since_my_review: None,
+            re_review_requested: None,
+            changes_requested: None,
+            reactions: None,
+            comments: None,
+            team_review_requested: None,
+            ci_status: None,
+            commits_behind: None,
+            authored: None,
+            authored_base_score: None,
+            sla: None,
+            disabled_factors: None,
         };
         let result = validate_scoring(&config);
         assert!(result.is_err());
@@ -657,9 +1320,12 @@ mod tests {
         let config = ScoringConfig {
             base_score: Some(-10.0), // Error 1
             age: None,
+            age_basis: None,
             approvals: None,
             size: Some(SizeConfig {
                 exclude: Some(vec!["[bad".to_string()]), // Error 2
+                metric: None,
+                on_exclude_failure: None,
                 buckets: Some(vec![SizeBucket {
                     range: "<100".to_string(),
                     effect: "x5".to_string(),
@@ -668,6 +1334,19 @@ mod tests {
             labels: None,
             previously_reviewed: None,
             draft: None,
+            approved_by_trusted: None,
+            updated_since_my_review: None,
+            re_review_requested: None,
+            changes_requested: None,
+            reactions: None,
+            comments: None,
+            team_review_requested: None,
+            ci_status: None,
+            commits_behind: None,
+            authored: None,
+            authored_base_score: None,
+            sla: None,
+            disabled_factors: None,
         };
         let result = validate_scoring(&config);
         assert!(result.is_err());
@@ -682,16 +1361,31 @@ mod tests {
         let config = ScoringConfig {
             base_score: Some(-10.0),      // Error 1
             age: Some("bad".to_string()), // Error 2
+            age_basis: None,
             approvals: None,
             size: None,
             labels: Some(vec![
                 LabelEffect {
                     name: "".to_string(),
                     effect: "bad".to_string(),
+                    set_base: None,
                 }, // Error 3 & 4
             ]),
             previously_reviewed: Some("invalid".to_string()), // Error 5
             draft: None,
+            approved_by_trusted: None,
+            updated_since_my_review: None,
+            re_review_requested: None,
+            changes_requested: None,
+            reactions: None,
+            comments: None,
+            team_review_requested: None,
+            ci_status: None,
+            commits_behind: None,
+            authored: None,
+            authored_base_score: None,
+            sla: None,
+            disabled_factors: None,
         };
         let result = validate_scoring(&config);
         assert!(result.is_err());
@@ -0,0 +1,78 @@
+//! Upgrades older `snooze.json` files to the current schema.
+//!
+//! To change the format, append a step to `MIGRATIONS` that rewrites the
+//! previous version's JSON into the new shape; `CURRENT_VERSION` follows.
+//! See `crate::state_file` for how the steps are applied.
+
+use crate::state_file::Migration;
+use anyhow::Result;
+use serde_json::Value;
+
+/// `MIGRATIONS[i]` upgrades version `i + 1` to `i + 2`; no upgrades exist yet
+pub const MIGRATIONS: &[Migration] = &[];
+
+/// Schema version written by this build
+pub const CURRENT_VERSION: u32 = MIGRATIONS.len() as u32 + 1;
+
+/// Upgrade a raw snooze state document to `CURRENT_VERSION`
+pub fn migrate(value: Value) -> Result<Value> {
+    crate::state_file::migrate_to(value, "snooze state", CURRENT_VERSION, MIGRATIONS)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::state_file::migrate_to;
+    use anyhow::anyhow;
+    use serde_json::json;
+
+    fn v1_state() -> Value {
+        json!({
+            "version": 1,
+            "snoozed": {
+                "https://github.com/owner/repo/pull/1": {
+                    "snoozed_at": "2025-01-01T00:00:00Z",
+                    "snooze_until": null
+                }
+            }
+        })
+    }
+
+    /// Example future step: v1 -> v2 adds a per-entry snooze count
+    fn add_snooze_count(value: &mut Value) -> Result<()> {
+        let entries = value["snoozed"]
+            .as_object_mut()
+            .ok_or_else(|| anyhow!("snoozed is not an object"))?;
+        for entry in entries.values_mut() {
+            entry["snooze_count"] = json!(1);
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_current_version_is_unchanged() {
+        assert_eq!(migrate(v1_state()).unwrap(), v1_state());
+    }
+
+    #[test]
+    fn test_migrates_v1_through_steps_and_bumps_version() {
+        let migrated = migrate_to(v1_state(), "snooze state", 2, &[add_snooze_count]).unwrap();
+        assert_eq!(migrated["version"], 2);
+        assert_eq!(
+            migrated["snoozed"]["https://github.com/owner/repo/pull/1"]["snooze_count"],
+            1
+        );
+    }
+
+    #[test]
+    fn test_rejects_newer_missing_and_unmigratable_versions() {
+        let newer = json!({ "version": 99, "snoozed": {} });
+        assert!(migrate(newer).unwrap_err().to_string().contains("newer"));
+
+        let missing = json!({ "snoozed": {} });
+        assert!(migrate(missing).is_err());
+
+        // Target 3 but only a v1 -> v2 step is registered
+        assert!(migrate_to(v1_state(), "snooze state", 3, &[add_snooze_count]).is_err());
+    }
+}
@@ -1,7 +1,10 @@
 pub mod filter;
+pub mod migrate;
 pub mod storage;
+pub mod toggle;
 pub mod types;
 
 pub use filter::{filter_active_prs, filter_snoozed_prs};
 pub use storage::{get_snooze_path, load_snooze_state, save_snooze_state};
+pub use toggle::{resolve_toggle_target, ToggleTarget};
 pub use types::{SnoozeEntry, SnoozeState};
@@ -1,7 +1,6 @@
+use super::migrate::MIGRATIONS;
 use super::types::SnoozeState;
-use anyhow::{Context, Result};
-use atomic_write_file::AtomicWriteFile;
-use std::fs::File;
+use anyhow::Result;
 use std::path::{Path, PathBuf};
 
 /// Get the default snooze state file path (~/.config/pr-bro/snooze.json)
@@ -12,45 +11,15 @@ pub fn get_snooze_path() -> PathBuf {
 /// Load snooze state from a JSON file
 ///
 /// If the file doesn't exist, returns a new empty state.
-/// If the file exists but has an unsupported version, returns an error.
+/// Older versions are migrated to the current schema (and saved as such on
+/// the next save); versions newer than this build supports return an error.
 pub fn load_snooze_state(path: &Path) -> Result<SnoozeState> {
-    if !path.exists() {
-        return Ok(SnoozeState::new());
-    }
-
-    let file = File::open(path)
-        .with_context(|| format!("Failed to open snooze state file at {}", path.display()))?;
-
-    let state: SnoozeState =
-        serde_json::from_reader(file).context("Failed to load snooze state")?;
-
-    // Version check
-    if state.version != 1 {
-        anyhow::bail!("Unsupported snooze state version: {}", state.version);
-    }
-
-    Ok(state)
+    crate::state_file::load_state(path, "snooze state", MIGRATIONS)
 }
 
 /// Save snooze state to a JSON file atomically
-///
-/// Uses atomic-write-file to ensure the file is never left in a corrupted state.
-/// Creates the config directory if it doesn't exist.
 pub fn save_snooze_state(path: &Path, state: &SnoozeState) -> Result<()> {
-    // Ensure config directory exists
-    crate::config::ensure_config_dir()?;
-
-    // Open atomic write file
-    let mut file = AtomicWriteFile::open(path)
-        .with_context(|| format!("Failed to open atomic write file at {}", path.display()))?;
-
-    // Write JSON with pretty formatting
-    serde_json::to_writer_pretty(&mut file, state).context("Failed to serialize snooze state")?;
-
-    // Commit the write atomically
-    file.commit().context("Failed to save snooze state")?;
-
-    Ok(())
+    crate::state_file::save_state(path, "snooze state", state)
 }
 
 #[cfg(test)]
@@ -100,4 +69,28 @@ mod tests {
         // Cleanup
         let _ = std::fs::remove_file(&temp_path);
     }
+
+    #[test]
+    fn test_load_v1_file() {
+        let temp_path = env::temp_dir().join("pr_bro_test_v1.json");
+        std::fs::write(
+            &temp_path,
+            r#"{
+  "version": 1,
+  "snoozed": {
+    "https://github.com/owner/repo/pull/1": {
+      "snoozed_at": "2025-01-01T00:00:00Z",
+      "snooze_until": null
+    }
+  }
+}"#,
+        )
+        .unwrap();
+
+        let loaded = load_snooze_state(&temp_path).unwrap();
+        assert_eq!(loaded.version, crate::snooze::migrate::CURRENT_VERSION);
+        assert!(loaded.is_snoozed("https://github.com/owner/repo/pull/1"));
+
+        let _ = std::fs::remove_file(&temp_path);
+    }
 }
@@ -0,0 +1,161 @@
+use crate::config::IndexStyle;
+use crate::github::types::PullRequest;
+use crate::scoring::ScoreResult;
+use anyhow::{bail, Result};
+
+/// The PR `snooze toggle` acts on
+#[derive(Debug, Clone, PartialEq)]
+pub struct ToggleTarget {
+    pub url: String,
+    /// "PR #N: title" for a listed PR, otherwise the URL
+    pub label: String,
+}
+
+/// Resolve a `snooze toggle` target.
+///
+/// An index addresses the active list, or the snoozed list (as shown by
+/// `list --show-snoozed`) when `in_snoozed` is set. Anything else must be the
+/// URL of a listed PR, or a GitHub pull request or issue URL, so a typo is
+/// rejected rather than saved as a snooze entry.
+pub fn resolve_toggle_target(
+    target: &str,
+    style: IndexStyle,
+    active: &[(PullRequest, ScoreResult)],
+    snoozed: &[(PullRequest, ScoreResult)],
+    in_snoozed: bool,
+) -> Result<ToggleTarget> {
+    let indexed = if in_snoozed { snoozed } else { active };
+    let position =
+        crate::output::resolve_index(style, target, indexed.iter().map(|(pr, _)| pr.url.as_str()));
+    let known = match position {
+        Some(position) => Some(&indexed[position].0),
+        None => active
+            .iter()
+            .chain(snoozed)
+            .map(|(pr, _)| pr)
+            .find(|pr| pr.url == target),
+    };
+    if let Some(pr) = known {
+        return Ok(ToggleTarget {
+            url: pr.url.clone(),
+            label: format!("PR #{}: {}", pr.number, pr.title),
+        });
+    }
+
+    if !is_github_pr_url(target) {
+        let list = if in_snoozed { "snoozed" } else { "active" };
+        bail!(
+            "'{}' is neither an index in the {} list ({} PRs) nor a GitHub pull request URL",
+            target,
+            list,
+            indexed.len()
+        );
+    }
+    Ok(ToggleTarget {
+        url: target.to_string(),
+        label: target.to_string(),
+    })
+}
+
+/// Whether `url` looks like https://github.com/OWNER/REPO/pull/N (or /issues/N)
+fn is_github_pr_url(url: &str) -> bool {
+    let Some(path) = url.strip_prefix("https://github.com/") else {
+        return false;
+    };
+    let parts: Vec<&str> = path.split('/').collect();
+    matches!(
+        parts.as_slice(),
+        [owner, repo, "pull" | "issues", number]
+            if !owner.is_empty()
+                && !repo.is_empty()
+                && !number.is_empty()
+                && number.bytes().all(|b| b.is_ascii_digit())
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::scoring::ScoreBreakdown;
+
+    fn scored(url: &str, number: u64) -> (PullRequest, ScoreResult) {
+        let pr = PullRequest {
+            url: url.to_string(),
+            number,
+            title: format!("PR {}", number),
+            ..PullRequest::test_default()
+        };
+        let result = ScoreResult {
+            score: 0.0,
+            incomplete: false,
+            sla_breached: false,
+            oldest_alert: false,
+            breakdown: ScoreBreakdown {
+                base_score: 0.0,
+                factors: vec![],
+                disabled: vec![],
+            },
+        };
+        (pr, result)
+    }
+
+    #[test]
+    fn test_index_resolves_against_active_or_snoozed_list() {
+        let active = vec![scored("https://github.com/o/r/pull/1", 1)];
+        let snoozed = vec![scored("https://github.com/o/r/pull/2", 2)];
+
+        let target =
+            resolve_toggle_target("1", IndexStyle::Number, &active, &snoozed, false).unwrap();
+        assert_eq!(target.url, "https://github.com/o/r/pull/1");
+        assert_eq!(target.label, "PR #1: PR 1");
+
+        let target =
+            resolve_toggle_target("1", IndexStyle::Number, &active, &snoozed, true).unwrap();
+        assert_eq!(target.url, "https://github.com/o/r/pull/2");
+    }
+
+    #[test]
+    fn test_url_targets() {
+        let snoozed = vec![scored("https://github.com/o/r/pull/2", 2)];
+
+        // A listed PR gets its title
+        let target = resolve_toggle_target(
+            "https://github.com/o/r/pull/2",
+            IndexStyle::Number,
+            &[],
+            &snoozed,
+            false,
+        )
+        .unwrap();
+        assert_eq!(target.label, "PR #2: PR 2");
+
+        // An unlisted pull URL is taken as-is
+        let target = resolve_toggle_target(
+            "https://github.com/o/r/pull/9",
+            IndexStyle::Number,
+            &[],
+            &snoozed,
+            false,
+        )
+        .unwrap();
+        assert_eq!(target.url, "https://github.com/o/r/pull/9");
+    }
+
+    #[test]
+    fn test_rejects_unknown_targets() {
+        let active = vec![scored("https://github.com/o/r/pull/1", 1)];
+        for target in [
+            "5",
+            "typo",
+            "https://github.com/o/r",
+            "https://github.com/o/r/pull/x",
+            "https://example.com/o/r/pull/1",
+        ] {
+            assert!(
+                resolve_toggle_target(target, IndexStyle::Number, &active, &[], false).is_err(),
+                "{} should be rejected",
+                target
+            );
+        }
+    }
+}
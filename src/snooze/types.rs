@@ -58,10 +58,10 @@ impl Default for SnoozeState {
 }
 
 impl SnoozeState {
-    /// Create a new empty snooze state with version 1
+    /// Create a new empty snooze state at the current schema version
     pub fn new() -> Self {
         Self {
-            version: 1,
+            version: super::migrate::CURRENT_VERSION,
             snoozed: HashMap::new(),
         }
     }
@@ -104,6 +104,29 @@ impl SnoozeState {
         });
     }
 
+    /// Heads-up for snoozes ending within `window` of `now`, which will put
+    /// their PRs back in the active list soon: "2 snoozes expire within 6h".
+    /// `window_label` is the window as configured. None when nothing expires.
+    pub fn expiring_notice(
+        &self,
+        window: std::time::Duration,
+        window_label: &str,
+        now: DateTime<Utc>,
+    ) -> Option<String> {
+        let horizon = now + chrono::Duration::from_std(window).ok()?;
+        let count = self
+            .snoozed
+            .values()
+            .filter_map(|entry| entry.snooze_until)
+            .filter(|&until| now < until && until <= horizon)
+            .count();
+        match count {
+            0 => None,
+            1 => Some(format!("1 snooze expires within {}", window_label)),
+            n => Some(format!("{} snoozes expire within {}", n, window_label)),
+        }
+    }
+
     /// Get a reference to all snoozed entries (for listing snoozed PRs)
     pub fn snoozed_entries(&self) -> &HashMap<String, SnoozeEntry> {
         &self.snoozed
@@ -115,6 +138,26 @@ mod tests {
     use super::*;
     use chrono::Duration;
 
+    #[test]
+    fn test_format_remaining_edges() {
+        let entry = |until| SnoozeEntry {
+            snoozed_at: Utc::now(),
+            snooze_until: Some(until),
+        };
+        assert_eq!(
+            entry(Utc::now() - Duration::days(2)).format_remaining(),
+            "expired"
+        );
+        assert_eq!(
+            entry(Utc::now() + Duration::seconds(30)).format_remaining(),
+            "<1m left"
+        );
+        assert_eq!(
+            entry(Utc::now() + Duration::minutes(90)).format_remaining(),
+            "1h left"
+        );
+    }
+
     #[test]
     fn test_new_state_empty() {
         let state = SnoozeState::new();
@@ -260,4 +303,26 @@ mod tests {
             result
         );
     }
+
+    #[test]
+    fn test_expiring_notice() {
+        let now = Utc::now();
+        let window = std::time::Duration::from_secs(6 * 3600);
+        let mut state = SnoozeState::new();
+        assert_eq!(state.expiring_notice(window, "6h", now), None);
+
+        state.snooze("a".to_string(), Some(now + Duration::hours(1)));
+        state.snooze("b".to_string(), Some(now + Duration::hours(5)));
+        state.snooze("c".to_string(), Some(now + Duration::days(2)));
+        state.snooze("d".to_string(), None);
+        assert_eq!(
+            state.expiring_notice(window, "6h", now),
+            Some("2 snoozes expire within 6h".to_string())
+        );
+        state.unsnooze("a");
+        assert_eq!(
+            state.expiring_notice(window, "6h", now),
+            Some("1 snooze expires within 6h".to_string())
+        );
+    }
 }
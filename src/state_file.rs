@@ -0,0 +1,174 @@
+//! Versioned JSON state files under the config directory (snooze, pins,
+//! marks, review requests, last run).
+//!
+//! Every file carries a top-level `version`. To change a format, append a
+//! step to that store's migration list that rewrites the previous version's
+//! JSON into the new shape; the current version is one past the number of
+//! steps. Steps run in order, so a file several versions behind is upgraded
+//! one version at a time.
+
+use anyhow::{anyhow, bail, Context, Result};
+use atomic_write_file::AtomicWriteFile;
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+use serde_json::Value;
+use std::fs::File;
+use std::path::Path;
+
+/// Rewrites a state document from one version to the next
+pub type Migration = fn(&mut Value) -> Result<()>;
+
+/// Schema version written for a store with these migrations
+pub fn current_version(migrations: &[Migration]) -> u32 {
+    migrations.len() as u32 + 1
+}
+
+/// Load a state file, migrating older versions to the current schema
+///
+/// `what` names the file in errors (e.g. "pins"). If the file doesn't exist,
+/// returns the default (empty) state. Versions newer than this build
+/// supports return an error.
+pub fn load_state<T: DeserializeOwned + Default>(
+    path: &Path,
+    what: &str,
+    migrations: &[Migration],
+) -> Result<T> {
+    if !path.exists() {
+        return Ok(T::default());
+    }
+
+    let file = File::open(path)
+        .with_context(|| format!("Failed to open {} file at {}", what, path.display()))?;
+
+    let raw: Value =
+        serde_json::from_reader(file).with_context(|| format!("Failed to load {}", what))?;
+    let migrated = migrate_to(raw, what, current_version(migrations), migrations)?;
+    serde_json::from_value(migrated).with_context(|| format!("Failed to load {}", what))
+}
+
+/// Save a state file atomically
+///
+/// Uses atomic-write-file so the file is never left half-written.
+/// Creates the config directory if it doesn't exist.
+pub fn save_state<T: Serialize>(path: &Path, what: &str, state: &T) -> Result<()> {
+    crate::config::ensure_config_dir()?;
+
+    let mut file = AtomicWriteFile::open(path)
+        .with_context(|| format!("Failed to open atomic write file at {}", path.display()))?;
+
+    serde_json::to_writer_pretty(&mut file, state)
+        .with_context(|| format!("Failed to serialize {}", what))?;
+
+    file.commit()
+        .with_context(|| format!("Failed to save {}", what))?;
+
+    Ok(())
+}
+
+/// Upgrade `value` to `target` by applying `migrations` in order
+///
+/// `migrations[i]` upgrades version `i + 1` to `i + 2`.
+pub fn migrate_to(
+    mut value: Value,
+    what: &str,
+    target: u32,
+    migrations: &[Migration],
+) -> Result<Value> {
+    let version = value
+        .get("version")
+        .and_then(Value::as_u64)
+        .ok_or_else(|| anyhow!("The {} file is missing a version", what))?;
+    let version =
+        u32::try_from(version).map_err(|_| anyhow!("Unsupported {} version: {}", what, version))?;
+
+    if version == 0 {
+        bail!("Unsupported {} version: 0", what);
+    }
+    if version > target {
+        bail!(
+            "The {} file version {} is newer than this pr-bro supports ({}); upgrade pr-bro",
+            what,
+            version,
+            target
+        );
+    }
+
+    for from in version..target {
+        let step = migrations
+            .get((from - 1) as usize)
+            .ok_or_else(|| anyhow!("No migration from {} version {}", what, from))?;
+        step(&mut value)
+            .map_err(|e| anyhow!("Failed to migrate {} from version {}: {}", what, from, e))?;
+        value["version"] = Value::from(from + 1);
+    }
+
+    Ok(value)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde::Deserialize;
+    use serde_json::json;
+    use std::env;
+
+    #[derive(Debug, Default, PartialEq, Serialize, Deserialize)]
+    struct Counter {
+        version: u32,
+        count: u32,
+    }
+
+    /// Example step: v1 stored `hits`, v2 renames it to `count`
+    fn rename_hits(value: &mut Value) -> Result<()> {
+        let hits = value
+            .as_object_mut()
+            .and_then(|o| o.remove("hits"))
+            .ok_or_else(|| anyhow!("hits is missing"))?;
+        value["count"] = hits;
+        Ok(())
+    }
+
+    #[test]
+    fn test_load_missing_file_returns_default() {
+        let path = env::temp_dir().join("pr_bro_test_state_missing.json");
+        let _ = std::fs::remove_file(&path);
+
+        let state: Counter = load_state(&path, "counter", &[]).unwrap();
+        assert_eq!(state, Counter::default());
+    }
+
+    #[test]
+    fn test_save_and_load_migrates_older_version() {
+        let path = env::temp_dir().join("pr_bro_test_state_migrate.json");
+        std::fs::write(&path, r#"{ "version": 1, "hits": 3 }"#).unwrap();
+
+        let state: Counter = load_state(&path, "counter", &[rename_hits]).unwrap();
+        assert_eq!(
+            state,
+            Counter {
+                version: 2,
+                count: 3
+            }
+        );
+
+        save_state(&path, "counter", &state).unwrap();
+        let reloaded: Counter = load_state(&path, "counter", &[rename_hits]).unwrap();
+        assert_eq!(reloaded, state);
+
+        let _ = std::fs::remove_file(&path);
+    }
+
+    #[test]
+    fn test_rejects_newer_missing_and_unmigratable_versions() {
+        let newer = json!({ "version": 99 });
+        let err = migrate_to(newer, "counter", 1, &[]).unwrap_err();
+        assert!(err.to_string().contains("newer"));
+
+        assert!(migrate_to(json!({}), "counter", 1, &[]).is_err());
+        assert!(migrate_to(json!({ "version": 0 }), "counter", 1, &[]).is_err());
+
+        // Target 3 but only a v1 -> v2 step is registered
+        let v1 = json!({ "version": 1, "hits": 3 });
+        assert!(migrate_to(v1, "counter", 3, &[rename_hits]).is_err());
+    }
+}
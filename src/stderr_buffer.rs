@@ -2,7 +2,7 @@ use std::sync::Mutex;
 
 static BUFFER: Mutex<Option<Vec<String>>> = Mutex::new(None);
 
-/// Activate buffering. While active, `warn!()` calls store messages
+/// Activate buffering. While active, `write()` calls store messages
 /// instead of printing to stderr.
 pub fn activate() {
     *BUFFER.lock().unwrap() = Some(Vec::new());
@@ -13,9 +13,10 @@ pub fn drain() -> Vec<String> {
     BUFFER.lock().unwrap().take().unwrap_or_default()
 }
 
-/// Write a warning message. If buffering is active the message is stored;
-/// otherwise it is printed to stderr immediately.
-pub fn warn(msg: String) {
+/// Write a diagnostic line. If buffering is active the message is stored;
+/// otherwise it is printed to stderr immediately. The logger in
+/// `crate::logging` is the only caller; fatal errors use `eprintln!` directly.
+pub fn write(msg: String) {
     let mut guard = BUFFER.lock().unwrap();
     if let Some(buf) = guard.as_mut() {
         buf.push(msg);
@@ -24,12 +25,3 @@ pub fn warn(msg: String) {
         eprintln!("{}", msg);
     }
 }
-
-/// Convenience macro that works like `eprintln!` but routes through the
-/// stderr buffer when it is active.
-#[macro_export]
-macro_rules! buffered_eprintln {
-    ($($arg:tt)*) => {
-        $crate::stderr_buffer::warn(format!($($arg)*))
-    };
-}
@@ -1,18 +1,27 @@
-use crate::config::Config;
+use crate::config::{Config, SnoozedSort};
 use crate::github::cache::{CacheConfig, DiskCache};
+use crate::github::search::ChangedFile;
 use crate::github::types::PullRequest;
+use crate::marks::MarkState;
+use crate::pins::PinState;
 use crate::scoring::ScoreResult;
 use crate::snooze::SnoozeState;
+use crate::tui::commands::{filter_commands, PaletteCommand};
+use crate::tui::snooze_presets::SnoozePreset;
 use crate::tui::theme::{Theme, ThemeColors};
 use crate::version_check::VersionStatus;
-use chrono::{DateTime, Utc};
-use std::collections::VecDeque;
+use chrono::{DateTime, Local, Utc};
+use std::collections::{HashMap, HashSet, VecDeque};
 use std::path::PathBuf;
 use std::sync::Arc;
 use std::time::Instant;
 
 const MAX_UNDO: usize = 50;
 
+/// How long a PR must stay selected before open-on-select opens it, so that
+/// rapid j/k navigation doesn't spawn a tab per row
+const OPEN_ON_SELECT_DEBOUNCE: std::time::Duration = std::time::Duration::from_millis(600);
+
 #[derive(Debug, Clone, PartialEq)]
 pub enum View {
     Active,
@@ -25,6 +34,18 @@ pub enum InputMode {
     SnoozeInput,
     Help,
     ScoreBreakdown,
+    RepoPicker,
+    ConfirmQuit,
+    ChangedFiles,
+    CommandPalette,
+}
+
+/// Changed files popup data for one PR
+#[derive(Debug, Clone)]
+pub enum ChangedFilesState {
+    Loading,
+    Loaded(Vec<ChangedFile>),
+    Failed(String),
 }
 
 #[derive(Debug, Clone)]
@@ -52,18 +73,41 @@ pub struct App {
     pub current_view: View,
     pub snooze_state: SnoozeState,
     pub snooze_path: PathBuf,
+    /// Local "reviewed for now" marks (dimmed until the PR is updated)
+    pub mark_state: MarkState,
+    pub marks_path: PathBuf,
+    /// PRs floated to the top of both views
+    pub pin_state: PinState,
+    pub pins_path: PathBuf,
     pub input_mode: InputMode,
     pub snooze_input: String,
+    /// Highlighted quick-pick in the snooze popup (index into `SnoozePreset::ALL`)
+    pub snooze_preset: Option<usize>,
+    /// Only show PRs from this repo (set via the jump-to-repo picker)
+    pub repo_filter: Option<String>,
+    /// Highlighted entry in the repo picker (index into `repo_choices()`)
+    pub repo_picker_index: usize,
+    /// Command palette search text and highlighted match
+    pub palette_query: String,
+    pub palette_index: usize,
+    /// Changed files fetched for the files popup, by PR URL (kept for the session)
+    pub changed_files: HashMap<String, ChangedFilesState>,
+    /// PR whose file list the event loop should fetch next
+    pub files_fetch_request: Option<PullRequest>,
+    /// PR shown in the changed files popup, and how far its list is scrolled
+    pub files_popup_url: Option<String>,
+    pub files_scroll: usize,
     pub flash_message: Option<(String, Instant)>,
     pub undo_stack: VecDeque<UndoAction>,
     pub last_refresh: Instant,
     pub needs_refresh: bool,
     pub force_refresh: bool,
+    /// Set by Esc / Ctrl-c during a refresh; the event loop aborts the fetch
+    pub cancel_refresh: bool,
     pub should_quit: bool,
     pub config: Config,
     pub cache_config: CacheConfig,
     pub cache_handle: Option<Arc<DiskCache>>,
-    pub verbose: bool,
     pub is_loading: bool,
     pub spinner_frame: usize,
     pub rate_limit_remaining: Option<u64>,
@@ -73,6 +117,27 @@ pub struct App {
     pub theme: Theme,
     pub theme_colors: ThemeColors,
     pub last_interaction: Instant,
+    /// When focus mode ends; auto-refresh is paused until then (toggled with `t`)
+    pub focus_until: Option<Instant>,
+    pub open_on_select: bool,
+    /// Show the author column in the table (toggled with `a`)
+    pub show_author: bool,
+    /// Show scores as 0-100 relative to the top score (`--normalize`)
+    pub normalize_scores: bool,
+    /// Order both views by score per changed line, with a column for it
+    /// (`--sort efficiency`, toggled with `e`)
+    pub sort_by_efficiency: bool,
+    /// PRs scoring below this are left out of both views, pinned ones
+    /// excepted (`--min-score`)
+    pub min_score: f64,
+    /// Order of the Snoozed view (toggled with `S`)
+    pub snoozed_sort: SnoozedSort,
+    /// URL selected while open-on-select is on, and when it was selected
+    pub pending_open: Option<(String, Instant)>,
+    /// Last URL opened by open-on-select (avoids reopening when navigating back)
+    pub last_auto_opened: Option<String>,
+    /// Active PR URLs from the previous refresh (None until the first load)
+    pub seen_urls: Option<HashSet<String>>,
 }
 
 impl App {
@@ -85,11 +150,14 @@ impl App {
         config: Config,
         cache_config: CacheConfig,
         cache_handle: Option<Arc<DiskCache>>,
-        verbose: bool,
         auth_username: Option<String>,
         no_version_check: bool,
         theme: Theme,
     ) -> Self {
+        let open_on_select = config.open_on_select;
+        let show_author = config.show_author;
+        let snoozed_sort = config.snoozed_sort;
+        let theme_colors = ThemeColors::new(theme, config.colorblind);
         let mut table_state = ratatui::widgets::TableState::default();
         if !active_prs.is_empty() {
             table_state.select(Some(0));
@@ -102,18 +170,31 @@ impl App {
             current_view: View::Active,
             snooze_state,
             snooze_path,
+            mark_state: load_marks(&crate::marks::get_marks_path()),
+            marks_path: crate::marks::get_marks_path(),
+            pin_state: load_pins(&crate::pins::get_pins_path()),
+            pins_path: crate::pins::get_pins_path(),
             input_mode: InputMode::Normal,
             snooze_input: String::new(),
+            snooze_preset: None,
+            repo_filter: None,
+            repo_picker_index: 0,
+            palette_query: String::new(),
+            palette_index: 0,
+            changed_files: HashMap::new(),
+            files_fetch_request: None,
+            files_popup_url: None,
+            files_scroll: 0,
             flash_message: None,
             undo_stack: VecDeque::new(),
             last_refresh: Instant::now(),
             needs_refresh: false,
             force_refresh: false,
+            cancel_refresh: false,
             should_quit: false,
             config,
             cache_config,
             cache_handle,
-            verbose,
             is_loading: false,
             spinner_frame: 0,
             rate_limit_remaining: None,
@@ -121,8 +202,18 @@ impl App {
             version_status: VersionStatus::Unknown,
             no_version_check,
             theme,
-            theme_colors: ThemeColors::new(theme),
+            theme_colors,
             last_interaction: Instant::now(),
+            focus_until: None,
+            open_on_select,
+            show_author,
+            normalize_scores: false,
+            sort_by_efficiency: false,
+            min_score: 0.0,
+            snoozed_sort,
+            pending_open: None,
+            last_auto_opened: None,
+            seen_urls: None,
         }
     }
 
@@ -135,11 +226,17 @@ impl App {
         config: Config,
         cache_config: CacheConfig,
         cache_handle: Option<Arc<DiskCache>>,
-        verbose: bool,
         auth_username: Option<String>,
         no_version_check: bool,
         theme: Theme,
     ) -> Self {
+        let open_on_select = config.open_on_select;
+        let show_author = config.show_author;
+        let snoozed_sort = config.snoozed_sort;
+        let theme_colors = ThemeColors::new(theme, config.colorblind);
+        let flash_message = config
+            .snooze_reminder(&snooze_state)
+            .map(|notice| (notice, Instant::now()));
         Self {
             active_prs: Vec::new(),
             snoozed_prs: Vec::new(),
@@ -147,18 +244,31 @@ impl App {
             current_view: View::Active,
             snooze_state,
             snooze_path,
+            mark_state: load_marks(&crate::marks::get_marks_path()),
+            marks_path: crate::marks::get_marks_path(),
+            pin_state: load_pins(&crate::pins::get_pins_path()),
+            pins_path: crate::pins::get_pins_path(),
             input_mode: InputMode::Normal,
             snooze_input: String::new(),
-            flash_message: None,
+            snooze_preset: None,
+            repo_filter: None,
+            repo_picker_index: 0,
+            palette_query: String::new(),
+            palette_index: 0,
+            changed_files: HashMap::new(),
+            files_fetch_request: None,
+            files_popup_url: None,
+            files_scroll: 0,
+            flash_message,
             undo_stack: VecDeque::new(),
             last_refresh: Instant::now(),
             needs_refresh: false,
             force_refresh: false,
+            cancel_refresh: false,
             should_quit: false,
             config,
             cache_config,
             cache_handle,
-            verbose,
             is_loading: true,
             spinner_frame: 0,
             rate_limit_remaining: None,
@@ -166,18 +276,252 @@ impl App {
             version_status: VersionStatus::Unknown,
             no_version_check,
             theme,
-            theme_colors: ThemeColors::new(theme),
+            theme_colors,
             last_interaction: Instant::now(),
+            focus_until: None,
+            open_on_select,
+            show_author,
+            normalize_scores: false,
+            sort_by_efficiency: false,
+            min_score: 0.0,
+            snoozed_sort,
+            pending_open: None,
+            last_auto_opened: None,
+            seen_urls: None,
         }
     }
 
-    pub fn current_prs(&self) -> &[(PullRequest, ScoreResult)] {
+    /// PRs in the current view, before the repo filter is applied
+    fn view_prs(&self) -> &[(PullRequest, ScoreResult)] {
         match self.current_view {
             View::Active => &self.active_prs,
             View::Snoozed => &self.snoozed_prs,
         }
     }
 
+    /// PRs shown in the table: the current view, narrowed to `repo_filter`,
+    /// ordered by score per line with `sort_by_efficiency` and, in the Snoozed
+    /// view, by `snoozed_sort`. Pinned PRs come first either way.
+    pub fn current_prs(&self) -> Vec<&(PullRequest, ScoreResult)> {
+        let mut prs: Vec<_> = self
+            .view_prs()
+            .iter()
+            .filter(|(pr, _)| {
+                self.repo_filter
+                    .as_ref()
+                    .is_none_or(|repo| &pr.repo == repo)
+            })
+            .collect();
+        if self.sort_by_efficiency {
+            crate::fetch::sort_by_efficiency(&mut prs);
+        }
+        if self.current_view == View::Snoozed && self.snoozed_sort == SnoozedSort::Remaining {
+            // Stable sort keeps score order among equal expiries; indefinite
+            // (and unknown) snoozes go last
+            let entries = self.snooze_state.snoozed_entries();
+            prs.sort_by_key(|(pr, _)| {
+                let until = entries.get(&pr.url).and_then(|entry| entry.snooze_until);
+                (until.is_none(), until)
+            });
+        }
+        crate::fetch::float_pinned(&mut prs, &self.pin_state);
+        prs
+    }
+
+    /// Switch the Snoozed view between score order and soonest-to-expire
+    /// first, keeping the selected PR selected
+    pub fn toggle_snoozed_sort(&mut self) {
+        if self.current_view != View::Snoozed {
+            return;
+        }
+        let selected_url = self.selected_pr().map(|pr| pr.url.clone());
+        self.snoozed_sort = match self.snoozed_sort {
+            SnoozedSort::Score => SnoozedSort::Remaining,
+            SnoozedSort::Remaining => SnoozedSort::Score,
+        };
+        self.select_url_or_first(selected_url.as_deref());
+        let label = match self.snoozed_sort {
+            SnoozedSort::Score => "score",
+            SnoozedSort::Remaining => "time remaining",
+        };
+        self.show_flash(format!("Snoozed sorted by {}", label));
+    }
+
+    /// Switch both views between score order and score per changed line,
+    /// keeping the selected PR selected
+    pub fn toggle_efficiency_sort(&mut self) {
+        let selected_url = self.selected_pr().map(|pr| pr.url.clone());
+        self.sort_by_efficiency = !self.sort_by_efficiency;
+        self.select_url_or_first(selected_url.as_deref());
+        let label = if self.sort_by_efficiency {
+            "score per line"
+        } else {
+            "score"
+        };
+        self.show_flash(format!("Sorted by {}", label));
+    }
+
+    /// Start focus mode for `focus_duration`, or end it early. While it runs,
+    /// auto-refresh (and the reshuffle it brings) waits; `r` still refreshes.
+    pub fn toggle_focus(&mut self) {
+        if self.focus_until.take().is_some() {
+            self.show_flash("Focus ended. Auto-refresh resumed.".to_string());
+            return;
+        }
+        let duration = self.config.focus_duration();
+        self.focus_until = Some(Instant::now() + duration);
+        self.show_flash(format!(
+            "Focus for {}: auto-refresh paused (t to end)",
+            humantime::format_duration(duration)
+        ));
+    }
+
+    /// Time left in focus mode, or None when not focusing
+    pub fn focus_remaining(&self) -> Option<std::time::Duration> {
+        self.focus_until
+            .map(|until| until.saturating_duration_since(Instant::now()))
+            .filter(|left| !left.is_zero())
+    }
+
+    /// End focus mode once its time is up (called every tick)
+    pub fn update_focus(&mut self) {
+        if self.focus_until.is_some() && self.focus_remaining().is_none() {
+            self.focus_until = None;
+            self.show_flash("Focus ended. Auto-refresh resumed.".to_string());
+        }
+    }
+
+    /// Distinct repos in the current view with their PR counts, sorted by name
+    pub fn repo_choices(&self) -> Vec<(String, usize)> {
+        let mut counts: std::collections::BTreeMap<&str, usize> = Default::default();
+        for (pr, _) in self.view_prs() {
+            *counts.entry(pr.repo.as_str()).or_default() += 1;
+        }
+        counts
+            .into_iter()
+            .map(|(repo, count)| (repo.to_string(), count))
+            .collect()
+    }
+
+    /// Open the jump-to-repo picker, highlighting the active filter if any
+    pub fn start_repo_picker(&mut self) {
+        let choices = self.repo_choices();
+        if choices.is_empty() {
+            return;
+        }
+        self.repo_picker_index = self
+            .repo_filter
+            .as_ref()
+            .and_then(|repo| choices.iter().position(|(r, _)| r == repo))
+            .unwrap_or(0);
+        self.input_mode = InputMode::RepoPicker;
+    }
+
+    pub fn next_repo_choice(&mut self) {
+        let len = self.repo_choices().len();
+        if len > 0 {
+            self.repo_picker_index = (self.repo_picker_index + 1) % len;
+        }
+    }
+
+    pub fn previous_repo_choice(&mut self) {
+        let len = self.repo_choices().len();
+        if len > 0 {
+            self.repo_picker_index = (self.repo_picker_index + len - 1) % len;
+        }
+    }
+
+    /// Apply the highlighted repo as the table filter
+    pub fn confirm_repo_picker(&mut self) {
+        let repo = self
+            .repo_choices()
+            .into_iter()
+            .nth(self.repo_picker_index)
+            .map(|(repo, _)| repo);
+        self.input_mode = InputMode::Normal;
+        if let Some(repo) = repo {
+            self.set_repo_filter(Some(repo));
+        }
+    }
+
+    pub fn cancel_repo_picker(&mut self) {
+        self.input_mode = InputMode::Normal;
+    }
+
+    /// Open the command palette with an empty search
+    pub fn start_command_palette(&mut self) {
+        self.palette_query.clear();
+        self.palette_index = 0;
+        self.input_mode = InputMode::CommandPalette;
+    }
+
+    /// Commands matching the palette search, best first
+    pub fn palette_matches(&self) -> Vec<&'static PaletteCommand> {
+        filter_commands(&self.palette_query)
+    }
+
+    pub fn palette_push(&mut self, c: char) {
+        self.palette_query.push(c);
+        self.palette_index = 0;
+    }
+
+    pub fn palette_pop(&mut self) {
+        self.palette_query.pop();
+        self.palette_index = 0;
+    }
+
+    pub fn next_palette_match(&mut self) {
+        let len = self.palette_matches().len();
+        if len > 0 {
+            self.palette_index = (self.palette_index + 1) % len;
+        }
+    }
+
+    pub fn previous_palette_match(&mut self) {
+        let len = self.palette_matches().len();
+        if len > 0 {
+            self.palette_index = (self.palette_index + len - 1) % len;
+        }
+    }
+
+    /// Close the palette and return the key of the highlighted command, for
+    /// the caller to run as if it were pressed
+    pub fn confirm_command_palette(&mut self) -> Option<crossterm::event::KeyCode> {
+        self.input_mode = InputMode::Normal;
+        self.palette_matches()
+            .get(self.palette_index)
+            .map(|command| command.key)
+    }
+
+    pub fn cancel_command_palette(&mut self) {
+        self.input_mode = InputMode::Normal;
+    }
+
+    /// Drop the repo filter, keeping the selected PR selected
+    pub fn clear_repo_filter(&mut self) {
+        if self.repo_filter.is_some() {
+            self.set_repo_filter(None);
+        }
+    }
+
+    fn set_repo_filter(&mut self, repo: Option<String>) {
+        let selected_url = self.selected_pr().map(|pr| pr.url.clone());
+        self.repo_filter = repo;
+        self.select_url_or_first(selected_url.as_deref());
+    }
+
+    /// Select the row for `url` if it's visible, otherwise the first row
+    fn select_url_or_first(&mut self, url: Option<&str>) {
+        let prs = self.current_prs();
+        let position = url.and_then(|url| prs.iter().position(|(pr, _)| pr.url == url));
+        let selection = match position {
+            Some(i) => Some(i),
+            None if prs.is_empty() => None,
+            None => Some(0),
+        };
+        self.table_state.select(selection);
+    }
+
     pub fn next_row(&mut self) {
         let prs = self.current_prs();
         if prs.is_empty() {
@@ -244,22 +588,110 @@ impl App {
         std::time::Duration::from_secs(self.config.auto_refresh_interval)
     }
 
-    /// Open the selected PR in the browser
-    pub fn open_selected(&self) -> anyhow::Result<()> {
+    /// Open the selected PR in the browser, on the given tab if any
+    pub fn open_selected(&self, tab: Option<crate::browser::PrTab>) -> anyhow::Result<()> {
         if let Some(pr) = self.selected_pr() {
-            crate::browser::open_url(&pr.url)?;
+            let url = crate::browser::pr_tab_url(&pr.url, tab);
+            crate::browser::open_url(&url, self.config.browser.as_deref())?;
         }
         Ok(())
     }
 
+    /// Open the selected PR's repo or author page in the browser
+    pub fn open_selected_page(&self, page: crate::browser::PrPage) -> anyhow::Result<()> {
+        if let Some(pr) = self.selected_pr() {
+            let url = crate::browser::pr_page_url(&pr.url, page, &pr.repo, &pr.author);
+            crate::browser::open_url(&url, self.config.browser.as_deref())?;
+        }
+        Ok(())
+    }
+
+    /// Toggle open-on-select mode
+    pub fn toggle_open_on_select(&mut self) {
+        self.open_on_select = !self.open_on_select;
+        self.pending_open = None;
+        self.last_auto_opened = None;
+        if self.open_on_select {
+            self.show_flash("Open on select: on".to_string());
+            self.schedule_open_on_select();
+        } else {
+            self.show_flash("Open on select: off".to_string());
+        }
+    }
+
+    pub fn toggle_author_column(&mut self) {
+        self.show_author = !self.show_author;
+        let state = if self.show_author { "on" } else { "off" };
+        self.show_flash(format!("Author column: {}", state));
+    }
+
+    /// Record the selected PR as pending for open-on-select (no-op when disabled)
+    pub fn schedule_open_on_select(&mut self) {
+        if !self.open_on_select {
+            return;
+        }
+        self.pending_open = self
+            .selected_pr()
+            .map(|pr| (pr.url.clone(), Instant::now()));
+    }
+
+    /// Open the pending PR once the selection has settled for the debounce period
+    pub fn poll_open_on_select(&mut self) {
+        let Some((url, selected_at)) = &self.pending_open else {
+            return;
+        };
+        if selected_at.elapsed() < OPEN_ON_SELECT_DEBOUNCE {
+            return;
+        }
+        let url = url.clone();
+        self.pending_open = None;
+
+        // Selection may have changed (e.g. after a refresh); only open what's still selected
+        if self.selected_pr().map(|pr| &pr.url) != Some(&url)
+            || self.last_auto_opened.as_ref() == Some(&url)
+        {
+            return;
+        }
+        if let Err(e) = crate::browser::open_url(&url, self.config.browser.as_deref()) {
+            self.show_flash(format!("Failed to open browser: {}", e));
+        }
+        self.last_auto_opened = Some(url);
+    }
+
     /// Start snooze input mode (works on both Active and Snoozed views)
     pub fn start_snooze_input(&mut self) {
         if self.selected_pr().is_some() {
             self.input_mode = InputMode::SnoozeInput;
             self.snooze_input.clear();
+            self.snooze_preset = None;
         }
     }
 
+    /// Highlight the next quick-pick, clearing any typed duration
+    pub fn next_snooze_preset(&mut self) {
+        let last = SnoozePreset::ALL.len() - 1;
+        self.snooze_preset = Some(match self.snooze_preset {
+            Some(i) => (i + 1).min(last),
+            None => 0,
+        });
+        self.snooze_input.clear();
+    }
+
+    /// Highlight the previous quick-pick, clearing any typed duration
+    pub fn previous_snooze_preset(&mut self) {
+        self.snooze_preset = Some(match self.snooze_preset {
+            Some(i) => i.saturating_sub(1),
+            None => 0,
+        });
+        self.snooze_input.clear();
+    }
+
+    /// Quick-pick currently highlighted in the snooze popup
+    pub fn selected_snooze_preset(&self) -> Option<SnoozePreset> {
+        self.snooze_preset
+            .and_then(|i| SnoozePreset::ALL.get(i).copied())
+    }
+
     /// Confirm and apply the snooze input
     pub fn confirm_snooze_input(&mut self) {
         // Get selected PR info before mutating
@@ -271,10 +703,11 @@ impl App {
             }
         };
 
-        // Parse duration from input
+        // Parse duration from input; a highlighted quick-pick applies when nothing is typed
         let computed_until = if self.snooze_input.trim().is_empty() {
-            // Empty string = indefinite snooze
-            None
+            // Empty string with no quick-pick = indefinite snooze
+            self.selected_snooze_preset()
+                .and_then(|preset| preset.until(&Local::now()))
         } else {
             // Parse duration string
             match humantime::parse_duration(&self.snooze_input) {
@@ -340,12 +773,86 @@ impl App {
         // Return to normal mode
         self.input_mode = InputMode::Normal;
         self.snooze_input.clear();
+        self.snooze_preset = None;
     }
 
     /// Cancel snooze input
     pub fn cancel_snooze_input(&mut self) {
         self.input_mode = InputMode::Normal;
         self.snooze_input.clear();
+        self.snooze_preset = None;
+    }
+
+    /// Toggle the local "reviewed" mark on the selected PR
+    pub fn toggle_mark_selected(&mut self) {
+        let Some(pr) = self.selected_pr().cloned() else {
+            return;
+        };
+
+        let msg = if self.mark_state.is_marked(&pr) {
+            self.mark_state.unmark(&pr.url);
+            format!("Unmarked: {}", pr.title)
+        } else {
+            self.mark_state.mark(&pr);
+            format!("Marked reviewed: {} (until updated)", pr.title)
+        };
+
+        if let Err(e) = crate::marks::save_mark_state(&self.marks_path, &self.mark_state) {
+            self.show_flash(format!("Failed to save marks: {}", e));
+            return;
+        }
+        self.show_flash(msg);
+    }
+
+    /// Pin or unpin the selected PR, keeping it selected as it moves
+    pub fn toggle_pin_selected(&mut self) {
+        let Some(pr) = self.selected_pr().cloned() else {
+            return;
+        };
+
+        let msg = if self.pin_state.toggle(&pr.url) {
+            format!("Pinned: {}", pr.title)
+        } else {
+            format!("Unpinned: {}", pr.title)
+        };
+
+        if let Err(e) = crate::pins::save_pin_state(&self.pins_path, &self.pin_state) {
+            self.show_flash(format!("Failed to save pins: {}", e));
+            return;
+        }
+        self.select_url_or_first(Some(&pr.url));
+        self.show_flash(msg);
+    }
+
+    /// Unpin every PR, keeping the selected PR selected
+    pub fn clear_pins(&mut self) {
+        if self.pin_state.pinned.is_empty() {
+            self.show_flash("No pinned PRs".to_string());
+            return;
+        }
+
+        let selected_url = self.selected_pr().map(|pr| pr.url.clone());
+        let count = self.pin_state.clear();
+        if let Err(e) = crate::pins::save_pin_state(&self.pins_path, &self.pin_state) {
+            self.show_flash(format!("Failed to save pins: {}", e));
+            return;
+        }
+        self.select_url_or_first(selected_url.as_deref());
+        self.show_flash(format!("Unpinned {} PRs", count));
+    }
+
+    /// Flash the selected PR's snooze status, from either view, for when a
+    /// PR seems to be in the wrong list or to have disappeared
+    pub fn show_snooze_status(&mut self) {
+        let Some(pr) = self.selected_pr() else {
+            return;
+        };
+        let message = snooze_status_message(
+            self.snooze_state.snoozed_entries().get(&pr.url),
+            self.config.display_timezone(),
+            Utc::now(),
+        );
+        self.show_flash(format!("#{}: {}", pr.number, message));
     }
 
     /// Unsnooze the selected PR (only works in Snoozed view)
@@ -510,6 +1017,34 @@ impl App {
         }
     }
 
+    /// Ask the event loop to abort the running refresh, if there is one
+    pub fn request_cancel_refresh(&mut self) {
+        if self.is_loading {
+            self.cancel_refresh = true;
+        }
+    }
+
+    /// The refresh was aborted: the previous data stays as it was
+    pub fn refresh_cancelled(&mut self) {
+        self.cancel_refresh = false;
+        self.is_loading = false;
+        self.show_flash("Refresh cancelled.".to_string());
+    }
+
+    /// Quit, or ask first when `confirm_quit` is set and a refresh is still
+    /// running (it may be mid-way through writing the cache or review state)
+    pub fn request_quit(&mut self) {
+        if self.config.confirm_quit && self.is_loading {
+            self.input_mode = InputMode::ConfirmQuit;
+        } else {
+            self.should_quit = true;
+        }
+    }
+
+    pub fn cancel_quit(&mut self) {
+        self.input_mode = InputMode::Normal;
+    }
+
     /// Show help overlay
     pub fn show_help(&mut self) {
         self.input_mode = InputMode::Help;
@@ -527,6 +1062,64 @@ impl App {
         }
     }
 
+    /// Open the changed files popup for the selected PR, fetching its file
+    /// list unless it's already loaded (failed fetches are retried)
+    pub fn show_changed_files(&mut self) {
+        let Some(pr) = self.selected_pr() else {
+            return;
+        };
+        if pr.is_issue {
+            self.show_flash("Issues have no changed files".to_string());
+            return;
+        }
+        let pr = pr.clone();
+        let needs_fetch = !matches!(
+            self.changed_files.get(&pr.url),
+            Some(ChangedFilesState::Loading | ChangedFilesState::Loaded(_))
+        );
+        if needs_fetch {
+            self.changed_files
+                .insert(pr.url.clone(), ChangedFilesState::Loading);
+            self.files_fetch_request = Some(pr.clone());
+        }
+        self.files_popup_url = Some(pr.url);
+        self.files_scroll = 0;
+        self.input_mode = InputMode::ChangedFiles;
+    }
+
+    /// Store the result of a file list fetch started by `show_changed_files`
+    pub fn finish_files_fetch(&mut self, url: String, result: anyhow::Result<Vec<ChangedFile>>) {
+        let state = match result {
+            Ok(files) => ChangedFilesState::Loaded(files),
+            Err(e) => ChangedFilesState::Failed(format!("{:#}", e)),
+        };
+        self.changed_files.insert(url, state);
+    }
+
+    /// Changed files state for the PR in the popup
+    pub fn popup_files(&self) -> Option<&ChangedFilesState> {
+        self.files_popup_url
+            .as_ref()
+            .and_then(|url| self.changed_files.get(url))
+    }
+
+    pub fn scroll_files_down(&mut self) {
+        if let Some(ChangedFilesState::Loaded(files)) = self.popup_files() {
+            if self.files_scroll + 1 < files.len() {
+                self.files_scroll += 1;
+            }
+        }
+    }
+
+    pub fn scroll_files_up(&mut self) {
+        self.files_scroll = self.files_scroll.saturating_sub(1);
+    }
+
+    pub fn dismiss_changed_files(&mut self) {
+        self.files_popup_url = None;
+        self.input_mode = InputMode::Normal;
+    }
+
     /// Dismiss score breakdown overlay
     pub fn dismiss_score_breakdown(&mut self) {
         self.input_mode = InputMode::Normal;
@@ -550,6 +1143,8 @@ impl App {
         // Replace PR lists
         self.active_prs = active;
         self.snoozed_prs = snoozed;
+        crate::fetch::drop_below_min_score(&mut self.active_prs, self.min_score, &self.pin_state);
+        crate::fetch::drop_below_min_score(&mut self.snoozed_prs, self.min_score, &self.pin_state);
 
         // Update rate limit info
         self.rate_limit_remaining = rate_limit_remaining;
@@ -573,6 +1168,14 @@ impl App {
             self.snooze_state = loaded_state;
         }
 
+        // Marks lapse once the author pushes (or anything else bumps updated_at)
+        let all_prs = self.active_prs.iter().chain(&self.snoozed_prs);
+        if self.mark_state.clear_updated(all_prs.map(|(pr, _)| pr)) {
+            if let Err(e) = crate::marks::save_mark_state(&self.marks_path, &self.mark_state) {
+                log::warn!("Failed to save marks: {}", e);
+            }
+        }
+
         // Update refresh timestamp
         self.last_refresh = Instant::now();
 
@@ -583,6 +1186,47 @@ impl App {
             "Refreshed ({} active, {} snoozed)",
             active_count, snoozed_count
         ));
+
+        // New high-priority PRs take over the flash message
+        if let Some(alert) = self.alert_new_high_priority() {
+            self.show_flash(alert);
+        }
+    }
+
+    /// Ring the bell (and optionally show a desktop notification) for active PRs
+    /// above `notify_threshold` that weren't present in the previous refresh.
+    /// The first load only records the current PRs, so startup stays quiet.
+    /// Returns a flash message describing the new PRs, if any.
+    fn alert_new_high_priority(&mut self) -> Option<String> {
+        let current: HashSet<String> = self
+            .active_prs
+            .iter()
+            .map(|(pr, _)| pr.url.clone())
+            .collect();
+        let previous = self.seen_urls.replace(current);
+
+        let threshold = self.config.notify_threshold?;
+        let new_prs = new_high_priority_prs(&self.active_prs, &previous?, threshold);
+        let (first, first_result) = new_prs.first()?;
+
+        crate::notify::ring_bell();
+        let summary = if new_prs.len() == 1 {
+            "New high-priority PR".to_string()
+        } else {
+            format!("{} new high-priority PRs", new_prs.len())
+        };
+        let body = format!(
+            "{} ({:.0}): {}",
+            first.short_ref(),
+            first_result.score,
+            first.title
+        );
+        if self.config.notify {
+            if let Err(e) = crate::notify::desktop_notify(&summary, &body) {
+                log::warn!("Desktop notification failed: {}", e);
+            }
+        }
+        Some(format!("{}: {}", summary, body))
     }
 
     /// Advance the loading spinner animation frame
@@ -609,3 +1253,350 @@ impl App {
         matches!(self.version_status, VersionStatus::UpdateAvailable { .. })
     }
 }
+
+/// Load pinned PRs, falling back to none (with a warning) if the file is unreadable
+fn load_pins(path: &std::path::Path) -> PinState {
+    crate::pins::load_pin_state(path).unwrap_or_else(|e| {
+        log::warn!("Could not load pins: {}", e);
+        PinState::new()
+    })
+}
+
+/// Load reviewed marks, falling back to none (with a warning) if the file is unreadable
+fn load_marks(path: &std::path::Path) -> MarkState {
+    crate::marks::load_mark_state(path).unwrap_or_else(|e| {
+        log::warn!("Could not load marks: {}", e);
+        MarkState::new()
+    })
+}
+
+/// Describe a snooze entry (or its absence) as of `now`
+fn snooze_status_message(
+    entry: Option<&crate::snooze::SnoozeEntry>,
+    timezone: crate::config::DisplayTimezone,
+    now: DateTime<Utc>,
+) -> String {
+    let Some(entry) = entry else {
+        return "not snoozed".to_string();
+    };
+    let snoozed = crate::output::format_ago(now - entry.snoozed_at);
+    match entry.snooze_until {
+        None => format!("snoozed indefinitely ({})", snoozed),
+        Some(until) if until > now => format!(
+            "snoozed {} until {} ({})",
+            snoozed,
+            timezone.format(until),
+            entry.format_remaining()
+        ),
+        Some(until) => format!(
+            "snooze expired {}; it clears on the next refresh",
+            crate::output::format_ago(now - until)
+        ),
+    }
+}
+
+/// Active PRs scoring at least `threshold` whose URL is not in `previous`
+fn new_high_priority_prs<'a>(
+    prs: &'a [(PullRequest, ScoreResult)],
+    previous: &HashSet<String>,
+    threshold: f64,
+) -> Vec<&'a (PullRequest, ScoreResult)> {
+    prs.iter()
+        .filter(|(pr, result)| result.score >= threshold && !previous.contains(&pr.url))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::scoring::ScoreBreakdown;
+
+    fn scored(url: &str, score: f64) -> (PullRequest, ScoreResult) {
+        let pr = PullRequest {
+            url: url.to_string(),
+            ..PullRequest::test_default()
+        };
+        let result = ScoreResult {
+            score,
+            incomplete: false,
+            sla_breached: false,
+            oldest_alert: false,
+            breakdown: ScoreBreakdown {
+                base_score: score,
+                factors: vec![],
+                disabled: vec![],
+            },
+        };
+        (pr, result)
+    }
+
+    #[test]
+    fn test_new_high_priority_prs_only_new_above_threshold() {
+        let prs = vec![
+            scored("https://github.com/o/r/pull/1", 500.0), // seen before
+            scored("https://github.com/o/r/pull/2", 500.0), // new, above threshold
+            scored("https://github.com/o/r/pull/3", 50.0),  // new, below threshold
+        ];
+        let previous: HashSet<String> = ["https://github.com/o/r/pull/1".to_string()].into();
+
+        let new_prs = new_high_priority_prs(&prs, &previous, 100.0);
+        assert_eq!(new_prs.len(), 1);
+        assert_eq!(new_prs[0].0.url, "https://github.com/o/r/pull/2");
+    }
+
+    fn app_with(active: Vec<(PullRequest, ScoreResult)>) -> App {
+        let config: Config = serde_saphyr::from_str("queries: []").unwrap();
+        App::new(
+            active,
+            Vec::new(),
+            SnoozeState::new(),
+            PathBuf::from("/nonexistent/snooze.json"),
+            config,
+            CacheConfig {
+                enabled: false,
+                compress: false,
+                bypass_reads: false,
+            },
+            None,
+            None,
+            true,
+            Theme::Dark,
+        )
+    }
+
+    fn in_repo(repo: &str, url: &str) -> (PullRequest, ScoreResult) {
+        let mut entry = scored(url, 1.0);
+        entry.0.repo = repo.to_string();
+        entry
+    }
+
+    #[test]
+    fn test_repo_filter_narrows_table_and_keeps_selection() {
+        let mut app = app_with(vec![
+            in_repo("o/b", "https://github.com/o/b/pull/1"),
+            in_repo("o/a", "https://github.com/o/a/pull/2"),
+            in_repo("o/b", "https://github.com/o/b/pull/3"),
+        ]);
+        assert_eq!(
+            app.repo_choices(),
+            vec![("o/a".to_string(), 1), ("o/b".to_string(), 2)]
+        );
+
+        // Select the second o/b PR, then filter to o/b: it stays selected
+        app.table_state.select(Some(2));
+        app.start_repo_picker();
+        assert_eq!(app.input_mode, InputMode::RepoPicker);
+        app.next_repo_choice();
+        app.confirm_repo_picker();
+        assert_eq!(app.repo_filter.as_deref(), Some("o/b"));
+        assert_eq!(app.current_prs().len(), 2);
+        assert_eq!(
+            app.selected_pr().unwrap().url,
+            "https://github.com/o/b/pull/3"
+        );
+
+        // Clearing the filter keeps the same PR selected in the full list
+        app.clear_repo_filter();
+        assert_eq!(app.current_prs().len(), 3);
+        assert_eq!(app.table_state.selected(), Some(2));
+    }
+
+    #[test]
+    fn test_confirm_quit_only_while_loading() {
+        let mut app = app_with(vec![]);
+        app.config.confirm_quit = true;
+
+        app.is_loading = true;
+        app.request_quit();
+        assert_eq!(app.input_mode, InputMode::ConfirmQuit);
+        assert!(!app.should_quit);
+        app.cancel_quit();
+        assert_eq!(app.input_mode, InputMode::Normal);
+
+        app.is_loading = false;
+        app.request_quit();
+        assert!(app.should_quit);
+    }
+
+    #[test]
+    fn test_focus_mode_toggles_and_expires() {
+        let mut app = app_with(vec![scored("https://github.com/o/r/pull/1", 1.0)]);
+        assert!(app.focus_remaining().is_none());
+
+        app.toggle_focus();
+        let left = app.focus_remaining().unwrap();
+        assert!(left > std::time::Duration::from_secs(24 * 60));
+        assert!(left <= std::time::Duration::from_secs(25 * 60));
+
+        // Ends early on a second press
+        app.toggle_focus();
+        assert!(app.focus_remaining().is_none());
+        assert_eq!(
+            app.flash_message.as_ref().unwrap().0,
+            "Focus ended. Auto-refresh resumed."
+        );
+
+        // Ends by itself once the timer runs out
+        app.focus_until = Some(Instant::now());
+        app.flash_message = None;
+        app.update_focus();
+        assert!(app.focus_until.is_none());
+        assert!(app.flash_message.is_some());
+    }
+
+    #[test]
+    fn test_cancel_refresh_only_while_loading() {
+        let mut app = app_with(vec![scored("https://github.com/o/r/pull/1", 1.0)]);
+
+        app.request_cancel_refresh();
+        assert!(!app.cancel_refresh);
+
+        app.is_loading = true;
+        app.request_cancel_refresh();
+        assert!(app.cancel_refresh);
+        app.refresh_cancelled();
+        assert!(!app.cancel_refresh);
+        assert!(!app.is_loading);
+        assert_eq!(app.current_prs().len(), 1);
+        assert_eq!(app.flash_message.as_ref().unwrap().0, "Refresh cancelled.");
+    }
+
+    #[test]
+    fn test_changed_files_fetched_once_and_retried_after_failure() {
+        let mut app = app_with(vec![scored("https://github.com/o/r/pull/1", 1.0)]);
+        let url = "https://github.com/o/r/pull/1".to_string();
+
+        app.show_changed_files();
+        assert_eq!(app.input_mode, InputMode::ChangedFiles);
+        assert!(app.files_fetch_request.take().is_some());
+        assert!(matches!(
+            app.popup_files(),
+            Some(ChangedFilesState::Loading)
+        ));
+
+        // A failed fetch is retried when the popup is reopened
+        app.finish_files_fetch(url.clone(), Err(anyhow::anyhow!("boom")));
+        app.dismiss_changed_files();
+        app.show_changed_files();
+        assert!(app.files_fetch_request.take().is_some());
+
+        // A loaded list is reused for the rest of the session
+        app.finish_files_fetch(url, Ok(Vec::new()));
+        app.dismiss_changed_files();
+        app.show_changed_files();
+        assert!(app.files_fetch_request.is_none());
+        assert!(matches!(
+            app.popup_files(),
+            Some(ChangedFilesState::Loaded(_))
+        ));
+    }
+
+    #[test]
+    fn test_snoozed_sort_by_remaining_puts_indefinite_last() {
+        let mut app = app_with(Vec::new());
+        app.snoozed_prs = vec![
+            scored("https://github.com/o/r/pull/1", 30.0),
+            scored("https://github.com/o/r/pull/2", 20.0),
+            scored("https://github.com/o/r/pull/3", 10.0),
+        ];
+        let now = Utc::now();
+        app.snooze_state
+            .snooze("https://github.com/o/r/pull/1".to_string(), None);
+        app.snooze_state.snooze(
+            "https://github.com/o/r/pull/2".to_string(),
+            Some(now + chrono::Duration::days(3)),
+        );
+        app.snooze_state.snooze(
+            "https://github.com/o/r/pull/3".to_string(),
+            Some(now + chrono::Duration::hours(1)),
+        );
+        app.toggle_view();
+        app.table_state.select(Some(1));
+
+        app.toggle_snoozed_sort();
+        let order: Vec<&str> = app
+            .current_prs()
+            .iter()
+            .map(|(pr, _)| pr.url.rsplit('/').next().unwrap())
+            .collect();
+        assert_eq!(order, vec!["3", "2", "1"]);
+        // The selected PR (#2) stays selected
+        assert!(app.selected_pr().unwrap().url.ends_with("/2"));
+
+        app.toggle_snoozed_sort();
+        let order: Vec<&str> = app
+            .current_prs()
+            .iter()
+            .map(|(pr, _)| pr.url.rsplit('/').next().unwrap())
+            .collect();
+        assert_eq!(order, vec!["1", "2", "3"]);
+    }
+
+    #[test]
+    fn test_pinned_prs_float_to_top_and_stay_selected() {
+        let mut app = app_with(vec![
+            scored("https://github.com/o/r/pull/1", 30.0),
+            scored("https://github.com/o/r/pull/2", 20.0),
+            scored("https://github.com/o/r/pull/3", 10.0),
+        ]);
+        app.pin_state = PinState::new();
+        app.pins_path =
+            std::env::temp_dir().join(format!("pr-bro-test-pins-{}.json", std::process::id()));
+        let order = |app: &App| -> Vec<String> {
+            app.current_prs()
+                .iter()
+                .map(|(pr, _)| pr.url.rsplit('/').next().unwrap().to_string())
+                .collect()
+        };
+
+        app.table_state.select(Some(2));
+        app.toggle_pin_selected();
+        assert_eq!(order(&app), vec!["3", "1", "2"]);
+        assert!(app.selected_pr().unwrap().url.ends_with("/3"));
+
+        // Pins survive a refresh that re-sorts by score
+        app.update_prs(
+            vec![
+                scored("https://github.com/o/r/pull/2", 50.0),
+                scored("https://github.com/o/r/pull/1", 30.0),
+                scored("https://github.com/o/r/pull/3", 10.0),
+            ],
+            Vec::new(),
+            None,
+        );
+        assert_eq!(order(&app), vec!["3", "2", "1"]);
+
+        app.clear_pins();
+        assert_eq!(order(&app), vec!["2", "1", "3"]);
+        let _ = std::fs::remove_file(&app.pins_path);
+    }
+
+    #[test]
+    fn test_snooze_status_message() {
+        use crate::config::DisplayTimezone;
+        use crate::snooze::SnoozeEntry;
+
+        let now = Utc::now();
+        let entry = |until: Option<DateTime<Utc>>| SnoozeEntry {
+            snoozed_at: now - chrono::Duration::days(2),
+            snooze_until: until,
+        };
+        let status =
+            |entry: Option<&SnoozeEntry>| snooze_status_message(entry, DisplayTimezone::Utc, now);
+
+        assert_eq!(status(None), "not snoozed");
+        assert_eq!(status(Some(&entry(None))), "snoozed indefinitely (2d ago)");
+        let until = now + chrono::Duration::days(10);
+        assert_eq!(
+            status(Some(&entry(Some(until)))),
+            format!(
+                "snoozed 2d ago until {} (1w left)",
+                DisplayTimezone::Utc.format(until)
+            )
+        );
+        assert_eq!(
+            status(Some(&entry(Some(now - chrono::Duration::hours(3))))),
+            "snooze expired 3h ago; it clears on the next refresh"
+        );
+    }
+}
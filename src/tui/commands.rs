@@ -0,0 +1,105 @@
+//! Actions offered by the command palette (`:`). Each one replays its key in
+//! Normal mode, so the palette and the key binding share a handler.
+
+use crossterm::event::KeyCode;
+
+/// One palette entry: a name to search, and the key that runs it
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PaletteCommand {
+    pub name: &'static str,
+    /// Key as shown in the palette and the help popup
+    pub key_label: &'static str,
+    pub key: KeyCode,
+}
+
+const fn command(name: &'static str, key_label: &'static str, key: KeyCode) -> PaletteCommand {
+    PaletteCommand {
+        name,
+        key_label,
+        key,
+    }
+}
+
+/// Every palette action, in the order listed when the search is empty
+pub const COMMANDS: &[PaletteCommand] = &[
+    command("Open PR in browser", "o", KeyCode::Char('o')),
+    command("Open Files changed tab", "F", KeyCode::Char('F')),
+    command("Open repository", "R", KeyCode::Char('R')),
+    command("Open author profile", "A", KeyCode::Char('A')),
+    command("Score breakdown", "b", KeyCode::Char('b')),
+    command("Changed files", "f", KeyCode::Char('f')),
+    command("Snooze / re-snooze PR", "s", KeyCode::Char('s')),
+    command("Unsnooze PR", "u", KeyCode::Char('u')),
+    command("Snooze status", "i", KeyCode::Char('i')),
+    command("Mark reviewed", "m", KeyCode::Char('m')),
+    command("Pin / unpin PR", "p", KeyCode::Char('p')),
+    command("Unpin all", "P", KeyCode::Char('P')),
+    command("Undo last action", "z", KeyCode::Char('z')),
+    command("Toggle Active/Snoozed", "Tab", KeyCode::Tab),
+    command("Sort Snoozed by score/expiry", "S", KeyCode::Char('S')),
+    command("Sort by score per line", "e", KeyCode::Char('e')),
+    command("Jump to repo", "g", KeyCode::Char('g')),
+    command("Show all repos", "Esc", KeyCode::Esc),
+    command("Toggle author column", "a", KeyCode::Char('a')),
+    command("Toggle open on select", "O", KeyCode::Char('O')),
+    command("Refresh PRs", "r", KeyCode::Char('r')),
+    command("Focus mode (pause auto-refresh)", "t", KeyCode::Char('t')),
+    command("Dismiss update banner", "x", KeyCode::Char('x')),
+    command("Help", "?", KeyCode::Char('?')),
+    command("Quit", "q", KeyCode::Char('q')),
+];
+
+/// Fuzzy match: every query character appears in `name` in order, ignoring
+/// case and spaces in the query. Lower is a better match (an earlier start
+/// and fewer skipped characters); None when it doesn't match.
+fn match_cost(query: &str, name: &str) -> Option<usize> {
+    let name: Vec<char> = name.to_lowercase().chars().collect();
+    let mut pos = 0;
+    let mut first = None;
+    let mut skipped = 0;
+    for q in query.to_lowercase().chars().filter(|c| !c.is_whitespace()) {
+        let offset = name[pos..].iter().position(|&c| c == q)?;
+        if first.is_none() {
+            first = Some(pos + offset);
+        } else {
+            skipped += offset;
+        }
+        pos += offset + 1;
+    }
+    Some(first.unwrap_or(0) + skipped)
+}
+
+/// Commands matching `query`, best first (ties keep list order)
+pub fn filter_commands(query: &str) -> Vec<&'static PaletteCommand> {
+    let mut matches: Vec<(usize, &'static PaletteCommand)> = COMMANDS
+        .iter()
+        .filter_map(|command| match_cost(query, command.name).map(|cost| (cost, command)))
+        .collect();
+    matches.sort_by_key(|(cost, _)| *cost);
+    matches.into_iter().map(|(_, command)| command).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn names(query: &str) -> Vec<&'static str> {
+        filter_commands(query).iter().map(|c| c.name).collect()
+    }
+
+    #[test]
+    fn test_empty_query_lists_everything_in_order() {
+        assert_eq!(names("").len(), COMMANDS.len());
+        assert_eq!(names("")[0], "Open PR in browser");
+    }
+
+    #[test]
+    fn test_fuzzy_filter() {
+        // Subsequence, case-insensitive
+        assert_eq!(names("unsnz"), vec!["Unsnooze PR"]);
+        assert_eq!(names("OPEN REPO"), vec!["Open repository"]);
+        // Closer matches rank first
+        assert_eq!(names("snooze")[0], "Snooze / re-snooze PR");
+        assert!(names("zzzz").is_empty());
+    }
+}
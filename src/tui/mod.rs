@@ -1,5 +1,9 @@
 pub mod app;
+pub mod commands;
 pub mod event;
+#[cfg(test)]
+mod snapshot_tests;
+pub mod snooze_presets;
 pub mod theme;
 pub mod ui;
 
@@ -8,6 +12,7 @@ pub use theme::{resolve_theme, Theme, ThemeColors};
 
 use std::time::Duration;
 
+use crate::browser::{PrPage, PrTab};
 use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
 use event::{Event, EventHandler};
 
@@ -27,7 +32,6 @@ pub async fn run_tui(mut app: App, mut client: octocrab::Octocrab) -> anyhow::Re
     let config_clone = app.config.clone();
     let snooze_clone = app.snooze_state.clone();
     let cache_config_clone = app.cache_config.clone();
-    let verbose = app.verbose;
     let auth_username_clone = app.auth_username.clone();
 
     let mut pending_fetch: Option<tokio::task::JoinHandle<_>> = Some(tokio::spawn(async move {
@@ -38,7 +42,6 @@ pub async fn run_tui(mut app: App, mut client: octocrab::Octocrab) -> anyhow::Re
                 &config_clone,
                 &snooze_clone,
                 &cache_config_clone,
-                verbose,
                 auth_username_clone.as_deref(),
             ),
         )
@@ -70,6 +73,9 @@ pub async fn run_tui(mut app: App, mut client: octocrab::Octocrab) -> anyhow::Re
         None
     };
 
+    // File list fetches for the changed files popup, by PR URL
+    let mut pending_files: Vec<(String, tokio::task::JoinHandle<_>)> = Vec::new();
+
     // Main loop
     loop {
         // Draw UI
@@ -83,19 +89,29 @@ pub async fn run_tui(mut app: App, mut client: octocrab::Octocrab) -> anyhow::Re
             }
             Event::Tick => {
                 app.update_flash();
+                app.update_focus();
                 app.advance_spinner();
+                app.poll_open_on_select();
             }
             Event::Refresh => {
                 app.needs_refresh = true;
             }
         }
 
+        // Abort the running fetch if asked; the current data stays
+        if app.cancel_refresh {
+            if let Some(handle) = pending_fetch.take() {
+                handle.abort();
+            }
+            app.refresh_cancelled();
+        }
+
         // Check if background fetch has completed
         if let Some(handle) = &mut pending_fetch {
             if handle.is_finished() {
                 let handle = pending_fetch.take().unwrap();
                 match handle.await {
-                    Ok(Ok(Ok((active, snoozed, rate_limit)))) => {
+                    Ok(Ok(Ok((active, snoozed, rate_limit, _timings)))) => {
                         app.update_prs(active, snoozed, rate_limit);
                     }
                     Ok(Ok(Err(e))) => {
@@ -163,9 +179,43 @@ pub async fn run_tui(mut app: App, mut client: octocrab::Octocrab) -> anyhow::Re
                     }
                 }
                 app.is_loading = false;
+
+                // The refresh the quit prompt was waiting on is done
+                if app.input_mode == app::InputMode::ConfirmQuit {
+                    app.should_quit = true;
+                }
             }
         }
 
+        // Start a file list fetch the changed files popup asked for
+        if let Some(pr) = app.files_fetch_request.take() {
+            let client_clone = client.clone();
+            pending_files.push((
+                pr.url.clone(),
+                tokio::spawn(async move {
+                    tokio::time::timeout(
+                        Duration::from_secs(20),
+                        crate::github::fetch_changed_files(&client_clone, &pr),
+                    )
+                    .await
+                }),
+            ));
+        }
+
+        // Collect finished file list fetches
+        let (finished, running): (Vec<_>, Vec<_>) = pending_files
+            .into_iter()
+            .partition(|(_, handle)| handle.is_finished());
+        pending_files = running;
+        for (url, handle) in finished {
+            let result = match handle.await {
+                Ok(Ok(result)) => result,
+                Ok(Err(_elapsed)) => Err(anyhow::anyhow!("Timed out fetching the file list (20s)")),
+                Err(e) => Err(anyhow::anyhow!("File list task panicked: {}", e)),
+            };
+            app.finish_files_fetch(url, result);
+        }
+
         // Check if background version check completed
         if let Some(handle) = &mut pending_version_check {
             if handle.is_finished() {
@@ -183,11 +233,12 @@ pub async fn run_tui(mut app: App, mut client: octocrab::Octocrab) -> anyhow::Re
             let is_manual = app.force_refresh;
             let modal_open = app.input_mode != app::InputMode::Normal;
             let recent_interaction = app.last_interaction.elapsed() < Duration::from_secs(10);
+            let focusing = app.focus_remaining().is_some();
 
-            // Suppress auto-refresh if modal is open or user interacted recently.
-            // Manual refresh ('r' key) always proceeds.
+            // Suppress auto-refresh if modal is open, user interacted recently,
+            // or focus mode is on. Manual refresh ('r' key) always proceeds.
             // When suppressed, needs_refresh stays true so it retries on the next tick.
-            if is_manual || (!modal_open && !recent_interaction) {
+            if is_manual || (!modal_open && !recent_interaction && !focusing) {
                 app.needs_refresh = false;
 
                 if is_manual {
@@ -202,7 +253,6 @@ pub async fn run_tui(mut app: App, mut client: octocrab::Octocrab) -> anyhow::Re
                 let config_clone = app.config.clone();
                 let snooze_clone = app.snooze_state.clone();
                 let cache_config_clone = app.cache_config.clone();
-                let verbose = app.verbose;
                 let auth_username_clone = app.auth_username.clone();
 
                 pending_fetch = Some(tokio::spawn(async move {
@@ -213,7 +263,6 @@ pub async fn run_tui(mut app: App, mut client: octocrab::Octocrab) -> anyhow::Re
                             &config_clone,
                             &snooze_clone,
                             &cache_config_clone,
-                            verbose,
                             auth_username_clone.as_deref(),
                         ),
                     )
@@ -244,20 +293,39 @@ fn handle_key_event(app: &mut App, key: KeyEvent) {
         app::InputMode::Normal => {
             match key.code {
                 // Quit
-                KeyCode::Char('q') => app.should_quit = true,
+                KeyCode::Char('q') => app.request_quit(),
+                // Ctrl-c cancels a running refresh first, then quits
+                KeyCode::Char('c')
+                    if key.modifiers.contains(KeyModifiers::CONTROL) && app.is_loading =>
+                {
+                    app.request_cancel_refresh()
+                }
                 KeyCode::Char('c') if key.modifiers.contains(KeyModifiers::CONTROL) => {
-                    app.should_quit = true
+                    app.request_quit()
                 }
 
                 // Navigation
-                KeyCode::Char('j') | KeyCode::Down => app.next_row(),
-                KeyCode::Char('k') | KeyCode::Up => app.previous_row(),
+                KeyCode::Char('j') | KeyCode::Down => {
+                    app.next_row();
+                    app.schedule_open_on_select();
+                }
+                KeyCode::Char('k') | KeyCode::Up => {
+                    app.previous_row();
+                    app.schedule_open_on_select();
+                }
+
+                // Toggle open-on-select
+                KeyCode::Char('O') => app.toggle_open_on_select(),
 
-                // Open PR in browser
-                KeyCode::Enter | KeyCode::Char('o') => {
+                // Toggle author column
+                KeyCode::Char('a') => app.toggle_author_column(),
+
+                // Open PR in browser; F goes straight to the Files changed tab
+                KeyCode::Enter | KeyCode::Char('o') | KeyCode::Char('F') => {
+                    let tab = (key.code == KeyCode::Char('F')).then_some(PrTab::Files);
                     if let Some(pr) = app.selected_pr() {
                         let title = pr.title.clone();
-                        if let Err(e) = app.open_selected() {
+                        if let Err(e) = app.open_selected(tab) {
                             app.show_flash(format!("Failed to open browser: {}", e));
                         } else {
                             app.show_flash(format!("Opened: {}", title));
@@ -265,18 +333,56 @@ fn handle_key_event(app: &mut App, key: KeyEvent) {
                     }
                 }
 
+                // Open the repo / author profile
+                KeyCode::Char('R') | KeyCode::Char('A') => {
+                    let (page, name) = match app.selected_pr() {
+                        Some(pr) if key.code == KeyCode::Char('R') => {
+                            (PrPage::Repo, pr.repo.clone())
+                        }
+                        Some(pr) => (PrPage::Author, format!("@{}", pr.author)),
+                        None => return,
+                    };
+                    if let Err(e) = app.open_selected_page(page) {
+                        app.show_flash(format!("Failed to open browser: {}", e));
+                    } else {
+                        app.show_flash(format!("Opened: {}", name));
+                    }
+                }
+
                 // Snooze
                 KeyCode::Char('s') => app.start_snooze_input(),
 
                 // Unsnooze
                 KeyCode::Char('u') => app.unsnooze_selected(),
 
+                // Why is this (not) snoozed?
+                KeyCode::Char('i') => app.show_snooze_status(),
+
+                // Mark reviewed locally
+                KeyCode::Char('m') => app.toggle_mark_selected(),
+
+                // Pin to the top / clear all pins
+                KeyCode::Char('p') => app.toggle_pin_selected(),
+                KeyCode::Char('P') => app.clear_pins(),
+
                 // Undo
                 KeyCode::Char('z') => app.undo_last(),
 
                 // Tab switching
                 KeyCode::Tab => app.toggle_view(),
 
+                // Snoozed view order / score per line order
+                KeyCode::Char('S') => app.toggle_snoozed_sort(),
+                KeyCode::Char('e') => app.toggle_efficiency_sort(),
+
+                // Focus mode: pause auto-refresh
+                KeyCode::Char('t') => app.toggle_focus(),
+
+                // Jump to repo / clear repo filter
+                KeyCode::Char('g') => app.start_repo_picker(),
+                KeyCode::Esc if app.is_loading => app.request_cancel_refresh(),
+                KeyCode::Esc => app.clear_repo_filter(),
+
                 // Refresh (manual = force fresh data)
                 KeyCode::Char('r') => {
                     app.needs_refresh = true;
@@ -284,17 +390,19 @@ fn handle_key_event(app: &mut App, key: KeyEvent) {
                     app.show_flash("Refreshing (fresh data)...".to_string());
                 }
 
-                // Help
+                // Help / command palette
                 KeyCode::Char('?') => app.show_help(),
+                KeyCode::Char(':') => app.start_command_palette(),
 
                 // Score breakdown
                 KeyCode::Char('b') => app.show_score_breakdown(),
 
+                // Changed files preview
+                KeyCode::Char('f') => app.show_changed_files(),
+
                 // Dismiss update banner
-                KeyCode::Char('x') => {
-                    if app.has_update_banner() {
-                        app.dismiss_update_banner();
-                    }
+                KeyCode::Char('x') if app.has_update_banner() => {
+                    app.dismiss_update_banner();
                 }
 
                 _ => {}
@@ -308,6 +416,17 @@ fn handle_key_event(app: &mut App, key: KeyEvent) {
                 // Cancel snooze
                 KeyCode::Esc => app.cancel_snooze_input(),
 
+                // Quick-pick navigation. j/k are duration text here ("1week"),
+                // so only arrows and Ctrl-n/p move
+                KeyCode::Down => app.next_snooze_preset(),
+                KeyCode::Up => app.previous_snooze_preset(),
+                KeyCode::Char('n') if key.modifiers.contains(KeyModifiers::CONTROL) => {
+                    app.next_snooze_preset()
+                }
+                KeyCode::Char('p') if key.modifiers.contains(KeyModifiers::CONTROL) => {
+                    app.previous_snooze_preset()
+                }
+
                 // Backspace
                 KeyCode::Backspace => {
                     app.snooze_input.pop();
@@ -316,6 +435,7 @@ fn handle_key_event(app: &mut App, key: KeyEvent) {
                 // Character input (alphanumeric + space)
                 KeyCode::Char(c) if c.is_alphanumeric() || c == ' ' => {
                     app.snooze_input.push(c);
+                    app.snooze_preset = None;
                 }
 
                 // Ignore all other keys (don't propagate to Normal mode)
@@ -328,6 +448,49 @@ fn handle_key_event(app: &mut App, key: KeyEvent) {
             KeyCode::Char('k') | KeyCode::Up => app.previous_row(),
             _ => {}
         },
+        app::InputMode::ChangedFiles => match key.code {
+            KeyCode::Esc | KeyCode::Char('f') | KeyCode::Char('q') => app.dismiss_changed_files(),
+            KeyCode::Char('j') | KeyCode::Down => app.scroll_files_down(),
+            KeyCode::Char('k') | KeyCode::Up => app.scroll_files_up(),
+            _ => {}
+        },
+        app::InputMode::RepoPicker => match key.code {
+            KeyCode::Enter => app.confirm_repo_picker(),
+            KeyCode::Esc | KeyCode::Char('g') => app.cancel_repo_picker(),
+            KeyCode::Char('j') | KeyCode::Down => app.next_repo_choice(),
+            KeyCode::Char('k') | KeyCode::Up => app.previous_repo_choice(),
+            _ => {}
+        },
+        app::InputMode::CommandPalette => match key.code {
+            KeyCode::Enter => {
+                if let Some(code) = app.confirm_command_palette() {
+                    handle_key_event(app, KeyEvent::new(code, KeyModifiers::NONE));
+                }
+            }
+            KeyCode::Esc => app.cancel_command_palette(),
+            // j/k are search text here, so only arrows and Ctrl-n/p move
+            KeyCode::Down => app.next_palette_match(),
+            KeyCode::Up => app.previous_palette_match(),
+            KeyCode::Char('n') if key.modifiers.contains(KeyModifiers::CONTROL) => {
+                app.next_palette_match()
+            }
+            KeyCode::Char('p') if key.modifiers.contains(KeyModifiers::CONTROL) => {
+                app.previous_palette_match()
+            }
+            KeyCode::Backspace => app.palette_pop(),
+            KeyCode::Char(c) if !key.modifiers.contains(KeyModifiers::CONTROL) => {
+                app.palette_push(c)
+            }
+            _ => {}
+        },
+        app::InputMode::ConfirmQuit => match key.code {
+            KeyCode::Char('y') | KeyCode::Char('q') => app.should_quit = true,
+            // A second Ctrl-c always gets out
+            KeyCode::Char('c') if key.modifiers.contains(KeyModifiers::CONTROL) => {
+                app.should_quit = true
+            }
+            _ => app.cancel_quit(),
+        },
         app::InputMode::Help => {
             // Any key exits help
             app.dismiss_help();
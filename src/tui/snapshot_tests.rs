@@ -0,0 +1,338 @@
+//! Headless render tests: each case draws the UI into a ratatui `TestBackend`
+//! and compares the text of the buffer with a file in `src/tui/snapshots/`.
+//!
+//! Snapshots hold characters only, not colors. After an intended UI change,
+//! regenerate them with `UPDATE_SNAPSHOTS=1 cargo test snapshot` and review
+//! the diff like any other change.
+
+use std::path::{Path, PathBuf};
+use std::time::Instant;
+
+use chrono::{Duration, Utc};
+use ratatui::backend::TestBackend;
+use ratatui::Terminal;
+
+use crate::config::{Config, DisplayTimezone};
+use crate::github::cache::CacheConfig;
+use crate::github::search::ChangedFile;
+use crate::github::types::PullRequest;
+use crate::marks::MarkState;
+use crate::pins::PinState;
+use crate::scoring::{FactorContribution, ScoreBreakdown, ScoreResult};
+use crate::snooze::SnoozeState;
+use crate::tui::app::{App, ChangedFilesState, InputMode, View};
+use crate::tui::theme::Theme;
+use crate::tui::ui;
+use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+
+const WIDTH: u16 = 100;
+const HEIGHT: u16 = 30;
+
+fn snapshot_dir() -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR")).join("src/tui/snapshots")
+}
+
+fn pr(number: u64, repo: &str, author: &str, title: &str) -> PullRequest {
+    PullRequest {
+        title: title.to_string(),
+        number,
+        author: author.to_string(),
+        repo: repo.to_string(),
+        url: format!("https://github.com/{}/pull/{}", repo, number),
+        created_at: Utc::now() - Duration::days(3),
+        updated_at: Utc::now() - Duration::days(1),
+        additions: 120,
+        deletions: 30,
+        ..PullRequest::test_default()
+    }
+}
+
+fn scored(pr: PullRequest, score: f64) -> (PullRequest, ScoreResult) {
+    let result = ScoreResult {
+        score,
+        incomplete: false,
+        sla_breached: false,
+        oldest_alert: false,
+        breakdown: ScoreBreakdown {
+            base_score: 1.0,
+            factors: vec![
+                FactorContribution {
+                    label: "Age".to_string(),
+                    description: "+1 per 1h (72 units)".to_string(),
+                    before: 1.0,
+                    after: 73.0,
+                },
+                FactorContribution {
+                    label: "Size".to_string(),
+                    description: "matched '<500' -> x1.5".to_string(),
+                    before: 73.0,
+                    after: score,
+                },
+            ],
+            disabled: vec![],
+        },
+    };
+    (pr, result)
+}
+
+fn sample_active() -> Vec<(PullRequest, ScoreResult)> {
+    vec![
+        scored(
+            pr(42, "acme/api", "octocat", "Add retry to webhook delivery"),
+            109.5,
+        ),
+        scored(
+            pr(
+                7,
+                "acme/web",
+                "hubot",
+                "Fix dark mode contrast on settings page",
+            ),
+            64.0,
+        ),
+        scored(
+            PullRequest {
+                labels: vec!["dependencies".to_string()],
+                ..pr(311, "acme/api", "monalisa", "Bump serde to 1.0.210")
+            },
+            12.0,
+        ),
+    ]
+}
+
+fn config() -> Config {
+    serde_saphyr::from_str("queries: []").unwrap()
+}
+
+fn cache_config() -> CacheConfig {
+    CacheConfig {
+        enabled: false,
+        compress: false,
+        bypass_reads: false,
+    }
+}
+
+/// Build an App that renders the same way on every machine: no marks or pins
+/// from the user's data dir and a refresh that just happened
+fn app_with(
+    active: Vec<(PullRequest, ScoreResult)>,
+    snoozed: Vec<(PullRequest, ScoreResult)>,
+) -> App {
+    let mut snooze_state = SnoozeState::new();
+    for (pr, _) in &snoozed {
+        snooze_state.snooze(pr.url.clone(), None);
+    }
+    let mut app = App::new(
+        active,
+        snoozed,
+        snooze_state,
+        PathBuf::from("/nonexistent/snooze.json"),
+        config(),
+        cache_config(),
+        None,
+        None,
+        true,
+        Theme::Dark,
+    );
+    app.mark_state = MarkState::new();
+    app.pin_state = PinState::new();
+    app
+}
+
+fn render(app: &mut App) -> String {
+    app.last_refresh = Instant::now();
+    let mut terminal = Terminal::new(TestBackend::new(WIDTH, HEIGHT)).unwrap();
+    terminal.draw(|frame| ui::draw(frame, app)).unwrap();
+
+    let buffer = terminal.backend().buffer();
+    let mut text = String::new();
+    for y in 0..buffer.area.height {
+        let line: String = (0..buffer.area.width)
+            .map(|x| buffer[(x, y)].symbol())
+            .collect();
+        text.push_str(line.trim_end());
+        text.push('\n');
+    }
+    text
+}
+
+/// Compare `actual` with the stored snapshot, or rewrite it when
+/// `UPDATE_SNAPSHOTS` is set
+fn assert_snapshot(name: &str, actual: &str) {
+    let path = snapshot_dir().join(format!("{}.txt", name));
+    if std::env::var_os("UPDATE_SNAPSHOTS").is_some() {
+        std::fs::create_dir_all(snapshot_dir()).unwrap();
+        std::fs::write(&path, actual).unwrap();
+        return;
+    }
+
+    let expected = std::fs::read_to_string(&path).unwrap_or_else(|_| {
+        panic!(
+            "missing snapshot {}; run with UPDATE_SNAPSHOTS=1 to create it",
+            path.display()
+        )
+    });
+    if expected != actual {
+        let diff: Vec<String> = expected
+            .lines()
+            .zip(actual.lines())
+            .enumerate()
+            .filter(|(_, (e, a))| e != a)
+            .map(|(i, (e, a))| format!("line {}:\n  - {}\n  + {}", i + 1, e, a))
+            .collect();
+        panic!(
+            "snapshot {} does not match (UPDATE_SNAPSHOTS=1 to accept):\n{}\n\nactual:\n{}",
+            name,
+            diff.join("\n"),
+            actual
+        );
+    }
+}
+
+#[test]
+fn snapshot_empty() {
+    let mut app = app_with(vec![], vec![]);
+    assert_snapshot("empty", &render(&mut app));
+}
+
+#[test]
+fn snapshot_loading() {
+    let mut app = App::new_loading(
+        SnoozeState::new(),
+        PathBuf::from("/nonexistent/snooze.json"),
+        config(),
+        cache_config(),
+        None,
+        None,
+        true,
+        Theme::Dark,
+    );
+    app.mark_state = MarkState::new();
+    app.pin_state = PinState::new();
+    assert_snapshot("loading", &render(&mut app));
+}
+
+#[test]
+fn snapshot_active() {
+    let mut app = app_with(sample_active(), vec![]);
+    assert_snapshot("active", &render(&mut app));
+}
+
+#[test]
+fn snapshot_efficiency_sort() {
+    let mut app = app_with(sample_active(), vec![]);
+    app.toggle_efficiency_sort();
+    assert_snapshot("efficiency_sort", &render(&mut app));
+}
+
+#[test]
+fn snapshot_focus_mode() {
+    let mut app = app_with(sample_active(), vec![]);
+    app.rate_limit_remaining = Some(4321);
+    app.toggle_focus();
+    app.flash_message = None;
+    assert_snapshot("focus_mode", &render(&mut app));
+}
+
+#[test]
+fn snapshot_snoozed_view() {
+    let snoozed = vec![scored(
+        pr(9, "acme/infra", "octocat", "Migrate CI runners"),
+        30.0,
+    )];
+    let mut app = app_with(sample_active(), snoozed);
+    app.current_view = View::Snoozed;
+    app.table_state.select(Some(0));
+    assert_snapshot("snoozed_view", &render(&mut app));
+}
+
+#[test]
+fn snapshot_help_popup() {
+    let mut app = app_with(sample_active(), vec![]);
+    app.show_help();
+    assert_snapshot("help_popup", &render(&mut app));
+}
+
+#[test]
+fn snapshot_command_palette() {
+    let mut app = app_with(sample_active(), vec![]);
+    app.start_command_palette();
+    for c in "snoo".chars() {
+        app.palette_push(c);
+    }
+    app.next_palette_match();
+    assert_snapshot("command_palette", &render(&mut app));
+}
+
+#[test]
+fn snapshot_snooze_popup() {
+    let mut app = app_with(sample_active(), vec![]);
+    app.start_snooze_input();
+    assert_eq!(app.input_mode, InputMode::SnoozeInput);
+    assert_snapshot("snooze_popup", &render(&mut app));
+}
+
+#[test]
+fn snooze_popup_accepts_durations_with_j_and_k() {
+    let mut app = app_with(sample_active(), vec![]);
+    app.start_snooze_input();
+    for c in "1week".chars() {
+        super::handle_key_event(
+            &mut app,
+            KeyEvent::new(KeyCode::Char(c), KeyModifiers::NONE),
+        );
+    }
+    assert_eq!(app.snooze_input, "1week");
+    assert_eq!(app.input_mode, InputMode::SnoozeInput);
+}
+
+#[test]
+fn snapshot_score_breakdown_popup() {
+    let mut app = app_with(sample_active(), vec![]);
+    app.config.timezone = Some("utc".to_string());
+    app.show_score_breakdown();
+    // Absolute times move with the clock; mask them, keeping their width
+    let pr = app.selected_pr().unwrap().clone();
+    let mut text = render(&mut app);
+    for time in [pr.created_at, pr.updated_at] {
+        text = text.replace(&DisplayTimezone::Utc.format(time), "YYYY-MM-DD hh:mm UTC");
+    }
+    assert_snapshot("score_breakdown_popup", &text);
+}
+
+#[test]
+fn snapshot_repo_picker_popup() {
+    let mut app = app_with(sample_active(), vec![]);
+    app.start_repo_picker();
+    assert_snapshot("repo_picker_popup", &render(&mut app));
+}
+
+#[test]
+fn snapshot_changed_files_popup() {
+    let mut app = app_with(sample_active(), vec![]);
+    app.show_changed_files();
+    let url = app.files_popup_url.clone().unwrap();
+    let file = |filename: &str, additions, deletions| ChangedFile {
+        filename: filename.to_string(),
+        additions,
+        deletions,
+        hunks: None,
+    };
+    app.changed_files.insert(
+        url,
+        ChangedFilesState::Loaded(vec![
+            file("src/webhook/deliver.rs", 96, 20),
+            file("src/webhook/retry.rs", 24, 0),
+            file("CHANGELOG.md", 0, 10),
+        ]),
+    );
+    assert_snapshot("changed_files_popup", &render(&mut app));
+}
+
+#[test]
+fn snapshot_confirm_quit_popup() {
+    let mut app = app_with(sample_active(), vec![]);
+    app.is_loading = true;
+    app.input_mode = InputMode::ConfirmQuit;
+    assert_snapshot("confirm_quit_popup", &render(&mut app));
+}
@@ -0,0 +1,115 @@
+use chrono::{DateTime, Datelike, Duration, NaiveTime, TimeZone, Utc};
+
+/// Hour of day (local time) used for "tomorrow" and "next week" presets
+const MORNING_HOUR: u32 = 9;
+
+/// Quick-pick choices offered in the snooze popup
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SnoozePreset {
+    OneHour,
+    EndOfDay,
+    Tomorrow,
+    NextWeek,
+    Indefinitely,
+}
+
+impl SnoozePreset {
+    pub const ALL: [SnoozePreset; 5] = [
+        SnoozePreset::OneHour,
+        SnoozePreset::EndOfDay,
+        SnoozePreset::Tomorrow,
+        SnoozePreset::NextWeek,
+        SnoozePreset::Indefinitely,
+    ];
+
+    pub fn label(self) -> &'static str {
+        match self {
+            SnoozePreset::OneHour => "1 hour",
+            SnoozePreset::EndOfDay => "End of day",
+            SnoozePreset::Tomorrow => "Tomorrow 9am",
+            SnoozePreset::NextWeek => "Next week (Mon 9am)",
+            SnoozePreset::Indefinitely => "Indefinitely",
+        }
+    }
+
+    /// Compute the snooze end time relative to `now`.
+    ///
+    /// Calendar-based presets are evaluated in `now`'s timezone, so "end of day"
+    /// means local midnight rather than UTC midnight. Returns None for an
+    /// indefinite snooze.
+    pub fn until<Tz: TimeZone>(self, now: &DateTime<Tz>) -> Option<DateTime<Utc>> {
+        let tz = now.timezone();
+        let today = now.date_naive();
+        let morning = NaiveTime::from_hms_opt(MORNING_HOUR, 0, 0).expect("valid time");
+
+        let local_at = |date: chrono::NaiveDate, time: NaiveTime| {
+            // `earliest` picks the first instant when a DST transition makes the
+            // local time ambiguous; fall back to UTC interpretation if it's skipped
+            tz.from_local_datetime(&date.and_time(time))
+                .earliest()
+                .map(|dt| dt.with_timezone(&Utc))
+                .unwrap_or_else(|| date.and_time(time).and_utc())
+        };
+
+        match self {
+            SnoozePreset::OneHour => Some(now.with_timezone(&Utc) + Duration::hours(1)),
+            SnoozePreset::EndOfDay => Some(local_at(today + Duration::days(1), NaiveTime::MIN)),
+            SnoozePreset::Tomorrow => Some(local_at(today + Duration::days(1), morning)),
+            SnoozePreset::NextWeek => {
+                let days_to_monday = 7 - now.weekday().num_days_from_monday() as i64;
+                Some(local_at(today + Duration::days(days_to_monday), morning))
+            }
+            SnoozePreset::Indefinitely => None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::FixedOffset;
+
+    fn at(offset_hours: i32, s: &str) -> DateTime<FixedOffset> {
+        let tz = FixedOffset::east_opt(offset_hours * 3600).unwrap();
+        tz.from_local_datetime(&chrono::NaiveDateTime::parse_from_str(s, "%Y-%m-%d %H:%M").unwrap())
+            .unwrap()
+    }
+
+    #[test]
+    fn test_end_of_day_is_local_midnight() {
+        // 2025-03-05 22:30 at UTC-5 is already 03:30 on the 6th in UTC
+        let now = at(-5, "2025-03-05 22:30");
+        let until = SnoozePreset::EndOfDay.until(&now).unwrap();
+        assert_eq!(until, at(-5, "2025-03-06 00:00").with_timezone(&Utc));
+    }
+
+    #[test]
+    fn test_tomorrow_is_next_morning() {
+        let now = at(2, "2025-03-05 14:00");
+        let until = SnoozePreset::Tomorrow.until(&now).unwrap();
+        assert_eq!(until, at(2, "2025-03-06 09:00").with_timezone(&Utc));
+    }
+
+    #[test]
+    fn test_next_week_is_following_monday() {
+        // Wednesday -> next Monday
+        let now = at(0, "2025-03-05 14:00");
+        let until = SnoozePreset::NextWeek.until(&now).unwrap();
+        assert_eq!(until, at(0, "2025-03-10 09:00").with_timezone(&Utc));
+
+        // Monday -> the Monday after, not today
+        let now = at(0, "2025-03-10 08:00");
+        let until = SnoozePreset::NextWeek.until(&now).unwrap();
+        assert_eq!(until, at(0, "2025-03-17 09:00").with_timezone(&Utc));
+    }
+
+    #[test]
+    fn test_one_hour_and_indefinite() {
+        let now = at(0, "2025-03-05 14:00");
+        assert_eq!(
+            SnoozePreset::OneHour.until(&now).unwrap(),
+            at(0, "2025-03-05 15:00").with_timezone(&Utc)
+        );
+        assert_eq!(SnoozePreset::Indefinitely.until(&now), None);
+    }
+}
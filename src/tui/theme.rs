@@ -1,20 +1,65 @@
 //! Centralized theme module for TUI color constants and styles
 
+use crate::output::palette::{palette_index, PALETTE_LEN};
 use ratatui::prelude::*;
 
+/// Score percentage (relative to the list's max score) at or above which a score is "high"
+pub const SCORE_HIGH_THRESHOLD: f64 = 70.0;
+
+/// Score percentage (relative to the list's max score) at or above which a score is "mid"
+pub const SCORE_MID_THRESHOLD: f64 = 40.0;
+
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum Theme {
     Light,
     Dark,
 }
 
+/// Score band relative to the top score in the list
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ScoreLevel {
+    High,
+    Mid,
+    Low,
+}
+
+impl ScoreLevel {
+    /// Band for a score as a percentage of the list's max score
+    pub fn of(score: f64, max_score: f64) -> Self {
+        let percentage = if max_score > 0.0 {
+            (score / max_score) * 100.0
+        } else {
+            0.0
+        };
+
+        if percentage >= SCORE_HIGH_THRESHOLD {
+            ScoreLevel::High
+        } else if percentage >= SCORE_MID_THRESHOLD {
+            ScoreLevel::Mid
+        } else {
+            ScoreLevel::Low
+        }
+    }
+
+    /// Shape shown next to scores when levels must not rely on color alone
+    pub fn glyph(self) -> &'static str {
+        match self {
+            ScoreLevel::High => "▲",
+            ScoreLevel::Mid => "▶",
+            ScoreLevel::Low => "▼",
+        }
+    }
+}
+
 /// Complete color palette for the TUI
 #[derive(Debug, Clone)]
 pub struct ThemeColors {
-    // Score-based colors (traffic light pattern)
+    // Score-based colors (traffic light pattern, or orange/blue/gray when colorblind)
     pub score_high: Color,
     pub score_mid: Color,
     pub score_low: Color,
+    /// Also mark score levels with `ScoreLevel::glyph` (colorblind mode)
+    pub score_glyphs: bool,
 
     // Score bar colors
     pub bar_filled_high: Color,
@@ -62,14 +107,40 @@ pub struct ThemeColors {
     pub banner_bg: Color,
     pub banner_fg: Color,
     pub banner_key: Color,
+
+    // Per-name colors (authors), indexed by `output::palette::palette_index`
+    pub name_palette: [Color; PALETTE_LEN],
 }
 
 impl ThemeColors {
-    /// Create a ThemeColors palette for the given theme
-    pub fn new(theme: Theme) -> Self {
-        match theme {
+    /// Create a ThemeColors palette for the given theme, optionally with
+    /// color-blind-friendly score colors
+    pub fn new(theme: Theme, colorblind: bool) -> Self {
+        let colors = match theme {
             Theme::Dark => Self::dark(),
             Theme::Light => Self::light(),
+        };
+        if colorblind {
+            colors.colorblind()
+        } else {
+            colors
+        }
+    }
+
+    /// Swap red/yellow/green score colors for orange/blue/gray, which stay
+    /// distinguishable with red-green color blindness, and turn on score glyphs
+    fn colorblind(self) -> Self {
+        let orange = Color::Rgb(230, 159, 0);
+        let blue = Color::Rgb(0, 114, 178);
+        Self {
+            score_high: orange,
+            score_mid: blue,
+            score_low: self.muted,
+            score_glyphs: true,
+            bar_filled_high: orange,
+            bar_filled_mid: blue,
+            bar_filled_low: self.muted,
+            ..self
         }
     }
 
@@ -79,6 +150,7 @@ impl ThemeColors {
             score_high: Color::Red,
             score_mid: Color::Yellow,
             score_low: Color::Green,
+            score_glyphs: false,
             bar_filled_high: Color::Red,
             bar_filled_mid: Color::Yellow,
             bar_filled_low: Color::Green,
@@ -106,6 +178,14 @@ impl ThemeColors {
             banner_bg: Color::Rgb(50, 50, 120),
             banner_fg: Color::White,
             banner_key: Color::Yellow,
+            name_palette: [
+                Color::Cyan,
+                Color::Green,
+                Color::Yellow,
+                Color::LightBlue,
+                Color::Magenta,
+                Color::LightRed,
+            ],
         }
     }
 
@@ -116,6 +196,7 @@ impl ThemeColors {
             score_high: Color::Red,
             score_mid: Color::Yellow,
             score_low: Color::Green,
+            score_glyphs: false,
             bar_filled_high: Color::Red,
             bar_filled_mid: Color::Yellow,
             bar_filled_low: Color::Green,
@@ -153,25 +234,42 @@ impl ThemeColors {
             banner_bg: Color::Rgb(180, 180, 230), // Lighter blue-purple
             banner_fg: Color::Black,              // Dark text on light banner
             banner_key: Color::Indexed(88),       // Dark red for highlight
+            // Darker hues so names stay readable on a light background
+            name_palette: [
+                Color::Indexed(30),  // Teal
+                Color::Indexed(28),  // Green
+                Color::Indexed(130), // Orange
+                Color::Indexed(25),  // Blue
+                Color::Indexed(90),  // Purple
+                Color::Indexed(124), // Dark red
+            ],
         }
     }
 
+    /// Stable color for a name (e.g. an author login)
+    pub fn name_color(&self, name: &str) -> Color {
+        self.name_palette[palette_index(name)]
+    }
+
     /// Returns the appropriate color for a score based on its percentage of max score
     pub fn score_color(&self, score: f64, max_score: f64) -> Color {
-        let percentage = if max_score > 0.0 {
-            (score / max_score) * 100.0
-        } else {
-            0.0
-        };
+        self.level_color(ScoreLevel::of(score, max_score))
+    }
 
-        if percentage >= 70.0 {
-            self.score_high
-        } else if percentage >= 40.0 {
-            self.score_mid
-        } else {
-            self.score_low
+    /// Color for a score level
+    pub fn level_color(&self, level: ScoreLevel) -> Color {
+        match level {
+            ScoreLevel::High => self.score_high,
+            ScoreLevel::Mid => self.score_mid,
+            ScoreLevel::Low => self.score_low,
         }
     }
+
+    /// Glyph for a score's level, when score glyphs are on
+    pub fn score_glyph(&self, score: f64, max_score: f64) -> Option<&'static str> {
+        self.score_glyphs
+            .then(|| ScoreLevel::of(score, max_score).glyph())
+    }
 }
 
 /// Resolve theme from config string ("dark", "light", "auto")
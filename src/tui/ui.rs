@@ -1,14 +1,23 @@
-use crate::tui::app::{App, InputMode, View};
-use crate::tui::theme::ThemeColors;
+use crate::config::{ScoreBarConfig, SnoozedSort};
+use crate::github::types::PullRequest;
+use crate::output::formatter::AUTHOR_MAX_WIDTH;
+use crate::output::{format_efficiency, index_label, index_width, normalized_score};
+use crate::scoring::{efficiency, ScoreResult};
+use crate::tui::app::{App, ChangedFilesState, InputMode, View};
+use crate::tui::snooze_presets::SnoozePreset;
+use crate::tui::theme::{ScoreLevel, ThemeColors, SCORE_HIGH_THRESHOLD, SCORE_MID_THRESHOLD};
 use crate::version_check::VersionStatus;
 use chrono::{Datelike, Local};
 use ratatui::layout::Margin;
 use ratatui::prelude::*;
 use ratatui::widgets::{
     Block, Cell, Clear, Paragraph, Row, Scrollbar, ScrollbarOrientation, ScrollbarState, Table,
-    Tabs,
+    TableState, Tabs, Wrap,
 };
 
+/// Extra rows built past the viewport so a one-row scroll never shows a gap
+const VIEWPORT_BUFFER: usize = 4;
+
 pub fn draw(frame: &mut Frame, app: &mut App) {
     let area = frame.area();
 
@@ -56,13 +65,21 @@ pub fn draw(frame: &mut Frame, app: &mut App) {
         InputMode::SnoozeInput => render_snooze_popup(frame, app),
         InputMode::Help => render_help_popup(frame, app),
         InputMode::ScoreBreakdown => render_score_breakdown_popup(frame, app),
-        InputMode::Normal => {}
+        InputMode::RepoPicker => render_repo_picker_popup(frame, app),
+        InputMode::ChangedFiles => render_changed_files_popup(frame, app),
+        InputMode::CommandPalette => render_command_palette(frame, app),
+        InputMode::Normal | InputMode::ConfirmQuit => {}
     }
 
     // Render loading overlay if loading (appears on top of everything)
     if app.is_loading {
         render_loading_overlay(frame, app);
     }
+
+    // The quit prompt goes above the loading overlay, which is what it's about
+    if app.input_mode == InputMode::ConfirmQuit {
+        render_confirm_quit_popup(frame, app);
+    }
 }
 
 fn render_title(frame: &mut Frame, area: Rect, app: &App) {
@@ -72,19 +89,42 @@ fn render_title(frame: &mut Frame, area: Rect, app: &App) {
         Style::default().fg(app.theme_colors.title_color).bold(),
     )];
 
+    // Focus mode countdown, in whole minutes rounded up
+    let focus_text = app
+        .focus_remaining()
+        .map(|left| format!("Focus {}m", left.as_secs().div_ceil(60)));
     // Add rate limit info on the right if available
-    if let Some(remaining) = app.rate_limit_remaining {
-        let rate_limit_text = format!("API: {} remaining", remaining);
+    let rate_limit_text = app
+        .rate_limit_remaining
+        .map(|remaining| format!("API: {} remaining", remaining));
+
+    if focus_text.is_some() || rate_limit_text.is_some() {
+        let separator = if focus_text.is_some() && rate_limit_text.is_some() {
+            "  "
+        } else {
+            ""
+        };
         let left_len = "PR Bro".len();
-        let right_len = rate_limit_text.len();
+        let right_len = focus_text.as_ref().map_or(0, |t| t.len())
+            + separator.len()
+            + rate_limit_text.as_ref().map_or(0, |t| t.len());
         let padding_len = (area.width as usize).saturating_sub(left_len + right_len);
 
-        // Add padding and rate limit text
+        // Add padding, the focus indicator, and rate limit text
         spans.push(Span::raw(" ".repeat(padding_len)));
-        spans.push(Span::styled(
-            rate_limit_text,
-            Style::default().fg(app.theme_colors.muted),
-        ));
+        if let Some(text) = focus_text {
+            spans.push(Span::styled(
+                text,
+                Style::default().fg(app.theme_colors.title_color).bold(),
+            ));
+        }
+        spans.push(Span::raw(separator));
+        if let Some(text) = rate_limit_text {
+            spans.push(Span::styled(
+                text,
+                Style::default().fg(app.theme_colors.muted),
+            ));
+        }
     }
 
     let title = Line::from(spans);
@@ -135,6 +175,80 @@ fn render_tabs(frame: &mut Frame, area: Rect, app: &App) {
     frame.render_widget(tabs, area);
 }
 
+/// Marker shown before the titles of PRs breaching the review SLA
+const SLA_MARKER: &str = "⚑ ";
+
+/// Marker before the title of the PR flagged by `oldest_alert`
+const OLDEST_MARKER: &str = "⧗ ";
+
+/// Marker before the titles of pinned PRs
+const PIN_MARKER: &str = "★ ";
+
+/// PR title, flagged when the PR is pinned, the oldest awaiting review, or
+/// over its review SLA
+fn title_line<'a>(pr: &PullRequest, score_result: &ScoreResult, app: &App) -> Line<'a> {
+    let mut spans = Vec::new();
+    if app.pin_state.is_pinned(&pr.url) {
+        spans.push(Span::styled(
+            PIN_MARKER,
+            Style::default().fg(app.theme_colors.title_color).bold(),
+        ));
+    }
+    if score_result.oldest_alert {
+        spans.push(Span::styled(
+            OLDEST_MARKER,
+            Style::default().fg(app.theme_colors.flash_error).bold(),
+        ));
+    }
+    if score_result.sla_breached {
+        spans.push(Span::styled(
+            SLA_MARKER,
+            Style::default().fg(app.theme_colors.flash_error).bold(),
+        ));
+    }
+    spans.push(Span::raw(pr.title.clone()));
+    for label in &pr.labels {
+        let color = app
+            .config
+            .label_color(label)
+            .unwrap_or_else(|| app.theme_colors.name_color(label));
+        spans.push(Span::raw(" "));
+        spans.push(Span::styled(
+            format!("[{}]", label),
+            Style::default().fg(color),
+        ));
+    }
+    Line::from(spans)
+}
+
+/// Score as shown in the table: raw, or 0-100 with `--normalize`. Colors,
+/// bars, and the breakdown always use the raw score.
+fn display_score(app: &App, score: f64, max_score: f64) -> f64 {
+    if app.normalize_scores {
+        normalized_score(score, max_score)
+    } else {
+        score
+    }
+}
+
+/// Author column width; longer logins are cut off by the table
+const AUTHOR_WIDTH: Constraint = Constraint::Length(AUTHOR_MAX_WIDTH as u16);
+
+/// Score per line column, shown while sorting by it ("Score/line" header)
+const EFFICIENCY_WIDTH: Constraint = Constraint::Length(10);
+
+/// Score per changed line from the raw score, which is what the list is sorted by
+fn efficiency_cell<'a>(pr: &PullRequest, score_result: &ScoreResult, app: &App) -> Cell<'a> {
+    let value = format_efficiency(efficiency(score_result.score, pr.size()));
+    Cell::from(format!("{:>10}", value)).style(Style::default().fg(app.theme_colors.muted))
+}
+
+/// Author login colored by the theme's per-name palette
+fn author_cell<'a>(pr: &PullRequest, app: &App) -> Cell<'a> {
+    Cell::from(pr.author.clone())
+        .style(Style::default().fg(app.theme_colors.name_color(&pr.author)))
+}
+
 fn render_table(frame: &mut Frame, area: Rect, app: &mut App) {
     let prs = app.current_prs();
 
@@ -152,10 +266,28 @@ fn render_table(frame: &mut Frame, area: Rect, app: &mut App) {
         .map(|(_, result)| result.score)
         .fold(0.0_f64, f64::max);
 
+    // Score column: 5-char score, a space, the bar, and padding (plus a
+    // level glyph and space in colorblind mode)
+    let glyph_width = if app.theme_colors.score_glyphs { 2 } else { 0 };
+    let score_width = (app.config.score_bar.width + 8 + glyph_width) as u16;
+    // Index column: the widest label plus a space
+    let index_column_width = (index_width(app.config.index_style) + 1) as u16;
+
     // Store PR count and selected position for scrollbar (before borrowing table_state)
     let pr_count = prs.len();
     let selected_pos = app.table_state.selected().unwrap_or(0);
 
+    // Only build rows for the visible viewport: with org-wide queries the list
+    // can hold thousands of PRs, and building every Row each frame dominates
+    let visible_rows = area.height.saturating_sub(2) as usize; // Subtract header and margin
+    let offset = viewport_offset(
+        app.table_state.selected(),
+        app.table_state.offset(),
+        visible_rows,
+        pr_count,
+    );
+    let window = visible_rows + VIEWPORT_BUFFER;
+
     // Build rows, widths, and header based on current view
     let (rows, widths, header_cells): (Vec<Row>, Vec<Constraint>, Vec<&str>) =
         if matches!(app.current_view, View::Snoozed) {
@@ -163,10 +295,20 @@ fn render_table(frame: &mut Frame, area: Rect, app: &mut App) {
             let rows: Vec<Row> = prs
                 .iter()
                 .enumerate()
+                .skip(offset)
+                .take(window)
                 .map(|(idx, (pr, score_result))| {
-                    let index = format!("{}.", idx + 1);
-                    let score_str = format_score(score_result.score, score_result.incomplete);
-                    let bar_line = score_bar(score_result.score, max_score, 8, &app.theme_colors);
+                    let index = format!("{}.", index_label(app.config.index_style, idx, &pr.url));
+                    let score_str = format_score(
+                        display_score(app, score_result.score, max_score),
+                        score_result.incomplete,
+                    );
+                    let bar_line = score_bar(
+                        score_result.score,
+                        max_score,
+                        &app.config.score_bar,
+                        &app.theme_colors,
+                    );
 
                     // Build score cell with colored text and bar
                     let score_color = app.theme_colors.score_color(score_result.score, max_score);
@@ -177,7 +319,7 @@ fn render_table(frame: &mut Frame, area: Rect, app: &mut App) {
                     score_spans.extend(bar_line.spans);
                     let score_line = Line::from(score_spans);
 
-                    let title = pr.title.clone();
+                    let title = title_line(pr, score_result, app);
 
                     // Get duration from snooze entry
                     let duration = app
@@ -193,27 +335,45 @@ fn render_table(frame: &mut Frame, area: Rect, app: &mut App) {
                     } else {
                         Style::default()
                     };
+                    let row_style = marked_style(row_style, app.mark_state.is_marked(pr), app);
 
-                    Row::new(vec![
+                    let mut cells = vec![
                         Cell::from(index).style(Style::default().fg(app.theme_colors.index_color)),
                         Cell::from(score_line),
                         Cell::from(title),
                         Cell::from(duration).style(Style::default().fg(app.theme_colors.muted)),
-                        Cell::from(pr.short_ref()),
-                    ])
-                    .style(row_style)
+                    ];
+                    if app.show_author {
+                        cells.push(author_cell(pr, app));
+                    }
+                    cells.push(Cell::from(pr.short_ref()));
+                    if app.sort_by_efficiency {
+                        cells.insert(2, efficiency_cell(pr, score_result, app));
+                    }
+                    Row::new(cells).style(row_style)
                 })
                 .collect();
 
-            let widths = vec![
-                Constraint::Length(4),  // Index
-                Constraint::Length(16), // Score + bar
-                Constraint::Fill(1),    // Title
+            let mut widths = vec![
+                Constraint::Length(index_column_width), // Index
+                Constraint::Length(score_width),        // Score + bar
+                Constraint::Fill(1),                    // Title
                 Constraint::Length(12), // Duration: "indefinite" = 10 chars + padding
                 Constraint::Length(40), // PR ref
             ];
-
-            let header = vec!["#", "Score", "Title", "Duration", "PR"];
+            let duration_header = match app.snoozed_sort {
+                SnoozedSort::Score => "Duration",
+                SnoozedSort::Remaining => "Duration ↑",
+            };
+            let mut header = vec!["#", "Score", "Title", duration_header, "PR"];
+            if app.show_author {
+                widths.insert(4, AUTHOR_WIDTH);
+                header.insert(4, "Author");
+            }
+            if app.sort_by_efficiency {
+                widths.insert(2, EFFICIENCY_WIDTH);
+                header.insert(2, "Score/line");
+            }
 
             (rows, widths, header)
         } else {
@@ -221,10 +381,20 @@ fn render_table(frame: &mut Frame, area: Rect, app: &mut App) {
             let rows: Vec<Row> = prs
                 .iter()
                 .enumerate()
+                .skip(offset)
+                .take(window)
                 .map(|(idx, (pr, score_result))| {
-                    let index = format!("{}.", idx + 1);
-                    let score_str = format_score(score_result.score, score_result.incomplete);
-                    let bar_line = score_bar(score_result.score, max_score, 8, &app.theme_colors);
+                    let index = format!("{}.", index_label(app.config.index_style, idx, &pr.url));
+                    let score_str = format_score(
+                        display_score(app, score_result.score, max_score),
+                        score_result.incomplete,
+                    );
+                    let bar_line = score_bar(
+                        score_result.score,
+                        max_score,
+                        &app.config.score_bar,
+                        &app.theme_colors,
+                    );
 
                     // Build score cell with colored text and bar
                     let score_color = app.theme_colors.score_color(score_result.score, max_score);
@@ -235,7 +405,7 @@ fn render_table(frame: &mut Frame, area: Rect, app: &mut App) {
                     score_spans.extend(bar_line.spans);
                     let score_line = Line::from(score_spans);
 
-                    let title = pr.title.clone();
+                    let title = title_line(pr, score_result, app);
 
                     // Alternating row background (odd rows get subtle background)
                     let row_style = if idx % 2 == 1 {
@@ -243,25 +413,39 @@ fn render_table(frame: &mut Frame, area: Rect, app: &mut App) {
                     } else {
                         Style::default()
                     };
+                    let row_style = marked_style(row_style, app.mark_state.is_marked(pr), app);
 
-                    Row::new(vec![
+                    let mut cells = vec![
                         Cell::from(index).style(Style::default().fg(app.theme_colors.index_color)),
                         Cell::from(score_line),
                         Cell::from(title),
-                        Cell::from(pr.short_ref()),
-                    ])
-                    .style(row_style)
+                    ];
+                    if app.show_author {
+                        cells.push(author_cell(pr, app));
+                    }
+                    cells.push(Cell::from(pr.short_ref()));
+                    if app.sort_by_efficiency {
+                        cells.insert(2, efficiency_cell(pr, score_result, app));
+                    }
+                    Row::new(cells).style(row_style)
                 })
                 .collect();
 
-            let widths = vec![
-                Constraint::Length(4),  // Index: "99."
-                Constraint::Length(16), // Score + bar: "12.3k ████░░░░"
-                Constraint::Fill(1),    // Title
-                Constraint::Length(40), // PR: "owner/repo-name#12345"
+            let mut widths = vec![
+                Constraint::Length(index_column_width), // Index: "99." plus a space
+                Constraint::Length(score_width),        // Score + bar: "12.3k ████░░░░"
+                Constraint::Fill(1),                    // Title
+                Constraint::Length(40),                 // PR: "owner/repo-name#12345"
             ];
-
-            let header = vec!["#", "Score", "Title", "PR"];
+            let mut header = vec!["#", "Score", "Title", "PR"];
+            if app.show_author {
+                widths.insert(3, AUTHOR_WIDTH);
+                header.insert(3, "Author");
+            }
+            if app.sort_by_efficiency {
+                widths.insert(2, EFFICIENCY_WIDTH);
+                header.insert(2, "Score/line");
+            }
 
             (rows, widths, header)
         };
@@ -274,10 +458,14 @@ fn render_table(frame: &mut Frame, area: Rect, app: &mut App) {
         )
         .row_highlight_style(app.theme_colors.row_selected);
 
-    frame.render_stateful_widget(table, area, &mut app.table_state);
+    // Render against the windowed rows, then remember the scroll offset so the
+    // viewport only moves when the selection leaves it (as with a full table)
+    let mut window_state = TableState::default()
+        .with_selected(app.table_state.selected().map(|i| i.saturating_sub(offset)));
+    frame.render_stateful_widget(table, area, &mut window_state);
+    *app.table_state.offset_mut() = offset;
 
     // Render scrollbar if PR list exceeds visible area
-    let visible_rows = area.height.saturating_sub(2) as usize; // Subtract header and margin
     if pr_count > visible_rows {
         let scrollbar = Scrollbar::new(ScrollbarOrientation::VerticalRight)
             .thumb_style(Style::default().fg(app.theme_colors.scrollbar_thumb))
@@ -312,9 +500,11 @@ fn render_status_bar(frame: &mut Frame, area: Rect, app: &App) {
         let prs = app.current_prs();
         let count = format!("{} PRs", prs.len());
 
-        let view_mode = match app.current_view {
-            View::Active => "Active",
-            View::Snoozed => "Snoozed",
+        let view_mode = match (&app.current_view, &app.repo_filter) {
+            (View::Active, None) => "Active".to_string(),
+            (View::Snoozed, None) => "Snoozed".to_string(),
+            (View::Active, Some(repo)) => format!("Active in {} (Esc: all)", repo),
+            (View::Snoozed, Some(repo)) => format!("Snoozed in {} (Esc: all)", repo),
         };
 
         let elapsed = app.last_refresh.elapsed();
@@ -408,9 +598,11 @@ fn format_score(score: f64, incomplete: bool) -> String {
 fn score_bar(
     score: f64,
     max_score: f64,
-    width: usize,
+    bar_config: &ScoreBarConfig,
     theme_colors: &ThemeColors,
 ) -> Line<'static> {
+    let width = bar_config.width;
+    let (filled_glyph, empty_glyph) = bar_config.style.glyphs();
     let ratio = if max_score > 0.0 {
         (score / max_score).min(1.0)
     } else {
@@ -423,15 +615,22 @@ fn score_bar(
     let bar_color = theme_colors.score_color(score, max_score);
 
     let mut spans = Vec::new();
+    // Shape encodes the level too, so it doesn't depend on telling colors apart
+    if let Some(glyph) = theme_colors.score_glyph(score, max_score) {
+        spans.push(Span::styled(
+            format!("{} ", glyph),
+            Style::default().fg(bar_color),
+        ));
+    }
     if filled > 0 {
         spans.push(Span::styled(
-            "█".repeat(filled),
+            filled_glyph.repeat(filled),
             Style::default().fg(bar_color),
         ));
     }
     if empty > 0 {
         spans.push(Span::styled(
-            "░".repeat(empty),
+            empty_glyph.repeat(empty),
             Style::default().fg(theme_colors.bar_empty),
         ));
     }
@@ -441,7 +640,8 @@ fn score_bar(
 
 /// Render the snooze duration input popup
 fn render_snooze_popup(frame: &mut Frame, app: &App) {
-    let popup_area = centered_rect_fixed(40, 7, frame.area());
+    let preset_count = SnoozePreset::ALL.len() as u16;
+    let popup_area = centered_rect_fixed(44, 8 + preset_count, frame.area());
 
     // Clear the background
     frame.render_widget(Clear, popup_area);
@@ -457,12 +657,14 @@ fn render_snooze_popup(frame: &mut Frame, app: &App) {
     // Get inner area (inside the border)
     let inner = block.inner(popup_area);
 
-    // Split inner area for input, duration preview, end time, and help text
+    // Split inner area for input, duration preview, end time, quick-picks, and help text
     let chunks = Layout::vertical([
-        Constraint::Length(1), // Input line
-        Constraint::Length(1), // Duration preview
-        Constraint::Length(1), // End time preview
-        Constraint::Length(1), // Help text (Enter/Esc)
+        Constraint::Length(1),            // Input line
+        Constraint::Length(1),            // Duration preview
+        Constraint::Length(1),            // End time preview
+        Constraint::Length(1),            // Spacer
+        Constraint::Length(preset_count), // Quick-pick list
+        Constraint::Length(1),            // Help text (Enter/Esc)
     ])
     .split(inner);
 
@@ -474,23 +676,30 @@ fn render_snooze_popup(frame: &mut Frame, app: &App) {
     let input = Paragraph::new(input_line);
     frame.render_widget(input, chunks[0]);
 
-    // Render live duration preview
-    let parse_result = if app.snooze_input.trim().is_empty() {
-        None // Not an error, just empty = indefinite
-    } else {
-        Some(humantime::parse_duration(app.snooze_input.trim()))
-    };
-
-    let preview_text = match &parse_result {
-        None => "indefinite".to_string(),
-        Some(Ok(d)) => humantime::format_duration(*d).to_string(),
-        Some(Err(_)) => "invalid duration".to_string(),
-    };
-
-    let preview_color = match &parse_result {
-        None => app.theme_colors.muted,
-        Some(Ok(_)) => Color::Green,
-        Some(Err(_)) => Color::Red,
+    // Render live duration preview. A highlighted quick-pick takes precedence
+    // (typing clears it); otherwise the typed duration is parsed.
+    let now = Local::now();
+    let selected_preset = app.selected_snooze_preset();
+    let (preview_text, preview_color, end) = match selected_preset {
+        Some(preset) => (
+            preset.label().to_string(),
+            Color::Green,
+            Some(preset.until(&now).map(|until| until.with_timezone(&Local))),
+        ),
+        None if app.snooze_input.trim().is_empty() => {
+            // Not an error, just empty = indefinite
+            ("indefinite".to_string(), app.theme_colors.muted, Some(None))
+        }
+        None => match humantime::parse_duration(app.snooze_input.trim()) {
+            Ok(d) => (
+                humantime::format_duration(d).to_string(),
+                Color::Green,
+                Some(Some(
+                    now + chrono::Duration::from_std(d).unwrap_or_default(),
+                )),
+            ),
+            Err(_) => ("invalid duration".to_string(), Color::Red, None),
+        },
     };
 
     let preview = Paragraph::new(Line::from(vec![
@@ -500,11 +709,9 @@ fn render_snooze_popup(frame: &mut Frame, app: &App) {
     frame.render_widget(preview, chunks[1]);
 
     // Render end time preview
-    let end_time_text = match &parse_result {
-        None => "Ends: never".to_string(),
-        Some(Ok(d)) => {
-            let now = Local::now();
-            let end = now + chrono::Duration::from_std(*d).unwrap_or_default();
+    let end_time_text = match end {
+        Some(None) => "Ends: never".to_string(),
+        Some(Some(end)) => {
             let days_away = (end.date_naive() - now.date_naive()).num_days();
             let time = end.format("%H:%M");
             // Days until end of current ISO week (Mon=1..Sun=7)
@@ -525,15 +732,303 @@ fn render_snooze_popup(frame: &mut Frame, app: &App) {
             };
             format!("Ends: {} {}", date_part, time)
         }
-        Some(Err(_)) => String::new(),
+        None => String::new(),
     };
     let end_time = Paragraph::new(end_time_text).style(Style::default().fg(app.theme_colors.muted));
     frame.render_widget(end_time, chunks[2]);
 
+    // Render quick-pick list with the highlighted entry marked
+    let preset_lines: Vec<Line> = SnoozePreset::ALL
+        .iter()
+        .map(|preset| {
+            if selected_preset == Some(*preset) {
+                Line::from(Span::styled(
+                    format!("> {}", preset.label()),
+                    Style::default()
+                        .fg(app.theme_colors.popup_border)
+                        .add_modifier(Modifier::BOLD),
+                ))
+            } else {
+                Line::from(Span::styled(
+                    format!("  {}", preset.label()),
+                    Style::default().fg(app.theme_colors.muted),
+                ))
+            }
+        })
+        .collect();
+    frame.render_widget(Paragraph::new(preset_lines), chunks[4]);
+
     // Render help text
-    let help = Paragraph::new("Enter: confirm | Esc: cancel")
+    let help = Paragraph::new("↑/↓: pick | Enter: confirm | Esc: cancel")
+        .style(Style::default().fg(app.theme_colors.muted));
+    frame.render_widget(help, chunks[5]);
+}
+
+fn render_repo_picker_popup(frame: &mut Frame, app: &App) {
+    let choices = app.repo_choices();
+    let popup_area = centered_rect_fixed(50, choices.len() as u16 + 4, frame.area());
+
+    // Clear the background
+    frame.render_widget(Clear, popup_area);
+
+    let block = Block::bordered()
+        .title("Jump to Repo")
+        .border_style(Style::default().fg(app.theme_colors.popup_border))
+        .title_style(app.theme_colors.popup_title)
+        .style(Style::default().bg(app.theme_colors.popup_bg));
+    frame.render_widget(block.clone(), popup_area);
+
+    let inner = block.inner(popup_area);
+    let chunks = Layout::vertical([
+        Constraint::Min(1),    // Repo list
+        Constraint::Length(1), // Spacer
+        Constraint::Length(1), // Help text
+    ])
+    .split(inner);
+
+    // Keep the highlighted repo visible when the list is taller than the popup
+    let visible = chunks[0].height as usize;
+    let skip = (app.repo_picker_index + 1).saturating_sub(visible);
+    let repo_lines: Vec<Line> = choices
+        .iter()
+        .enumerate()
+        .skip(skip)
+        .map(|(idx, (repo, count))| {
+            let text = format!("{} ({})", repo, count);
+            if idx == app.repo_picker_index {
+                Line::from(Span::styled(
+                    format!("> {}", text),
+                    Style::default()
+                        .fg(app.theme_colors.popup_border)
+                        .add_modifier(Modifier::BOLD),
+                ))
+            } else {
+                Line::from(Span::styled(
+                    format!("  {}", text),
+                    Style::default().fg(app.theme_colors.muted),
+                ))
+            }
+        })
+        .collect();
+    frame.render_widget(Paragraph::new(repo_lines), chunks[0]);
+
+    let help = Paragraph::new("j/k: pick | Enter: filter | Esc: cancel")
+        .style(Style::default().fg(app.theme_colors.muted));
+    frame.render_widget(help, chunks[2]);
+}
+
+/// Most palette matches shown at once; the list scrolls past this
+const PALETTE_ROWS: usize = 12;
+
+fn render_command_palette(frame: &mut Frame, app: &App) {
+    let matches = app.palette_matches();
+    let rows = matches.len().clamp(1, PALETTE_ROWS);
+    let popup_area = centered_rect_fixed(50, rows as u16 + 6, frame.area());
+
+    // Clear the background
+    frame.render_widget(Clear, popup_area);
+
+    let block = Block::bordered()
+        .title("Commands")
+        .border_style(Style::default().fg(app.theme_colors.popup_border))
+        .title_style(app.theme_colors.popup_title)
+        .style(Style::default().bg(app.theme_colors.popup_bg));
+    frame.render_widget(block.clone(), popup_area);
+
+    let inner = block.inner(popup_area);
+    let chunks = Layout::vertical([
+        Constraint::Length(1), // Search input
+        Constraint::Length(1), // Spacer
+        Constraint::Min(1),    // Matches
+        Constraint::Length(1), // Spacer
+        Constraint::Length(1), // Help text
+    ])
+    .split(inner);
+
+    // Search input with cursor, like the snooze popup
+    let input_line = Line::from(vec![
+        Span::raw(format!(": {}", app.palette_query)),
+        Span::styled("|", Style::default().fg(Color::Cyan)),
+    ]);
+    frame.render_widget(Paragraph::new(input_line), chunks[0]);
+
+    // Key hints right-aligned after the name
+    let name_width = (inner.width as usize).saturating_sub(8);
+    let visible = chunks[2].height as usize;
+    let skip = (app.palette_index + 1).saturating_sub(visible);
+    let lines: Vec<Line> = if matches.is_empty() {
+        vec![Line::from(Span::styled(
+            "  No matching commands",
+            Style::default().fg(app.theme_colors.muted),
+        ))]
+    } else {
+        matches
+            .iter()
+            .enumerate()
+            .skip(skip)
+            .map(|(idx, command)| {
+                let (marker, style) = if idx == app.palette_index {
+                    (
+                        "> ",
+                        Style::default()
+                            .fg(app.theme_colors.popup_border)
+                            .add_modifier(Modifier::BOLD),
+                    )
+                } else {
+                    ("  ", Style::default())
+                };
+                Line::from(vec![
+                    Span::styled(
+                        format!("{}{:<width$}", marker, command.name, width = name_width),
+                        style,
+                    ),
+                    Span::styled(
+                        command.key_label,
+                        Style::default().fg(app.theme_colors.status_key_color),
+                    ),
+                ])
+            })
+            .collect()
+    };
+    frame.render_widget(Paragraph::new(lines), chunks[2]);
+
+    let help = Paragraph::new("Up/Down: pick | Enter: run | Esc: cancel")
+        .style(Style::default().fg(app.theme_colors.muted));
+    frame.render_widget(help, chunks[4]);
+}
+
+/// Render the changed files popup for the PR it was opened on
+fn render_changed_files_popup(frame: &mut Frame, app: &App) {
+    let Some(state) = app.popup_files() else {
+        return;
+    };
+    let file_count = match state {
+        ChangedFilesState::Loaded(files) => files.len().max(1),
+        ChangedFilesState::Failed(_) => 3, // Room for a wrapped error
+        ChangedFilesState::Loading => 1,
+    };
+    let area = frame.area();
+    let popup_area = centered_rect_fixed(
+        area.width.saturating_sub(8).min(90),
+        (file_count as u16).saturating_add(4),
+        area,
+    );
+
+    // Clear the background
+    frame.render_widget(Clear, popup_area);
+
+    let title = match state {
+        ChangedFilesState::Loaded(files) => format!("Changed Files ({})", files.len()),
+        _ => "Changed Files".to_string(),
+    };
+    let block = Block::bordered()
+        .title(title)
+        .border_style(Style::default().fg(app.theme_colors.popup_border))
+        .title_style(app.theme_colors.popup_title)
+        .style(Style::default().bg(app.theme_colors.popup_bg));
+    frame.render_widget(block.clone(), popup_area);
+
+    let inner = block.inner(popup_area);
+    let chunks = Layout::vertical([
+        Constraint::Min(1),    // File list
+        Constraint::Length(1), // Spacer
+        Constraint::Length(1), // Help text
+    ])
+    .split(inner);
+
+    let lines: Vec<Line> = match state {
+        ChangedFilesState::Loading => vec![Line::from(Span::styled(
+            format!("{} Fetching file list...", spinner_glyph(app.spinner_frame)),
+            Style::default().fg(app.theme_colors.title_color),
+        ))],
+        ChangedFilesState::Failed(error) => vec![Line::from(Span::styled(
+            format!("Failed to fetch files: {} (press f again to retry)", error),
+            Style::default().fg(app.theme_colors.flash_error),
+        ))],
+        ChangedFilesState::Loaded(files) if files.is_empty() => {
+            vec![Line::from("No changed files")]
+        }
+        ChangedFilesState::Loaded(files) => {
+            // Size the count columns to the largest counts in this PR
+            let add_width = files
+                .iter()
+                .map(|f| f.additions)
+                .max()
+                .unwrap_or(0)
+                .to_string()
+                .len()
+                + 1;
+            let del_width = files
+                .iter()
+                .map(|f| f.deletions)
+                .max()
+                .unwrap_or(0)
+                .to_string()
+                .len()
+                + 1;
+            files
+                .iter()
+                .skip(app.files_scroll)
+                .map(|file| {
+                    Line::from(vec![
+                        Span::styled(
+                            format!(
+                                "{:>width$} ",
+                                format!("+{}", file.additions),
+                                width = add_width
+                            ),
+                            Style::default().fg(app.theme_colors.flash_success),
+                        ),
+                        Span::styled(
+                            format!(
+                                "{:>width$}  ",
+                                format!("-{}", file.deletions),
+                                width = del_width
+                            ),
+                            Style::default().fg(app.theme_colors.flash_error),
+                        ),
+                        Span::raw(file.filename.clone()),
+                    ])
+                })
+                .collect()
+        }
+    };
+    let mut list = Paragraph::new(lines);
+    if matches!(state, ChangedFilesState::Failed(_)) {
+        list = list.wrap(Wrap { trim: true });
+    }
+    frame.render_widget(list, chunks[0]);
+
+    let help = Paragraph::new("j/k: scroll | Esc: close")
         .style(Style::default().fg(app.theme_colors.muted));
-    frame.render_widget(help, chunks[3]);
+    frame.render_widget(help, chunks[2]);
+}
+
+/// Compute the first visible row, scrolling only as far as needed to keep
+/// the selection on screen
+fn viewport_offset(
+    selected: Option<usize>,
+    offset: usize,
+    visible_rows: usize,
+    len: usize,
+) -> usize {
+    let visible_rows = visible_rows.max(1);
+    let max_offset = len.saturating_sub(visible_rows);
+    let offset = match selected {
+        Some(i) if i < offset => i,
+        Some(i) if i >= offset + visible_rows => i + 1 - visible_rows,
+        _ => offset,
+    };
+    offset.min(max_offset)
+}
+
+/// Dim rows the user marked as reviewed
+fn marked_style(style: Style, marked: bool, app: &App) -> Style {
+    if marked {
+        style.fg(app.theme_colors.muted).add_modifier(Modifier::DIM)
+    } else {
+        style
+    }
 }
 
 /// Create a centered rectangle with fixed width and height
@@ -556,7 +1051,7 @@ fn centered_rect_fixed(width: u16, height: u16, area: Rect) -> Rect {
 
 /// Render the help overlay popup
 fn render_help_popup(frame: &mut Frame, app: &App) {
-    let popup_area = centered_rect_fixed(50, 17, frame.area());
+    let popup_area = centered_rect_fixed(50, 30, frame.area());
 
     // Clear the background
     frame.render_widget(Clear, popup_area);
@@ -577,13 +1072,21 @@ fn render_help_popup(frame: &mut Frame, app: &App) {
         ("j / Down", "Move down"),
         ("k / Up", "Move up"),
         ("Enter / o", "Open PR in browser"),
+        ("O", "Toggle open on select"),
+        ("a", "Toggle author column"),
         ("b", "Score breakdown"),
+        ("f / F", "Changed files / open Files tab"),
+        ("R / A", "Open repo / author profile"),
         ("s", "Snooze / re-snooze PR"),
-        ("u", "Unsnooze PR"),
-        ("z", "Undo last action"),
+        ("u / i", "Unsnooze PR / snooze status"),
+        ("m", "Mark reviewed (until updated)"),
+        ("p / P", "Pin PR to top / unpin all"),
+        ("z / t", "Undo last action / focus mode"),
         ("Tab", "Toggle Active/Snoozed"),
-        ("r", "Refresh PRs (bypasses cache)"),
-        ("?", "Show/hide this help"),
+        ("S / e", "Sort by expiry / score per line"),
+        ("g / Esc", "Jump to repo / show all repos"),
+        ("r", "Refresh, no cache (Esc cancels)"),
+        ("? / :", "Show/hide this help / commands"),
         ("q / Ctrl-c", "Quit"),
     ];
 
@@ -604,6 +1107,64 @@ fn render_help_popup(frame: &mut Frame, app: &App) {
         })
         .collect();
 
+    // Score color legend: colors are relative to the top score in the current list
+    help_lines.push(Line::from(""));
+    help_lines.push(Line::from(Span::styled(
+        "Score colors (relative to top score in list)",
+        Style::default().fg(app.theme_colors.muted),
+    )));
+    let legend_entries = [
+        (
+            ScoreLevel::High,
+            "high",
+            format!(">= {:.0}% of top", SCORE_HIGH_THRESHOLD),
+        ),
+        (
+            ScoreLevel::Mid,
+            "mid",
+            format!(
+                "{:.0}-{:.0}% of top",
+                SCORE_MID_THRESHOLD, SCORE_HIGH_THRESHOLD
+            ),
+        ),
+        (
+            ScoreLevel::Low,
+            "low",
+            format!("< {:.0}% of top", SCORE_MID_THRESHOLD),
+        ),
+    ];
+    for (level, name, range) in legend_entries {
+        let color = app.theme_colors.level_color(level);
+        let swatch = if app.theme_colors.score_glyphs {
+            format!("█{} ", level.glyph())
+        } else {
+            "██ ".to_string()
+        };
+        help_lines.push(Line::from(vec![
+            Span::styled(swatch, Style::default().fg(color)),
+            Span::styled(
+                format!("{:<width$}", name, width = max_key_width + 1),
+                Style::default().fg(color).bold(),
+            ),
+            Span::raw(range),
+        ]));
+    }
+
+    help_lines.push(Line::from(vec![
+        Span::styled(
+            SLA_MARKER,
+            Style::default().fg(app.theme_colors.flash_error).bold(),
+        ),
+        Span::raw(" Over the review SLA (scoring.sla)"),
+    ]));
+    help_lines.push(Line::from(vec![
+        Span::styled(
+            PIN_MARKER,
+            Style::default().fg(app.theme_colors.title_color).bold(),
+        ),
+        Span::raw(" Pinned"),
+    ]));
+
     help_lines.push(Line::from(""));
     help_lines.push(Line::from(Span::styled(
         "Press any key to close",
@@ -614,9 +1175,39 @@ fn render_help_popup(frame: &mut Frame, app: &App) {
     frame.render_widget(help_text, inner);
 }
 
+/// Render the quit confirmation popup
+fn render_confirm_quit_popup(frame: &mut Frame, app: &App) {
+    let popup_area = centered_rect_fixed(40, 4, frame.area());
+
+    // Clear the background
+    frame.render_widget(Clear, popup_area);
+
+    let block = Block::bordered()
+        .title("Quit?")
+        .border_style(Style::default().fg(app.theme_colors.popup_border))
+        .title_style(app.theme_colors.popup_title)
+        .style(Style::default().bg(app.theme_colors.popup_bg));
+    frame.render_widget(block.clone(), popup_area);
+
+    let lines = vec![
+        Line::from("A refresh is still running."),
+        Line::from(Span::styled(
+            "y: quit anyway | any other key: stay",
+            Style::default().fg(app.theme_colors.muted),
+        )),
+    ];
+    frame.render_widget(Paragraph::new(lines), block.inner(popup_area));
+}
+
+/// Braille spinner frame for loading indicators
+fn spinner_glyph(frame: usize) -> &'static str {
+    const SPINNER_CHARS: [&str; 10] = ["⠋", "⠙", "⠹", "⠸", "⠼", "⠴", "⠦", "⠧", "⠇", "⠏"];
+    SPINNER_CHARS[frame % SPINNER_CHARS.len()]
+}
+
 /// Render the loading spinner overlay
 fn render_loading_overlay(frame: &mut Frame, app: &App) {
-    let popup_area = centered_rect_fixed(30, 3, frame.area());
+    let popup_area = centered_rect_fixed(30, 4, frame.area());
 
     // Clear the background
     frame.render_widget(Clear, popup_area);
@@ -630,9 +1221,7 @@ fn render_loading_overlay(frame: &mut Frame, app: &App) {
     // Get inner area (inside the border)
     let inner = block.inner(popup_area);
 
-    // Braille spinner animation
-    let spinner_chars = ["⠋", "⠙", "⠹", "⠸", "⠼", "⠴", "⠦", "⠧", "⠇", "⠏"];
-    let spinner = spinner_chars[app.spinner_frame % 10];
+    let spinner = spinner_glyph(app.spinner_frame);
 
     // Display different text based on whether this is initial load or refresh
     let text = if app.active_prs.is_empty() && app.snoozed_prs.is_empty() {
@@ -641,9 +1230,12 @@ fn render_loading_overlay(frame: &mut Frame, app: &App) {
         format!("{} Refreshing...", spinner)
     };
 
-    let loading_text = Paragraph::new(text)
-        .alignment(Alignment::Center)
-        .style(Style::default().fg(app.theme_colors.title_color));
+    let loading_text = Paragraph::new(vec![
+        Line::from(text),
+        Line::styled("Esc: cancel", Style::default().fg(app.theme_colors.muted)),
+    ])
+    .alignment(Alignment::Center)
+    .style(Style::default().fg(app.theme_colors.title_color));
 
     frame.render_widget(loading_text, inner);
 }
@@ -658,11 +1250,12 @@ fn render_score_breakdown_popup(frame: &mut Frame, app: &App) {
 
     let breakdown = &score_result.breakdown;
 
-    // Calculate dynamic height: 4 header lines + 2 lines per factor + 3 footer lines
+    // Calculate dynamic height: 7 header lines + 2 lines per factor + 1 per
+    // disabled factor + 3 footer lines
     // Each factor uses 2 lines: values + indented description
     let num_factors = breakdown.factors.len();
     let factor_lines = if num_factors == 0 { 2 } else { num_factors * 2 };
-    let content_height = 4 + factor_lines + 3;
+    let content_height = 7 + factor_lines + breakdown.disabled.len() + 3;
     let popup_height = (content_height as u16).min(frame.area().height.saturating_sub(2));
 
     let popup_area = centered_rect_fixed(57, popup_height + 2, frame.area());
@@ -703,10 +1296,39 @@ fn render_score_breakdown_popup(frame: &mut Frame, app: &App) {
     };
     lines.push(Line::from(title));
 
-    // Line 3: Empty separator
+    // Line 3: Review counts in muted text
+    lines.push(Line::from(Span::styled(
+        format!(
+            "{} approvals, {} changes requested",
+            pr.approvals, pr.changes_requested_count
+        ),
+        Style::default().fg(app.theme_colors.muted),
+    )));
+
+    // Lines 4-5: Created and updated times in the configured zone
+    let timezone = app.config.display_timezone();
+    let now = chrono::Utc::now();
+    lines.push(Line::from(Span::styled(
+        format!(
+            "Created {} ({})",
+            timezone.format(pr.created_at),
+            crate::output::format_ago(now - pr.created_at)
+        ),
+        Style::default().fg(app.theme_colors.muted),
+    )));
+    lines.push(Line::from(Span::styled(
+        format!(
+            "Updated {} ({})",
+            timezone.format(pr.updated_at),
+            crate::output::format_ago(now - pr.updated_at)
+        ),
+        Style::default().fg(app.theme_colors.muted),
+    )));
+
+    // Line 6: Empty separator
     lines.push(Line::from(""));
 
-    // Line 4: Base score
+    // Line 7: Base score
     lines.push(Line::from(vec![
         Span::raw("Base score:  "),
         Span::styled(
@@ -715,7 +1337,7 @@ fn render_score_breakdown_popup(frame: &mut Frame, app: &App) {
         ),
     ]));
 
-    // Lines 5+: Factor contributions
+    // Lines 8+: Factor contributions
     if breakdown.factors.is_empty() {
         lines.push(Line::from(""));
         lines.push(Line::from(Span::styled(
@@ -758,6 +1380,14 @@ fn render_score_breakdown_popup(frame: &mut Frame, app: &App) {
         }
     }
 
+    // Disabled factors, grayed out: configured but skipped
+    for name in &breakdown.disabled {
+        lines.push(Line::from(Span::styled(
+            format!("{}: disabled", name),
+            Style::default().fg(app.theme_colors.muted).italic(),
+        )));
+    }
+
     // Line N-2: Empty separator
     lines.push(Line::from(""));
 
@@ -768,11 +1398,16 @@ fn render_score_breakdown_popup(frame: &mut Frame, app: &App) {
         .map(|(_, sr)| sr.score)
         .fold(0.0_f64, f64::max);
     let score_color = app.theme_colors.score_color(score_result.score, max_score);
+    let glyph = app
+        .theme_colors
+        .score_glyph(score_result.score, max_score)
+        .map(|g| format!(" {}", g))
+        .unwrap_or_default();
 
     lines.push(Line::from(vec![
         Span::raw("Final score: "),
         Span::styled(
-            format!("{:.1}", score_result.score),
+            format!("{:.1}{}", score_result.score, glyph),
             Style::default().fg(score_color).bold(),
         ),
     ]));
@@ -786,3 +1421,42 @@ fn render_score_breakdown_popup(frame: &mut Frame, app: &App) {
     let paragraph = Paragraph::new(lines);
     frame.render_widget(paragraph, inner);
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_viewport_offset_scrolls_only_when_selection_leaves_view() {
+        // Selection inside the viewport keeps the current offset
+        assert_eq!(viewport_offset(Some(12), 10, 20, 5000), 10);
+        // Moving below the viewport scrolls just enough to show it at the bottom
+        assert_eq!(viewport_offset(Some(30), 10, 20, 5000), 11);
+        // Moving above the viewport puts it at the top
+        assert_eq!(viewport_offset(Some(3), 10, 20, 5000), 3);
+        // Wrapping to the last row never scrolls past the end of the list
+        assert_eq!(viewport_offset(Some(4999), 0, 20, 5000), 4980);
+        // Short lists never scroll
+        assert_eq!(viewport_offset(Some(4), 2, 20, 5), 0);
+    }
+
+    #[test]
+    fn test_colorblind_score_bar_marks_level_with_glyph() {
+        let bar_config = ScoreBarConfig::default();
+        let text = |colors: &ThemeColors, score: f64| {
+            score_bar(score, 100.0, &bar_config, colors)
+                .spans
+                .iter()
+                .map(|span| span.content.to_string())
+                .collect::<String>()
+        };
+
+        let colorblind = ThemeColors::new(crate::tui::theme::Theme::Dark, true);
+        assert!(text(&colorblind, 90.0).starts_with("▲ "));
+        assert!(text(&colorblind, 50.0).starts_with("▶ "));
+        assert!(text(&colorblind, 10.0).starts_with("▼ "));
+
+        let default = ThemeColors::new(crate::tui::theme::Theme::Dark, false);
+        assert!(!text(&default, 90.0).contains('▲'));
+    }
+}
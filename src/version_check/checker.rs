@@ -1,24 +1,61 @@
 use anyhow::{Context, Result};
+use std::time::Duration;
+
+/// GitHub repository (owner/name) whose releases are checked for updates.
+/// Forks can point this at their own repository.
+pub const RELEASES_REPO: &str = "toniperic/pr-bro";
+
+/// Per-request timeout for the releases API call
+const REQUEST_TIMEOUT: Duration = Duration::from_secs(5);
+
+/// Total attempts (first try + retries) for the releases API call
+const MAX_ATTEMPTS: u32 = 2;
 
 /// Fetch the latest version from GitHub Releases API
-pub async fn fetch_latest_version(token: &str) -> Result<String> {
-    let client = reqwest::Client::new();
+///
+/// Returns `Ok(None)` when the API responded but no non-draft release exists.
+/// Transient failures are retried once after a short delay.
+pub async fn fetch_latest_version(token: &str) -> Result<Option<String>> {
+    let client = reqwest::Client::builder()
+        .timeout(REQUEST_TIMEOUT)
+        .build()
+        .context("Failed to build HTTP client")?;
+
+    let mut attempt = 0;
+    loop {
+        attempt += 1;
+        match fetch_releases(&client, token).await {
+            Ok(releases) => return Ok(latest_release_version(&releases)),
+            Err(e) if attempt >= MAX_ATTEMPTS => return Err(e),
+            Err(_) => tokio::time::sleep(Duration::from_millis(500)).await,
+        }
+    }
+}
 
+/// Perform a single request against the releases API
+async fn fetch_releases(client: &reqwest::Client, token: &str) -> Result<Vec<serde_json::Value>> {
     let response = client
-        .get("https://api.github.com/repos/toniperic/pr-bro/releases")
+        .get(format!(
+            "https://api.github.com/repos/{}/releases",
+            RELEASES_REPO
+        ))
         .header("Authorization", format!("Bearer {}", token))
         .header("Accept", "application/vnd.github+json")
         .header("User-Agent", "pr-bro")
         .send()
         .await
-        .context("Failed to fetch releases from GitHub")?;
+        .context("Failed to fetch releases from GitHub")?
+        .error_for_status()
+        .context("GitHub releases API returned an error")?;
 
-    let releases: Vec<serde_json::Value> = response
+    response
         .json()
         .await
-        .context("Failed to parse releases JSON")?;
+        .context("Failed to parse releases JSON")
+}
 
-    // Find first non-draft release
+/// Find the version of the first non-draft release (leading 'v' stripped)
+fn latest_release_version(releases: &[serde_json::Value]) -> Option<String> {
     for release in releases {
         if let Some(draft) = release.get("draft").and_then(|v| v.as_bool()) {
             if draft {
@@ -29,11 +66,11 @@ pub async fn fetch_latest_version(token: &str) -> Result<String> {
         if let Some(tag_name) = release.get("tag_name").and_then(|v| v.as_str()) {
             // Strip leading 'v' if present
             let version = tag_name.strip_prefix('v').unwrap_or(tag_name);
-            return Ok(version.to_string());
+            return Some(version.to_string());
         }
     }
 
-    anyhow::bail!("No non-draft releases found")
+    None
 }
 
 /// Check if latest version is newer than current version using semver comparison
@@ -78,6 +115,20 @@ mod tests {
         assert!(!is_newer("invalid", "also-invalid"));
     }
 
+    #[test]
+    fn test_latest_release_skips_drafts() {
+        let releases = vec![
+            serde_json::json!({"tag_name": "v0.3.0", "draft": true}),
+            serde_json::json!({"tag_name": "v0.2.0", "draft": false}),
+        ];
+        assert_eq!(latest_release_version(&releases), Some("0.2.0".to_string()));
+    }
+
+    #[test]
+    fn test_latest_release_none_when_empty() {
+        assert_eq!(latest_release_version(&[]), None);
+    }
+
     #[test]
     fn test_is_newer_prerelease_ordering() {
         // Pre-releases should be ordered correctly
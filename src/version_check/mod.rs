@@ -17,7 +17,8 @@ pub enum VersionStatus {
 /// Check for available updates
 ///
 /// This function checks the GitHub Releases API for newer versions.
-/// Results are cached for 24 hours. Dismissed versions are not shown.
+/// Requests time out after a few seconds and are retried once.
+/// Results are cached for 24 hours, including responses with no releases. Dismissed versions are not shown.
 /// All errors fail silently and return Unknown.
 pub async fn check_version(token: &str, current_version: &str) -> VersionStatus {
     let cache_path = crate::github::cache::get_cache_path();
@@ -46,7 +47,10 @@ pub async fn check_version(token: &str, current_version: &str) -> VersionStatus
 
     // No fresh cache, fetch from API
     let latest_version = match checker::fetch_latest_version(token).await {
-        Ok(version) => version,
+        Ok(Some(version)) => version,
+        // API responded but has no releases yet: cache the current version so
+        // we don't re-query on every launch for the next 24h
+        Ok(None) => current_version.to_string(),
         Err(_) => return VersionStatus::Unknown, // Fail silently
     };
 
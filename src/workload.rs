@@ -0,0 +1,76 @@
+use crate::github::types::PullRequest;
+use serde::Serialize;
+use std::collections::HashMap;
+
+/// Outstanding review requests for one reviewer
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct ReviewerLoad {
+    pub reviewer: String,
+    pub outstanding: usize,
+}
+
+/// Count how many PRs each requested reviewer still has pending.
+///
+/// Sorted by count descending, then reviewer name. PRs whose reviewer data
+/// couldn't be fetched are skipped; the second value counts them.
+pub fn tally_reviewers<'a>(
+    prs: impl IntoIterator<Item = &'a PullRequest>,
+) -> (Vec<ReviewerLoad>, usize) {
+    let mut counts: HashMap<&str, usize> = HashMap::new();
+    let mut unknown = 0;
+    for pr in prs {
+        match &pr.requested_reviewers {
+            Some(reviewers) => {
+                for reviewer in reviewers {
+                    *counts.entry(reviewer.as_str()).or_default() += 1;
+                }
+            }
+            None => unknown += 1,
+        }
+    }
+
+    let mut loads: Vec<ReviewerLoad> = counts
+        .into_iter()
+        .map(|(reviewer, outstanding)| ReviewerLoad {
+            reviewer: reviewer.to_string(),
+            outstanding,
+        })
+        .collect();
+    loads.sort_by(|a, b| {
+        b.outstanding
+            .cmp(&a.outstanding)
+            .then_with(|| a.reviewer.cmp(&b.reviewer))
+    });
+    (loads, unknown)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn pr_requesting(reviewers: Option<&[&str]>) -> PullRequest {
+        PullRequest {
+            requested_reviewers: reviewers.map(|r| r.iter().map(|s| s.to_string()).collect()),
+            ..PullRequest::test_default()
+        }
+    }
+
+    #[test]
+    fn test_tally_sorts_by_count_then_name() {
+        let prs = vec![
+            pr_requesting(Some(&["bob", "alice"])),
+            pr_requesting(Some(&["carol"])),
+            pr_requesting(Some(&["bob"])),
+            pr_requesting(Some(&[])),
+            pr_requesting(None),
+        ];
+        let (loads, unknown) = tally_reviewers(&prs);
+
+        let summary: Vec<(&str, usize)> = loads
+            .iter()
+            .map(|l| (l.reviewer.as_str(), l.outstanding))
+            .collect();
+        assert_eq!(summary, vec![("bob", 2), ("alice", 1), ("carol", 1)]);
+        assert_eq!(unknown, 1);
+    }
+}